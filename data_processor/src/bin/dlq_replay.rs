@@ -0,0 +1,66 @@
+//! Republish messages parked in the dead-letter topic to the topics they failed on.
+//!
+//! Usage: dlq_replay [--topic <original topic>] [--limit <n>] [--dry-run]
+//!
+//! Reads the same environment as `data_processor` (`KAFKA_BROKERS`, `KAFKA_GROUP_ID`,
+//! `KAFKA_DLQ_TOPIC`) and exits once the DLQ has no more messages.
+use data_processor::config::AppConfig;
+use data_processor::dlq::{ReplayOptions, replay_dlq};
+use log::{error, info};
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: dlq_replay [--topic <original topic>] [--limit <n>] [--dry-run]";
+
+fn parse_args() -> Result<ReplayOptions, String> {
+    let mut options = ReplayOptions::default();
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--topic" => {
+                options.topic = Some(args.next().ok_or("--topic needs a value")?);
+            }
+            "--limit" => {
+                let limit = args.next().ok_or("--limit needs a value")?;
+                options.limit = Some(
+                    limit
+                        .parse()
+                        .map_err(|e| format!("Invalid --limit {limit}: {e}"))?,
+                );
+            }
+            "--dry-run" => options.dry_run = true,
+            other => return Err(format!("Unknown argument {other}")),
+        }
+    }
+
+    Ok(options)
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    env_logger::init();
+
+    let options = match parse_args() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let config = AppConfig::from_env();
+
+    match replay_dlq(&config, &options).await {
+        Ok(replayed) => {
+            info!(
+                "Replayed {replayed} messages from {}{}",
+                config.kafka_dlq_topic,
+                if options.dry_run { " (dry run)" } else { "" }
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            error!("DLQ replay failed: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
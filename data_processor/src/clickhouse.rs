@@ -1,7 +1,18 @@
 use crate::config::AppConfig;
-use anyhow::Result;
-use clickhouse::Client;
-use log::info;
+use crate::models::pump_models::pf_models::pf_ch_dto::{
+    PfChCreatorFeeDto, PfChCurveStateDto, PfChTokenIncentiveDto, PfChTradeDto,
+};
+use crate::models::pump_models::ps_models::ps_ch_dto::{PsChPoolPriceDto, PsChTradeDto};
+use crate::models::raydium_models::rll_models::rll_ch_dto::RllChPoolPriceDto;
+use crate::models::token_lifecycle::ChTokenLifecycleDto;
+use anyhow::{Result, anyhow};
+use clickhouse::inserter::Inserter;
+use clickhouse::{Client, Row};
+use log::{debug, error, info};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
 
 pub fn init_clickhouse_client(config: &AppConfig) -> Result<Client> {
     let clickhouse_url = config.clickhouse_url.clone();
@@ -15,3 +26,202 @@ pub fn init_clickhouse_client(config: &AppConfig) -> Result<Client> {
 
     Ok(client)
 }
+
+/// Kafka position: (topic, partition) -> offset
+pub type Offsets = HashMap<(String, i32), i64>;
+
+/// A row type with its own table in the batch
+pub trait ChTable: Row + Serialize {
+    fn inserter(inserters: &mut ChInserters) -> &mut Inserter<Self>;
+}
+
+/// One open `INSERT` per table; rows stream to ClickHouse as they are written
+/// and become visible once the batch is flushed.
+pub struct ChInserters {
+    pf_ch_trades: Inserter<PfChTradeDto>,
+    pf_creator_fee_ledger: Inserter<PfChCreatorFeeDto>,
+    pf_token_incentive_ledger: Inserter<PfChTokenIncentiveDto>,
+    pf_curve_states: Inserter<PfChCurveStateDto>,
+    token_lifecycle_ledger: Inserter<ChTokenLifecycleDto>,
+    ps_trades: Inserter<PsChTradeDto>,
+    ps_pool_prices: Inserter<PsChPoolPriceDto>,
+    rll_pool_prices: Inserter<RllChPoolPriceDto>,
+}
+
+impl ChInserters {
+    fn new(client: &Client) -> Result<Self> {
+        Ok(Self {
+            pf_ch_trades: client.inserter("pf_ch_trades")?,
+            pf_creator_fee_ledger: client.inserter("pf_creator_fee_ledger")?,
+            pf_token_incentive_ledger: client.inserter("pf_token_incentive_ledger")?,
+            pf_curve_states: client.inserter("pf_curve_states")?,
+            token_lifecycle_ledger: client.inserter("token_lifecycle_ledger")?,
+            ps_trades: client.inserter("ps_trades")?,
+            ps_pool_prices: client.inserter("ps_pool_prices")?,
+            rll_pool_prices: client.inserter("rll_pool_prices")?,
+        })
+    }
+
+    /// End every open `INSERT`, carrying on past failures so no table keeps rows
+    /// of a batch that is about to be replayed. Tables without rows are no-ops.
+    async fn commit_all(&mut self) -> Result<u64> {
+        let results = [
+            ("pf_ch_trades", self.pf_ch_trades.force_commit().await),
+            (
+                "pf_creator_fee_ledger",
+                self.pf_creator_fee_ledger.force_commit().await,
+            ),
+            (
+                "pf_token_incentive_ledger",
+                self.pf_token_incentive_ledger.force_commit().await,
+            ),
+            ("pf_curve_states", self.pf_curve_states.force_commit().await),
+            (
+                "token_lifecycle_ledger",
+                self.token_lifecycle_ledger.force_commit().await,
+            ),
+            ("ps_trades", self.ps_trades.force_commit().await),
+            ("ps_pool_prices", self.ps_pool_prices.force_commit().await),
+            ("rll_pool_prices", self.rll_pool_prices.force_commit().await),
+        ];
+
+        let mut rows = 0;
+        let mut failed = Vec::new();
+        for (table, result) in results {
+            match result {
+                Ok(quantities) => rows += quantities.rows,
+                Err(e) => failed.push(format!("{table}: {e}")),
+            }
+        }
+
+        if !failed.is_empty() {
+            return Err(anyhow!("Failed to commit {}", failed.join("; ")));
+        }
+
+        Ok(rows)
+    }
+}
+
+macro_rules! ch_table {
+    ($row:ty, $field:ident) => {
+        impl ChTable for $row {
+            fn inserter(inserters: &mut ChInserters) -> &mut Inserter<Self> {
+                &mut inserters.$field
+            }
+        }
+    };
+}
+
+ch_table!(PfChTradeDto, pf_ch_trades);
+ch_table!(PfChCreatorFeeDto, pf_creator_fee_ledger);
+ch_table!(PfChTokenIncentiveDto, pf_token_incentive_ledger);
+ch_table!(PfChCurveStateDto, pf_curve_states);
+ch_table!(ChTokenLifecycleDto, token_lifecycle_ledger);
+ch_table!(PsChTradeDto, ps_trades);
+ch_table!(PsChPoolPriceDto, ps_pool_prices);
+ch_table!(RllChPoolPriceDto, rll_pool_prices);
+
+struct ChBatch {
+    inserters: ChInserters,
+    rows: u64,
+    /// When the first row or offset of the batch was recorded
+    started: Option<Instant>,
+    /// First offset per partition whose rows are not flushed yet
+    unflushed: Offsets,
+    /// Next offset to consume per partition, once the batch is flushed
+    processed: Offsets,
+}
+
+/// Why a flush failed, and where each partition must resume from.
+/// Tables are committed one by one, so some rows of the batch may already be durable;
+/// the whole batch is replayed anyway and those rows collapse on their tables' dedupe keys.
+#[derive(Debug)]
+pub struct ChFlushError {
+    pub error: anyhow::Error,
+    pub rewind: Offsets,
+}
+
+/// Batches every ClickHouse write of the consumer. A batch is flushed once it holds
+/// `max_rows` rows or its first entry is `period` old, all tables together, so a single
+/// watermark covers them: Kafka offsets are only handed out after their rows are durable.
+pub struct ChWriter {
+    client: Client,
+    max_rows: u64,
+    period: Duration,
+    batch: Mutex<ChBatch>,
+}
+
+impl ChWriter {
+    pub fn new(client: &Client, config: &AppConfig) -> Result<Self> {
+        Ok(Self {
+            client: client.clone(),
+            max_rows: config.clickhouse_batch_max_rows,
+            period: Duration::from_millis(config.clickhouse_batch_period_ms),
+            batch: Mutex::new(ChBatch {
+                inserters: ChInserters::new(client)?,
+                rows: 0,
+                started: None,
+                unflushed: Offsets::new(),
+                processed: Offsets::new(),
+            }),
+        })
+    }
+
+    pub async fn write<T: ChTable>(&self, row: &T) -> Result<()> {
+        let mut batch = self.batch.lock().await;
+
+        T::inserter(&mut batch.inserters).write(row)?;
+        batch.rows += 1;
+        batch.started.get_or_insert_with(Instant::now);
+
+        Ok(())
+    }
+
+    /// Record that every row of the message at `offset` has been written.
+    pub async fn mark_processed(&self, topic: &str, partition: i32, offset: i64) {
+        let mut batch = self.batch.lock().await;
+        let key = (topic.to_string(), partition);
+
+        batch.unflushed.entry(key.clone()).or_insert(offset);
+        batch.processed.insert(key, offset + 1);
+        // Messages without rows still wait for the period, so offsets are committed in batches
+        batch.started.get_or_insert_with(Instant::now);
+    }
+
+    /// Flush when the batch is full or old enough.
+    /// Returns the offsets that became safe to commit, if any.
+    pub async fn flush_if_due(&self) -> Result<Option<Offsets>, ChFlushError> {
+        let mut batch = self.batch.lock().await;
+
+        let full = batch.rows >= self.max_rows;
+        let expired = batch.started.is_some_and(|s| s.elapsed() >= self.period);
+        if !full && !expired {
+            return Ok(None);
+        }
+
+        let result = batch.inserters.commit_all().await;
+        batch.rows = 0;
+        batch.started = None;
+        let unflushed = std::mem::take(&mut batch.unflushed);
+        let processed = std::mem::take(&mut batch.processed);
+
+        match result {
+            Ok(rows) => {
+                debug!("Flushed {rows} ClickHouse rows");
+                Ok(Some(processed))
+            }
+            Err(error) => {
+                // Inserters keep the counters of a failed `INSERT`; the replay starts from fresh ones
+                match ChInserters::new(&self.client) {
+                    Ok(inserters) => batch.inserters = inserters,
+                    Err(e) => error!("Failed to reset ClickHouse inserters: {e}"),
+                }
+
+                Err(ChFlushError {
+                    error,
+                    rewind: unflushed,
+                })
+            }
+        }
+    }
+}
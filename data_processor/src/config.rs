@@ -10,9 +10,15 @@ pub struct AppConfig {
     pub clickhouse_user: String,
     pub clickhouse_password: String,
     pub clickhouse_database: String,
+    pub clickhouse_batch_max_rows: u64,
+    pub clickhouse_batch_period_ms: u64,
 
     pub kafka_brokers: String,
     pub kafka_group_id: String,
+    pub kafka_dlq_topic: String,
+    pub max_handle_attempts: u32,
+    pub schema_registry_url: Option<String>,
+    pub processed_window_secs: u64,
 
     pub new_accounts_limit: usize,
     pub new_accounts_key: String,
@@ -36,10 +42,30 @@ impl AppConfig {
             env::var("CLICKHOUSE_PASSWORD").unwrap_or_else(|_| "clickhouse_password".to_string());
         let clickhouse_database =
             env::var("CLICKHOUSE_DATABASE").unwrap_or_else(|_| "events_db".to_string());
+        let clickhouse_batch_max_rows = env::var("CLICKHOUSE_BATCH_MAX_ROWS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(10_000);
+        let clickhouse_batch_period_ms = env::var("CLICKHOUSE_BATCH_PERIOD_MS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(1_000);
 
         let kafka_brokers = env::var("KAFKA_BROKERS").unwrap_or_else(|_| "kafka:9092".to_string());
         let kafka_group_id =
             env::var("KAFKA_GROUP_ID").unwrap_or_else(|_| "ch_consumer_group".to_string());
+        let kafka_dlq_topic =
+            env::var("KAFKA_DLQ_TOPIC").unwrap_or_else(|_| "data_processor_dlq".to_string());
+        let max_handle_attempts = env::var("MAX_HANDLE_ATTEMPTS")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(5)
+            .max(1);
+        let schema_registry_url = env::var("SCHEMA_REGISTRY_URL").ok();
+        let processed_window_secs = env::var("PROCESSED_WINDOW_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(86_400);
 
         let new_accounts_limit = env::var("NEW_ACCOUNTS_CACHE_LIMIT")
             .ok()
@@ -55,11 +81,17 @@ impl AppConfig {
 
             kafka_brokers,
             kafka_group_id,
+            kafka_dlq_topic,
+            max_handle_attempts,
+            schema_registry_url,
+            processed_window_secs,
 
             clickhouse_url,
             clickhouse_user,
             clickhouse_password,
             clickhouse_database,
+            clickhouse_batch_max_rows,
+            clickhouse_batch_period_ms,
 
             new_accounts_limit,
             new_accounts_key,
@@ -0,0 +1,213 @@
+use crate::config::AppConfig;
+use log::{info, warn};
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::message::{BorrowedMessage, Header, Headers, Message, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use std::time::Duration;
+use tokio::time::timeout;
+
+// Headers added to dead-lettered messages; the original headers are kept as well
+pub const DLQ_TOPIC_HEADER: &str = "dlq-original-topic";
+pub const DLQ_PARTITION_HEADER: &str = "dlq-original-partition";
+pub const DLQ_OFFSET_HEADER: &str = "dlq-original-offset";
+pub const DLQ_ERROR_HEADER: &str = "dlq-error";
+pub const DLQ_ATTEMPTS_HEADER: &str = "dlq-attempts";
+
+const SEND_TIMEOUT_SECS: u64 = 5;
+/// The replay stops once the DLQ has been quiet this long
+const REPLAY_IDLE_TIMEOUT_SECS: u64 = 10;
+
+pub fn create_producer(config: &AppConfig) -> FutureProducer {
+    ClientConfig::new()
+        .set("bootstrap.servers", &config.kafka_brokers)
+        .set("message.timeout.ms", "5000")
+        .create()
+        .expect("Failed to create Kafka producer")
+}
+
+/// Dead-letter topic for messages `data_processor` cannot process.
+/// The original payload is forwarded untouched so it can be replayed as-is.
+pub struct DeadLetterQueue {
+    producer: FutureProducer,
+    topic: String,
+}
+
+impl DeadLetterQueue {
+    pub fn new(config: &AppConfig) -> Self {
+        Self {
+            producer: create_producer(config),
+            topic: config.kafka_dlq_topic.clone(),
+        }
+    }
+
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    pub async fn send(
+        &self,
+        message: &BorrowedMessage<'_>,
+        error: &str,
+        attempts: u32,
+    ) -> Result<(), String> {
+        let partition = message.partition().to_string();
+        let offset = message.offset().to_string();
+        let attempts = attempts.to_string();
+
+        let mut headers = OwnedHeaders::new();
+        if let Some(original) = message.headers() {
+            for h in original.iter() {
+                headers = headers.insert(h);
+            }
+        }
+        let headers = headers
+            .insert(Header {
+                key: DLQ_TOPIC_HEADER,
+                value: Some(message.topic()),
+            })
+            .insert(Header {
+                key: DLQ_PARTITION_HEADER,
+                value: Some(partition.as_str()),
+            })
+            .insert(Header {
+                key: DLQ_OFFSET_HEADER,
+                value: Some(offset.as_str()),
+            })
+            .insert(Header {
+                key: DLQ_ERROR_HEADER,
+                value: Some(error),
+            })
+            .insert(Header {
+                key: DLQ_ATTEMPTS_HEADER,
+                value: Some(attempts.as_str()),
+            });
+
+        let mut record = FutureRecord::<[u8], [u8]>::to(&self.topic).headers(headers);
+        if let Some(payload) = message.payload() {
+            record = record.payload(payload);
+        }
+        if let Some(key) = message.key() {
+            record = record.key(key);
+        }
+
+        self.producer
+            .send(record, Duration::from_secs(SEND_TIMEOUT_SECS))
+            .await
+            .map_err(|(e, _)| format!("DeadLetterQueue::send: {e}"))?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ReplayOptions {
+    /// Only replay messages that failed on this topic
+    pub topic: Option<String>,
+    /// Stop after this many replayed messages
+    pub limit: Option<usize>,
+    /// Log what would be replayed without producing or committing
+    pub dry_run: bool,
+}
+
+/// Republish dead-lettered messages to the topics they failed on, so they go through
+/// the regular consumer again. Progress is committed under its own consumer group;
+/// messages skipped by `options.topic` are committed too and will not be offered again.
+pub async fn replay_dlq(config: &AppConfig, options: &ReplayOptions) -> Result<usize, String> {
+    let consumer: StreamConsumer = ClientConfig::new()
+        .set("group.id", format!("{}_dlq_replay", config.kafka_group_id))
+        .set("bootstrap.servers", &config.kafka_brokers)
+        .set("enable.auto.commit", "false")
+        .set("auto.offset.reset", "earliest")
+        .create()
+        .map_err(|e| format!("replay_dlq: {e}"))?;
+    consumer
+        .subscribe(&[&config.kafka_dlq_topic])
+        .map_err(|e| format!("replay_dlq: {e}"))?;
+    let producer = create_producer(config);
+
+    let mut replayed = 0;
+    while options.limit.is_none_or(|limit| replayed < limit) {
+        let message = match timeout(
+            Duration::from_secs(REPLAY_IDLE_TIMEOUT_SECS),
+            consumer.recv(),
+        )
+        .await
+        {
+            Ok(received) => received.map_err(|e| format!("replay_dlq: {e}"))?,
+            Err(_) => break,
+        };
+
+        let Some(topic) = header(&message, DLQ_TOPIC_HEADER) else {
+            warn!(
+                "Skipping DLQ message at offset {} without {DLQ_TOPIC_HEADER}",
+                message.offset()
+            );
+            skip(&consumer, &message, options)?;
+            continue;
+        };
+        if options.topic.as_deref().is_some_and(|t| t != topic) {
+            skip(&consumer, &message, options)?;
+            continue;
+        }
+
+        info!(
+            "Replaying {topic} message (DLQ offset {}): {}",
+            message.offset(),
+            header(&message, DLQ_ERROR_HEADER).unwrap_or_default()
+        );
+        if options.dry_run {
+            replayed += 1;
+            continue;
+        }
+
+        // Forward the original headers only
+        let mut headers = OwnedHeaders::new();
+        if let Some(original) = message.headers() {
+            for h in original.iter().filter(|h| !h.key.starts_with("dlq-")) {
+                headers = headers.insert(h);
+            }
+        }
+        let mut record = FutureRecord::<[u8], [u8]>::to(topic).headers(headers);
+        if let Some(payload) = message.payload() {
+            record = record.payload(payload);
+        }
+        if let Some(key) = message.key() {
+            record = record.key(key);
+        }
+
+        producer
+            .send(record, Duration::from_secs(SEND_TIMEOUT_SECS))
+            .await
+            .map_err(|(e, _)| format!("replay_dlq: Failed to send to {topic}: {e}"))?;
+        consumer
+            .commit_message(&message, CommitMode::Sync)
+            .map_err(|e| format!("replay_dlq: {e}"))?;
+        replayed += 1;
+    }
+
+    Ok(replayed)
+}
+
+fn skip(
+    consumer: &StreamConsumer,
+    message: &BorrowedMessage<'_>,
+    options: &ReplayOptions,
+) -> Result<(), String> {
+    if options.dry_run {
+        return Ok(());
+    }
+
+    consumer
+        .commit_message(message, CommitMode::Sync)
+        .map_err(|e| format!("replay_dlq: {e}"))
+}
+
+fn header<'a>(message: &'a BorrowedMessage<'_>, key: &str) -> Option<&'a str> {
+    message
+        .headers()?
+        .iter()
+        .find(|h| h.key == key)
+        .and_then(|h| h.value)
+        .and_then(|v| std::str::from_utf8(v).ok())
+}
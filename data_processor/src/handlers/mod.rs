@@ -1 +1,4 @@
 pub mod pump_handlers;
+pub mod raydium_handlers;
+pub mod token_fulfill_handler;
+pub mod ws_publish_handler;
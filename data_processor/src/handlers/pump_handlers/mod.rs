@@ -1 +1,2 @@
 pub mod pf_handlers;
+pub mod ps_handlers;
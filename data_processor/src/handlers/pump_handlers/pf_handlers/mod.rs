@@ -1 +1,6 @@
+pub mod pf_creator_fee_handler;
+pub mod pf_price_update_handler;
+pub mod pf_protocol_params_handler;
+pub mod pf_token_incentive_handler;
+pub mod pf_token_lifecycle_handler;
 pub mod pf_trade_occurred_handler;
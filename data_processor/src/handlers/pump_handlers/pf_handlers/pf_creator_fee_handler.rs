@@ -0,0 +1,48 @@
+use crate::models::pump_models::pf_models::pf_ch_dto::PfChCreatorFeeDto;
+use crate::models::pump_models::pf_models::pf_kafka_event::KPfCreatorFeeClaim;
+use crate::models::pump_models::pf_models::pf_pgsql_dto::{
+    PfPgsqlCreatorFeeDto, build_ps_pgsql_creator_fee_claim,
+};
+use crate::models::pump_models::pf_models::pf_redis::PfRedisCreatorFee;
+use crate::models::pump_models::ps_models::ps_kafka_event::KPsCreatorFeeClaim;
+use crate::repositories::pump_repositories::pf_ch_repositories::pf_ch_creator_fees::insert_pf_ch_creator_fee;
+use crate::repositories::pump_repositories::pf_pgsql_repositories::pf_pgsql_creator_fees::upsert_pf_pgsql_creator_fee;
+use crate::repositories::pump_repositories::pf_redis_repositories::pf_redis_creator_fees::upsert_pf_redis_creator_fee;
+use crate::state::AppState;
+use log::debug;
+
+pub async fn handle_pf_creator_fee_claimed(
+    state: &AppState,
+    claim: KPfCreatorFeeClaim,
+) -> anyhow::Result<()> {
+    handle_creator_fee(state, PfPgsqlCreatorFeeDto::from(claim)).await
+}
+
+pub async fn handle_ps_creator_fee_claimed(
+    state: &AppState,
+    claim: KPsCreatorFeeClaim,
+) -> anyhow::Result<()> {
+    match build_ps_pgsql_creator_fee_claim(claim) {
+        Ok(fee) => handle_creator_fee(state, fee).await,
+        Err(e) => {
+            debug!("Skipping PumpSwap creator fee claim: {e}");
+            Ok(())
+        }
+    }
+}
+
+/// Record one accrual or claim: Postgres balance, the Redis copy, then the ClickHouse ledger
+/// (buffered last, so a failed sink does not leave a row behind for the retry to add again)
+pub async fn handle_creator_fee(
+    state: &AppState,
+    fee_dto: PfPgsqlCreatorFeeDto,
+) -> anyhow::Result<()> {
+    let fee_ch = PfChCreatorFeeDto::from(fee_dto.clone());
+
+    let balance = upsert_pf_pgsql_creator_fee(&state.pg_pool, fee_dto).await?;
+    upsert_pf_redis_creator_fee(state, PfRedisCreatorFee::from(balance)).await?;
+
+    insert_pf_ch_creator_fee(state, &fee_ch).await?;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::handlers::pump_handlers::pf_handlers::pf_protocol_params_handler::pf_initial_real_token_reserves_at;
+use crate::handlers::ws_publish_handler::publish_pf_tick;
+use crate::models::kafka_event::EventSource;
+use crate::models::pump_models::pf_models::pf_ch_dto::build_pf_ch_curve_state;
+use crate::models::pump_models::pf_models::pf_kafka_event::KPfPrice;
+use crate::models::pump_models::pf_models::pf_pgsql_dto::{
+    build_pf_pgsql_bonding_curve_progress_from_account, build_pf_pgsql_price_from_account,
+};
+use crate::models::pump_models::pf_models::pf_redis::{PfRedisBondingCurveProgress, PfRedisPrice};
+use crate::repositories::pump_repositories::pf_ch_repositories::pf_ch_curve_states::insert_pf_ch_curve_state;
+use crate::repositories::pump_repositories::pf_pgsql_repositories::pf_pgsql_prices::{
+    get_pf_pgsql_curve_token, upsert_pf_pgsql_bonding_curve_progress, upsert_pf_pgsql_price,
+};
+use crate::repositories::pump_repositories::pf_redis_repositories::pf_redis_prices::{
+    upsert_pf_redis_bonding_curve_progress, upsert_pf_redis_price,
+};
+use crate::state::AppState;
+use log::debug;
+
+/// Record a bonding curve account update: the Postgres price and progress with their Redis
+/// copies, then the ClickHouse curve history, ticked to subscribers. The account carries no
+/// mint, so all but the history wait until a trade or the token's creation has linked the
+/// curve to its mint.
+pub async fn handle_pf_price_updated(
+    state: &AppState,
+    k_pf_price: KPfPrice,
+    slot: u64,
+    source: EventSource,
+) -> anyhow::Result<()> {
+    let curve = get_pf_pgsql_curve_token(&state.pg_pool, &k_pf_price.bonding_curve).await?;
+
+    let curve_ch =
+        build_pf_ch_curve_state(&k_pf_price, curve.as_ref().map(|c| c.mint.clone()), source);
+
+    let Some(curve) = curve else {
+        debug!(
+            "Bonding curve {} has no known mint yet, skipping price update",
+            k_pf_price.bonding_curve
+        );
+        insert_pf_ch_curve_state(state, &curve_ch).await?;
+        return Ok(());
+    };
+
+    if let Ok(p) = build_pf_pgsql_price_from_account(&k_pf_price, &curve) {
+        let pf_redis_price = PfRedisPrice::from(p.clone());
+        upsert_pf_pgsql_price(&state.pg_pool, p).await?;
+        upsert_pf_redis_price(state, pf_redis_price).await?;
+    }
+
+    let initial_real_token_reserves = pf_initial_real_token_reserves_at(state, slot).await?;
+
+    if let Ok(bcp) = build_pf_pgsql_bonding_curve_progress_from_account(
+        &k_pf_price,
+        &curve,
+        initial_real_token_reserves,
+    ) {
+        let pf_redis_bcp = PfRedisBondingCurveProgress::from(bcp.clone());
+        upsert_pf_redis_bonding_curve_progress(state, pf_redis_bcp).await?;
+        upsert_pf_pgsql_bonding_curve_progress(&state.pg_pool, bcp).await?;
+    }
+
+    insert_pf_ch_curve_state(state, &curve_ch).await?;
+
+    publish_pf_tick(state, &curve.mint, None).await;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::models::pump_models::pf_models::pf_kafka_event::{KPfFeeConfig, KPfGlobalConfig};
+use crate::models::pump_models::pf_models::pf_pgsql_dto::{
+    build_pf_pgsql_fee_config, build_pf_pgsql_global_params,
+};
+use crate::repositories::pump_repositories::pf_pgsql_repositories::pf_pgsql_protocol_params::{
+    get_pf_pgsql_global_params_at_slot, insert_pf_pgsql_fee_config, insert_pf_pgsql_global_params,
+};
+use crate::state::AppState;
+use log::{info, warn};
+
+/// Pump.fun launch default, used until a `Global` version covering the slot is recorded
+const PF_DEFAULT_INITIAL_REAL_TOKEN_RESERVES: u64 = 793_100_000_000_000;
+
+pub async fn handle_pf_global_config_updated(
+    state: &AppState,
+    global: KPfGlobalConfig,
+    slot: u64,
+) -> anyhow::Result<()> {
+    let dto = build_pf_pgsql_global_params(global, slot);
+
+    if let Some(params) = insert_pf_pgsql_global_params(&state.pg_pool, dto).await? {
+        info!(
+            "Recorded pf_global_params v{} effective at slot {}",
+            params.version, params.effective_slot
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn handle_pf_fee_config_updated(
+    state: &AppState,
+    fee_config: KPfFeeConfig,
+    slot: u64,
+) -> anyhow::Result<()> {
+    let dto = build_pf_pgsql_fee_config(fee_config, slot);
+
+    if let Some(config) = insert_pf_pgsql_fee_config(&state.pg_pool, dto).await? {
+        info!(
+            "Recorded pf_fee_configs v{} effective at slot {}",
+            config.version, config.effective_slot
+        );
+    }
+
+    Ok(())
+}
+
+/// `initial_real_token_reserves` in effect at `slot`
+pub async fn pf_initial_real_token_reserves_at(state: &AppState, slot: u64) -> anyhow::Result<u64> {
+    match get_pf_pgsql_global_params_at_slot(&state.pg_pool, slot).await? {
+        Some(params) => Ok(params.initial_real_token_reserves as u64),
+        None => {
+            warn!("No pf_global_params version at slot {slot}, using launch defaults");
+            Ok(PF_DEFAULT_INITIAL_REAL_TOKEN_RESERVES)
+        }
+    }
+}
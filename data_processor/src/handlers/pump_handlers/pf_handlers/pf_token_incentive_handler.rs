@@ -0,0 +1,64 @@
+use crate::models::pump_models::pf_models::pf_ch_dto::PfChTokenIncentiveDto;
+use crate::models::pump_models::pf_models::pf_kafka_event::{
+    KPfGlobalVolumeAccumulator, KPfTokenIncentiveClaim, KPfUserVolumeAccumulator,
+};
+use crate::models::pump_models::pf_models::pf_pgsql_dto::{
+    PfPgsqlTokenIncentiveDto, build_pf_pgsql_token_incentive_from_account,
+};
+use crate::models::pump_models::pf_models::pf_redis::{
+    PfRedisTokenIncentive, build_pf_redis_global_volume_accumulator,
+};
+use crate::repositories::pump_repositories::pf_ch_repositories::pf_ch_token_incentives::insert_pf_ch_token_incentive;
+use crate::repositories::pump_repositories::pf_pgsql_repositories::pf_pgsql_token_incentives::upsert_pf_pgsql_token_incentive;
+use crate::repositories::pump_repositories::pf_redis_repositories::pf_redis_token_incentives::{
+    upsert_pf_redis_global_volume_accumulator, upsert_pf_redis_token_incentive,
+};
+use crate::state::AppState;
+
+pub async fn handle_pf_token_incentives_claimed(
+    state: &AppState,
+    claim: KPfTokenIncentiveClaim,
+) -> anyhow::Result<()> {
+    handle_token_incentive(state, PfPgsqlTokenIncentiveDto::from(claim)).await
+}
+
+pub async fn handle_pf_user_volume_accumulator_updated(
+    state: &AppState,
+    accumulator: KPfUserVolumeAccumulator,
+    slot: u64,
+) -> anyhow::Result<()> {
+    handle_token_incentive(
+        state,
+        build_pf_pgsql_token_incentive_from_account(accumulator, slot),
+    )
+    .await
+}
+
+pub async fn handle_pf_global_volume_accumulator_updated(
+    state: &AppState,
+    accumulator: KPfGlobalVolumeAccumulator,
+    slot: u64,
+) -> anyhow::Result<()> {
+    upsert_pf_redis_global_volume_accumulator(
+        state,
+        build_pf_redis_global_volume_accumulator(accumulator, slot),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Record one incentive update: Postgres balance, the Redis copy, then the ClickHouse history
+pub async fn handle_token_incentive(
+    state: &AppState,
+    incentive_dto: PfPgsqlTokenIncentiveDto,
+) -> anyhow::Result<()> {
+    let incentive_ch = PfChTokenIncentiveDto::from(incentive_dto.clone());
+
+    let balance = upsert_pf_pgsql_token_incentive(&state.pg_pool, incentive_dto).await?;
+    upsert_pf_redis_token_incentive(state, PfRedisTokenIncentive::from(balance)).await?;
+
+    insert_pf_ch_token_incentive(state, &incentive_ch).await?;
+
+    Ok(())
+}
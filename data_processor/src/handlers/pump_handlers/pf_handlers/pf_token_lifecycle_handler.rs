@@ -0,0 +1,156 @@
+use crate::handlers::pump_handlers::pf_handlers::pf_protocol_params_handler::pf_initial_real_token_reserves_at;
+use crate::handlers::ws_publish_handler::{
+    publish_pf_tick, publish_token_created, publish_token_migrated,
+};
+use crate::models::enums::TokenStatus;
+use crate::models::pump_models::pf_models::pf_kafka_event::{
+    KPfBondingCurveCompleted, KPfToken, KPfTokenLifecycle,
+};
+use crate::models::pump_models::pf_models::pf_pgsql_dto::{
+    build_pf_pgsql_bonding_curve_progress_from_token, build_pf_pgsql_price_from_token,
+};
+use crate::models::pump_models::pf_models::pf_redis::{
+    PfRedisBondingCurveProgress, build_pf_redis_price_from_token,
+};
+use crate::models::token_lifecycle::{
+    build_ch_token_completed_from_pf, build_ch_token_created_from_pf,
+    build_ch_token_migrated_from_pf,
+};
+use crate::models::token_registry::{
+    build_pgsql_pool_from_pf_migration, build_pgsql_token_from_pf,
+};
+use crate::models::ws_events::build_ws_token_migrated_from_pf;
+use crate::repositories::ch_token_lifecycle::insert_ch_token_lifecycle;
+use crate::repositories::pgsql_tokens::{upsert_pgsql_migration_pool, upsert_pgsql_token};
+use crate::repositories::pump_repositories::pf_pgsql_repositories::pf_pgsql_prices::{
+    complete_pf_pgsql_bonding_curve, get_pf_pgsql_curve_token, migrate_pf_pgsql_bonding_curve,
+    upsert_pf_pgsql_bonding_curve_progress, upsert_pf_pgsql_price,
+};
+use crate::repositories::pump_repositories::pf_redis_repositories::pf_redis_prices::{
+    complete_pf_redis_bonding_curve, merge_pf_redis_price_metadata, migrate_pf_redis_bonding_curve,
+    upsert_pf_redis_bonding_curve_progress, upsert_pf_redis_price,
+};
+use crate::state::AppState;
+use log::{debug, warn};
+
+/// Record a new token: the token registry row, the opening Postgres price and progress (which
+/// also link the curve to its mint), the Redis price with the token's metadata, then the
+/// ClickHouse lifecycle. The token is announced on `creation` and, if its opening price was
+/// stored, ticked to subscribers.
+pub async fn handle_pf_token_created(
+    state: &AppState,
+    k_pf_token: KPfToken,
+    slot: u64,
+) -> anyhow::Result<()> {
+    let token = build_pgsql_token_from_pf(&k_pf_token);
+    upsert_pgsql_token(&state.pg_pool, token.clone()).await?;
+
+    let opened = open_pf_token(state, &k_pf_token, slot).await?;
+
+    insert_ch_token_lifecycle(state, &build_ch_token_created_from_pf(&k_pf_token, slot)).await?;
+
+    publish_token_created(state, &token).await;
+    if opened {
+        publish_pf_tick(state, &k_pf_token.mint, None).await;
+    }
+
+    Ok(())
+}
+
+/// Store the opening price and progress of a new token with their Redis copies.
+/// Returns `false` when there was no opening state to store.
+async fn open_pf_token(state: &AppState, k_pf_token: &KPfToken, slot: u64) -> anyhow::Result<bool> {
+    let pf_pgsql_price_dto = match build_pf_pgsql_price_from_token(k_pf_token, slot) {
+        Ok(p) => p,
+        Err(e) => {
+            warn!("Skipping opening price of {}: {e}", k_pf_token.mint);
+            return Ok(false);
+        }
+    };
+
+    // Trades processed before the creation already hold newer state; only add the metadata
+    if get_pf_pgsql_curve_token(&state.pg_pool, &k_pf_token.bonding_curve)
+        .await?
+        .is_some()
+    {
+        let pf_redis_price = build_pf_redis_price_from_token(pf_pgsql_price_dto, k_pf_token);
+        merge_pf_redis_price_metadata(state, pf_redis_price).await?;
+        return Ok(false);
+    }
+
+    let pf_redis_price = build_pf_redis_price_from_token(pf_pgsql_price_dto.clone(), k_pf_token);
+    upsert_pf_pgsql_price(&state.pg_pool, pf_pgsql_price_dto).await?;
+    upsert_pf_redis_price(state, pf_redis_price).await?;
+
+    let initial_real_token_reserves = pf_initial_real_token_reserves_at(state, slot).await?;
+
+    if let Ok(bcp) = build_pf_pgsql_bonding_curve_progress_from_token(
+        k_pf_token,
+        initial_real_token_reserves,
+        slot,
+    ) {
+        let pf_redis_bcp = PfRedisBondingCurveProgress::from(bcp.clone());
+        upsert_pf_redis_bonding_curve_progress(state, pf_redis_bcp).await?;
+        upsert_pf_pgsql_bonding_curve_progress(&state.pg_pool, bcp).await?;
+    }
+
+    Ok(true)
+}
+
+/// Record a sold-out curve: stop trading in Postgres and Redis, then the ClickHouse lifecycle
+pub async fn handle_pf_bonding_curve_completed(
+    state: &AppState,
+    completed: KPfBondingCurveCompleted,
+    slot: u64,
+) -> anyhow::Result<()> {
+    let mint = complete_pf_pgsql_bonding_curve(&state.pg_pool, &completed.bonding_curve).await?;
+
+    let lifecycle_ch = build_ch_token_completed_from_pf(&completed, mint.clone(), slot);
+
+    match mint {
+        Some(mint) => complete_pf_redis_bonding_curve(state, &mint).await?,
+        None => debug!(
+            "Completed bonding curve {} has no stored progress",
+            completed.bonding_curve
+        ),
+    }
+
+    insert_ch_token_lifecycle(state, &lifecycle_ch).await?;
+
+    Ok(())
+}
+
+/// Record a migration: the destination pool in the registry, flag the curve as migrated in
+/// Postgres and Redis, then the ClickHouse lifecycle, and announce it on `migration`
+pub async fn handle_pf_token_migrated(
+    state: &AppState,
+    lifecycle: KPfTokenLifecycle,
+    slot: u64,
+) -> anyhow::Result<()> {
+    if lifecycle.status != TokenStatus::Migrated {
+        warn!(
+            "Ignoring PfTokenMigrated for {} with status {:?}",
+            lifecycle.mint, lifecycle.status
+        );
+        return Ok(());
+    }
+
+    match build_pgsql_pool_from_pf_migration(&lifecycle) {
+        Ok(pool) => upsert_pgsql_migration_pool(&state.pg_pool, pool).await?,
+        Err(e) => warn!("Skipping destination pool of {}: {e}", lifecycle.mint),
+    }
+
+    if migrate_pf_pgsql_bonding_curve(&state.pg_pool, &lifecycle.mint)
+        .await?
+        .is_none()
+    {
+        debug!("Migrated token {} has no stored progress", lifecycle.mint);
+    }
+    migrate_pf_redis_bonding_curve(state, &lifecycle.mint).await?;
+
+    insert_ch_token_lifecycle(state, &build_ch_token_migrated_from_pf(&lifecycle, slot)).await?;
+
+    publish_token_migrated(state, &build_ws_token_migrated_from_pf(&lifecycle, slot)).await;
+
+    Ok(())
+}
@@ -1,9 +1,15 @@
+use crate::handlers::pump_handlers::pf_handlers::pf_creator_fee_handler::handle_creator_fee;
+use crate::handlers::pump_handlers::pf_handlers::pf_protocol_params_handler::pf_initial_real_token_reserves_at;
+use crate::handlers::pump_handlers::pf_handlers::pf_token_incentive_handler::handle_token_incentive;
+use crate::handlers::ws_publish_handler::publish_pf_tick;
 use crate::models::pump_models::pf_models::pf_ch_dto::PfChTradeDto;
 use crate::models::pump_models::pf_models::pf_kafka_event::KPfChTrade;
 use crate::models::pump_models::pf_models::pf_pgsql_dto::{
-    build_pf_pgsql_bonding_curve_progress, build_pf_pgsql_price,
+    build_pf_pgsql_bonding_curve_progress, build_pf_pgsql_creator_fee_accrual,
+    build_pf_pgsql_price, build_pf_pgsql_token_incentive,
 };
 use crate::models::pump_models::pf_models::pf_redis::{PfRedisBondingCurveProgress, PfRedisPrice};
+use crate::models::ws_events::build_ws_trade_from_pf;
 use crate::repositories::pump_repositories::pf_ch_repositories::pf_ch_trade::insert_pf_ch_trade;
 use crate::repositories::pump_repositories::pf_pgsql_repositories::pf_pgsql_prices::{
     upsert_pf_pgsql_bonding_curve_progress, upsert_pf_pgsql_price,
@@ -13,9 +19,11 @@ use crate::repositories::pump_repositories::pf_redis_repositories::pf_redis_pric
 };
 use crate::state::AppState;
 
+/// Record a trade: Postgres price and progress with their Redis copies, the creator fee and
+/// token incentive it moved, then the ClickHouse trade, and tick the mint to subscribers.
+/// ClickHouse rows are buffered after the fallible sinks, so a retry does not add them again.
 pub async fn handle_pf_trade(state: &AppState, k_pf_ch_trade: KPfChTrade) -> anyhow::Result<()> {
     let trade_ch = PfChTradeDto::from(k_pf_ch_trade.clone());
-    insert_pf_ch_trade(state, &trade_ch).await?;
 
     let pf_pgsql_price_dto = build_pf_pgsql_price(k_pf_ch_trade.clone());
 
@@ -25,8 +33,10 @@ pub async fn handle_pf_trade(state: &AppState, k_pf_ch_trade: KPfChTrade) -> any
         upsert_pf_redis_price(state, pf_redis_price).await?;
     }
 
+    let initial_real_token_reserves =
+        pf_initial_real_token_reserves_at(state, k_pf_ch_trade.slot).await?;
     let pf_pgsql_bonding_curve_progress_dto =
-        build_pf_pgsql_bonding_curve_progress(k_pf_ch_trade.clone());
+        build_pf_pgsql_bonding_curve_progress(k_pf_ch_trade.clone(), initial_real_token_reserves);
 
     if let Ok(bcp) = pf_pgsql_bonding_curve_progress_dto {
         let pf_redis_bcp = PfRedisBondingCurveProgress::from(bcp.clone());
@@ -34,5 +44,18 @@ pub async fn handle_pf_trade(state: &AppState, k_pf_ch_trade: KPfChTrade) -> any
         upsert_pf_pgsql_bonding_curve_progress(&state.pg_pool, bcp).await?;
     }
 
+    if let Ok(fee) = build_pf_pgsql_creator_fee_accrual(&k_pf_ch_trade) {
+        handle_creator_fee(state, fee).await?;
+    }
+
+    if let Ok(incentive) = build_pf_pgsql_token_incentive(&k_pf_ch_trade) {
+        handle_token_incentive(state, incentive).await?;
+    }
+
+    insert_pf_ch_trade(state, &trade_ch).await?;
+
+    let last_trade = build_ws_trade_from_pf(&k_pf_ch_trade);
+    publish_pf_tick(state, &k_pf_ch_trade.mint, Some(last_trade)).await;
+
     Ok(())
 }
@@ -0,0 +1,3 @@
+pub mod ps_price_update_handler;
+pub mod ps_token_created_handler;
+pub mod ps_trade_occurred_handler;
@@ -0,0 +1,39 @@
+use crate::handlers::ws_publish_handler::publish_ps_tick;
+use crate::models::kafka_event::EventSource;
+use crate::models::pump_models::ps_models::ps_ch_dto::build_ps_ch_pool_price;
+use crate::models::pump_models::ps_models::ps_kafka_event::KPsPrice;
+use crate::models::pump_models::ps_models::ps_pgsql_dto::build_ps_pgsql_price;
+use crate::models::pump_models::ps_models::ps_redis::PsRedisPrice;
+use crate::repositories::pump_repositories::ps_ch_repositories::ps_ch_pool_prices::insert_ps_ch_pool_price;
+use crate::repositories::pump_repositories::ps_pgsql_repositories::ps_pgsql_prices::upsert_ps_pgsql_price;
+use crate::repositories::pump_repositories::ps_redis_repositories::ps_redis_prices::upsert_ps_redis_price;
+use crate::state::AppState;
+use log::warn;
+
+/// Record a pool's joined vault balances: Postgres latest price, the Redis copy, then the
+/// ClickHouse price history, ticked to subscribers
+pub async fn handle_ps_price_updated(
+    state: &AppState,
+    k_ps_price: KPsPrice,
+    source: EventSource,
+) -> anyhow::Result<()> {
+    let ps_pgsql_price_dto = match build_ps_pgsql_price(&k_ps_price) {
+        Ok(p) => p,
+        Err(e) => {
+            warn!("Skipping price of pool {}: {e}", k_ps_price.pool);
+            return Ok(());
+        }
+    };
+
+    let price_ch = build_ps_ch_pool_price(&ps_pgsql_price_dto, source);
+
+    let ps_redis_price = PsRedisPrice::from(ps_pgsql_price_dto.clone());
+    upsert_ps_pgsql_price(&state.pg_pool, ps_pgsql_price_dto).await?;
+    upsert_ps_redis_price(state, ps_redis_price).await?;
+
+    insert_ps_ch_pool_price(state, &price_ch).await?;
+
+    publish_ps_tick(state, &k_ps_price.pool, None).await;
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use crate::handlers::ws_publish_handler::publish_token_created;
+use crate::models::pump_models::ps_models::ps_kafka_event::KPsToken;
+use crate::models::pump_models::ps_models::ps_redis::build_ps_redis_price_from_token;
+use crate::models::token_registry::build_pgsql_token_from_ps;
+use crate::repositories::pgsql_tokens::upsert_pgsql_token;
+use crate::repositories::pump_repositories::ps_redis_repositories::ps_redis_prices::merge_ps_redis_price_metadata;
+use crate::state::AppState;
+
+/// Register the token, announce it on `creation`, and attach its metadata to the pool's
+/// Redis price.
+/// The pool's reserves arrive with its first vault update, which fills Postgres and ClickHouse.
+pub async fn handle_ps_token_created(state: &AppState, k_ps_token: KPsToken) -> anyhow::Result<()> {
+    let token = build_pgsql_token_from_ps(&k_ps_token);
+    upsert_pgsql_token(&state.pg_pool, token.clone()).await?;
+    publish_token_created(state, &token).await;
+
+    merge_ps_redis_price_metadata(state, build_ps_redis_price_from_token(&k_ps_token)).await?;
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::handlers::pump_handlers::pf_handlers::pf_creator_fee_handler::handle_creator_fee;
+use crate::handlers::ws_publish_handler::publish_ps_tick;
+use crate::models::pump_models::pf_models::pf_pgsql_dto::build_ps_pgsql_creator_fee_accrual;
+use crate::models::pump_models::ps_models::ps_ch_dto::build_ps_ch_trade;
+use crate::models::pump_models::ps_models::ps_kafka_event::KPsTrade;
+use crate::models::ws_events::build_ws_trade_from_ps;
+use crate::repositories::pump_repositories::ps_ch_repositories::ps_ch_trade::insert_ps_ch_trade;
+use crate::state::AppState;
+
+/// Record a swap's coin creator fee (Postgres balance, Redis copy, ledger), then the swap in
+/// ClickHouse, and tick the pool's cached price to subscribers with the swap as last trade
+pub async fn handle_ps_trade(
+    state: &AppState,
+    k_ps_trade: KPsTrade,
+    slot: u64,
+) -> anyhow::Result<()> {
+    if let Ok(fee) = build_ps_pgsql_creator_fee_accrual(&k_ps_trade, slot) {
+        handle_creator_fee(state, fee).await?;
+    }
+
+    insert_ps_ch_trade(state, &build_ps_ch_trade(&k_ps_trade, slot)).await?;
+
+    let last_trade = build_ws_trade_from_ps(&k_ps_trade, slot);
+    publish_ps_tick(state, &k_ps_trade.pool, Some(last_trade)).await;
+
+    Ok(())
+}
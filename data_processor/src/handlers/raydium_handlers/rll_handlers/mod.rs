@@ -0,0 +1,2 @@
+pub mod rll_price_update_handler;
+pub mod rll_token_created_handler;
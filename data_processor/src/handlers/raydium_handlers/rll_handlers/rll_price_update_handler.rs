@@ -0,0 +1,32 @@
+use crate::handlers::ws_publish_handler::publish_rll_tick;
+use crate::models::kafka_event::EventSource;
+use crate::models::raydium_models::rll_models::rll_ch_dto::build_rll_ch_pool_price;
+use crate::models::raydium_models::rll_models::rll_kafka_event::KRllPrice;
+use crate::models::raydium_models::rll_models::rll_pgsql_dto::build_rll_pgsql_price;
+use crate::models::raydium_models::rll_models::rll_redis::RllRedisPrice;
+use crate::repositories::raydium_repositories::rll_ch_repositories::rll_ch_pool_prices::insert_rll_ch_pool_price;
+use crate::repositories::raydium_repositories::rll_pgsql_repositories::rll_pgsql_prices::upsert_rll_pgsql_price;
+use crate::repositories::raydium_repositories::rll_redis_repositories::rll_redis_prices::upsert_rll_redis_price;
+use crate::state::AppState;
+
+/// Record a `PoolState` account update: Postgres latest state, the Redis copy,
+/// then the ClickHouse history, ticked to subscribers
+pub async fn handle_rll_price_updated(
+    state: &AppState,
+    k_rll_price: KRllPrice,
+    source: EventSource,
+) -> anyhow::Result<()> {
+    let rll_pgsql_price_dto = build_rll_pgsql_price(&k_rll_price);
+
+    let price_ch = build_rll_ch_pool_price(&rll_pgsql_price_dto, source);
+
+    let rll_redis_price = RllRedisPrice::from(rll_pgsql_price_dto.clone());
+    upsert_rll_pgsql_price(&state.pg_pool, rll_pgsql_price_dto).await?;
+    upsert_rll_redis_price(state, rll_redis_price).await?;
+
+    insert_rll_ch_pool_price(state, &price_ch).await?;
+
+    publish_rll_tick(state, &k_rll_price.pool_state).await;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use crate::handlers::ws_publish_handler::{publish_rll_tick, publish_token_created};
+use crate::models::raydium_models::rll_models::rll_kafka_event::KRllToken;
+use crate::models::raydium_models::rll_models::rll_pgsql_dto::build_rll_pgsql_price_from_token;
+use crate::models::raydium_models::rll_models::rll_redis::build_rll_redis_price_from_token;
+use crate::models::token_lifecycle::build_ch_token_created_from_rll;
+use crate::models::token_registry::build_pgsql_token_from_rll;
+use crate::repositories::ch_token_lifecycle::insert_ch_token_lifecycle;
+use crate::repositories::pgsql_tokens::upsert_pgsql_token;
+use crate::repositories::raydium_repositories::rll_pgsql_repositories::rll_pgsql_prices::upsert_rll_pgsql_price;
+use crate::repositories::raydium_repositories::rll_redis_repositories::rll_redis_prices::{
+    merge_rll_redis_price_metadata, upsert_rll_redis_price,
+};
+use crate::state::AppState;
+use log::warn;
+
+/// Record a new LaunchLab token: the token registry row, the opening Postgres state, the Redis
+/// price with the token's metadata, then the ClickHouse lifecycle. The token is announced on
+/// `creation` and, if its opening state was stored, ticked to subscribers.
+pub async fn handle_rll_token_created(
+    state: &AppState,
+    k_rll_token: KRllToken,
+    slot: u64,
+) -> anyhow::Result<()> {
+    let token = build_pgsql_token_from_rll(&k_rll_token);
+    upsert_pgsql_token(&state.pg_pool, token.clone()).await?;
+
+    let opened = open_rll_token(state, &k_rll_token, slot).await?;
+
+    insert_ch_token_lifecycle(state, &build_ch_token_created_from_rll(&k_rll_token, slot)).await?;
+
+    publish_token_created(state, &token).await;
+    if opened {
+        publish_rll_tick(state, &k_rll_token.pool_state).await;
+    }
+
+    Ok(())
+}
+
+/// Store the opening state of a new token with its Redis copy and metadata.
+/// Returns `false` when there was no opening state to store.
+async fn open_rll_token(
+    state: &AppState,
+    k_rll_token: &KRllToken,
+    slot: u64,
+) -> anyhow::Result<bool> {
+    let rll_pgsql_price_dto = match build_rll_pgsql_price_from_token(k_rll_token, slot) {
+        Ok(p) => p,
+        Err(e) => {
+            warn!("Skipping opening price of {}: {e}", k_rll_token.mint);
+            return Ok(false);
+        }
+    };
+
+    let rll_redis_price =
+        build_rll_redis_price_from_token(rll_pgsql_price_dto.clone(), k_rll_token);
+    // Both sinks ignore the opening state if a newer pool state update already landed,
+    // so the metadata is merged on its own
+    upsert_rll_pgsql_price(&state.pg_pool, rll_pgsql_price_dto).await?;
+    upsert_rll_redis_price(state, rll_redis_price.clone()).await?;
+    merge_rll_redis_price_metadata(state, rll_redis_price).await?;
+
+    Ok(true)
+}
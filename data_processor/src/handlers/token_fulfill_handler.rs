@@ -0,0 +1,44 @@
+use crate::models::kafka_req::{KReqPlatform, KTokenReqFulfill};
+use crate::models::pump_models::pf_models::pf_redis::build_pf_redis_price_from_fulfill;
+use crate::models::pump_models::ps_models::ps_redis::build_ps_redis_price_from_fulfill;
+use crate::models::raydium_models::rll_models::rll_redis::build_rll_redis_price_from_fulfill;
+use crate::models::token_registry::build_pgsql_token_from_fulfill;
+use crate::repositories::pgsql_tokens::merge_pgsql_token_metadata;
+use crate::repositories::pump_repositories::pf_redis_repositories::pf_redis_prices::merge_pf_redis_price_metadata;
+use crate::repositories::pump_repositories::ps_redis_repositories::ps_redis_prices::merge_ps_redis_price_metadata;
+use crate::repositories::raydium_repositories::rll_redis_repositories::rll_redis_prices::merge_rll_redis_price_metadata;
+use crate::state::AppState;
+use log::warn;
+
+/// Merge metadata resolved by the historian into the token registry and the token's Redis price.
+/// On-chain state in Postgres and ClickHouse is left alone, and the price in the request
+/// is a one-off quote the next account update supersedes.
+pub async fn handle_token_fulfill(
+    state: &AppState,
+    fulfill: KTokenReqFulfill,
+) -> anyhow::Result<()> {
+    merge_pgsql_token_metadata(&state.pg_pool, build_pgsql_token_from_fulfill(&fulfill)).await?;
+
+    match fulfill.platform {
+        KReqPlatform::PumpFun => {
+            let price = build_pf_redis_price_from_fulfill(&fulfill).map_err(anyhow::Error::msg)?;
+            merge_pf_redis_price_metadata(state, price).await?;
+        }
+        KReqPlatform::PumpSwap => {
+            let price = build_ps_redis_price_from_fulfill(&fulfill).map_err(anyhow::Error::msg)?;
+            merge_ps_redis_price_metadata(state, price).await?;
+        }
+        KReqPlatform::RaydiumLaunchLab => {
+            let price = build_rll_redis_price_from_fulfill(&fulfill).map_err(anyhow::Error::msg)?;
+            merge_rll_redis_price_metadata(state, price).await?;
+        }
+        platform => {
+            warn!(
+                "Ignoring fulfilled token {} on unindexed platform {platform:?}",
+                fulfill.mint
+            );
+        }
+    }
+
+    Ok(())
+}
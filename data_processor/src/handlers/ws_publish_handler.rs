@@ -0,0 +1,102 @@
+use crate::models::pump_models::pf_models::pf_redis::{PfRedisBondingCurveProgress, PfRedisPrice};
+use crate::models::pump_models::ps_models::ps_redis::PsRedisPrice;
+use crate::models::raydium_models::rll_models::rll_redis::RllRedisPrice;
+use crate::models::token_registry::PgsqlTokenDto;
+use crate::models::ws_events::{
+    WS_CREATION_CHANNEL, WS_MIGRATION_CHANNEL, WsPriceTick, WsTokenCreated, WsTokenMigrated,
+    WsTrade, build_ws_price_tick_from_pf, build_ws_price_tick_from_ps,
+    build_ws_price_tick_from_rll, ws_price_channel,
+};
+use crate::repositories::redis_json::get_redis_json;
+use crate::repositories::redis_ws::publish_redis_ws;
+use crate::state::AppState;
+use log::warn;
+
+// Publishing is best-effort: the sinks are already written, and the next event
+// supersedes a lost tick, so failures are logged rather than failing the message.
+
+/// Publish the cached state of a Pump.fun token to `ws:{mint}`
+pub async fn publish_pf_tick(state: &AppState, mint: &str, last_trade: Option<WsTrade>) {
+    if let Err(e) = try_publish_pf_tick(state, mint, last_trade).await {
+        warn!("Failed to publish tick of {mint}: {e}");
+    }
+}
+
+/// Publish the cached state of a PumpSwap pool to `ws:{base mint}`
+pub async fn publish_ps_tick(state: &AppState, pool: &str, last_trade: Option<WsTrade>) {
+    if let Err(e) = try_publish_ps_tick(state, pool, last_trade).await {
+        warn!("Failed to publish tick of pool {pool}: {e}");
+    }
+}
+
+/// Publish the cached state of a LaunchLab curve to `ws:{base mint}`
+pub async fn publish_rll_tick(state: &AppState, pool_state: &str) {
+    if let Err(e) = try_publish_rll_tick(state, pool_state).await {
+        warn!("Failed to publish tick of pool state {pool_state}: {e}");
+    }
+}
+
+/// Announce a new token on `creation`
+pub async fn publish_token_created(state: &AppState, token: &PgsqlTokenDto) {
+    if let Err(e) = publish_redis_ws(state, WS_CREATION_CHANNEL, &WsTokenCreated::from(token)).await
+    {
+        warn!("Failed to publish creation of {}: {e}", token.mint);
+    }
+}
+
+/// Announce a migration on `migration`
+pub async fn publish_token_migrated(state: &AppState, migrated: &WsTokenMigrated) {
+    if let Err(e) = publish_redis_ws(state, WS_MIGRATION_CHANNEL, migrated).await {
+        warn!("Failed to publish migration of {}: {e}", migrated.mint);
+    }
+}
+
+async fn try_publish_pf_tick(
+    state: &AppState,
+    mint: &str,
+    last_trade: Option<WsTrade>,
+) -> Result<(), redis::RedisError> {
+    let Some(price) = get_redis_json::<PfRedisPrice>(state, "pf_prices", mint).await? else {
+        return Ok(());
+    };
+    let progress =
+        get_redis_json::<PfRedisBondingCurveProgress>(state, "pf_bonding_curve_progress", mint)
+            .await?;
+
+    publish_tick(
+        state,
+        &build_ws_price_tick_from_pf(&price, progress.as_ref(), last_trade),
+    )
+    .await
+}
+
+async fn try_publish_ps_tick(
+    state: &AppState,
+    pool: &str,
+    last_trade: Option<WsTrade>,
+) -> Result<(), redis::RedisError> {
+    let Some(price) = get_redis_json::<PsRedisPrice>(state, "ps_prices", pool).await? else {
+        return Ok(());
+    };
+
+    match build_ws_price_tick_from_ps(&price, last_trade) {
+        Some(tick) => publish_tick(state, &tick).await,
+        None => Ok(()),
+    }
+}
+
+async fn try_publish_rll_tick(state: &AppState, pool_state: &str) -> Result<(), redis::RedisError> {
+    let Some(price) = get_redis_json::<RllRedisPrice>(state, "rll_prices", pool_state).await?
+    else {
+        return Ok(());
+    };
+
+    match build_ws_price_tick_from_rll(&price) {
+        Some(tick) => publish_tick(state, &tick).await,
+        None => Ok(()),
+    }
+}
+
+async fn publish_tick(state: &AppState, tick: &WsPriceTick) -> Result<(), redis::RedisError> {
+    publish_redis_ws(state, &ws_price_channel(&tick.mint), tick).await
+}
@@ -1,41 +1,84 @@
 use crate::config::AppConfig;
+use crate::dlq::DeadLetterQueue;
+use crate::handlers::pump_handlers::pf_handlers::pf_creator_fee_handler::{
+    handle_pf_creator_fee_claimed, handle_ps_creator_fee_claimed,
+};
+use crate::handlers::pump_handlers::pf_handlers::pf_price_update_handler::handle_pf_price_updated;
+use crate::handlers::pump_handlers::pf_handlers::pf_protocol_params_handler::{
+    handle_pf_fee_config_updated, handle_pf_global_config_updated,
+};
+use crate::handlers::pump_handlers::pf_handlers::pf_token_incentive_handler::{
+    handle_pf_global_volume_accumulator_updated, handle_pf_token_incentives_claimed,
+    handle_pf_user_volume_accumulator_updated,
+};
+use crate::handlers::pump_handlers::pf_handlers::pf_token_lifecycle_handler::{
+    handle_pf_bonding_curve_completed, handle_pf_token_created, handle_pf_token_migrated,
+};
 use crate::handlers::pump_handlers::pf_handlers::pf_trade_occurred_handler::handle_pf_trade;
-use crate::models::kafka_event::{KEvent, KEventData, KEventType};
+use crate::handlers::pump_handlers::ps_handlers::ps_price_update_handler::handle_ps_price_updated;
+use crate::handlers::pump_handlers::ps_handlers::ps_token_created_handler::handle_ps_token_created;
+use crate::handlers::pump_handlers::ps_handlers::ps_trade_occurred_handler::handle_ps_trade;
+use crate::handlers::raydium_handlers::rll_handlers::rll_price_update_handler::handle_rll_price_updated;
+use crate::handlers::raydium_handlers::rll_handlers::rll_token_created_handler::handle_rll_token_created;
+use crate::handlers::token_fulfill_handler::handle_token_fulfill;
+use crate::models::kafka_event::{KEvent, KEventData};
+use crate::models::kafka_req::{KReq, KReqData};
+use crate::repositories::redis_processed_events::{
+    is_redis_event_processed, mark_redis_events_processed, processed_event_key,
+};
 use crate::state::AppState;
-use log::{error, info, warn};
+use event_contract::codec::registry::{DecodeError, EventDecoder};
+use event_contract::codec::{CONTENT_TYPE_HEADER, ContentType};
+use log::{debug, error, info, warn};
 use rdkafka::config::ClientConfig;
-use rdkafka::consumer::{Consumer, StreamConsumer};
-use rdkafka::message::Message;
+use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::message::{BorrowedMessage, Headers, Message};
+use rdkafka::{Offset, TopicPartitionList};
 use std::time::Duration;
-use tokio::time::sleep;
+use tokio::time::{interval, sleep};
 
 const TOPICS: &[&str] = &[
     "pf_trade_event",
     "pf_price_update_event",
     "pf_create_event",
     "pf_migrate_event",
+    "pf_creator_fee_event",
+    "pf_incentive_event",
+    "pf_protocol_params_event",
     "ps_trade_event",
     "ps_price_update_event",
     "ps_create_event",
+    "ps_creator_fee_event",
     "rll_trade_event",
     "rll_price_update_event",
     "rll_create_event",
     "rll_migrate_event",
-    "fulfill_req",
+    FULFILL_REQ_TOPIC,
 ];
+const FULFILL_REQ_TOPIC: &str = "fulfill_req";
 const MAX_RETRIES: u32 = 30;
 const RETRY_DELAY_SECS: u64 = 2;
+/// How often the ClickHouse batch is checked for its period while no messages arrive
+const FLUSH_CHECK_INTERVAL_MS: u64 = 100;
+const SEEK_TIMEOUT_SECS: u64 = 5;
+const RETRY_BASE_DELAY_MS: u64 = 200;
+const RETRY_MAX_DELAY_MS: u64 = 10_000;
+/// DLQ sends per failed message before its partition is rewound to it instead
+const DLQ_MAX_ATTEMPTS: u32 = 5;
 
 pub async fn start_kafka_consumer(config: AppConfig, state: AppState) {
     info!("Starting Kafka consumer...");
     let consumer: StreamConsumer = ClientConfig::new()
         .set("group.id", &config.kafka_group_id)
         .set("bootstrap.servers", &config.kafka_brokers)
-        .set("enable.auto.commit", "true")
+        .set("enable.auto.commit", "false")
         .set("auto.offset.reset", "earliest")
         .create()
         .expect("Failed to create Kafka consumer");
 
+    let decoder = EventDecoder::new(config.schema_registry_url.clone());
+    let dlq = DeadLetterQueue::new(&config);
+
     // Retry subscription until topic is available
     for attempt in 1..=MAX_RETRIES {
         match consumer.subscribe(TOPICS) {
@@ -53,58 +96,373 @@ pub async fn start_kafka_consumer(config: AppConfig, state: AppState) {
         }
     }
 
+    let mut flush_tick = interval(Duration::from_millis(FLUSH_CHECK_INTERVAL_MS));
+    // Events handled since the last flush; marked processed once their rows are durable
+    let mut processed_keys = Vec::new();
     loop {
-        match consumer.recv().await {
-            Ok(message) => {
-                let topic = message.topic();
-                let payload = match message.payload_view::<str>() {
-                    Some(Ok(s)) => s,
-                    Some(Err(e)) => {
-                        error!("Error deserializing message payload: {e:?}");
-                        continue;
-                    }
-                    None => {
-                        warn!("Empty message payload");
-                        continue;
+        tokio::select! {
+            received = consumer.recv() => match received {
+                Ok(message) => match process_message(&message, &decoder, &state, &dlq).await {
+                    Outcome::Done(key) => {
+                        processed_keys.extend(key);
+                        state
+                            .ch_writer
+                            .mark_processed(message.topic(), message.partition(), message.offset())
+                            .await;
                     }
-                };
-
-                if let Err(e) = handle_message(topic, payload, &state).await {
-                    error!("Failed to handle message from {topic}: {e}");
+                    // Not marked processed, so no offset past it is committed either
+                    Outcome::Deferred => seek(
+                        &consumer,
+                        message.topic(),
+                        message.partition(),
+                        message.offset(),
+                    ),
+                },
+                Err(e) => {
+                    error!("Kafka consumer error: {e:?}");
+                    sleep(Duration::from_secs(1)).await;
                 }
+            },
+            _ = flush_tick.tick() => {}
+        }
+
+        flush_and_commit(&consumer, &state, &mut processed_keys).await;
+    }
+}
+
+/// Why a message failed. Poison messages go straight to the DLQ; transient
+/// failures are retried with backoff first.
+enum MessageError {
+    Poison(anyhow::Error),
+    Transient(anyhow::Error),
+}
+
+/// What became of a message
+enum Outcome {
+    /// Handled or dead-lettered, so its offset may be committed.
+    /// Carries the processed-event key of a handled event.
+    Done(Option<String>),
+    /// Neither handled nor dead-lettered; it has to be consumed again
+    Deferred,
+}
+
+/// Handle a message until it succeeds or is dead-lettered.
+async fn process_message(
+    message: &BorrowedMessage<'_>,
+    decoder: &EventDecoder,
+    state: &AppState,
+    dlq: &DeadLetterQueue,
+) -> Outcome {
+    let topic = message.topic();
+    let payload = match message.payload() {
+        Some(p) => p,
+        None => {
+            warn!("Empty message payload");
+            return Outcome::Done(None);
+        }
+    };
+
+    let content_type_header = message.headers().and_then(|headers| {
+        headers
+            .iter()
+            .find(|h| h.key == CONTENT_TYPE_HEADER)
+            .and_then(|h| h.value)
+    });
+    let content_type = match ContentType::from_header(content_type_header) {
+        Ok(ct) => ct,
+        Err(e) => {
+            let e = anyhow::anyhow!("Unsupported message encoding: {e}");
+            return dead_letter(message, &e, 1, dlq).await;
+        }
+    };
+
+    let max_attempts = state.config.max_handle_attempts;
+    let mut attempt = 1;
+    let mut backoff = Duration::from_millis(RETRY_BASE_DELAY_MS);
+    let error = loop {
+        match handle_message(topic, payload, content_type, decoder, state).await {
+            Ok(key) => return Outcome::Done(key),
+            Err(MessageError::Poison(e)) => break e,
+            Err(MessageError::Transient(e)) if attempt < max_attempts => {
+                warn!(
+                    "Attempt {attempt}/{max_attempts} for {topic}@{} failed: {e}. Retrying in {backoff:?}",
+                    message.offset()
+                );
+                sleep(backoff).await;
+                backoff = (backoff * 2).min(Duration::from_millis(RETRY_MAX_DELAY_MS));
+                attempt += 1;
             }
-            Err(e) => {
-                error!("Kafka consumer error: {e:?}");
-                sleep(Duration::from_secs(1)).await;
+            Err(MessageError::Transient(e)) => break e,
+        }
+    };
+
+    dead_letter(message, &error, attempt, dlq).await
+}
+
+/// Park a message in the DLQ. Committing the offset without it would lose the message,
+/// so when Kafka keeps refusing it the message is deferred to be consumed again.
+async fn dead_letter(
+    message: &BorrowedMessage<'_>,
+    error: &anyhow::Error,
+    attempts: u32,
+    dlq: &DeadLetterQueue,
+) -> Outcome {
+    let topic = message.topic();
+    let offset = message.offset();
+    error!("Failed to handle message from {topic}@{offset} after {attempts} attempts: {error:#}");
+
+    let mut backoff = Duration::from_millis(RETRY_BASE_DELAY_MS);
+    for dlq_attempt in 1..=DLQ_MAX_ATTEMPTS {
+        match dlq.send(message, &format!("{error:#}"), attempts).await {
+            Ok(()) => {
+                warn!("Sent {topic}@{offset} to {}", dlq.topic());
+                return Outcome::Done(None);
+            }
+            Err(e) if dlq_attempt < DLQ_MAX_ATTEMPTS => {
+                error!("Failed to dead-letter {topic}@{offset}: {e}. Retrying in {backoff:?}");
+                sleep(backoff).await;
+                backoff = (backoff * 2).min(Duration::from_millis(RETRY_MAX_DELAY_MS));
             }
+            Err(e) => error!("Failed to dead-letter {topic}@{offset}: {e}. Consuming it again"),
         }
     }
+
+    Outcome::Deferred
 }
 
-async fn handle_message(topic: &str, payload: &str, state: &AppState) -> anyhow::Result<()> {
-    match topic {
-        "pf_trade_event" => {
-            let msg: KEvent = serde_json::from_str(payload)?;
-
-            match msg.event_type {
-                KEventType::PfChTradeOccurred => {
-                    match msg.data {
-                        KEventData::PfChTradeOccurred(t) => {
-                            handle_pf_trade(state, t).await?;
-                        }
-                        _ => {
-                            return Err(anyhow::anyhow!("Invalid event type"));
-                        }
-                    };
-                }
-                _ => {
-                    return Err(anyhow::anyhow!("Invalid event type"));
+/// Resume `topic`/`partition` from `offset`
+fn seek(consumer: &StreamConsumer, topic: &str, partition: i32, offset: i64) {
+    if let Err(e) = consumer.seek(
+        topic,
+        partition,
+        Offset::Offset(offset),
+        Duration::from_secs(SEEK_TIMEOUT_SECS),
+    ) {
+        error!("Failed to rewind {topic}/{partition} to {offset}: {e}");
+    }
+}
+
+/// Commit offsets once the ClickHouse rows of their messages are durable.
+/// A failed flush loses the batch, so its messages are consumed again; they are only
+/// marked processed after a successful flush, or the replay would skip them.
+async fn flush_and_commit(
+    consumer: &StreamConsumer,
+    state: &AppState,
+    processed_keys: &mut Vec<String>,
+) {
+    match state.ch_writer.flush_if_due().await {
+        Ok(Some(offsets)) => {
+            let keys = std::mem::take(processed_keys);
+            // Without the marks redeliveries are still absorbed by the sinks, just not skipped
+            if !keys.is_empty()
+                && let Err(e) = mark_redis_events_processed(state, &keys).await
+            {
+                warn!("Failed to mark {} events processed: {e}", keys.len());
+            }
+
+            if offsets.is_empty() {
+                return;
+            }
+
+            let mut tpl = TopicPartitionList::new();
+            for ((topic, partition), offset) in &offsets {
+                if let Err(e) = tpl.add_partition_offset(topic, *partition, Offset::Offset(*offset))
+                {
+                    error!("Invalid offset {offset} for {topic}/{partition}: {e}");
                 }
-            };
+            }
+            if let Err(e) = consumer.commit(&tpl, CommitMode::Async) {
+                error!("Failed to commit Kafka offsets: {e}");
+            }
         }
-        _ => {
-            warn!("Unknown topic: {topic}")
+        Ok(None) => {}
+        Err(e) => {
+            processed_keys.clear();
+            error!(
+                "ClickHouse flush failed, replaying {} partitions: {}",
+                e.rewind.len(),
+                e.error
+            );
+            for ((topic, partition), offset) in &e.rewind {
+                seek(consumer, topic, *partition, *offset);
+            }
         }
     }
+}
+
+/// Handle one message and return the processed-event key of the event, if it has one.
+async fn handle_message(
+    topic: &str,
+    payload: &[u8],
+    content_type: ContentType,
+    decoder: &EventDecoder,
+    state: &AppState,
+) -> Result<Option<String>, MessageError> {
+    // The historian publishes plain JSON requests, not `KEvent`s
+    if topic == FULFILL_REQ_TOPIC {
+        let req: KReq =
+            serde_json::from_slice(payload).map_err(|e| MessageError::Poison(e.into()))?;
+        return match req.data {
+            KReqData::TokenReqFulfill(fulfill) => handle_token_fulfill(state, *fulfill)
+                .await
+                .map(|()| None)
+                .map_err(MessageError::Transient),
+            _ => Err(MessageError::Poison(anyhow::anyhow!(
+                "Invalid request type {:?} on {topic}",
+                req.req_type
+            ))),
+        };
+    }
+
+    // Only an unreachable schema registry is worth retrying; a bad payload stays bad
+    let msg: KEvent = decoder
+        .decode(topic, payload, content_type)
+        .await
+        .map_err(|e| match e {
+            DecodeError::Unavailable(_) => MessageError::Transient(e.into()),
+            DecodeError::Rejected(_) => MessageError::Poison(e.into()),
+        })?;
+
+    if !msg.is_supported() {
+        warn!(
+            "Skipping event {} with unsupported schema version {}",
+            msg.event_id, msg.schema_version
+        );
+        return Ok(None);
+    }
+
+    if msg.event_type != msg.data.event_type() {
+        return Err(MessageError::Poison(anyhow::anyhow!(
+            "Event {} is tagged {:?} but carries {:?}",
+            msg.event_id,
+            msg.event_type,
+            msg.data.event_type()
+        )));
+    }
+
+    let key = processed_event_key_of(&msg.data);
+    if let Some(key) = &key
+        && is_redis_event_processed(state, key)
+            .await
+            .map_err(|e| MessageError::Transient(e.into()))?
+    {
+        debug!("Skipping already processed event {} ({key})", msg.event_id);
+        return Ok(None);
+    }
+
+    handle_event(msg, state)
+        .await
+        .map_err(MessageError::Transient)?;
+
+    Ok(key)
+}
+
+/// Events tied to one instruction of a transaction are identified by their type, signature,
+/// ix_index and ordinal in the transaction; account updates carry none and rely on slot ordering alone.
+fn processed_event_key_of(data: &KEventData) -> Option<String> {
+    let event_type = data.event_type();
+    match data {
+        KEventData::PfChTradeOccurred(t) => Some(processed_event_key(
+            event_type,
+            &t.signature,
+            t.ix_index,
+            t.event_ordinal,
+        )),
+        KEventData::PsTradeOccurred(t) => Some(processed_event_key(
+            event_type,
+            &t.signature,
+            t.ix_index,
+            t.event_ordinal,
+        )),
+        KEventData::PfCreatorFeeClaimed(c) => Some(processed_event_key(
+            event_type,
+            &c.signature,
+            c.ix_index,
+            c.event_ordinal,
+        )),
+        KEventData::PsCreatorFeeClaimed(c) => Some(processed_event_key(
+            event_type,
+            &c.signature,
+            c.ix_index,
+            c.event_ordinal,
+        )),
+        KEventData::PfTokenIncentivesClaimed(c) => Some(processed_event_key(
+            event_type,
+            &c.signature,
+            c.ix_index,
+            c.event_ordinal,
+        )),
+        _ => None,
+    }
+}
+
+/// Route a decoded event by its type; the topic it came from plays no part.
+async fn handle_event(msg: KEvent, state: &AppState) -> anyhow::Result<()> {
+    match msg.data {
+        KEventData::PfChTradeOccurred(t) => {
+            handle_pf_trade(state, t).await?;
+        }
+        KEventData::PfPriceUpdated(p) => {
+            handle_pf_price_updated(state, p, msg.slot, msg.source).await?;
+        }
+        KEventData::PfTokenCreated(t) => {
+            handle_pf_token_created(state, t, msg.slot).await?;
+        }
+        KEventData::PfTokenMigrated(l) => {
+            handle_pf_token_migrated(state, l, msg.slot).await?;
+        }
+        KEventData::PfBondingCurveCompleted(c) => {
+            handle_pf_bonding_curve_completed(state, c, msg.slot).await?;
+        }
+        KEventData::PsTradeOccurred(t) => {
+            handle_ps_trade(state, t, msg.slot).await?;
+        }
+        KEventData::PsTokenCreated(t) => {
+            handle_ps_token_created(state, t).await?;
+        }
+        KEventData::PsPriceUpdated(p) => {
+            handle_ps_price_updated(state, p, msg.source).await?;
+        }
+        KEventData::RllTokenCreated(t) => {
+            handle_rll_token_created(state, t, msg.slot).await?;
+        }
+        KEventData::RllPriceUpdated(p) => {
+            handle_rll_price_updated(state, p, msg.source).await?;
+        }
+        KEventData::PfCreatorFeeClaimed(c) => {
+            handle_pf_creator_fee_claimed(state, c).await?;
+        }
+        KEventData::PsCreatorFeeClaimed(c) => {
+            handle_ps_creator_fee_claimed(state, c).await?;
+        }
+        KEventData::PfTokenIncentivesClaimed(c) => {
+            handle_pf_token_incentives_claimed(state, c).await?;
+        }
+        KEventData::PfUserVolumeAccumulatorUpdated(a) => {
+            handle_pf_user_volume_accumulator_updated(state, a, msg.slot).await?;
+        }
+        KEventData::PfGlobalVolumeAccumulatorUpdated(a) => {
+            handle_pf_global_volume_accumulator_updated(state, a, msg.slot).await?;
+        }
+        KEventData::PfGlobalConfigUpdated(g) => {
+            handle_pf_global_config_updated(state, g, msg.slot).await?;
+        }
+        KEventData::PfFeeConfigUpdated(f) => {
+            handle_pf_fee_config_updated(state, f, msg.slot).await?;
+        }
+        // Carry no fields: the streamer does not decode LaunchLab trades or migrations yet.
+        // Pool state updates (`RllPriceUpdated`) record prices and the migration status.
+        KEventData::RllTradeOccurred(_) | KEventData::RllTokenMigrated(_) => {
+            debug!(
+                "Nothing to record for {:?} {}",
+                msg.event_type, msg.event_id
+            );
+        }
+        // Superseded by `PfChTradeOccurred`, which carries the full trade
+        KEventData::PfTradeOccurred(_) => {
+            debug!("Ignoring legacy {:?} {}", msg.event_type, msg.event_id);
+        }
+    };
+
     Ok(())
 }
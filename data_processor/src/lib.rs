@@ -2,6 +2,7 @@ pub mod cache;
 pub mod clickhouse;
 pub mod config;
 pub mod db;
+pub mod dlq;
 pub mod handlers;
 pub mod kafka;
 pub mod models;
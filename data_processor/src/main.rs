@@ -1,5 +1,5 @@
 use data_processor::cache::init_cache;
-use data_processor::clickhouse::init_clickhouse_client;
+use data_processor::clickhouse::{ChWriter, init_clickhouse_client};
 use data_processor::config::AppConfig;
 use data_processor::db::init_db;
 use data_processor::kafka::start_kafka_consumer;
@@ -14,7 +14,8 @@ async fn main() {
     let pg_pool = init_db(config.clone()).await;
 
     let clickhouse = init_clickhouse_client(&config).unwrap();
-    let state = init_state(config.clone(), pg_pool, clickhouse, cache);
+    let ch_writer = ChWriter::new(&clickhouse, &config).unwrap();
+    let state = init_state(config.clone(), pg_pool, clickhouse, ch_writer, cache);
 
     start_kafka_consumer(config, state).await;
 }
@@ -1,3 +1,5 @@
+pub use event_contract::models::enums::{Platform, PriceSource, TokenStatus, TradeDirection};
+
 // /// Represents the lifecycle state of a token.
 // #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 // pub enum TokenStatus {
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+
+// Mirror of `historian::models::kafka::KReq`; only `TokenReqFulfill` reaches `fulfill_req`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum KReqType {
+    PriceReqBondingCurve,
+    PriceReqPool,
+    PriceReqPoolState,
+    TokenReqFulfill,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KPriceReqBondingCurve {
+    pub bonding_curves: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KPriceReqPool {
+    pub pools: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KPriceReqPoolState {
+    pub pools_states: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KReqData {
+    PriceReqBondingCurve(KPriceReqBondingCurve),
+    PriceReqPool(KPriceReqPool),
+    PriceReqPoolState(KPriceReqPoolState),
+    TokenReqFulfill(Box<KTokenReqFulfill>),
+}
+
+/// Platforms the historian resolves tokens on. Kept as the full historian set so
+/// requests for platforms this service does not index still decode.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum KReqPlatform {
+    PumpFun,
+    PumpSwap,
+
+    RaydiumAmmV4,
+    RaydiumCpmm,
+    RaydiumClmm,
+    RaydiumLaunchLab,
+
+    MeteoraDlmm,
+    MeteoraDlmmLaunch,
+    MeteoraDammV1,
+    MeteoraDammV2,
+    MeteoraMemecoinV1,
+    MeteoraMemecoinV2,
+    MeteoraStake2Earn,
+    MeteoraDbc,
+
+    Unknown,
+}
+
+impl KReqPlatform {
+    /// Member of the Postgres `platform_type` enum
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KReqPlatform::PumpFun => "pump_fun",
+            KReqPlatform::PumpSwap => "pump_swap",
+
+            KReqPlatform::RaydiumAmmV4 => "raydium_amm_v4",
+            KReqPlatform::RaydiumCpmm => "raydium_cpmm",
+            KReqPlatform::RaydiumClmm => "raydium_clmm",
+            KReqPlatform::RaydiumLaunchLab => "raydium_launch_lab",
+
+            KReqPlatform::MeteoraDlmm => "meteora_dlmm",
+            KReqPlatform::MeteoraDlmmLaunch => "meteora_dlmm_launch",
+            KReqPlatform::MeteoraDammV1 => "meteora_damm_v1",
+            KReqPlatform::MeteoraDammV2 => "meteora_damm_v2",
+            KReqPlatform::MeteoraMemecoinV1 => "meteora_memecoin_v1",
+            KReqPlatform::MeteoraMemecoinV2 => "meteora_memecoin_v2",
+            KReqPlatform::MeteoraStake2Earn => "meteora_stake2_earn",
+            KReqPlatform::MeteoraDbc => "meteora_dbc",
+
+            KReqPlatform::Unknown => "unknown",
+        }
+    }
+}
+
+/// Token metadata resolved by the historian (on-chain metadata + off-chain JSON).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KTokenReqFulfill {
+    pub mint: String,
+    pub platform: KReqPlatform,
+
+    pub bonding_curve: Option<String>,
+    pub pool: Option<String>,
+    pub pool_state: Option<String>,
+
+    pub price: i64,
+    pub decimal: u8,
+
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub twitter: String,
+    #[serde(default)]
+    pub telegram: String,
+    #[serde(default)]
+    pub website: String,
+    #[serde(default)]
+    pub image: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KReq {
+    pub req_type: KReqType,
+    pub platform: KReqPlatform,
+    pub data: KReqData,
+}
@@ -1,2 +1,9 @@
+pub mod enums;
 pub mod kafka_event;
+pub mod kafka_req;
 pub mod pump_models;
+pub mod raydium_models;
+pub mod token_lifecycle;
+pub mod token_metadata;
+pub mod token_registry;
+pub mod ws_events;
@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use clickhouse::Row;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,3 +55,55 @@ pub struct PfChTrade {
     /* ========= Timestamp ========= */
     pub timestamp: DateTime<Utc>,
 }
+
+/// Candle widths kept in `pf_candles`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PfCandleInterval {
+    #[serde(rename = "1s")]
+    OneSecond,
+    #[serde(rename = "1m")]
+    OneMinute,
+    #[serde(rename = "5m")]
+    FiveMinutes,
+    #[serde(rename = "15m")]
+    FifteenMinutes,
+    #[serde(rename = "1h")]
+    OneHour,
+    #[serde(rename = "1d")]
+    OneDay,
+}
+
+impl PfCandleInterval {
+    /// Width in seconds, as stored in `pf_candles.interval_secs`
+    pub fn secs(self) -> u32 {
+        match self {
+            Self::OneSecond => 1,
+            Self::OneMinute => 60,
+            Self::FiveMinutes => 300,
+            Self::FifteenMinutes => 900,
+            Self::OneHour => 3_600,
+            Self::OneDay => 86_400,
+        }
+    }
+}
+
+/// OHLCV candle of one mint, read from `pf_candles`.
+/// Prices are marginal prices in lamports per whole token.
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct PfChCandle {
+    #[serde(with = "clickhouse::serde::chrono::datetime")]
+    pub bucket: DateTime<Utc>, // start of the candle
+
+    /* ========= Price ========= */
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+
+    /* ========= Volume ========= */
+    pub sol_volume: u64,     // SOL traded (lamports)
+    pub token_volume: u64,   // Tokens traded (raw units)
+    pub buy_count: u64,      // Buys in the candle
+    pub sell_count: u64,     // Sells in the candle
+    pub unique_traders: u64, // Distinct traders
+}
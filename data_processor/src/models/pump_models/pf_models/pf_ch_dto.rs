@@ -1,12 +1,18 @@
-use crate::models::pump_models::pf_models::pf_kafka_event::KPfChTrade;
+use crate::models::kafka_event::EventSource;
+use crate::models::pump_models::pf_models::pf_kafka_event::{KPfChTrade, KPfPrice};
+use crate::models::pump_models::pf_models::pf_pgsql_dto::{
+    PfPgsqlCreatorFeeDto, PfPgsqlTokenIncentiveDto,
+};
 use chrono::{DateTime, Utc};
+use clickhouse::Row;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
 pub struct PfChTradeDto {
     /* ========= Transaction identity ========= */
     pub signature: String, // Transaction signature (base58)
     pub slot: u64,         // Solana slot
+    pub tx_index: u64,     // Position of the transaction within its slot
     pub blockhash: String,
 
     /* ========= Actors ========= */
@@ -38,7 +44,7 @@ pub struct PfChTradeDto {
     pub creator_fee_basis_points: u64, // Creator fee bps
 
     /* ========= Market / bonding curve state (post-trade) ========= */
-    pub decimals: i16,
+    pub decimals: u32,
     pub virtual_sol_reserves: u64,   // Virtual SOL reserves
     pub virtual_token_reserves: u64, // Virtual token reserves
     pub real_sol_reserves: u64,      // Real SOL reserves
@@ -53,7 +59,13 @@ pub struct PfChTradeDto {
     pub last_update_timestamp: i64,  // Last update timestamp
 
     /* ========= Timestamp ========= */
-    pub timestamp: DateTime<Utc>,
+    #[serde(with = "clickhouse::serde::chrono::datetime64::millis")]
+    pub timestamp: DateTime<Utc>, // Block time
+
+    /* ========= Deduplication ========= */
+    pub ix_index: u32, // With the signature and ordinal, the ReplacingMergeTree key
+    pub event_ordinal: u32, // Position among the events the transaction emitted
+    pub version: u64,  // Processing time (ms); the latest copy survives merges
 }
 
 impl From<KPfChTrade> for PfChTradeDto {
@@ -62,6 +74,7 @@ impl From<KPfChTrade> for PfChTradeDto {
             /* ========= Transaction identity ========= */
             signature: src.signature,
             slot: src.slot,
+            tx_index: src.tx_index,
             blockhash: src.blockhash,
 
             /* ========= Actors ========= */
@@ -93,7 +106,7 @@ impl From<KPfChTrade> for PfChTradeDto {
             creator_fee_basis_points: src.creator_fee_basis_points,
 
             /* ========= Market / bonding curve state ========= */
-            decimals: src.decimals,
+            decimals: u32::from(src.decimals),
             virtual_sol_reserves: src.virtual_sol_reserves,
             virtual_token_reserves: src.virtual_token_reserves,
             real_sol_reserves: src.real_sol_reserves,
@@ -109,6 +122,148 @@ impl From<KPfChTrade> for PfChTradeDto {
 
             /* ========= Timestamp ========= */
             timestamp: src.ts,
+
+            /* ========= Deduplication ========= */
+            ix_index: src.ix_index,
+            event_ordinal: src.event_ordinal,
+            version: Utc::now().timestamp_millis() as u64,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct PfChCreatorFeeDto {
+    /* ========= Transaction identity ========= */
+    pub signature: String,
+    pub slot: u64,
+
+    /* ========= Creator ========= */
+    pub creator: String,
+    pub platform: String, // pump_fun | pump_swap
+    pub kind: i8,         // `Enum8` value of accrual | claim
+
+    /* ========= Amounts ========= */
+    pub mint: String, // traded mint for accruals, empty for claims
+    pub amount_lamports: u64,
+
+    /* ========= Timestamp ========= */
+    #[serde(with = "clickhouse::serde::chrono::datetime64::millis")]
+    pub timestamp: DateTime<Utc>, // Block time
+
+    /* ========= Deduplication ========= */
+    pub ix_index: u32, // With the signature and ordinal, the ReplacingMergeTree key
+    pub event_ordinal: u32, // Position among the events the transaction emitted
+    pub version: u64,  // Processing time (ms); the latest copy survives merges
+}
+
+impl From<PfPgsqlCreatorFeeDto> for PfChCreatorFeeDto {
+    fn from(src: PfPgsqlCreatorFeeDto) -> Self {
+        Self {
+            signature: src.signature,
+            slot: src.slot,
+            creator: src.creator,
+            platform: src.platform.as_str().to_string(),
+            kind: src.kind.ch_enum8(),
+            mint: src.mint.unwrap_or_default(),
+            amount_lamports: src.amount_lamports,
+            timestamp: src.ts,
+            ix_index: src.ix_index,
+            event_ordinal: src.event_ordinal,
+            version: Utc::now().timestamp_millis() as u64,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct PfChTokenIncentiveDto {
+    /* ========= Transaction identity ========= */
+    pub signature: String, // empty for account updates
+    pub slot: u64,
+
+    /* ========= Wallet ========= */
+    pub wallet: String,
+    pub source: i8, // `Enum8` value of trade | claim | account_update
+
+    /* ========= Amounts ========= */
+    pub mint: String, // incentive mint, empty when unknown
+    pub amount: u64,  // tokens claimed, 0 for other sources
+
+    /* ========= Accumulator state ========= */
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+
+    /* ========= Timestamp ========= */
+    #[serde(with = "clickhouse::serde::chrono::datetime64::millis")]
+    pub timestamp: DateTime<Utc>, // Block time
+
+    /* ========= Deduplication ========= */
+    pub ix_index: u32, // With the signature and ordinal, the ReplacingMergeTree key
+    pub event_ordinal: u32, // Position among the events the transaction emitted
+    pub version: u64,  // Processing time (ms); the latest copy survives merges
+}
+
+impl From<PfPgsqlTokenIncentiveDto> for PfChTokenIncentiveDto {
+    fn from(src: PfPgsqlTokenIncentiveDto) -> Self {
+        Self {
+            signature: src.signature.unwrap_or_default(),
+            slot: src.slot,
+            wallet: src.wallet,
+            source: src.source.ch_enum8(),
+            mint: src.mint.unwrap_or_default(),
+            amount: src.claim_amount.unwrap_or_default(),
+            total_unclaimed_tokens: src.total_unclaimed_tokens,
+            total_claimed_tokens: src.total_claimed_tokens,
+            current_sol_volume: src.current_sol_volume,
+            timestamp: src.ts,
+            ix_index: src.ix_index,
+            event_ordinal: src.event_ordinal,
+            version: Utc::now().timestamp_millis() as u64,
         }
     }
 }
+
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct PfChCurveStateDto {
+    /* ========= Identity ========= */
+    pub bonding_curve: String,
+    pub mint: String, // empty until the curve's token is known
+    pub slot: u64,
+    pub write_version: u64,
+    pub source: String, // producing service, `snapshot` for RPC fetches
+
+    /* ========= Curve state ========= */
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub token_total_supply: u64,
+    pub complete: bool,
+
+    /* ========= Timestamp ========= */
+    #[serde(with = "clickhouse::serde::chrono::datetime64::millis")]
+    pub timestamp: DateTime<Utc>,
+}
+
+pub fn build_pf_ch_curve_state(
+    src: &KPfPrice,
+    mint: Option<String>,
+    source: EventSource,
+) -> PfChCurveStateDto {
+    PfChCurveStateDto {
+        bonding_curve: src.bonding_curve.clone(),
+        mint: mint.unwrap_or_default(),
+        slot: src.slot,
+        write_version: src.write_version,
+        source: source.as_str().to_string(),
+
+        virtual_token_reserves: src.virtual_token_reserves,
+        virtual_sol_reserves: src.virtual_sol_reserves,
+        real_token_reserves: src.real_token_reserves,
+        real_sol_reserves: src.real_sol_reserves,
+        token_total_supply: src.token_total_supply,
+        complete: src.complete,
+
+        timestamp: src.ts,
+    }
+}
@@ -17,3 +17,73 @@ pub enum PfTradeDirection {
     Buy,
     Sell,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "pf_creator_fee_platform")]
+#[sqlx(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PfCreatorFeePlatform {
+    PumpFun,
+    PumpSwap,
+}
+
+impl PfCreatorFeePlatform {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PfCreatorFeePlatform::PumpFun => "pump_fun",
+            PfCreatorFeePlatform::PumpSwap => "pump_swap",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PfCreatorFeeKind {
+    Accrual,
+    Claim,
+}
+
+impl PfCreatorFeeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PfCreatorFeeKind::Accrual => "accrual",
+            PfCreatorFeeKind::Claim => "claim",
+        }
+    }
+
+    /// Value of the member in `pf_creator_fee_ledger.kind`
+    pub fn ch_enum8(&self) -> i8 {
+        match self {
+            PfCreatorFeeKind::Accrual => 1,
+            PfCreatorFeeKind::Claim => 2,
+        }
+    }
+}
+
+/// What produced a row in the token incentive ledger
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PfTokenIncentiveSource {
+    Trade,
+    Claim,
+    AccountUpdate,
+}
+
+impl PfTokenIncentiveSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PfTokenIncentiveSource::Trade => "trade",
+            PfTokenIncentiveSource::Claim => "claim",
+            PfTokenIncentiveSource::AccountUpdate => "account_update",
+        }
+    }
+
+    /// Value of the member in `pf_token_incentive_ledger.source`
+    pub fn ch_enum8(&self) -> i8 {
+        match self {
+            PfTokenIncentiveSource::Trade => 1,
+            PfTokenIncentiveSource::Claim => 2,
+            PfTokenIncentiveSource::AccountUpdate => 3,
+        }
+    }
+}
@@ -1,7 +1,10 @@
-use crate::models::pump_models::pf_models::pf_enums::{PfPriceSource, PfTradeDirection};
+use crate::models::pump_models::pf_models::pf_enums::{
+    PfCreatorFeePlatform, PfPriceSource, PfTradeDirection,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use sqlx::types::Json;
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct PfPgsqlPrice {
@@ -50,3 +53,111 @@ pub struct PfPgsqlBondingCurveProgress {
     pub created_at: DateTime<Utc>, // internal, creation timestamp in DB
     pub updated_at: DateTime<Utc>, // internal, last update timestamp
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PfPgsqlCreatorFee {
+    /* ========= Identity ========= */
+    pub creator: String,                // Creator / coin creator wallet
+    pub platform: PfCreatorFeePlatform, // Where the fees were earned
+
+    /* ========= Balances (lamports) ========= */
+    pub accrued_lamports: i64, // Sum of creator fees paid by indexed trades
+    pub claimed_lamports: i64, // Sum of indexed claims
+    pub unclaimed_lamports: i64, // max(accrued - claimed, 0)
+
+    /* ========= Counters ========= */
+    pub accrual_count: i64,
+    pub claim_count: i64,
+
+    /* ========= Last activity ========= */
+    pub last_accrual_signature: Option<String>,
+    pub last_claim_signature: Option<String>,
+    pub last_accrual_ts: Option<DateTime<Utc>>,
+    pub last_claim_ts: Option<DateTime<Utc>>,
+
+    pub created_at: DateTime<Utc>, // internal, creation timestamp in DB
+    pub updated_at: DateTime<Utc>, // internal, last update timestamp
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PfPgsqlTokenIncentive {
+    /* ========= Identity ========= */
+    pub wallet: String,       // Trader wallet owning the UserVolumeAccumulator
+    pub mint: Option<String>, // Incentive token mint, once seen
+
+    /* ========= Accumulator state (raw token units) ========= */
+    pub needs_claim: bool,
+    pub total_unclaimed_tokens: i64,
+    pub total_claimed_tokens: i64,
+    pub current_sol_volume: i64, // Lamports traded in the current period
+    pub last_update_timestamp: i64, // On-chain unix time of the last sync
+    pub slot: i64,               // Slot the state was observed at
+
+    /* ========= Claims ========= */
+    pub claim_count: i64,
+    pub last_claim_signature: Option<String>,
+    pub last_claim_amount: Option<i64>,
+    pub last_claim_ts: Option<DateTime<Utc>>,
+
+    pub created_at: DateTime<Utc>, // internal, creation timestamp in DB
+    pub updated_at: DateTime<Utc>, // internal, last update timestamp
+}
+
+/// One version of the Pump.fun `Global` parameters
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PfPgsqlGlobalParams {
+    /* ========= Version ========= */
+    pub version: i64,
+    pub effective_slot: i64, // First slot these parameters were observed at
+
+    /* ========= Curve launch parameters ========= */
+    pub fee_recipient: String,
+    pub initial_virtual_token_reserves: i64,
+    pub initial_virtual_sol_reserves: i64,
+    pub initial_real_token_reserves: i64, // Tokens sold along the curve before completion
+    pub token_total_supply: i64,
+
+    /* ========= Fees & migration ========= */
+    pub fee_basis_points: i64,
+    pub creator_fee_basis_points: i64,
+    pub enable_migrate: bool,
+    pub pool_migration_fee: i64,
+
+    pub observed_at: DateTime<Utc>, // wall-clock time of the account update
+    pub created_at: DateTime<Utc>,  // internal, creation timestamp in DB
+}
+
+/// Fee split of one Pump Fees market cap tier, stored in `pf_fee_configs.fee_tiers`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PfFeeTier {
+    pub market_cap_lamports_threshold: u64,
+    pub lp_fee_bps: u64,
+    pub protocol_fee_bps: u64,
+    pub creator_fee_bps: u64,
+}
+
+/// One version of the Pump Fees `FeeConfig` for Pump.fun
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PfPgsqlFeeConfig {
+    /* ========= Version ========= */
+    pub version: i64,
+    pub effective_slot: i64, // First slot this fee schedule was observed at
+
+    /* ========= Fees (bps) ========= */
+    pub flat_lp_fee_bps: i64,
+    pub flat_protocol_fee_bps: i64,
+    pub flat_creator_fee_bps: i64,
+    pub fee_tiers: Json<Vec<PfFeeTier>>, // ascending by threshold
+
+    pub observed_at: DateTime<Utc>, // wall-clock time of the account update
+    pub created_at: DateTime<Utc>,  // internal, creation timestamp in DB
+}
+
+/// What account updates need to know about a bonding curve's token, taken from `pf_prices`
+#[derive(Debug, Clone, FromRow)]
+pub struct PfPgsqlCurveToken {
+    pub mint: String,
+    pub decimals: i16,
+    pub virtual_sol_reserves: i64, // as of the last stored price
+    pub is_buy: bool,              // direction of the last stored price
+}
@@ -1,5 +1,14 @@
-use crate::models::pump_models::pf_models::pf_enums::{PfPriceSource, PfTradeDirection};
-use crate::models::pump_models::pf_models::pf_kafka_event::KPfChTrade;
+use crate::models::kafka_event::KEventType;
+use crate::models::pump_models::pf_models::pf_enums::{
+    PfCreatorFeeKind, PfCreatorFeePlatform, PfPriceSource, PfTokenIncentiveSource, PfTradeDirection,
+};
+use crate::models::pump_models::pf_models::pf_kafka_event::{
+    KPfChTrade, KPfCreatorFeeClaim, KPfFeeConfig, KPfGlobalConfig, KPfPrice, KPfToken,
+    KPfTokenIncentiveClaim, KPfUserVolumeAccumulator,
+};
+use crate::models::pump_models::pf_models::pf_pgsql::{PfFeeTier, PfPgsqlCurveToken};
+use crate::models::pump_models::ps_models::ps_kafka_event::{KPsCreatorFeeClaim, KPsTrade};
+use crate::models::token_registry::WSOL_MINT;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
@@ -17,9 +26,18 @@ pub struct PfPgsqlPriceDto {
     pub virtual_sol_reserves: i64,
     pub real_token_reserves: i64,
     pub real_sol_reserves: i64,
+    pub slot: i64,     // slot of the state
+    pub tx_index: i64, // position in the slot; older (slot, tx_index) never overwrite newer ones
     pub ts: DateTime<Utc>,
 }
 
+/// `tx_index` of state read from an account update. Geyser gives the write's slot but not
+/// its position in the slot, so it ranks before every trade of its slot: each curve change
+/// comes from a trade whose event carries the post-trade state, and the slot's last trade
+/// holds its final state. The update only stands for slots whose trades have not been seen;
+/// later updates of the slot still replace it.
+pub const START_OF_SLOT_TX_INDEX: i64 = -1;
+
 #[derive(Debug, Clone)]
 pub struct PfPgsqlBondingCurveProgressDto {
     /* ========= Identity ========= */
@@ -44,6 +62,90 @@ pub struct PfPgsqlBondingCurveProgressDto {
     pub is_pre_migration: bool,
     pub is_migrated: bool,
     pub is_tradeable: bool,
+
+    pub slot: u64, // slot of the state; older slots never overwrite newer ones
+}
+
+/// A single creator fee movement: one trade paying a creator fee, or one claim.
+#[derive(Debug, Clone)]
+pub struct PfPgsqlCreatorFeeDto {
+    /* ========= Transaction identity ========= */
+    pub signature: String,
+    pub ix_index: u32,
+    pub event_type: KEventType, // event the movement comes from
+    pub event_ordinal: u32,
+    pub slot: u64,
+
+    /* ========= Creator ========= */
+    pub creator: String,
+    pub platform: PfCreatorFeePlatform,
+    pub kind: PfCreatorFeeKind,
+
+    /* ========= Amount ========= */
+    pub mint: Option<String>, // traded mint (accruals only)
+    pub amount_lamports: u64,
+
+    pub ts: DateTime<Utc>,
+}
+
+/// `Global` parameters as observed at `slot`; stored only if they differ from the version in effect.
+#[derive(Debug, Clone)]
+pub struct PfPgsqlGlobalParamsDto {
+    pub slot: u64,
+
+    pub fee_recipient: String,
+    pub initial_virtual_token_reserves: u64,
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_real_token_reserves: u64,
+    pub token_total_supply: u64,
+
+    pub fee_basis_points: u64,
+    pub creator_fee_basis_points: u64,
+    pub enable_migrate: bool,
+    pub pool_migration_fee: u64,
+
+    pub ts: DateTime<Utc>,
+}
+
+/// `FeeConfig` as observed at `slot`; stored only if it differs from the version in effect.
+#[derive(Debug, Clone)]
+pub struct PfPgsqlFeeConfigDto {
+    pub slot: u64,
+
+    pub flat_lp_fee_bps: u64,
+    pub flat_protocol_fee_bps: u64,
+    pub flat_creator_fee_bps: u64,
+    pub fee_tiers: Vec<PfFeeTier>,
+
+    pub ts: DateTime<Utc>,
+}
+
+/// A single change to a wallet's volume incentive state: a tracked trade, a claim or an accumulator update.
+#[derive(Debug, Clone)]
+pub struct PfPgsqlTokenIncentiveDto {
+    /* ========= Transaction identity ========= */
+    pub signature: Option<String>, // None for account updates
+    pub ix_index: u32,
+    pub event_type: KEventType, // event the update comes from
+    pub event_ordinal: u32,
+    pub slot: u64,
+
+    /* ========= Wallet ========= */
+    pub wallet: String,
+    pub source: PfTokenIncentiveSource,
+    pub mint: Option<String>, // incentive mint (claims only)
+
+    /* ========= Accumulator state (raw token units) ========= */
+    pub needs_claim: bool,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+
+    /* ========= Claim ========= */
+    pub claim_amount: Option<u64>, // tokens paid out (claims only)
+
+    pub ts: DateTime<Utc>,
 }
 
 /// Build `PfPgsqlPriceDto` from `KPfChTrade`, validating invariants.
@@ -78,21 +180,25 @@ pub fn build_pf_pgsql_price(src: KPfChTrade) -> Result<PfPgsqlPriceDto, String>
             PfTradeDirection::Sell
         },
 
-        decimals: src.decimals,
+        decimals: i16::from(src.decimals),
 
         virtual_token_reserves: src.virtual_token_reserves as i64,
         virtual_sol_reserves: src.virtual_sol_reserves as i64,
         real_token_reserves: src.real_token_reserves as i64,
         real_sol_reserves: src.real_sol_reserves as i64,
 
+        slot: src.slot as i64,
+        tx_index: src.tx_index as i64,
         ts: src.ts,
     })
 }
 
 /// Build `PfPgsqlBondingCurveProgressDto` from `KPfChTrade`,
 /// validating Pump.fun bonding-curve invariants.
+/// `initial_real_token_reserves` must come from the `Global` version in effect at the trade's slot.
 pub fn build_pf_pgsql_bonding_curve_progress(
     src: KPfChTrade,
+    initial_real_token_reserves: u64,
 ) -> Result<PfPgsqlBondingCurveProgressDto, String> {
     // Invariants
     if src.virtual_token_reserves == 0 {
@@ -110,18 +216,17 @@ pub fn build_pf_pgsql_bonding_curve_progress(
         / src.virtual_token_reserves as u128;
 
     // Progress (basis points)
-    // Conceptually: how much of real token supply has been consumed.
-    // progress = (claimed / (claimed + remaining)) * 10_000
-    let total_tokens = src
-        .total_claimed_tokens
-        .checked_add(src.real_token_reserves)
-        .ok_or("build_pf_pgsql_bonding_curve_progress: token sum overflow")?;
-
-    if total_tokens == 0 {
-        return Err("build_pf_pgsql_bonding_curve_progress: total_tokens is zero".into());
+    // Conceptually: how much of the curve's sellable supply has been bought.
+    // progress = ((initial_real - remaining) / initial_real) * 10_000
+    if initial_real_token_reserves == 0 {
+        return Err(
+            "build_pf_pgsql_bonding_curve_progress: initial_real_token_reserves is zero".into(),
+        );
     }
 
-    let progress_bps = ((src.total_claimed_tokens as u128 * 10_000) / total_tokens as u128) as u16;
+    let sold_tokens = initial_real_token_reserves.saturating_sub(src.real_token_reserves);
+    let progress_bps =
+        ((sold_tokens as u128 * 10_000) / initial_real_token_reserves as u128).min(10_000) as u16;
 
     let progress_pct = progress_bps as f64 / 100.0;
 
@@ -137,7 +242,7 @@ pub fn build_pf_pgsql_bonding_curve_progress(
         last_signature: Some(src.signature),
 
         /* ========= Reserves ========= */
-        decimals: src.decimals,
+        decimals: i16::from(src.decimals),
 
         virtual_sol_reserves: src.virtual_sol_reserves,
         virtual_token_reserves: src.virtual_token_reserves,
@@ -154,5 +259,426 @@ pub fn build_pf_pgsql_bonding_curve_progress(
         is_pre_migration,
         is_migrated,
         is_tradeable,
+
+        slot: src.slot,
     })
 }
+
+/// Build `PfPgsqlPriceDto` from a bonding curve account update.
+/// The account carries neither mint nor decimals, so `curve` must come from the stored price;
+/// the direction is inferred from the move in SOL reserves.
+pub fn build_pf_pgsql_price_from_account(
+    src: &KPfPrice,
+    curve: &PfPgsqlCurveToken,
+) -> Result<PfPgsqlPriceDto, String> {
+    let price = pf_marginal_price(
+        src.virtual_sol_reserves,
+        src.virtual_token_reserves,
+        curve.decimals,
+    )
+    .map_err(|e| format!("build_pf_pgsql_price_from_account: {e}"))?;
+
+    let is_buy = match (src.virtual_sol_reserves as i64).cmp(&curve.virtual_sol_reserves) {
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Less => false,
+        std::cmp::Ordering::Equal => curve.is_buy,
+    };
+
+    Ok(PfPgsqlPriceDto {
+        mint: curve.mint.clone(),
+        bonding_curve: src.bonding_curve.clone(),
+        last_signature: None,
+
+        price: price as i64,
+        source: PfPriceSource::PfBondingCurve,
+        direction: if is_buy {
+            PfTradeDirection::Buy
+        } else {
+            PfTradeDirection::Sell
+        },
+
+        decimals: curve.decimals,
+
+        virtual_token_reserves: src.virtual_token_reserves as i64,
+        virtual_sol_reserves: src.virtual_sol_reserves as i64,
+        real_token_reserves: src.real_token_reserves as i64,
+        real_sol_reserves: src.real_sol_reserves as i64,
+
+        slot: src.slot as i64,
+        tx_index: START_OF_SLOT_TX_INDEX,
+        ts: src.ts,
+    })
+}
+
+/// Build `PfPgsqlBondingCurveProgressDto` from a bonding curve account update.
+/// A completed curve stops trading; migration itself is only known from the migrate event.
+pub fn build_pf_pgsql_bonding_curve_progress_from_account(
+    src: &KPfPrice,
+    curve: &PfPgsqlCurveToken,
+    initial_real_token_reserves: u64,
+) -> Result<PfPgsqlBondingCurveProgressDto, String> {
+    let price_lamports = pf_marginal_price(
+        src.virtual_sol_reserves,
+        src.virtual_token_reserves,
+        curve.decimals,
+    )
+    .map_err(|e| format!("build_pf_pgsql_bonding_curve_progress_from_account: {e}"))?;
+    let progress_bps = pf_progress_bps(initial_real_token_reserves, src.real_token_reserves)
+        .map_err(|e| format!("build_pf_pgsql_bonding_curve_progress_from_account: {e}"))?;
+
+    // Market cap = marginal price of the whole supply
+    let scale = 10_u128.pow(curve.decimals as u32);
+    let market_cap_lamports = price_lamports * src.token_total_supply as u128 / scale;
+
+    Ok(PfPgsqlBondingCurveProgressDto {
+        mint: curve.mint.clone(),
+        bonding_curve: src.bonding_curve.clone(),
+        last_signature: None,
+
+        decimals: curve.decimals,
+
+        virtual_sol_reserves: src.virtual_sol_reserves,
+        virtual_token_reserves: src.virtual_token_reserves,
+        real_sol_reserves: src.real_sol_reserves,
+        real_token_reserves: src.real_token_reserves,
+
+        progress_bps: if src.complete { 10_000 } else { progress_bps },
+        progress_pct: if src.complete {
+            100.0
+        } else {
+            progress_bps as f64 / 100.0
+        },
+        price_lamports: price_lamports as u64,
+        market_cap_lamports: u64::try_from(market_cap_lamports).unwrap_or(u64::MAX),
+
+        is_pre_migration: true,
+        is_migrated: false,
+        is_tradeable: !src.complete,
+
+        slot: src.slot,
+    })
+}
+
+/// Build the opening `PfPgsqlPriceDto` of a newly created token, created at `slot`.
+pub fn build_pf_pgsql_price_from_token(
+    src: &KPfToken,
+    slot: u64,
+) -> Result<PfPgsqlPriceDto, String> {
+    let price = pf_marginal_price(
+        src.virtual_sol_reserves as u64,
+        src.virtual_token_reserves as u64,
+        src.decimals,
+    )
+    .map_err(|e| format!("build_pf_pgsql_price_from_token: {e}"))?;
+
+    Ok(PfPgsqlPriceDto {
+        mint: src.mint.clone(),
+        bonding_curve: src.bonding_curve.clone(),
+        last_signature: None,
+
+        price: price as i64,
+        source: PfPriceSource::PfBondingCurve,
+        // Nothing has traded yet; the curve can only be bought first
+        direction: PfTradeDirection::Buy,
+
+        decimals: src.decimals,
+
+        virtual_token_reserves: src.virtual_token_reserves,
+        virtual_sol_reserves: src.virtual_sol_reserves,
+        real_token_reserves: src.real_token_reserves,
+        real_sol_reserves: 0,
+
+        slot: slot as i64,
+        tx_index: src.tx_index as i64,
+        ts: src.ts,
+    })
+}
+
+/// Build the opening `PfPgsqlBondingCurveProgressDto` of a newly created token, created at `slot`.
+pub fn build_pf_pgsql_bonding_curve_progress_from_token(
+    src: &KPfToken,
+    initial_real_token_reserves: u64,
+    slot: u64,
+) -> Result<PfPgsqlBondingCurveProgressDto, String> {
+    let price_lamports = pf_marginal_price(
+        src.virtual_sol_reserves as u64,
+        src.virtual_token_reserves as u64,
+        src.decimals,
+    )
+    .map_err(|e| format!("build_pf_pgsql_bonding_curve_progress_from_token: {e}"))?;
+    let progress_bps = pf_progress_bps(initial_real_token_reserves, src.real_token_reserves as u64)
+        .map_err(|e| format!("build_pf_pgsql_bonding_curve_progress_from_token: {e}"))?;
+
+    let scale = 10_u128.pow(src.decimals as u32);
+    let market_cap_lamports = price_lamports * src.token_total_supply as u128 / scale;
+
+    Ok(PfPgsqlBondingCurveProgressDto {
+        mint: src.mint.clone(),
+        bonding_curve: src.bonding_curve.clone(),
+        last_signature: None,
+
+        decimals: src.decimals,
+
+        virtual_sol_reserves: src.virtual_sol_reserves as u64,
+        virtual_token_reserves: src.virtual_token_reserves as u64,
+        real_sol_reserves: 0,
+        real_token_reserves: src.real_token_reserves as u64,
+
+        progress_bps,
+        progress_pct: progress_bps as f64 / 100.0,
+        price_lamports: price_lamports as u64,
+        market_cap_lamports: u64::try_from(market_cap_lamports).unwrap_or(u64::MAX),
+
+        is_pre_migration: true,
+        is_migrated: false,
+        is_tradeable: true,
+
+        slot,
+    })
+}
+
+/// Marginal curve price in lamports per whole token
+fn pf_marginal_price(
+    virtual_sol_reserves: u64,
+    virtual_token_reserves: u64,
+    decimals: i16,
+) -> Result<u128, String> {
+    if virtual_token_reserves == 0 {
+        return Err("virtual_token_reserves is zero".into());
+    }
+
+    let scale = 10_u128
+        .checked_pow(decimals as u32)
+        .ok_or("decimals overflow")?;
+
+    Ok((virtual_sol_reserves as u128)
+        .checked_mul(scale)
+        .ok_or("price overflow")?
+        / virtual_token_reserves as u128)
+}
+
+/// Share of the curve's sellable supply already bought, in basis points
+fn pf_progress_bps(
+    initial_real_token_reserves: u64,
+    real_token_reserves: u64,
+) -> Result<u16, String> {
+    if initial_real_token_reserves == 0 {
+        return Err("initial_real_token_reserves is zero".into());
+    }
+
+    let sold_tokens = initial_real_token_reserves.saturating_sub(real_token_reserves);
+    Ok(((sold_tokens as u128 * 10_000) / initial_real_token_reserves as u128).min(10_000) as u16)
+}
+
+/// Build a Pump.fun creator fee accrual from `KPfChTrade`.
+/// Returns `Err` for trades that paid no creator fee.
+pub fn build_pf_pgsql_creator_fee_accrual(
+    src: &KPfChTrade,
+) -> Result<PfPgsqlCreatorFeeDto, String> {
+    if src.creator_fee_lamports == 0 {
+        return Err("build_pf_pgsql_creator_fee_accrual: no creator fee".into());
+    }
+
+    Ok(PfPgsqlCreatorFeeDto {
+        signature: src.signature.clone(),
+        ix_index: src.ix_index,
+        event_type: KEventType::PfChTradeOccurred,
+        event_ordinal: src.event_ordinal,
+        slot: src.slot,
+        creator: src.creator.clone(),
+        platform: PfCreatorFeePlatform::PumpFun,
+        kind: PfCreatorFeeKind::Accrual,
+        mint: Some(src.mint.clone()),
+        amount_lamports: src.creator_fee_lamports,
+        ts: src.ts,
+    })
+}
+
+/// Build a PumpSwap coin creator fee accrual from `KPsTrade`.
+/// Returns `Err` when the trade carries no coin creator fee (pre-fee pools, older producers),
+/// or when the pool is not quoted in WSOL: the balance is kept in lamports only.
+pub fn build_ps_pgsql_creator_fee_accrual(
+    src: &KPsTrade,
+    slot: u64,
+) -> Result<PfPgsqlCreatorFeeDto, String> {
+    if src.quote_mint.as_deref() != Some(WSOL_MINT) {
+        return Err("build_ps_pgsql_creator_fee_accrual: quote mint is not WSOL".into());
+    }
+
+    let creator = src
+        .coin_creator
+        .clone()
+        .ok_or("build_ps_pgsql_creator_fee_accrual: coin_creator missing")?;
+
+    let amount_lamports = match src.coin_creator_fee {
+        Some(fee) if fee > 0 => fee,
+        _ => return Err("build_ps_pgsql_creator_fee_accrual: no coin creator fee".into()),
+    };
+
+    Ok(PfPgsqlCreatorFeeDto {
+        signature: src.signature.clone(),
+        ix_index: src.ix_index,
+        event_type: KEventType::PsTradeOccurred,
+        event_ordinal: src.event_ordinal,
+        slot,
+        creator,
+        platform: PfCreatorFeePlatform::PumpSwap,
+        kind: PfCreatorFeeKind::Accrual,
+        mint: Some(src.mint.clone()),
+        amount_lamports,
+        ts: src.ts,
+    })
+}
+
+impl From<KPfCreatorFeeClaim> for PfPgsqlCreatorFeeDto {
+    fn from(src: KPfCreatorFeeClaim) -> Self {
+        Self {
+            signature: src.signature,
+            ix_index: src.ix_index,
+            event_type: KEventType::PfCreatorFeeClaimed,
+            event_ordinal: src.event_ordinal,
+            slot: src.slot,
+            creator: src.creator,
+            platform: PfCreatorFeePlatform::PumpFun,
+            kind: PfCreatorFeeKind::Claim,
+            mint: None,
+            amount_lamports: src.creator_fee,
+            ts: src.ts,
+        }
+    }
+}
+
+/// Build a PumpSwap coin creator fee claim from `KPsCreatorFeeClaim`.
+/// Returns `Err` when the fees were not paid in WSOL: the balance is kept in lamports only.
+pub fn build_ps_pgsql_creator_fee_claim(
+    src: KPsCreatorFeeClaim,
+) -> Result<PfPgsqlCreatorFeeDto, String> {
+    if src.quote_mint != WSOL_MINT {
+        return Err("build_ps_pgsql_creator_fee_claim: quote mint is not WSOL".into());
+    }
+
+    Ok(PfPgsqlCreatorFeeDto {
+        signature: src.signature,
+        ix_index: src.ix_index,
+        event_type: KEventType::PsCreatorFeeClaimed,
+        event_ordinal: src.event_ordinal,
+        slot: src.slot,
+        creator: src.coin_creator,
+        platform: PfCreatorFeePlatform::PumpSwap,
+        kind: PfCreatorFeeKind::Claim,
+        mint: None,
+        amount_lamports: src.coin_creator_fee,
+        ts: src.ts,
+    })
+}
+
+/// Build a token incentive snapshot from a volume-tracked `KPfChTrade`.
+/// Returns `Err` for trades that do not count towards incentives.
+pub fn build_pf_pgsql_token_incentive(
+    src: &KPfChTrade,
+) -> Result<PfPgsqlTokenIncentiveDto, String> {
+    if !src.track_volume {
+        return Err("build_pf_pgsql_token_incentive: volume not tracked".into());
+    }
+
+    Ok(PfPgsqlTokenIncentiveDto {
+        signature: Some(src.signature.clone()),
+        ix_index: src.ix_index,
+        event_type: KEventType::PfChTradeOccurred,
+        event_ordinal: src.event_ordinal,
+        slot: src.slot,
+        wallet: src.user.clone(),
+        source: PfTokenIncentiveSource::Trade,
+        mint: None,
+        needs_claim: src.total_unclaimed_tokens > 0,
+        total_unclaimed_tokens: src.total_unclaimed_tokens,
+        total_claimed_tokens: src.total_claimed_tokens,
+        current_sol_volume: src.current_sol_volume,
+        last_update_timestamp: src.last_update_timestamp,
+        claim_amount: None,
+        ts: src.ts,
+    })
+}
+
+/// Build a token incentive snapshot from a `UserVolumeAccumulator` account update
+pub fn build_pf_pgsql_token_incentive_from_account(
+    src: KPfUserVolumeAccumulator,
+    slot: u64,
+) -> PfPgsqlTokenIncentiveDto {
+    PfPgsqlTokenIncentiveDto {
+        signature: None,
+        ix_index: 0,
+        event_type: KEventType::PfUserVolumeAccumulatorUpdated,
+        event_ordinal: 0,
+        slot,
+        wallet: src.user,
+        source: PfTokenIncentiveSource::AccountUpdate,
+        mint: None,
+        needs_claim: src.needs_claim,
+        total_unclaimed_tokens: src.total_unclaimed_tokens,
+        total_claimed_tokens: src.total_claimed_tokens,
+        current_sol_volume: src.current_sol_volume,
+        last_update_timestamp: src.last_update_timestamp,
+        claim_amount: None,
+        ts: src.ts,
+    }
+}
+
+// A claim pays out everything accrued so far, leaving nothing unclaimed.
+impl From<KPfTokenIncentiveClaim> for PfPgsqlTokenIncentiveDto {
+    fn from(src: KPfTokenIncentiveClaim) -> Self {
+        Self {
+            signature: Some(src.signature),
+            ix_index: src.ix_index,
+            event_type: KEventType::PfTokenIncentivesClaimed,
+            event_ordinal: src.event_ordinal,
+            slot: src.slot,
+            wallet: src.user,
+            source: PfTokenIncentiveSource::Claim,
+            mint: Some(src.mint),
+            needs_claim: false,
+            total_unclaimed_tokens: 0,
+            total_claimed_tokens: src.total_claimed_tokens,
+            current_sol_volume: src.current_sol_volume,
+            last_update_timestamp: src.ts.timestamp(),
+            claim_amount: Some(src.amount),
+            ts: src.ts,
+        }
+    }
+}
+
+pub fn build_pf_pgsql_global_params(src: KPfGlobalConfig, slot: u64) -> PfPgsqlGlobalParamsDto {
+    PfPgsqlGlobalParamsDto {
+        slot,
+        fee_recipient: src.fee_recipient,
+        initial_virtual_token_reserves: src.initial_virtual_token_reserves,
+        initial_virtual_sol_reserves: src.initial_virtual_sol_reserves,
+        initial_real_token_reserves: src.initial_real_token_reserves,
+        token_total_supply: src.token_total_supply,
+        fee_basis_points: src.fee_basis_points,
+        creator_fee_basis_points: src.creator_fee_basis_points,
+        enable_migrate: src.enable_migrate,
+        pool_migration_fee: src.pool_migration_fee,
+        ts: src.ts,
+    }
+}
+
+pub fn build_pf_pgsql_fee_config(src: KPfFeeConfig, slot: u64) -> PfPgsqlFeeConfigDto {
+    PfPgsqlFeeConfigDto {
+        slot,
+        flat_lp_fee_bps: src.flat_fees.lp_fee_bps,
+        flat_protocol_fee_bps: src.flat_fees.protocol_fee_bps,
+        flat_creator_fee_bps: src.flat_fees.creator_fee_bps,
+        fee_tiers: src
+            .fee_tiers
+            .into_iter()
+            .map(|t| PfFeeTier {
+                market_cap_lamports_threshold: t.market_cap_lamports_threshold,
+                lp_fee_bps: t.fees.lp_fee_bps,
+                protocol_fee_bps: t.fees.protocol_fee_bps,
+                creator_fee_bps: t.fees.creator_fee_bps,
+            })
+            .collect(),
+        ts: src.ts,
+    }
+}
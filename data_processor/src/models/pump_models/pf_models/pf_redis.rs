@@ -1,4 +1,9 @@
-use crate::models::pump_models::pf_models::pf_enums::{PfPriceSource, PfTradeDirection};
+use crate::models::kafka_req::KTokenReqFulfill;
+use crate::models::pump_models::pf_models::pf_enums::{
+    PfCreatorFeePlatform, PfPriceSource, PfTradeDirection,
+};
+use crate::models::pump_models::pf_models::pf_kafka_event::{KPfGlobalVolumeAccumulator, KPfToken};
+use crate::models::pump_models::pf_models::pf_pgsql::{PfPgsqlCreatorFee, PfPgsqlTokenIncentive};
 use crate::models::pump_models::pf_models::pf_pgsql_dto::{
     PfPgsqlBondingCurveProgressDto, PfPgsqlPriceDto,
 };
@@ -30,6 +35,11 @@ pub struct PfRedisPrice {
     pub website: Option<String>,
     pub image: Option<String>,
 
+    #[serde(default)]
+    pub slot: i64, // slot of the state
+    #[serde(default)]
+    pub tx_index: i64, // position in the slot; older (slot, tx_index) never overwrite newer ones
+
     pub ts: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -64,6 +74,9 @@ impl From<PfPgsqlPriceDto> for PfRedisPrice {
             website: None,
             image: None,
 
+            slot: src.slot,
+            tx_index: src.tx_index,
+
             ts: src.ts,
             created_at: now,
             updated_at: now,
@@ -71,6 +84,65 @@ impl From<PfPgsqlPriceDto> for PfRedisPrice {
     }
 }
 
+/// Opening price of a new token, with the metadata from its create instruction
+pub fn build_pf_redis_price_from_token(dto: PfPgsqlPriceDto, src: &KPfToken) -> PfRedisPrice {
+    let non_empty = |s: &str| (!s.is_empty()).then(|| s.to_string());
+
+    PfRedisPrice {
+        name: non_empty(&src.name),
+        symbol: non_empty(&src.symbol),
+        uri: src.uri.clone(),
+        description: non_empty(&src.description),
+        twitter: non_empty(&src.twitter),
+        telegram: non_empty(&src.telegram),
+        website: non_empty(&src.website),
+        image: non_empty(&src.image),
+        ..PfRedisPrice::from(dto)
+    }
+}
+
+/// Seed entry from a fulfill request; reserves stay zero until the curve trades or updates.
+pub fn build_pf_redis_price_from_fulfill(src: &KTokenReqFulfill) -> Result<PfRedisPrice, String> {
+    let bonding_curve = src
+        .bonding_curve
+        .clone()
+        .ok_or("build_pf_redis_price_from_fulfill: bonding_curve missing")?;
+    let non_empty = |s: &str| (!s.is_empty()).then(|| s.to_string());
+    let now = Utc::now();
+
+    Ok(PfRedisPrice {
+        mint: src.mint.clone(),
+        bonding_curve,
+
+        name: non_empty(&src.name),
+        symbol: non_empty(&src.symbol),
+
+        price: src.price,
+        source: PfPriceSource::Unknown,
+        direction: PfTradeDirection::Buy,
+        decimals: i16::from(src.decimal),
+        virtual_token_reserves: 0,
+        virtual_sol_reserves: 0,
+        real_token_reserves: 0,
+        real_sol_reserves: 0,
+
+        uri: non_empty(&src.uri),
+        description: non_empty(&src.description),
+        twitter: non_empty(&src.twitter),
+        telegram: non_empty(&src.telegram),
+        website: non_empty(&src.website),
+        image: non_empty(&src.image),
+
+        // no on-chain position; any stored price is newer
+        slot: 0,
+        tx_index: 0,
+
+        ts: now,
+        created_at: now,
+        updated_at: now,
+    })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct PfRedisBondingCurveProgress {
     /* ========= Identity ========= */
@@ -95,6 +167,8 @@ pub struct PfRedisBondingCurveProgress {
     pub is_tradeable: bool,
 
     /* ========= Timing ========= */
+    #[serde(default)]
+    pub slot: u64, // slot of the state; older slots never overwrite newer ones
     pub last_trade_slot: u64,
     pub last_update_ts: i64,
 
@@ -129,6 +203,7 @@ impl From<PfPgsqlBondingCurveProgressDto> for PfRedisBondingCurveProgress {
             is_tradeable: src.is_tradeable,
 
             /* ========= Timing ========= */
+            slot: src.slot,
             last_trade_slot: 0,              // not available here
             last_update_ts: now.timestamp(), // wall-clock update
 
@@ -137,3 +212,106 @@ impl From<PfPgsqlBondingCurveProgressDto> for PfRedisBondingCurveProgress {
         }
     }
 }
+
+/// Creator fee balance for one platform, stored in the `pf_creator_fees:{creator}` hash
+/// (field = platform) and served by `pub_api`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PfRedisCreatorFee {
+    pub creator: String,
+    pub platform: PfCreatorFeePlatform,
+
+    pub accrued_lamports: i64,
+    pub claimed_lamports: i64,
+    pub unclaimed_lamports: i64,
+
+    pub accrual_count: i64,
+    pub claim_count: i64,
+
+    pub last_accrual_ts: Option<DateTime<Utc>>,
+    pub last_claim_ts: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<PfPgsqlCreatorFee> for PfRedisCreatorFee {
+    fn from(src: PfPgsqlCreatorFee) -> Self {
+        Self {
+            creator: src.creator,
+            platform: src.platform,
+
+            accrued_lamports: src.accrued_lamports,
+            claimed_lamports: src.claimed_lamports,
+            unclaimed_lamports: src.unclaimed_lamports,
+
+            accrual_count: src.accrual_count,
+            claim_count: src.claim_count,
+
+            last_accrual_ts: src.last_accrual_ts,
+            last_claim_ts: src.last_claim_ts,
+            updated_at: src.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PfRedisTokenIncentive {
+    pub wallet: String,
+    pub mint: Option<String>,
+
+    pub needs_claim: bool,
+    pub total_unclaimed_tokens: i64,
+    pub total_claimed_tokens: i64,
+    pub current_sol_volume: i64,
+    pub last_update_timestamp: i64,
+
+    pub claim_count: i64,
+    pub last_claim_ts: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<PfPgsqlTokenIncentive> for PfRedisTokenIncentive {
+    fn from(src: PfPgsqlTokenIncentive) -> Self {
+        Self {
+            wallet: src.wallet,
+            mint: src.mint,
+
+            needs_claim: src.needs_claim,
+            total_unclaimed_tokens: src.total_unclaimed_tokens,
+            total_claimed_tokens: src.total_claimed_tokens,
+            current_sol_volume: src.current_sol_volume,
+            last_update_timestamp: src.last_update_timestamp,
+
+            claim_count: src.claim_count,
+            last_claim_ts: src.last_claim_ts,
+            updated_at: src.updated_at,
+        }
+    }
+}
+
+/// Daily incentive supply and volume, needed to price a wallet's pending (unsynced) volume
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PfRedisGlobalVolumeAccumulator {
+    pub mint: String,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub seconds_in_a_day: i64,
+    pub total_token_supply: Vec<u64>,
+    pub sol_volumes: Vec<u64>,
+    pub slot: u64,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub fn build_pf_redis_global_volume_accumulator(
+    src: KPfGlobalVolumeAccumulator,
+    slot: u64,
+) -> PfRedisGlobalVolumeAccumulator {
+    PfRedisGlobalVolumeAccumulator {
+        mint: src.mint,
+        start_time: src.start_time,
+        end_time: src.end_time,
+        seconds_in_a_day: src.seconds_in_a_day,
+        total_token_supply: src.total_token_supply,
+        sol_volumes: src.sol_volumes,
+        slot,
+        updated_at: src.ts,
+    }
+}
@@ -1,2 +1,5 @@
+pub mod ps_ch_dto;
 pub mod ps_enums;
 pub mod ps_kafka_event;
+pub mod ps_pgsql_dto;
+pub mod ps_redis;
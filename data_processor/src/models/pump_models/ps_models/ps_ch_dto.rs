@@ -0,0 +1,95 @@
+use crate::models::enums::TradeDirection;
+use crate::models::kafka_event::EventSource;
+use crate::models::pump_models::ps_models::ps_kafka_event::KPsTrade;
+use crate::models::pump_models::ps_models::ps_pgsql_dto::PsPgsqlPriceDto;
+use chrono::{DateTime, Utc};
+use clickhouse::Row;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct PsChTradeDto {
+    /* ========= Transaction identity ========= */
+    pub signature: String,
+    pub slot: u64,
+
+    /* ========= Market ========= */
+    pub mint: String,
+    pub pool: String,
+    pub user: String,
+
+    /* ========= Trade ========= */
+    pub is_buy: bool,
+    pub sol_amount: i64,   // lamports
+    pub token_amount: i64, // raw token units
+
+    /* ========= Coin creator fee ========= */
+    pub coin_creator: String, // empty on pools without a coin creator
+    pub coin_creator_fee: u64,
+
+    /* ========= Timestamp ========= */
+    #[serde(with = "clickhouse::serde::chrono::datetime64::millis")]
+    pub timestamp: DateTime<Utc>,
+
+    /* ========= Deduplication ========= */
+    pub ix_index: u32, // with the signature and ordinal, the ReplacingMergeTree key
+    pub event_ordinal: u32, // position among the events the transaction emitted
+    pub version: u64,  // processing time (ms); the latest copy survives merges
+}
+
+pub fn build_ps_ch_trade(src: &KPsTrade, slot: u64) -> PsChTradeDto {
+    PsChTradeDto {
+        signature: src.signature.clone(),
+        slot,
+
+        mint: src.mint.clone(),
+        pool: src.pool.clone(),
+        user: src.user_pubkey.clone(),
+
+        is_buy: src.direction == TradeDirection::Buy,
+        sol_amount: src.sol_amount,
+        token_amount: src.token_amount,
+
+        coin_creator: src.coin_creator.clone().unwrap_or_default(),
+        coin_creator_fee: src.coin_creator_fee.unwrap_or_default(),
+
+        timestamp: src.ts,
+
+        ix_index: src.ix_index,
+        event_ordinal: src.event_ordinal,
+        version: Utc::now().timestamp_millis() as u64,
+    }
+}
+
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct PsChPoolPriceDto {
+    pub pool: String,
+    pub base_mint: String,
+    pub quote_mint: String,
+    pub slot: u64,
+    pub write_version: u64,
+    pub source: String, // producing service, `snapshot` for RPC fetches
+
+    pub price: u64,
+    pub base_reserves: u64,
+    pub quote_reserves: u64,
+
+    #[serde(with = "clickhouse::serde::chrono::datetime64::millis")]
+    pub timestamp: DateTime<Utc>,
+}
+
+pub fn build_ps_ch_pool_price(src: &PsPgsqlPriceDto, source: EventSource) -> PsChPoolPriceDto {
+    PsChPoolPriceDto {
+        pool: src.pool.clone(),
+        base_mint: src.base_mint.clone().unwrap_or_default(),
+        quote_mint: src.quote_mint.clone().unwrap_or_default(),
+        slot: src.slot,
+        write_version: src.write_version,
+        source: source.as_str().to_string(),
+
+        price: src.price as u64,
+        base_reserves: src.base_reserves as u64,
+        quote_reserves: src.quote_reserves as u64,
+
+        timestamp: src.ts,
+    }
+}
@@ -0,0 +1,52 @@
+use crate::models::pump_models::ps_models::ps_kafka_event::KPsPrice;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// PumpSwap pools are created by migrating Pump.fun coins, which all have 6 decimals.
+/// Neither the pool nor its vaults carry the base decimals.
+pub const PS_BASE_DECIMALS: u32 = 6;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PsPgsqlPriceDto {
+    /* ========= Identity ========= */
+    pub pool: String,
+    pub base_mint: Option<String>,
+    pub quote_mint: Option<String>,
+
+    /* ========= Price ========= */
+    pub price: i64, // quote smallest units per whole base token
+    pub base_reserves: i64,
+    pub quote_reserves: i64,
+
+    /* ========= Ordering ========= */
+    pub slot: u64,
+    pub write_version: u64,
+
+    pub ts: DateTime<Utc>,
+}
+
+/// Build `PsPgsqlPriceDto` from the joined vault balances of a pool.
+/// Returns `Err` for an empty base vault (price is undefined).
+pub fn build_ps_pgsql_price(src: &KPsPrice) -> Result<PsPgsqlPriceDto, String> {
+    if src.token_a_reserves == 0 {
+        return Err("build_ps_pgsql_price: base reserves are zero".into());
+    }
+
+    let price =
+        src.token_b_reserves as u128 * 10_u128.pow(PS_BASE_DECIMALS) / src.token_a_reserves as u128;
+
+    Ok(PsPgsqlPriceDto {
+        pool: src.pool.clone(),
+        base_mint: src.base_mint.clone(),
+        quote_mint: src.quote_mint.clone(),
+
+        price: i64::try_from(price).unwrap_or(i64::MAX),
+        base_reserves: src.token_a_reserves as i64,
+        quote_reserves: src.token_b_reserves as i64,
+
+        slot: src.slot,
+        write_version: src.write_version,
+
+        ts: src.ts,
+    })
+}
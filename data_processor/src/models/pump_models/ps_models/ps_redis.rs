@@ -0,0 +1,109 @@
+use crate::models::kafka_req::KTokenReqFulfill;
+use crate::models::pump_models::ps_models::ps_kafka_event::KPsToken;
+use crate::models::pump_models::ps_models::ps_pgsql_dto::PsPgsqlPriceDto;
+use crate::models::token_metadata::RedisTokenMetadata;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Latest PumpSwap pool price, stored in the `ps_prices` hash (field = pool)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PsRedisPrice {
+    pub pool: String,
+    pub base_mint: Option<String>,
+    pub quote_mint: Option<String>,
+
+    pub price: i64, // quote smallest units per whole base token
+    pub base_reserves: i64,
+    pub quote_reserves: i64,
+
+    pub slot: u64,
+    pub write_version: u64,
+
+    #[serde(flatten)]
+    pub metadata: RedisTokenMetadata,
+
+    pub ts: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<PsPgsqlPriceDto> for PsRedisPrice {
+    fn from(src: PsPgsqlPriceDto) -> Self {
+        Self {
+            pool: src.pool,
+            base_mint: src.base_mint,
+            quote_mint: src.quote_mint,
+
+            price: src.price,
+            base_reserves: src.base_reserves,
+            quote_reserves: src.quote_reserves,
+
+            slot: src.slot,
+            write_version: src.write_version,
+
+            // metadata is NOT available from vaults
+            metadata: RedisTokenMetadata::default(),
+
+            ts: src.ts,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+/// Seed entry from a pool creation; reserves stay zero until the pool's vaults report.
+pub fn build_ps_redis_price_from_token(src: &KPsToken) -> PsRedisPrice {
+    let non_empty = |s: &str| (!s.is_empty()).then(|| s.to_string());
+
+    PsRedisPrice {
+        pool: src.pool.clone(),
+        base_mint: Some(src.mint.clone()),
+        quote_mint: None,
+
+        price: 0,
+        base_reserves: 0,
+        quote_reserves: 0,
+
+        slot: 0,
+        write_version: 0,
+
+        metadata: RedisTokenMetadata {
+            name: non_empty(&src.name),
+            symbol: non_empty(&src.symbol),
+            uri: src.uri.clone(),
+            description: non_empty(&src.description),
+            twitter: non_empty(&src.twitter),
+            telegram: non_empty(&src.telegram),
+            website: non_empty(&src.website),
+            image: non_empty(&src.image),
+        },
+
+        ts: src.ts,
+        updated_at: Utc::now(),
+    }
+}
+
+/// Seed entry from a fulfill request; reserves stay zero until the pool's vaults report.
+pub fn build_ps_redis_price_from_fulfill(src: &KTokenReqFulfill) -> Result<PsRedisPrice, String> {
+    let pool = src
+        .pool
+        .clone()
+        .ok_or("build_ps_redis_price_from_fulfill: pool missing")?;
+    let now = Utc::now();
+
+    Ok(PsRedisPrice {
+        pool,
+        base_mint: Some(src.mint.clone()),
+        quote_mint: None,
+
+        price: src.price,
+        base_reserves: 0,
+        quote_reserves: 0,
+
+        slot: 0,
+        write_version: 0,
+
+        metadata: RedisTokenMetadata::from(src),
+
+        ts: now,
+        updated_at: now,
+    })
+}
@@ -0,0 +1,4 @@
+pub mod rll_ch_dto;
+pub mod rll_kafka_event;
+pub mod rll_pgsql_dto;
+pub mod rll_redis;
@@ -0,0 +1,45 @@
+use crate::models::kafka_event::EventSource;
+use crate::models::raydium_models::rll_models::rll_pgsql_dto::RllPgsqlPriceDto;
+use chrono::{DateTime, Utc};
+use clickhouse::Row;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct RllChPoolPriceDto {
+    pub pool_state: String,
+    pub base_mint: String,
+    pub slot: u64,
+    pub write_version: u64,
+    pub source: String, // producing service, `snapshot` for RPC fetches
+
+    pub status: u8,
+    pub price: u64,
+    pub virtual_base: u64,
+    pub virtual_quote: u64,
+    pub real_base: u64,
+    pub real_quote: u64,
+    pub progress_bps: u16,
+
+    #[serde(with = "clickhouse::serde::chrono::datetime64::millis")]
+    pub timestamp: DateTime<Utc>,
+}
+
+pub fn build_rll_ch_pool_price(src: &RllPgsqlPriceDto, source: EventSource) -> RllChPoolPriceDto {
+    RllChPoolPriceDto {
+        pool_state: src.pool_state.clone(),
+        base_mint: src.base_mint.clone().unwrap_or_default(),
+        slot: src.slot,
+        write_version: src.write_version,
+        source: source.as_str().to_string(),
+
+        status: src.status as u8,
+        price: src.price as u64,
+        virtual_base: src.virtual_base as u64,
+        virtual_quote: src.virtual_quote as u64,
+        real_base: src.real_base as u64,
+        real_quote: src.real_quote as u64,
+        progress_bps: src.progress_bps as u16,
+
+        timestamp: src.ts,
+    }
+}
@@ -0,0 +1,3 @@
+pub use event_contract::models::raydium_models::rll_kafka_event::{
+    KRllPrice, KRllToken, KRllTokenLifecycle, KRllTrade,
+};
@@ -0,0 +1,94 @@
+use crate::models::raydium_models::rll_models::rll_kafka_event::{KRllPrice, KRllToken};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RllPgsqlPriceDto {
+    /* ========= Identity ========= */
+    pub pool_state: String,
+    pub base_mint: Option<String>,
+    pub creator: Option<String>,
+    pub status: i16, // 0 fund-raising, 1 migrating, 2 trading on the AMM
+
+    /* ========= Price ========= */
+    pub price: i64, // quote smallest units per whole base token
+    pub base_decimals: i16,
+    pub quote_decimals: i16,
+
+    /* ========= Curve state ========= */
+    pub virtual_base: i64,
+    pub virtual_quote: i64,
+    pub real_base: i64,
+    pub real_quote: i64,
+    pub total_quote_fund_raising: i64,
+    pub progress_bps: i32,
+
+    /* ========= Ordering ========= */
+    pub slot: u64,
+    pub write_version: u64,
+
+    pub ts: DateTime<Utc>,
+}
+
+/// Build `RllPgsqlPriceDto` from a `PoolState` account update (price already derived upstream)
+pub fn build_rll_pgsql_price(src: &KRllPrice) -> RllPgsqlPriceDto {
+    RllPgsqlPriceDto {
+        pool_state: src.pool_state.clone(),
+        base_mint: src.base_mint.clone(),
+        creator: src.creator.clone(),
+        status: i16::from(src.status),
+
+        price: i64::try_from(src.price_quote).unwrap_or(i64::MAX),
+        base_decimals: i16::from(src.base_decimals),
+        quote_decimals: i16::from(src.quote_decimals),
+
+        virtual_base: src.virtual_base as i64,
+        virtual_quote: src.virtual_quote as i64,
+        real_base: src.real_base as i64,
+        real_quote: src.real_quote as i64,
+        total_quote_fund_raising: src.total_quote_fund_raising as i64,
+        progress_bps: src.progress_bps.min(10_000) as i32,
+
+        slot: src.slot,
+        write_version: src.write_version,
+
+        ts: src.ts,
+    }
+}
+
+/// Build the opening `RllPgsqlPriceDto` of a newly created token.
+/// Returns `Err` if the curve has no virtual base (price is undefined).
+pub fn build_rll_pgsql_price_from_token(
+    src: &KRllToken,
+    slot: u64,
+) -> Result<RllPgsqlPriceDto, String> {
+    if src.virtual_base == 0 {
+        return Err("build_rll_pgsql_price_from_token: virtual_base is zero".into());
+    }
+
+    let price = src.virtual_quote as u128 * 10_u128.pow(u32::from(src.base_decimals))
+        / src.virtual_base as u128;
+
+    Ok(RllPgsqlPriceDto {
+        pool_state: src.pool_state.clone(),
+        base_mint: Some(src.mint.clone()),
+        creator: Some(src.creator.clone()),
+        status: 0,
+
+        price: i64::try_from(price).unwrap_or(i64::MAX),
+        base_decimals: i16::from(src.base_decimals),
+        quote_decimals: i16::from(src.quote_decimals),
+
+        virtual_base: src.virtual_base as i64,
+        virtual_quote: src.virtual_quote as i64,
+        real_base: 0,
+        real_quote: 0,
+        total_quote_fund_raising: 0,
+        progress_bps: 0,
+
+        slot,
+        write_version: 0,
+
+        ts: src.ts,
+    })
+}
@@ -0,0 +1,108 @@
+use crate::models::kafka_req::KTokenReqFulfill;
+use crate::models::raydium_models::rll_models::rll_kafka_event::KRllToken;
+use crate::models::raydium_models::rll_models::rll_pgsql_dto::RllPgsqlPriceDto;
+use crate::models::token_metadata::RedisTokenMetadata;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Latest LaunchLab curve state, stored in the `rll_prices` hash (field = pool state)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RllRedisPrice {
+    pub pool_state: String,
+    pub base_mint: Option<String>,
+    pub creator: Option<String>,
+    pub status: i16,
+
+    pub price: i64, // quote smallest units per whole base token
+    pub base_decimals: i16,
+    pub quote_decimals: i16,
+    pub virtual_base: i64,
+    pub virtual_quote: i64,
+    pub real_base: i64,
+    pub real_quote: i64,
+    pub progress_bps: i32,
+
+    pub slot: u64,
+    pub write_version: u64,
+
+    #[serde(flatten)]
+    pub metadata: RedisTokenMetadata,
+
+    pub ts: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<RllPgsqlPriceDto> for RllRedisPrice {
+    fn from(src: RllPgsqlPriceDto) -> Self {
+        Self {
+            pool_state: src.pool_state,
+            base_mint: src.base_mint,
+            creator: src.creator,
+            status: src.status,
+
+            price: src.price,
+            base_decimals: src.base_decimals,
+            quote_decimals: src.quote_decimals,
+            virtual_base: src.virtual_base,
+            virtual_quote: src.virtual_quote,
+            real_base: src.real_base,
+            real_quote: src.real_quote,
+            progress_bps: src.progress_bps,
+
+            slot: src.slot,
+            write_version: src.write_version,
+
+            // metadata is NOT available from the pool state
+            metadata: RedisTokenMetadata::default(),
+
+            ts: src.ts,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+/// Opening state of a new token, with the metadata from its create instruction
+pub fn build_rll_redis_price_from_token(dto: RllPgsqlPriceDto, src: &KRllToken) -> RllRedisPrice {
+    RllRedisPrice {
+        metadata: RedisTokenMetadata {
+            name: Some(src.name.clone()),
+            symbol: Some(src.symbol.clone()),
+            uri: src.uri.clone(),
+            ..RedisTokenMetadata::default()
+        },
+        ..RllRedisPrice::from(dto)
+    }
+}
+
+/// Seed entry from a fulfill request; curve state stays zero until the pool state reports.
+pub fn build_rll_redis_price_from_fulfill(src: &KTokenReqFulfill) -> Result<RllRedisPrice, String> {
+    let pool_state = src
+        .pool_state
+        .clone()
+        .ok_or("build_rll_redis_price_from_fulfill: pool_state missing")?;
+    let now = Utc::now();
+
+    Ok(RllRedisPrice {
+        pool_state,
+        base_mint: Some(src.mint.clone()),
+        creator: None,
+        status: 0,
+
+        price: src.price,
+        base_decimals: i16::from(src.decimal),
+        quote_decimals: 0,
+        virtual_base: 0,
+        virtual_quote: 0,
+        real_base: 0,
+        real_quote: 0,
+        progress_bps: 0,
+
+        slot: 0,
+        write_version: 0,
+
+        metadata: RedisTokenMetadata::from(src),
+
+        ts: now,
+        updated_at: now,
+    })
+}
@@ -0,0 +1,124 @@
+use crate::models::enums::Platform;
+use crate::models::pump_models::pf_models::pf_kafka_event::{
+    KPfBondingCurveCompleted, KPfToken, KPfTokenLifecycle,
+};
+use crate::models::raydium_models::rll_models::rll_kafka_event::KRllToken;
+use chrono::{DateTime, Utc};
+use clickhouse::Row;
+use serde::{Deserialize, Serialize};
+
+/// Transition recorded in `token_lifecycle_ledger`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenLifecycleKind {
+    Created,
+    Completed, // bonding curve sold out, migration pending
+    Migrated,
+}
+
+impl TokenLifecycleKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenLifecycleKind::Created => "created",
+            TokenLifecycleKind::Completed => "completed",
+            TokenLifecycleKind::Migrated => "migrated",
+        }
+    }
+
+    /// Value of the member in `token_lifecycle_ledger.kind`
+    pub fn ch_enum8(&self) -> i8 {
+        match self {
+            TokenLifecycleKind::Created => 1,
+            TokenLifecycleKind::Completed => 2,
+            TokenLifecycleKind::Migrated => 3,
+        }
+    }
+}
+
+pub fn platform_as_str(platform: Platform) -> &'static str {
+    match platform {
+        Platform::PumpFun => "pump_fun",
+        Platform::PumpSwap => "pump_swap",
+        Platform::RaydiumLaunchLab => "raydium_launch_lab",
+    }
+}
+
+#[derive(Debug, Clone, Row, Serialize, Deserialize)]
+pub struct ChTokenLifecycleDto {
+    /* ========= Token ========= */
+    pub platform: String,
+    pub mint: String,    // empty when the event does not carry it
+    pub account: String, // bonding curve / pool state
+    pub kind: i8,        // `Enum8` value of created | completed | migrated
+
+    /* ========= Migration ========= */
+    pub pool: String, // destination pool, empty unless migrated
+    pub sol_amount: u64,
+    pub token_amount: u64,
+
+    /* ========= Origin ========= */
+    pub slot: u64,
+    #[serde(with = "clickhouse::serde::chrono::datetime64::millis")]
+    pub timestamp: DateTime<Utc>,
+}
+
+pub fn build_ch_token_created_from_pf(src: &KPfToken, slot: u64) -> ChTokenLifecycleDto {
+    ChTokenLifecycleDto {
+        platform: platform_as_str(Platform::PumpFun).to_string(),
+        mint: src.mint.clone(),
+        account: src.bonding_curve.clone(),
+        kind: TokenLifecycleKind::Created.ch_enum8(),
+        pool: String::new(),
+        sol_amount: 0,
+        token_amount: 0,
+        slot,
+        timestamp: src.ts,
+    }
+}
+
+/// `mint` is looked up from the curve; the completion event only carries the curve.
+pub fn build_ch_token_completed_from_pf(
+    src: &KPfBondingCurveCompleted,
+    mint: Option<String>,
+    slot: u64,
+) -> ChTokenLifecycleDto {
+    ChTokenLifecycleDto {
+        platform: platform_as_str(Platform::PumpFun).to_string(),
+        mint: mint.unwrap_or_default(),
+        account: src.bonding_curve.clone(),
+        kind: TokenLifecycleKind::Completed.ch_enum8(),
+        pool: String::new(),
+        sol_amount: src.real_sol_reserves,
+        token_amount: 0,
+        slot,
+        timestamp: src.ts,
+    }
+}
+
+pub fn build_ch_token_migrated_from_pf(src: &KPfTokenLifecycle, slot: u64) -> ChTokenLifecycleDto {
+    ChTokenLifecycleDto {
+        platform: platform_as_str(src.platform).to_string(),
+        mint: src.mint.clone(),
+        account: src.bonding_curve.clone().unwrap_or_default(),
+        kind: TokenLifecycleKind::Migrated.ch_enum8(),
+        pool: src.pool.clone().unwrap_or_default(),
+        sol_amount: src.sol_amount_migrated.unwrap_or_default().max(0) as u64,
+        token_amount: src.token_amount_migrated.unwrap_or_default().max(0) as u64,
+        slot,
+        timestamp: src.ts_migrated.unwrap_or_else(Utc::now),
+    }
+}
+
+pub fn build_ch_token_created_from_rll(src: &KRllToken, slot: u64) -> ChTokenLifecycleDto {
+    ChTokenLifecycleDto {
+        platform: platform_as_str(Platform::RaydiumLaunchLab).to_string(),
+        mint: src.mint.clone(),
+        account: src.pool_state.clone(),
+        kind: TokenLifecycleKind::Created.ch_enum8(),
+        pool: String::new(),
+        sol_amount: 0,
+        token_amount: 0,
+        slot,
+        timestamp: src.ts,
+    }
+}
@@ -0,0 +1,56 @@
+use crate::models::kafka_req::KTokenReqFulfill;
+use serde::{Deserialize, Serialize};
+
+/// Token metadata kept next to PumpSwap and LaunchLab prices in Redis.
+/// Only the historian's fulfill requests and creation events provide it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RedisTokenMetadata {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub uri: Option<String>,
+    pub description: Option<String>,
+    pub twitter: Option<String>,
+    pub telegram: Option<String>,
+    pub website: Option<String>,
+    pub image: Option<String>,
+}
+
+impl RedisTokenMetadata {
+    /// Take every field `incoming` provides, keep the rest
+    pub fn merge(&mut self, incoming: RedisTokenMetadata) {
+        let fields = [
+            (&mut self.name, incoming.name),
+            (&mut self.symbol, incoming.symbol),
+            (&mut self.uri, incoming.uri),
+            (&mut self.description, incoming.description),
+            (&mut self.twitter, incoming.twitter),
+            (&mut self.telegram, incoming.telegram),
+            (&mut self.website, incoming.website),
+            (&mut self.image, incoming.image),
+        ];
+
+        for (existing, incoming) in fields {
+            if incoming.is_some() {
+                *existing = incoming;
+            }
+        }
+    }
+}
+
+impl From<&KTokenReqFulfill> for RedisTokenMetadata {
+    fn from(src: &KTokenReqFulfill) -> Self {
+        // The historian sends empty strings for unresolved fields
+        let non_empty = |s: &str| (!s.is_empty()).then(|| s.to_string());
+
+        Self {
+            name: non_empty(&src.name),
+            symbol: non_empty(&src.symbol),
+            uri: non_empty(&src.uri),
+            description: non_empty(&src.description),
+            twitter: non_empty(&src.twitter),
+            telegram: non_empty(&src.telegram),
+            website: non_empty(&src.website),
+            image: non_empty(&src.image),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::models::kafka_req::KTokenReqFulfill;
+use crate::models::pump_models::pf_models::pf_kafka_event::{KPfToken, KPfTokenLifecycle};
+use crate::models::pump_models::ps_models::ps_kafka_event::KPsToken;
+use crate::models::raydium_models::rll_models::rll_kafka_event::KRllToken;
+use crate::models::token_lifecycle::platform_as_str;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Quote mint of the PumpSwap pool a Pump.fun token migrates to
+pub const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+/// Row of `tokens`, the registry `gap_filler` resolves tokens from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PgsqlTokenDto {
+    pub name: String,
+    pub symbol: String,
+    pub uri: Option<String>,
+    pub creator: String,
+    pub mint: String,
+    pub platform: String, // `platform_type` member
+
+    pub bonding_curve: Option<String>,
+    pub pool_state: Option<String>,
+    pub pool: Option<String>,
+
+    pub user_address: String,
+    pub decimal: i64,
+    pub ts: DateTime<Utc>,
+    pub virtual_token_reserves: i64,
+    pub virtual_sol_reserves: i64,
+    pub real_token_reserves: i64,
+    pub token_total_supply: i64,
+
+    pub description: String,
+    pub twitter: String,
+    pub telegram: String,
+    pub website: String,
+    pub image: String,
+}
+
+/// Row of `pools`; the pair is named from the token's symbol when stored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PgsqlPoolDto {
+    pub mint: String,
+    pub platform: String, // `platform_type` member
+    pub pool: String,
+    pub token_a: String,      // base mint
+    pub token_b: String,      // quote mint
+    pub quote_symbol: String, // quote side of `pair`
+}
+
+pub fn build_pgsql_token_from_pf(src: &KPfToken) -> PgsqlTokenDto {
+    PgsqlTokenDto {
+        name: src.name.clone(),
+        symbol: src.symbol.clone(),
+        uri: src.uri.clone(),
+        creator: src.creator.clone(),
+        mint: src.mint.clone(),
+        platform: "pump_fun".to_string(),
+
+        bonding_curve: Some(src.bonding_curve.clone()),
+        pool_state: None,
+        pool: None,
+
+        user_address: src.user_address.clone(),
+        decimal: i64::from(src.decimals),
+        ts: src.ts,
+        virtual_token_reserves: src.virtual_token_reserves,
+        virtual_sol_reserves: src.virtual_sol_reserves,
+        real_token_reserves: src.real_token_reserves,
+        token_total_supply: src.token_total_supply,
+
+        description: src.description.clone(),
+        twitter: src.twitter.clone(),
+        telegram: src.telegram.clone(),
+        website: src.website.clone(),
+        image: src.image.clone(),
+    }
+}
+
+pub fn build_pgsql_token_from_ps(src: &KPsToken) -> PgsqlTokenDto {
+    PgsqlTokenDto {
+        name: src.name.clone(),
+        symbol: src.symbol.clone(),
+        uri: src.uri.clone(),
+        creator: src.creator.clone(),
+        mint: src.mint.clone(),
+        platform: "pump_swap".to_string(),
+
+        bonding_curve: None,
+        pool_state: None,
+        pool: Some(src.pool.clone()),
+
+        user_address: src.user_address.clone(),
+        decimal: i64::from(src.decimals),
+        ts: src.ts,
+        // reserves only describe bonding curves
+        virtual_token_reserves: 0,
+        virtual_sol_reserves: 0,
+        real_token_reserves: 0,
+        token_total_supply: 0,
+
+        description: src.description.clone(),
+        twitter: src.twitter.clone(),
+        telegram: src.telegram.clone(),
+        website: src.website.clone(),
+        image: src.image.clone(),
+    }
+}
+
+pub fn build_pgsql_token_from_rll(src: &KRllToken) -> PgsqlTokenDto {
+    PgsqlTokenDto {
+        name: src.name.clone(),
+        symbol: src.symbol.clone(),
+        uri: src.uri.clone(),
+        creator: src.creator.clone(),
+        mint: src.mint.clone(),
+        platform: platform_as_str(src.platform).to_string(),
+
+        bonding_curve: None,
+        pool_state: Some(src.pool_state.clone()),
+        pool: None,
+
+        user_address: src.user_address.clone(),
+        decimal: i64::from(src.base_decimals),
+        ts: src.ts,
+        virtual_token_reserves: src.virtual_base as i64,
+        virtual_sol_reserves: src.virtual_quote as i64,
+        real_token_reserves: 0,
+        token_total_supply: 0,
+
+        description: String::new(),
+        twitter: String::new(),
+        telegram: String::new(),
+        website: String::new(),
+        image: String::new(),
+    }
+}
+
+/// Token resolved by the historian. The request carries no on-chain state, so those
+/// fields only seed a token no creation event has stored.
+pub fn build_pgsql_token_from_fulfill(src: &KTokenReqFulfill) -> PgsqlTokenDto {
+    PgsqlTokenDto {
+        name: src.name.clone(),
+        symbol: src.symbol.clone(),
+        uri: (!src.uri.is_empty()).then(|| src.uri.clone()),
+        creator: String::new(),
+        mint: src.mint.clone(),
+        platform: src.platform.as_str().to_string(),
+
+        bonding_curve: src.bonding_curve.clone(),
+        pool_state: src.pool_state.clone(),
+        pool: src.pool.clone(),
+
+        user_address: String::new(),
+        decimal: i64::from(src.decimal),
+        ts: Utc::now(),
+        virtual_token_reserves: 0,
+        virtual_sol_reserves: 0,
+        real_token_reserves: 0,
+        token_total_supply: 0,
+
+        description: src.description.clone(),
+        twitter: src.twitter.clone(),
+        telegram: src.telegram.clone(),
+        website: src.website.clone(),
+        image: src.image.clone(),
+    }
+}
+
+/// PumpSwap pool a Pump.fun token migrated to
+pub fn build_pgsql_pool_from_pf_migration(src: &KPfTokenLifecycle) -> Result<PgsqlPoolDto, String> {
+    let pool = src
+        .pool
+        .clone()
+        .ok_or("build_pgsql_pool_from_pf_migration: pool missing")?;
+
+    Ok(PgsqlPoolDto {
+        mint: src.mint.clone(),
+        platform: "pump_swap".to_string(),
+        pool,
+        token_a: src.mint.clone(),
+        token_b: WSOL_MINT.to_string(),
+        quote_symbol: "SOL".to_string(),
+    })
+}
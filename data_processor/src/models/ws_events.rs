@@ -0,0 +1,196 @@
+use crate::models::enums::TradeDirection;
+use crate::models::pump_models::pf_models::pf_kafka_event::{KPfChTrade, KPfTokenLifecycle};
+use crate::models::pump_models::pf_models::pf_redis::{PfRedisBondingCurveProgress, PfRedisPrice};
+use crate::models::pump_models::ps_models::ps_kafka_event::KPsTrade;
+use crate::models::pump_models::ps_models::ps_redis::PsRedisPrice;
+use crate::models::raydium_models::rll_models::rll_redis::RllRedisPrice;
+use crate::models::token_registry::PgsqlTokenDto;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Channel pub_api streams token creations from
+pub const WS_CREATION_CHANNEL: &str = "creation";
+/// Channel pub_api streams migrations from
+pub const WS_MIGRATION_CHANNEL: &str = "migration";
+
+/// Channel pub_api streams the ticks of `mint` from
+pub fn ws_price_channel(mint: &str) -> String {
+    format!("ws:{mint}")
+}
+
+/// Compact price update published to `ws:{mint}`. Read back from the cache after a trade
+/// or price event, so an event older than the cached state cannot roll subscribers back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WsPriceTick {
+    pub mint: String,
+    pub platform: String, // `platform_type` member
+
+    pub price: i64,                // quote smallest units per whole token
+    pub base_reserves: i64,        // virtual reserves on bonding curves
+    pub quote_reserves: i64,       // virtual reserves on bonding curves
+    pub progress_bps: Option<i64>, // bonding curves only
+
+    pub last_trade: Option<WsTrade>, // trade that triggered the tick
+
+    pub slot: u64,
+    pub ts: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WsTrade {
+    pub signature: String,
+    pub trader: String,
+    pub is_buy: bool,
+    pub base_amount: u64,  // token smallest units
+    pub quote_amount: u64, // quote smallest units
+    pub slot: u64,
+    pub ts: DateTime<Utc>,
+}
+
+/// Token creation published to `creation`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WsTokenCreated {
+    pub mint: String,
+    pub platform: String, // `platform_type` member
+    pub name: String,
+    pub symbol: String,
+    pub uri: Option<String>,
+    pub image: String,
+    pub creator: String,
+
+    pub bonding_curve: Option<String>,
+    pub pool_state: Option<String>,
+    pub pool: Option<String>,
+
+    pub ts: DateTime<Utc>,
+}
+
+/// Migration published to `migration`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WsTokenMigrated {
+    pub mint: String,
+    pub from_platform: String, // `platform_type` member
+    pub to_platform: String,   // `platform_type` member
+    pub bonding_curve: Option<String>,
+    pub pool: Option<String>,
+
+    pub slot: u64,
+    pub ts: DateTime<Utc>,
+}
+
+pub fn build_ws_price_tick_from_pf(
+    price: &PfRedisPrice,
+    progress: Option<&PfRedisBondingCurveProgress>,
+    last_trade: Option<WsTrade>,
+) -> WsPriceTick {
+    WsPriceTick {
+        mint: price.mint.clone(),
+        platform: "pump_fun".to_string(),
+
+        price: price.price,
+        base_reserves: price.virtual_token_reserves,
+        quote_reserves: price.virtual_sol_reserves,
+        progress_bps: progress.map(|p| i64::from(p.progress_bps)),
+
+        last_trade,
+
+        slot: price.slot as u64,
+        ts: price.ts,
+    }
+}
+
+/// `None` until the pool's base mint is known
+pub fn build_ws_price_tick_from_ps(
+    price: &PsRedisPrice,
+    last_trade: Option<WsTrade>,
+) -> Option<WsPriceTick> {
+    Some(WsPriceTick {
+        mint: price.base_mint.clone()?,
+        platform: "pump_swap".to_string(),
+
+        price: price.price,
+        base_reserves: price.base_reserves,
+        quote_reserves: price.quote_reserves,
+        progress_bps: None,
+
+        last_trade,
+
+        slot: price.slot,
+        ts: price.ts,
+    })
+}
+
+/// `None` until the curve's base mint is known
+pub fn build_ws_price_tick_from_rll(price: &RllRedisPrice) -> Option<WsPriceTick> {
+    Some(WsPriceTick {
+        mint: price.base_mint.clone()?,
+        platform: "raydium_launch_lab".to_string(),
+
+        price: price.price,
+        base_reserves: price.virtual_base,
+        quote_reserves: price.virtual_quote,
+        progress_bps: Some(i64::from(price.progress_bps)),
+
+        last_trade: None,
+
+        slot: price.slot,
+        ts: price.ts,
+    })
+}
+
+pub fn build_ws_trade_from_pf(src: &KPfChTrade) -> WsTrade {
+    WsTrade {
+        signature: src.signature.clone(),
+        trader: src.user.clone(),
+        is_buy: src.is_buy,
+        base_amount: src.token_amount,
+        quote_amount: src.sol_amount,
+        slot: src.slot,
+        ts: src.ts,
+    }
+}
+
+pub fn build_ws_trade_from_ps(src: &KPsTrade, slot: u64) -> WsTrade {
+    WsTrade {
+        signature: src.signature.clone(),
+        trader: src.user_pubkey.clone(),
+        is_buy: src.direction == TradeDirection::Buy,
+        base_amount: src.token_amount.max(0) as u64,
+        quote_amount: src.sol_amount.max(0) as u64,
+        slot,
+        ts: src.ts,
+    }
+}
+
+impl From<&PgsqlTokenDto> for WsTokenCreated {
+    fn from(src: &PgsqlTokenDto) -> Self {
+        Self {
+            mint: src.mint.clone(),
+            platform: src.platform.clone(),
+            name: src.name.clone(),
+            symbol: src.symbol.clone(),
+            uri: src.uri.clone(),
+            image: src.image.clone(),
+            creator: src.creator.clone(),
+
+            bonding_curve: src.bonding_curve.clone(),
+            pool_state: src.pool_state.clone(),
+            pool: src.pool.clone(),
+
+            ts: src.ts,
+        }
+    }
+}
+
+pub fn build_ws_token_migrated_from_pf(src: &KPfTokenLifecycle, slot: u64) -> WsTokenMigrated {
+    WsTokenMigrated {
+        mint: src.mint.clone(),
+        from_platform: "pump_fun".to_string(),
+        to_platform: "pump_swap".to_string(),
+        bonding_curve: src.bonding_curve.clone(),
+        pool: src.pool.clone(),
+
+        slot,
+        ts: src.ts_migrated.unwrap_or_else(Utc::now),
+    }
+}
@@ -0,0 +1,18 @@
+use crate::models::token_lifecycle::ChTokenLifecycleDto;
+use crate::state::AppState;
+use log::debug;
+
+/// Append a creation / completion / migration to `token_lifecycle_ledger`
+pub async fn insert_ch_token_lifecycle(
+    state: &AppState,
+    lifecycle_dto: &ChTokenLifecycleDto,
+) -> anyhow::Result<()> {
+    state.ch_writer.write(lifecycle_dto).await?;
+
+    debug!(
+        "Buffered token lifecycle for {} on {} at slot {}",
+        lifecycle_dto.account, lifecycle_dto.platform, lifecycle_dto.slot
+    );
+
+    Ok(())
+}
@@ -1 +1,8 @@
+pub mod ch_token_lifecycle;
+pub mod pgsql_ledger_events;
+pub mod pgsql_tokens;
 pub mod pump_repositories;
+pub mod raydium_repositories;
+pub mod redis_json;
+pub mod redis_processed_events;
+pub mod redis_ws;
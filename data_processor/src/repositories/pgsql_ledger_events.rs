@@ -0,0 +1,37 @@
+use crate::models::kafka_event::KEventType;
+use sqlx::PgConnection;
+
+pub const CREATOR_FEE_LEDGER: &str = "pf_creator_fees";
+pub const TOKEN_INCENTIVE_LEDGER: &str = "pf_token_incentives";
+
+/// Record that the event is applied to `ledger`. Events are told apart by type,
+/// signature, instruction and ordinal within the transaction.
+/// Returns `false` if it already was: the event is a redelivery and must not be counted again.
+/// Run it in the transaction that applies the event, so both commit or neither does.
+pub async fn claim_pgsql_ledger_event(
+    conn: &mut PgConnection,
+    ledger: &str,
+    event_type: KEventType,
+    signature: &str,
+    ix_index: u32,
+    event_ordinal: u32,
+    slot: u64,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        INSERT INTO applied_ledger_events (ledger, event_type, signature, ix_index, event_ordinal, slot)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(ledger)
+    .bind(event_type.as_str())
+    .bind(signature)
+    .bind(ix_index as i32)
+    .bind(event_ordinal as i32)
+    .bind(slot as i64)
+    .execute(conn)
+    .await?;
+
+    Ok(result.rows_affected() == 1)
+}
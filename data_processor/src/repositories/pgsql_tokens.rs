@@ -0,0 +1,244 @@
+use crate::models::token_registry::{PgsqlPoolDto, PgsqlTokenDto};
+use sqlx::PgPool;
+
+/// Insert or update a token from its creation event. On-chain fields are taken as-is;
+/// metadata and addresses the event leaves empty keep their stored values.
+pub async fn upsert_pgsql_token(pool: &PgPool, dto: PgsqlTokenDto) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO tokens (
+            name,
+            symbol,
+            uri,
+            creator,
+            mint,
+            platform,
+
+            bonding_curve,
+            pool_state,
+            pool,
+
+            user_address,
+            "decimal",
+            ts,
+            virtual_token_reserves,
+            virtual_sol_reserves,
+            real_token_reserves,
+            token_total_supply,
+
+            description,
+            twitter,
+            telegram,
+            website,
+            image
+        )
+        VALUES (
+            $1,$2,$3,$4,$5,$6::platform_type,
+            $7,$8,$9,
+            $10,$11,$12,$13,$14,$15,$16,
+            $17,$18,$19,$20,$21
+        )
+        ON CONFLICT (mint)
+        DO UPDATE SET
+            name                   = COALESCE(NULLIF(EXCLUDED.name, ''), tokens.name),
+            symbol                 = COALESCE(NULLIF(EXCLUDED.symbol, ''), tokens.symbol),
+            uri                    = COALESCE(EXCLUDED.uri, tokens.uri),
+            creator                = EXCLUDED.creator,
+            platform               = EXCLUDED.platform,
+
+            bonding_curve          = COALESCE(EXCLUDED.bonding_curve, tokens.bonding_curve),
+            pool_state             = COALESCE(EXCLUDED.pool_state, tokens.pool_state),
+            pool                   = COALESCE(EXCLUDED.pool, tokens.pool),
+
+            user_address           = EXCLUDED.user_address,
+            "decimal"              = EXCLUDED."decimal",
+            ts                     = EXCLUDED.ts,
+            virtual_token_reserves = EXCLUDED.virtual_token_reserves,
+            virtual_sol_reserves   = EXCLUDED.virtual_sol_reserves,
+            real_token_reserves    = EXCLUDED.real_token_reserves,
+            token_total_supply     = EXCLUDED.token_total_supply,
+
+            description            = COALESCE(NULLIF(EXCLUDED.description, ''), tokens.description),
+            twitter                = COALESCE(NULLIF(EXCLUDED.twitter, ''), tokens.twitter),
+            telegram               = COALESCE(NULLIF(EXCLUDED.telegram, ''), tokens.telegram),
+            website                = COALESCE(NULLIF(EXCLUDED.website, ''), tokens.website),
+            image                  = COALESCE(NULLIF(EXCLUDED.image, ''), tokens.image),
+
+            updated_at             = now()
+        "#,
+    )
+    .bind(&dto.name)
+    .bind(&dto.symbol)
+    .bind(&dto.uri)
+    .bind(&dto.creator)
+    .bind(&dto.mint)
+    .bind(&dto.platform)
+    .bind(&dto.bonding_curve)
+    .bind(&dto.pool_state)
+    .bind(&dto.pool)
+    .bind(&dto.user_address)
+    .bind(dto.decimal)
+    .bind(dto.ts)
+    .bind(dto.virtual_token_reserves)
+    .bind(dto.virtual_sol_reserves)
+    .bind(dto.real_token_reserves)
+    .bind(dto.token_total_supply)
+    .bind(&dto.description)
+    .bind(&dto.twitter)
+    .bind(&dto.telegram)
+    .bind(&dto.website)
+    .bind(&dto.image)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Merge resolved metadata and addresses into a token, registering it if unknown.
+/// On-chain fields of a stored token are left alone.
+pub async fn merge_pgsql_token_metadata(
+    pool: &PgPool,
+    dto: PgsqlTokenDto,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO tokens (
+            name,
+            symbol,
+            uri,
+            creator,
+            mint,
+            platform,
+
+            bonding_curve,
+            pool_state,
+            pool,
+
+            user_address,
+            "decimal",
+            ts,
+            virtual_token_reserves,
+            virtual_sol_reserves,
+            real_token_reserves,
+            token_total_supply,
+
+            description,
+            twitter,
+            telegram,
+            website,
+            image
+        )
+        VALUES (
+            $1,$2,$3,$4,$5,$6::platform_type,
+            $7,$8,$9,
+            $10,$11,$12,$13,$14,$15,$16,
+            $17,$18,$19,$20,$21
+        )
+        ON CONFLICT (mint)
+        DO UPDATE SET
+            name          = COALESCE(NULLIF(EXCLUDED.name, ''), tokens.name),
+            symbol        = COALESCE(NULLIF(EXCLUDED.symbol, ''), tokens.symbol),
+            uri           = COALESCE(EXCLUDED.uri, tokens.uri),
+
+            bonding_curve = COALESCE(EXCLUDED.bonding_curve, tokens.bonding_curve),
+            pool_state    = COALESCE(EXCLUDED.pool_state, tokens.pool_state),
+            pool          = COALESCE(EXCLUDED.pool, tokens.pool),
+
+            description   = COALESCE(NULLIF(EXCLUDED.description, ''), tokens.description),
+            twitter       = COALESCE(NULLIF(EXCLUDED.twitter, ''), tokens.twitter),
+            telegram      = COALESCE(NULLIF(EXCLUDED.telegram, ''), tokens.telegram),
+            website       = COALESCE(NULLIF(EXCLUDED.website, ''), tokens.website),
+            image         = COALESCE(NULLIF(EXCLUDED.image, ''), tokens.image),
+
+            updated_at    = now()
+        "#,
+    )
+    .bind(&dto.name)
+    .bind(&dto.symbol)
+    .bind(&dto.uri)
+    .bind(&dto.creator)
+    .bind(&dto.mint)
+    .bind(&dto.platform)
+    .bind(&dto.bonding_curve)
+    .bind(&dto.pool_state)
+    .bind(&dto.pool)
+    .bind(&dto.user_address)
+    .bind(dto.decimal)
+    .bind(dto.ts)
+    .bind(dto.virtual_token_reserves)
+    .bind(dto.virtual_sol_reserves)
+    .bind(dto.real_token_reserves)
+    .bind(dto.token_total_supply)
+    .bind(&dto.description)
+    .bind(&dto.twitter)
+    .bind(&dto.telegram)
+    .bind(&dto.website)
+    .bind(&dto.image)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Record the pool a token migrated to, and point the token at it
+pub async fn upsert_pgsql_migration_pool(
+    pool: &PgPool,
+    dto: PgsqlPoolDto,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO pools (
+            mint,
+            platform,
+            pool,
+            pair,
+            token_a,
+            token_b
+        )
+        VALUES (
+            $1,
+            $2::platform_type,
+            $3,
+            COALESCE((SELECT NULLIF(symbol, '') FROM tokens WHERE mint = $1), $1) || '/' || $6,
+            $4,
+            $5
+        )
+        ON CONFLICT (pool)
+        DO UPDATE SET
+            mint       = EXCLUDED.mint,
+            platform   = EXCLUDED.platform,
+            pair       = EXCLUDED.pair,
+            token_a    = EXCLUDED.token_a,
+            token_b    = EXCLUDED.token_b,
+            updated_at = now()
+        "#,
+    )
+    .bind(&dto.mint)
+    .bind(&dto.platform)
+    .bind(&dto.pool)
+    .bind(&dto.token_a)
+    .bind(&dto.token_b)
+    .bind(&dto.quote_symbol)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+        UPDATE tokens
+        SET
+            pool       = $2,
+            updated_at = now()
+        WHERE mint = $1
+        "#,
+    )
+    .bind(&dto.mint)
+    .bind(&dto.pool)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
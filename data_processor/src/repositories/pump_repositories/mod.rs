@@ -1,3 +1,6 @@
 pub mod pf_ch_repositories;
 pub mod pf_pgsql_repositories;
 pub mod pf_redis_repositories;
+pub mod ps_ch_repositories;
+pub mod ps_pgsql_repositories;
+pub mod ps_redis_repositories;
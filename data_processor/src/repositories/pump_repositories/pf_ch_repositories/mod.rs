@@ -1 +1,5 @@
+pub mod pf_ch_candles;
+pub mod pf_ch_creator_fees;
+pub mod pf_ch_curve_states;
+pub mod pf_ch_token_incentives;
 pub mod pf_ch_trade;
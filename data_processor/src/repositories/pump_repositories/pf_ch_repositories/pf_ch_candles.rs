@@ -0,0 +1,136 @@
+use crate::models::pump_models::pf_models::pf_ch::{PfCandleInterval, PfChCandle};
+use crate::state::AppState;
+use anyhow::bail;
+use chrono::{DateTime, Utc};
+use clickhouse::Row;
+use serde::Deserialize;
+
+/// Most candles a single query may return, gaps included
+const PF_MAX_CANDLES: i64 = 5_000;
+
+#[derive(Row, Deserialize)]
+struct PfChClose {
+    close: f64,
+}
+
+/// Candles of `mint` covering `[from, to)`, oldest first.
+/// Buckets without trades are filled with a flat candle at the previous close and no volume;
+/// buckets before the mint's first trade are left out.
+pub async fn get_pf_ch_candles(
+    state: &AppState,
+    mint: &str,
+    interval: PfCandleInterval,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> anyhow::Result<Vec<PfChCandle>> {
+    let step = i64::from(interval.secs());
+    let (first_bucket, buckets) = pf_candle_buckets(step, from, to);
+
+    if buckets > PF_MAX_CANDLES {
+        bail!("get_pf_ch_candles: {buckets} candles requested, at most {PF_MAX_CANDLES}");
+    }
+    if buckets <= 0 {
+        return Ok(Vec::new());
+    }
+
+    let candles = state
+        .clickhouse
+        .query(
+            r#"
+            SELECT
+                bucket, open, high, low, close,
+                sol_volume, token_volume, buy_count, sell_count, unique_traders
+            FROM pf_candles
+            WHERE mint = ?
+              AND interval_secs = ?
+              AND bucket >= toDateTime(?, 'UTC')
+              AND bucket < toDateTime(?, 'UTC')
+            ORDER BY bucket
+            "#,
+        )
+        .bind(mint)
+        .bind(interval.secs())
+        .bind(first_bucket)
+        .bind(to.timestamp())
+        .fetch_all::<PfChCandle>()
+        .await?;
+
+    let previous_close = state
+        .clickhouse
+        .query(
+            r#"
+            SELECT close
+            FROM pf_candles
+            WHERE mint = ?
+              AND interval_secs = ?
+              AND bucket < toDateTime(?, 'UTC')
+            ORDER BY bucket DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(mint)
+        .bind(interval.secs())
+        .bind(first_bucket)
+        .fetch_optional::<PfChClose>()
+        .await?
+        .map(|row| row.close);
+
+    Ok(fill_pf_candle_gaps(
+        candles,
+        previous_close,
+        first_bucket,
+        buckets,
+        step,
+    ))
+}
+
+/// First bucket and number of buckets of `step` seconds covering `[from, to)`.
+/// Partial buckets at either end are included.
+pub fn pf_candle_buckets(step: i64, from: DateTime<Utc>, to: DateTime<Utc>) -> (i64, i64) {
+    let first_bucket = from.timestamp().div_euclid(step) * step;
+    let buckets = (to.timestamp() - first_bucket + step - 1).div_euclid(step);
+    (first_bucket, buckets)
+}
+
+/// Lay `candles` out on `buckets` consecutive buckets of `step` seconds from `first_bucket`,
+/// carrying the last close forward through buckets without trades.
+pub fn fill_pf_candle_gaps(
+    candles: Vec<PfChCandle>,
+    previous_close: Option<f64>,
+    first_bucket: i64,
+    buckets: i64,
+    step: i64,
+) -> Vec<PfChCandle> {
+    let mut candles = candles.into_iter().peekable();
+    let mut last_close = previous_close;
+    let mut filled = Vec::with_capacity(buckets as usize);
+
+    for i in 0..buckets {
+        let bucket = first_bucket + i * step;
+
+        if let Some(candle) = candles.next_if(|c| c.bucket.timestamp() == bucket) {
+            last_close = Some(candle.close);
+            filled.push(candle);
+            continue;
+        }
+
+        let (Some(close), Some(bucket)) = (last_close, DateTime::from_timestamp(bucket, 0)) else {
+            continue;
+        };
+
+        filled.push(PfChCandle {
+            bucket,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            sol_volume: 0,
+            token_volume: 0,
+            buy_count: 0,
+            sell_count: 0,
+            unique_traders: 0,
+        });
+    }
+
+    filled
+}
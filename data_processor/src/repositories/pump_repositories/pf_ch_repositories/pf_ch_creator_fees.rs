@@ -0,0 +1,18 @@
+use crate::models::pump_models::pf_models::pf_ch_dto::PfChCreatorFeeDto;
+use crate::state::AppState;
+use log::debug;
+
+/// Append to the creator fee ledger; `pf_creator_fee_balances_mv` rolls it up per creator
+pub async fn insert_pf_ch_creator_fee(
+    state: &AppState,
+    fee_dto: &PfChCreatorFeeDto,
+) -> anyhow::Result<()> {
+    state.ch_writer.write(fee_dto).await?;
+
+    debug!(
+        "Buffered pf_creator_fee for {}: {}",
+        fee_dto.creator, fee_dto.signature
+    );
+
+    Ok(())
+}
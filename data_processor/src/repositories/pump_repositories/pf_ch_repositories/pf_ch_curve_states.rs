@@ -0,0 +1,18 @@
+use crate::models::pump_models::pf_models::pf_ch_dto::PfChCurveStateDto;
+use crate::state::AppState;
+use log::debug;
+
+/// Append an account-sourced bonding curve state to `pf_curve_states`
+pub async fn insert_pf_ch_curve_state(
+    state: &AppState,
+    curve_dto: &PfChCurveStateDto,
+) -> anyhow::Result<()> {
+    state.ch_writer.write(curve_dto).await?;
+
+    debug!(
+        "Buffered pf_curve_state for {} at slot {}",
+        curve_dto.bonding_curve, curve_dto.slot
+    );
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use crate::models::pump_models::pf_models::pf_ch_dto::PfChTokenIncentiveDto;
+use crate::state::AppState;
+use log::debug;
+
+/// Append to the per-wallet incentive history
+pub async fn insert_pf_ch_token_incentive(
+    state: &AppState,
+    incentive_dto: &PfChTokenIncentiveDto,
+) -> anyhow::Result<()> {
+    state.ch_writer.write(incentive_dto).await?;
+
+    debug!(
+        "Buffered pf_token_incentive for {} at slot {}",
+        incentive_dto.wallet, incentive_dto.slot
+    );
+
+    Ok(())
+}
@@ -1 +1,4 @@
+pub mod pf_pgsql_creator_fees;
 pub mod pf_pgsql_prices;
+pub mod pf_pgsql_protocol_params;
+pub mod pf_pgsql_token_incentives;
@@ -0,0 +1,110 @@
+use crate::models::pump_models::pf_models::pf_enums::PfCreatorFeeKind;
+use crate::models::pump_models::pf_models::pf_pgsql::PfPgsqlCreatorFee;
+use crate::models::pump_models::pf_models::pf_pgsql_dto::PfPgsqlCreatorFeeDto;
+use crate::repositories::pgsql_ledger_events::{CREATOR_FEE_LEDGER, claim_pgsql_ledger_event};
+use chrono::Utc;
+use sqlx::PgPool;
+
+/// Apply one accrual or claim to the (creator, platform) balance and return the updated row.
+/// A redelivered event leaves the balance untouched and returns it as stored.
+pub async fn upsert_pf_pgsql_creator_fee(
+    pool: &PgPool,
+    dto: PfPgsqlCreatorFeeDto,
+) -> Result<PfPgsqlCreatorFee, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    if !claim_pgsql_ledger_event(
+        &mut tx,
+        CREATOR_FEE_LEDGER,
+        dto.event_type,
+        &dto.signature,
+        dto.ix_index,
+        dto.event_ordinal,
+        dto.slot,
+    )
+    .await?
+    {
+        let balance = sqlx::query_as::<_, PfPgsqlCreatorFee>(
+            "SELECT * FROM pf_creator_fees WHERE creator = $1 AND platform = $2",
+        )
+        .bind(&dto.creator)
+        .bind(dto.platform)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        return Ok(balance);
+    }
+
+    let now = Utc::now();
+    let amount = dto.amount_lamports as i64;
+
+    let (accrued, claimed, accrual_signature, claim_signature, accrual_ts, claim_ts) = match dto
+        .kind
+    {
+        PfCreatorFeeKind::Accrual => (amount, 0, Some(&dto.signature), None, Some(dto.ts), None),
+        PfCreatorFeeKind::Claim => (0, amount, None, Some(&dto.signature), None, Some(dto.ts)),
+    };
+
+    let balance = sqlx::query_as::<_, PfPgsqlCreatorFee>(
+        r#"
+        INSERT INTO pf_creator_fees (
+            creator,
+            platform,
+
+            accrued_lamports,
+            claimed_lamports,
+
+            accrual_count,
+            claim_count,
+
+            last_accrual_signature,
+            last_claim_signature,
+            last_accrual_ts,
+            last_claim_ts,
+
+            created_at,
+            updated_at
+        )
+        VALUES (
+            $1,$2,
+            $3,$4,
+            $5,$6,
+            $7,$8,$9,$10,
+            $11,$12
+        )
+        ON CONFLICT (creator, platform)
+        DO UPDATE SET
+            accrued_lamports       = pf_creator_fees.accrued_lamports + EXCLUDED.accrued_lamports,
+            claimed_lamports       = pf_creator_fees.claimed_lamports + EXCLUDED.claimed_lamports,
+
+            accrual_count          = pf_creator_fees.accrual_count + EXCLUDED.accrual_count,
+            claim_count            = pf_creator_fees.claim_count + EXCLUDED.claim_count,
+
+            last_accrual_signature = COALESCE(EXCLUDED.last_accrual_signature, pf_creator_fees.last_accrual_signature),
+            last_claim_signature   = COALESCE(EXCLUDED.last_claim_signature, pf_creator_fees.last_claim_signature),
+            last_accrual_ts        = GREATEST(EXCLUDED.last_accrual_ts, pf_creator_fees.last_accrual_ts),
+            last_claim_ts          = GREATEST(EXCLUDED.last_claim_ts, pf_creator_fees.last_claim_ts),
+
+            updated_at             = EXCLUDED.updated_at
+        RETURNING *
+        "#,
+    )
+    .bind(&dto.creator)
+    .bind(dto.platform)
+    .bind(accrued)
+    .bind(claimed)
+    .bind(i64::from(accrued > 0))
+    .bind(i64::from(claimed > 0))
+    .bind(accrual_signature)
+    .bind(claim_signature)
+    .bind(accrual_ts)
+    .bind(claim_ts)
+    .bind(now)
+    .bind(now)
+    .fetch_one(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(balance)
+}
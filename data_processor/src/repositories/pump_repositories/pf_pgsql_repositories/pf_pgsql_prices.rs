@@ -1,10 +1,13 @@
+use crate::models::pump_models::pf_models::pf_pgsql::PfPgsqlCurveToken;
 use crate::models::pump_models::pf_models::pf_pgsql_dto::{
     PfPgsqlBondingCurveProgressDto, PfPgsqlPriceDto,
 };
 use chrono::Utc;
 use sqlx::PgPool;
 
-/// Insert or update latest price for a (mint, bonding_curve) pair
+/// Insert or update latest price for a (mint, bonding_curve) pair.
+/// Last writer wins by `(slot, tx_index)`: a price older than the stored one is ignored,
+/// so out-of-order deliveries and backfills cannot move the price backwards.
 pub async fn upsert_pf_pgsql_price(pool: &PgPool, dto: PfPgsqlPriceDto) -> Result<(), sqlx::Error> {
     let now = Utc::now();
 
@@ -22,6 +25,8 @@ pub async fn upsert_pf_pgsql_price(pool: &PgPool, dto: PfPgsqlPriceDto) -> Resul
             virtual_sol_reserves,
             real_token_reserves,
             real_sol_reserves,
+            slot,
+            tx_index,
             ts,
             created_at,
             updated_at
@@ -29,21 +34,24 @@ pub async fn upsert_pf_pgsql_price(pool: &PgPool, dto: PfPgsqlPriceDto) -> Resul
         VALUES (
             $1,$2,$3,$4,$5,$6,$7,
             $8,$9,$10,$11,
-            $12,$13,$14
+            $12,$13,$14,$15,$16
         )
-        ON CONFLICT (mint, bonding_curve)
+        ON CONFLICT (bonding_curve)
         DO UPDATE SET
             last_signature = EXCLUDED.last_signature,
             price = EXCLUDED.price,
             source = EXCLUDED.source,
             direction = EXCLUDED.direction,
-            decimal = EXCLUDED.decimal,
+            decimals = EXCLUDED.decimals,
             virtual_token_reserves = EXCLUDED.virtual_token_reserves,
             virtual_sol_reserves = EXCLUDED.virtual_sol_reserves,
             real_token_reserves = EXCLUDED.real_token_reserves,
             real_sol_reserves = EXCLUDED.real_sol_reserves,
+            slot = EXCLUDED.slot,
+            tx_index = EXCLUDED.tx_index,
             ts = EXCLUDED.ts,
             updated_at = EXCLUDED.updated_at
+        WHERE (pf_prices.slot, pf_prices.tx_index) <= (EXCLUDED.slot, EXCLUDED.tx_index)
         "#,
     )
     .bind(&dto.mint)
@@ -57,6 +65,8 @@ pub async fn upsert_pf_pgsql_price(pool: &PgPool, dto: PfPgsqlPriceDto) -> Resul
     .bind(dto.virtual_sol_reserves)
     .bind(dto.real_token_reserves)
     .bind(dto.real_sol_reserves)
+    .bind(dto.slot)
+    .bind(dto.tx_index)
     .bind(dto.ts)
     .bind(now)
     .bind(now)
@@ -66,7 +76,8 @@ pub async fn upsert_pf_pgsql_price(pool: &PgPool, dto: PfPgsqlPriceDto) -> Resul
     Ok(())
 }
 
-/// Insert or update bonding-curve progress for a bonding_curve (canonical row)
+/// Insert or update bonding-curve progress for a bonding_curve (canonical row).
+/// Progress from a slot older than the stored one is ignored.
 pub async fn upsert_pf_pgsql_bonding_curve_progress(
     pool: &PgPool,
     dto: PfPgsqlBondingCurveProgressDto,
@@ -96,6 +107,7 @@ pub async fn upsert_pf_pgsql_bonding_curve_progress(
             is_migrated,
             is_tradeable,
 
+            slot,
             created_at,
             updated_at
         )
@@ -105,7 +117,7 @@ pub async fn upsert_pf_pgsql_bonding_curve_progress(
             $5,$6,$7,$8,
             $9,$10,$11,$12,
             $13,$14,$15,
-            $16,$17
+            $16,$17,$18
         )
         ON CONFLICT (bonding_curve)
         DO UPDATE SET
@@ -124,11 +136,14 @@ pub async fn upsert_pf_pgsql_bonding_curve_progress(
             price_lamports         = EXCLUDED.price_lamports,
             market_cap_lamports    = EXCLUDED.market_cap_lamports,
 
-            is_pre_migration       = EXCLUDED.is_pre_migration,
-            is_migrated            = EXCLUDED.is_migrated,
-            is_tradeable           = EXCLUDED.is_tradeable,
+            -- migration is one-way; a late curve update must not undo it
+            is_pre_migration       = EXCLUDED.is_pre_migration AND NOT pf_bonding_curve_progress.is_migrated,
+            is_migrated            = EXCLUDED.is_migrated OR pf_bonding_curve_progress.is_migrated,
+            is_tradeable           = EXCLUDED.is_tradeable AND NOT pf_bonding_curve_progress.is_migrated,
 
+            slot                   = EXCLUDED.slot,
             updated_at             = EXCLUDED.updated_at
+        WHERE pf_bonding_curve_progress.slot <= EXCLUDED.slot
         "#,
     )
     .bind(&dto.mint)
@@ -146,6 +161,7 @@ pub async fn upsert_pf_pgsql_bonding_curve_progress(
     .bind(dto.is_pre_migration)
     .bind(dto.is_migrated)
     .bind(dto.is_tradeable)
+    .bind(dto.slot as i64)
     .bind(now)
     .bind(now)
     .execute(pool)
@@ -153,3 +169,69 @@ pub async fn upsert_pf_pgsql_bonding_curve_progress(
 
     Ok(())
 }
+
+/// Token behind a bonding curve, `None` until a trade or creation stored its price
+pub async fn get_pf_pgsql_curve_token(
+    pool: &PgPool,
+    bonding_curve: &str,
+) -> Result<Option<PfPgsqlCurveToken>, sqlx::Error> {
+    sqlx::query_as::<_, PfPgsqlCurveToken>(
+        r#"
+        SELECT
+            mint,
+            decimals,
+            virtual_sol_reserves,
+            direction = 'buy' AS is_buy
+        FROM pf_prices
+        WHERE bonding_curve = $1
+        "#,
+    )
+    .bind(bonding_curve)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Mark a sold-out curve as no longer tradeable. Returns the curve's mint, `None` if unknown.
+pub async fn complete_pf_pgsql_bonding_curve(
+    pool: &PgPool,
+    bonding_curve: &str,
+) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar::<_, String>(
+        r#"
+        UPDATE pf_bonding_curve_progress
+        SET
+            real_token_reserves = 0,
+            progress_bps        = 10000,
+            progress_pct        = 100,
+            is_tradeable        = FALSE,
+            updated_at          = now()
+        WHERE bonding_curve = $1
+        RETURNING mint
+        "#,
+    )
+    .bind(bonding_curve)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Mark a token's curve as migrated. Returns the bonding curve, `None` if unknown.
+pub async fn migrate_pf_pgsql_bonding_curve(
+    pool: &PgPool,
+    mint: &str,
+) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar::<_, String>(
+        r#"
+        UPDATE pf_bonding_curve_progress
+        SET
+            is_pre_migration = FALSE,
+            is_migrated      = TRUE,
+            is_tradeable     = FALSE,
+            updated_at       = now()
+        WHERE mint = $1
+        RETURNING bonding_curve
+        "#,
+    )
+    .bind(mint)
+    .fetch_optional(pool)
+    .await
+}
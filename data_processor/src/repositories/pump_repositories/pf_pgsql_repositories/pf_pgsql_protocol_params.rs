@@ -0,0 +1,167 @@
+use crate::models::pump_models::pf_models::pf_pgsql::{PfPgsqlFeeConfig, PfPgsqlGlobalParams};
+use crate::models::pump_models::pf_models::pf_pgsql_dto::{
+    PfPgsqlFeeConfigDto, PfPgsqlGlobalParamsDto,
+};
+use sqlx::PgPool;
+use sqlx::types::Json;
+
+/// Record a new `Global` version if the parameters differ from the version in effect at `dto.slot`.
+/// Returns the inserted row, or `None` when nothing changed.
+pub async fn insert_pf_pgsql_global_params(
+    pool: &PgPool,
+    dto: PfPgsqlGlobalParamsDto,
+) -> Result<Option<PfPgsqlGlobalParams>, sqlx::Error> {
+    sqlx::query_as::<_, PfPgsqlGlobalParams>(
+        r#"
+        WITH in_effect AS (
+            SELECT *
+            FROM pf_global_params
+            WHERE effective_slot <= $1
+            ORDER BY effective_slot DESC
+            LIMIT 1
+        )
+        INSERT INTO pf_global_params (
+            version,
+            effective_slot,
+
+            fee_recipient,
+            initial_virtual_token_reserves,
+            initial_virtual_sol_reserves,
+            initial_real_token_reserves,
+            token_total_supply,
+
+            fee_basis_points,
+            creator_fee_basis_points,
+            enable_migrate,
+            pool_migration_fee,
+
+            observed_at
+        )
+        SELECT
+            (SELECT COALESCE(MAX(version), 0) + 1 FROM pf_global_params),
+            $1,
+            $2,$3,$4,$5,$6,
+            $7,$8,$9,$10,
+            $11
+        WHERE NOT EXISTS (
+            SELECT 1
+            FROM in_effect
+            WHERE fee_recipient                  = $2
+              AND initial_virtual_token_reserves = $3
+              AND initial_virtual_sol_reserves   = $4
+              AND initial_real_token_reserves    = $5
+              AND token_total_supply             = $6
+              AND fee_basis_points               = $7
+              AND creator_fee_basis_points       = $8
+              AND enable_migrate                 = $9
+              AND pool_migration_fee             = $10
+        )
+        ON CONFLICT (effective_slot) DO NOTHING
+        RETURNING *
+        "#,
+    )
+    .bind(dto.slot as i64)
+    .bind(&dto.fee_recipient)
+    .bind(dto.initial_virtual_token_reserves as i64)
+    .bind(dto.initial_virtual_sol_reserves as i64)
+    .bind(dto.initial_real_token_reserves as i64)
+    .bind(dto.token_total_supply as i64)
+    .bind(dto.fee_basis_points as i64)
+    .bind(dto.creator_fee_basis_points as i64)
+    .bind(dto.enable_migrate)
+    .bind(dto.pool_migration_fee as i64)
+    .bind(dto.ts)
+    .fetch_optional(pool)
+    .await
+}
+
+/// `Global` parameters in effect at `slot`, `None` if no version that old is recorded
+pub async fn get_pf_pgsql_global_params_at_slot(
+    pool: &PgPool,
+    slot: u64,
+) -> Result<Option<PfPgsqlGlobalParams>, sqlx::Error> {
+    sqlx::query_as::<_, PfPgsqlGlobalParams>(
+        r#"
+        SELECT *
+        FROM pf_global_params
+        WHERE effective_slot <= $1
+        ORDER BY effective_slot DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(slot as i64)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Record a new `FeeConfig` version if it differs from the version in effect at `dto.slot`.
+/// Returns the inserted row, or `None` when nothing changed.
+pub async fn insert_pf_pgsql_fee_config(
+    pool: &PgPool,
+    dto: PfPgsqlFeeConfigDto,
+) -> Result<Option<PfPgsqlFeeConfig>, sqlx::Error> {
+    sqlx::query_as::<_, PfPgsqlFeeConfig>(
+        r#"
+        WITH in_effect AS (
+            SELECT *
+            FROM pf_fee_configs
+            WHERE effective_slot <= $1
+            ORDER BY effective_slot DESC
+            LIMIT 1
+        )
+        INSERT INTO pf_fee_configs (
+            version,
+            effective_slot,
+
+            flat_lp_fee_bps,
+            flat_protocol_fee_bps,
+            flat_creator_fee_bps,
+            fee_tiers,
+
+            observed_at
+        )
+        SELECT
+            (SELECT COALESCE(MAX(version), 0) + 1 FROM pf_fee_configs),
+            $1,
+            $2,$3,$4,$5,
+            $6
+        WHERE NOT EXISTS (
+            SELECT 1
+            FROM in_effect
+            WHERE flat_lp_fee_bps       = $2
+              AND flat_protocol_fee_bps = $3
+              AND flat_creator_fee_bps  = $4
+              AND fee_tiers             = $5
+        )
+        ON CONFLICT (effective_slot) DO NOTHING
+        RETURNING *
+        "#,
+    )
+    .bind(dto.slot as i64)
+    .bind(dto.flat_lp_fee_bps as i64)
+    .bind(dto.flat_protocol_fee_bps as i64)
+    .bind(dto.flat_creator_fee_bps as i64)
+    .bind(Json(&dto.fee_tiers))
+    .bind(dto.ts)
+    .fetch_optional(pool)
+    .await
+}
+
+/// `FeeConfig` in effect at `slot`, `None` if no version that old is recorded
+pub async fn get_pf_pgsql_fee_config_at_slot(
+    pool: &PgPool,
+    slot: u64,
+) -> Result<Option<PfPgsqlFeeConfig>, sqlx::Error> {
+    sqlx::query_as::<_, PfPgsqlFeeConfig>(
+        r#"
+        SELECT *
+        FROM pf_fee_configs
+        WHERE effective_slot <= $1
+        ORDER BY effective_slot DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(slot as i64)
+    .fetch_optional(pool)
+    .await
+}
@@ -0,0 +1,126 @@
+use crate::models::pump_models::pf_models::pf_pgsql::PfPgsqlTokenIncentive;
+use crate::models::pump_models::pf_models::pf_pgsql_dto::PfPgsqlTokenIncentiveDto;
+use crate::repositories::pgsql_ledger_events::{TOKEN_INCENTIVE_LEDGER, claim_pgsql_ledger_event};
+use chrono::Utc;
+use sqlx::PgPool;
+
+/// Apply one incentive update to the wallet row and return it.
+/// Accumulator state only moves forward in slot order; claims are counted once per
+/// event, so a redelivered event returns the row as stored.
+pub async fn upsert_pf_pgsql_token_incentive(
+    pool: &PgPool,
+    dto: PfPgsqlTokenIncentiveDto,
+) -> Result<PfPgsqlTokenIncentive, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    if let Some(signature) = &dto.signature
+        && !claim_pgsql_ledger_event(
+            &mut tx,
+            TOKEN_INCENTIVE_LEDGER,
+            dto.event_type,
+            signature,
+            dto.ix_index,
+            dto.event_ordinal,
+            dto.slot,
+        )
+        .await?
+    {
+        let balance = sqlx::query_as::<_, PfPgsqlTokenIncentive>(
+            "SELECT * FROM pf_token_incentives WHERE wallet = $1",
+        )
+        .bind(&dto.wallet)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        return Ok(balance);
+    }
+
+    let now = Utc::now();
+    let is_claim = dto.claim_amount.is_some();
+    let claim_signature = dto.signature.as_ref().filter(|_| is_claim);
+    let claim_ts = is_claim.then_some(dto.ts);
+
+    let balance = sqlx::query_as::<_, PfPgsqlTokenIncentive>(
+        r#"
+        INSERT INTO pf_token_incentives (
+            wallet,
+            mint,
+
+            needs_claim,
+            total_unclaimed_tokens,
+            total_claimed_tokens,
+            current_sol_volume,
+            last_update_timestamp,
+            slot,
+
+            claim_count,
+            last_claim_signature,
+            last_claim_amount,
+            last_claim_ts,
+
+            created_at,
+            updated_at
+        )
+        VALUES (
+            $1,$2,
+            $3,$4,$5,$6,$7,$8,
+            $9,$10,$11,$12,
+            $13,$14
+        )
+        ON CONFLICT (wallet)
+        DO UPDATE SET
+            mint                   = COALESCE(EXCLUDED.mint, pf_token_incentives.mint),
+
+            needs_claim            = CASE WHEN EXCLUDED.slot >= pf_token_incentives.slot
+                                          THEN EXCLUDED.needs_claim
+                                          ELSE pf_token_incentives.needs_claim END,
+            total_unclaimed_tokens = CASE WHEN EXCLUDED.slot >= pf_token_incentives.slot
+                                          THEN EXCLUDED.total_unclaimed_tokens
+                                          ELSE pf_token_incentives.total_unclaimed_tokens END,
+            total_claimed_tokens   = CASE WHEN EXCLUDED.slot >= pf_token_incentives.slot
+                                          THEN EXCLUDED.total_claimed_tokens
+                                          ELSE pf_token_incentives.total_claimed_tokens END,
+            current_sol_volume     = CASE WHEN EXCLUDED.slot >= pf_token_incentives.slot
+                                          THEN EXCLUDED.current_sol_volume
+                                          ELSE pf_token_incentives.current_sol_volume END,
+            last_update_timestamp  = CASE WHEN EXCLUDED.slot >= pf_token_incentives.slot
+                                          THEN EXCLUDED.last_update_timestamp
+                                          ELSE pf_token_incentives.last_update_timestamp END,
+            slot                   = GREATEST(EXCLUDED.slot, pf_token_incentives.slot),
+
+            claim_count            = pf_token_incentives.claim_count + EXCLUDED.claim_count,
+            last_claim_signature   = CASE WHEN EXCLUDED.last_claim_ts >= pf_token_incentives.last_claim_ts
+                                               OR pf_token_incentives.last_claim_ts IS NULL
+                                          THEN COALESCE(EXCLUDED.last_claim_signature, pf_token_incentives.last_claim_signature)
+                                          ELSE pf_token_incentives.last_claim_signature END,
+            last_claim_amount      = CASE WHEN EXCLUDED.last_claim_ts >= pf_token_incentives.last_claim_ts
+                                               OR pf_token_incentives.last_claim_ts IS NULL
+                                          THEN COALESCE(EXCLUDED.last_claim_amount, pf_token_incentives.last_claim_amount)
+                                          ELSE pf_token_incentives.last_claim_amount END,
+            last_claim_ts          = GREATEST(EXCLUDED.last_claim_ts, pf_token_incentives.last_claim_ts),
+
+            updated_at             = EXCLUDED.updated_at
+        RETURNING *
+        "#,
+    )
+    .bind(&dto.wallet)
+    .bind(&dto.mint)
+    .bind(dto.needs_claim)
+    .bind(dto.total_unclaimed_tokens as i64)
+    .bind(dto.total_claimed_tokens as i64)
+    .bind(dto.current_sol_volume as i64)
+    .bind(dto.last_update_timestamp)
+    .bind(dto.slot as i64)
+    .bind(i64::from(is_claim))
+    .bind(claim_signature)
+    .bind(dto.claim_amount.map(|a| a as i64))
+    .bind(claim_ts)
+    .bind(now)
+    .bind(now)
+    .fetch_one(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(balance)
+}
@@ -1 +1,3 @@
+pub mod pf_redis_creator_fees;
 pub mod pf_redis_prices;
+pub mod pf_redis_token_incentives;
@@ -0,0 +1,30 @@
+use crate::models::pump_models::pf_models::pf_redis::PfRedisCreatorFee;
+use crate::state::AppState;
+use redis::AsyncCommands;
+
+/// Overwrite the platform balance in `pf_creator_fees:{creator}`.
+/// Postgres is authoritative, so the row is stored as returned by the upsert.
+pub async fn upsert_pf_redis_creator_fee(
+    state: &AppState,
+    incoming: PfRedisCreatorFee,
+) -> Result<(), redis::RedisError> {
+    let mut redis = state.cache.clone();
+
+    let value = serde_json::to_string(&incoming).map_err(|e| {
+        redis::RedisError::from((
+            redis::ErrorKind::TypeError,
+            "failed to serialize PfRedisCreatorFee",
+            e.to_string(),
+        ))
+    })?;
+
+    let _: () = redis
+        .hset(
+            format!("pf_creator_fees:{}", incoming.creator),
+            incoming.platform.as_str(),
+            value,
+        )
+        .await?;
+
+    Ok(())
+}
@@ -1,28 +1,22 @@
 use crate::models::pump_models::pf_models::pf_redis::{PfRedisBondingCurveProgress, PfRedisPrice};
+use crate::repositories::redis_json::merge_redis_json;
 use crate::state::AppState;
 use chrono::Utc;
-use redis::AsyncCommands;
 
-/// Upsert `PfRedisPrice` into Redis without clobbering metadata fields
+/// Upsert `PfRedisPrice` into Redis without clobbering metadata fields.
+/// Last writer wins by `(slot, tx_index)`, like `upsert_pf_pgsql_price`.
 pub async fn upsert_pf_redis_price(
     state: &AppState,
     incoming: PfRedisPrice,
 ) -> Result<(), redis::RedisError> {
-    let mut redis = state.cache.clone();
-    // Fetch existing value (if any)
-    let existing_json: Option<String> = redis.hget("pf_prices", &incoming.mint).await?;
-
-    let merged = if let Some(json) = existing_json {
-        let mut existing: PfRedisPrice = serde_json::from_str(&json).map_err(|e| {
-            redis::RedisError::from((
-                redis::ErrorKind::TypeError,
-                "failed to deserialize PfRedisPrice",
-                e.to_string(),
-            ))
-        })?;
+    merge_pf_redis_price(state, &incoming.mint, |existing| {
+        let Some(mut existing) = existing else {
+            // First insert — safe to store as-is
+            return Some(incoming.clone());
+        };
 
         // ===== overwrite trade-derived fields =====
-        existing.bonding_curve = incoming.bonding_curve;
+        existing.bonding_curve = incoming.bonding_curve.clone();
         existing.price = incoming.price;
         existing.source = incoming.source;
         existing.direction = incoming.direction;
@@ -31,74 +25,32 @@ pub async fn upsert_pf_redis_price(
         existing.virtual_sol_reserves = incoming.virtual_sol_reserves;
         existing.real_token_reserves = incoming.real_token_reserves;
         existing.real_sol_reserves = incoming.real_sol_reserves;
+        existing.slot = incoming.slot;
+        existing.tx_index = incoming.tx_index;
         existing.ts = incoming.ts;
         existing.updated_at = Utc::now();
 
         // ===== preserve metadata unless explicitly provided =====
-        if incoming.name.is_some() {
-            existing.name = incoming.name;
-        }
-        if incoming.symbol.is_some() {
-            existing.symbol = incoming.symbol;
-        }
-        if incoming.uri.is_some() {
-            existing.uri = incoming.uri;
-        }
-        if incoming.description.is_some() {
-            existing.description = incoming.description;
-        }
-        if incoming.twitter.is_some() {
-            existing.twitter = incoming.twitter;
-        }
-        if incoming.telegram.is_some() {
-            existing.telegram = incoming.telegram;
-        }
-        if incoming.website.is_some() {
-            existing.website = incoming.website;
-        }
-        if incoming.image.is_some() {
-            existing.image = incoming.image;
-        }
-
-        existing
-    } else {
-        // First insert — safe to store as-is
-        incoming
-    };
-
-    let value = serde_json::to_string(&merged).map_err(|e| {
-        redis::RedisError::from((
-            redis::ErrorKind::TypeError,
-            "failed to serialize PfRedisPrice",
-            e.to_string(),
-        ))
-    })?;
-
-    let _: () = redis.hset("pf_prices", &merged.mint, value).await?;
-    Ok(())
+        merge_metadata(&mut existing, &incoming);
+
+        Some(existing)
+    })
+    .await
 }
 
+/// Upsert `PfRedisBondingCurveProgress` into Redis; progress from an older slot is ignored,
+/// like `upsert_pf_pgsql_bonding_curve_progress`.
 pub async fn upsert_pf_redis_bonding_curve_progress(
     state: &AppState,
     incoming: PfRedisBondingCurveProgress,
 ) -> Result<(), redis::RedisError> {
-    let mut redis = state.cache.clone();
-    let existing_json: Option<String> = redis
-        .hget("pf_bonding_curve_progress", &incoming.mint)
-        .await?;
-
-    let merged = if let Some(json) = existing_json {
-        let mut existing: PfRedisBondingCurveProgress =
-            serde_json::from_str(&json).map_err(|e| {
-                redis::RedisError::from((
-                    redis::ErrorKind::TypeError,
-                    "failed to deserialize PfRedisBondingCurveProgress",
-                    e.to_string(),
-                ))
-            })?;
+    merge_pf_redis_bonding_curve(state, &incoming.mint, |existing| {
+        let Some(mut existing) = existing else {
+            return Some(incoming.clone());
+        };
 
         /* overwrite authoritative fields */
-        existing.bonding_curve = incoming.bonding_curve;
+        existing.bonding_curve = incoming.bonding_curve.clone();
 
         existing.virtual_sol_reserves = incoming.virtual_sol_reserves;
         existing.virtual_token_reserves = incoming.virtual_token_reserves;
@@ -110,30 +62,138 @@ pub async fn upsert_pf_redis_bonding_curve_progress(
         existing.price_lamports = incoming.price_lamports;
         existing.market_cap_lamports = incoming.market_cap_lamports;
 
-        existing.is_pre_migration = incoming.is_pre_migration;
-        existing.is_migrated = incoming.is_migrated;
-        existing.is_tradeable = incoming.is_tradeable;
+        // migration is one-way; a late curve update must not undo it
+        existing.is_pre_migration = incoming.is_pre_migration && !existing.is_migrated;
+        existing.is_tradeable = incoming.is_tradeable && !existing.is_migrated;
+        existing.is_migrated = incoming.is_migrated || existing.is_migrated;
 
+        existing.slot = incoming.slot;
         existing.last_trade_slot = incoming.last_trade_slot;
         existing.last_update_ts = incoming.last_update_ts;
         existing.updated_at = Utc::now();
 
-        existing
-    } else {
-        incoming
-    };
+        Some(existing)
+    })
+    .await
+}
+
+/// Merge metadata into `pf_prices`, leaving the price fields alone.
+/// `incoming` is stored as-is when the mint has no entry yet.
+pub async fn merge_pf_redis_price_metadata(
+    state: &AppState,
+    incoming: PfRedisPrice,
+) -> Result<(), redis::RedisError> {
+    merge_pf_redis_price(state, &incoming.mint, |existing| {
+        let Some(mut existing) = existing else {
+            return Some(incoming.clone());
+        };
+
+        merge_metadata(&mut existing, &incoming);
+        existing.updated_at = Utc::now();
+
+        Some(existing)
+    })
+    .await
+}
 
-    let value = serde_json::to_string(&merged).map_err(|e| {
-        redis::RedisError::from((
-            redis::ErrorKind::TypeError,
-            "failed to serialize PfRedisBondingCurveProgress",
-            e.to_string(),
-        ))
-    })?;
+/// Mark a cached curve as sold out and no longer tradeable
+pub async fn complete_pf_redis_bonding_curve(
+    state: &AppState,
+    mint: &str,
+) -> Result<(), redis::RedisError> {
+    modify_pf_redis_bonding_curve(state, mint, |progress| {
+        progress.real_token_reserves = 0;
+        progress.progress_bps = 10_000;
+        progress.progress_pct = 100.0;
+        progress.is_tradeable = false;
+    })
+    .await
+}
 
-    let _: () = redis
-        .hset("pf_bonding_curve_progress", &merged.mint, value)
-        .await?;
+/// Mark a cached curve as migrated
+pub async fn migrate_pf_redis_bonding_curve(
+    state: &AppState,
+    mint: &str,
+) -> Result<(), redis::RedisError> {
+    modify_pf_redis_bonding_curve(state, mint, |progress| {
+        progress.is_pre_migration = false;
+        progress.is_migrated = true;
+        progress.is_tradeable = false;
+    })
+    .await
+}
 
-    Ok(())
+/// Apply `modify` to the cached progress of `mint`; no-op when the mint is not cached.
+async fn modify_pf_redis_bonding_curve(
+    state: &AppState,
+    mint: &str,
+    modify: impl Fn(&mut PfRedisBondingCurveProgress),
+) -> Result<(), redis::RedisError> {
+    merge_pf_redis_bonding_curve(state, mint, |existing| {
+        let mut existing = existing?;
+
+        modify(&mut existing);
+        existing.updated_at = Utc::now();
+
+        Some(existing)
+    })
+    .await
+}
+
+/// Copy the metadata `incoming` provides onto `existing`
+fn merge_metadata(existing: &mut PfRedisPrice, incoming: &PfRedisPrice) {
+    if incoming.name.is_some() {
+        existing.name = incoming.name.clone();
+    }
+    if incoming.symbol.is_some() {
+        existing.symbol = incoming.symbol.clone();
+    }
+    if incoming.uri.is_some() {
+        existing.uri = incoming.uri.clone();
+    }
+    if incoming.description.is_some() {
+        existing.description = incoming.description.clone();
+    }
+    if incoming.twitter.is_some() {
+        existing.twitter = incoming.twitter.clone();
+    }
+    if incoming.telegram.is_some() {
+        existing.telegram = incoming.telegram.clone();
+    }
+    if incoming.website.is_some() {
+        existing.website = incoming.website.clone();
+    }
+    if incoming.image.is_some() {
+        existing.image = incoming.image.clone();
+    }
+}
+
+async fn merge_pf_redis_price(
+    state: &AppState,
+    mint: &str,
+    merge: impl Fn(Option<PfRedisPrice>) -> Option<PfRedisPrice>,
+) -> Result<(), redis::RedisError> {
+    merge_redis_json(
+        state,
+        "pf_prices",
+        mint,
+        |price: &PfRedisPrice| (price.slot, price.tx_index),
+        merge,
+    )
+    .await
+}
+
+async fn merge_pf_redis_bonding_curve(
+    state: &AppState,
+    mint: &str,
+    merge: impl Fn(Option<PfRedisBondingCurveProgress>) -> Option<PfRedisBondingCurveProgress>,
+) -> Result<(), redis::RedisError> {
+    merge_redis_json(
+        state,
+        "pf_bonding_curve_progress",
+        mint,
+        |progress: &PfRedisBondingCurveProgress| (progress.slot as i64, 0),
+        merge,
+    )
+    .await
 }
@@ -0,0 +1,68 @@
+use crate::models::pump_models::pf_models::pf_redis::{
+    PfRedisGlobalVolumeAccumulator, PfRedisTokenIncentive,
+};
+use crate::state::AppState;
+use redis::{AsyncCommands, Script};
+
+pub const PF_GLOBAL_VOLUME_ACCUMULATOR_KEY: &str = "pf_volume_accumulator:global";
+
+/// Set `KEYS[1]` to `ARGV[1]` unless the cached value is of a later slot than `ARGV[2]`,
+/// in one step so concurrent writers cannot interleave between the check and the write.
+const SET_IF_NOT_OLDER_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1])
+if current then
+    local stored = cjson.decode(current)
+    if (stored.slot or 0) > tonumber(ARGV[2]) then
+        return 0
+    end
+end
+redis.call('SET', KEYS[1], ARGV[1])
+return 1
+"#;
+
+/// Overwrite `pf_token_incentives:{wallet}` with the row returned by the Postgres upsert
+pub async fn upsert_pf_redis_token_incentive(
+    state: &AppState,
+    incoming: PfRedisTokenIncentive,
+) -> Result<(), redis::RedisError> {
+    let mut redis = state.cache.clone();
+
+    let value = serde_json::to_string(&incoming).map_err(|e| {
+        redis::RedisError::from((
+            redis::ErrorKind::TypeError,
+            "failed to serialize PfRedisTokenIncentive",
+            e.to_string(),
+        ))
+    })?;
+
+    let _: () = redis
+        .set(format!("pf_token_incentives:{}", incoming.wallet), value)
+        .await?;
+
+    Ok(())
+}
+
+/// Store the latest `GlobalVolumeAccumulator`, ignoring updates older than the cached slot
+pub async fn upsert_pf_redis_global_volume_accumulator(
+    state: &AppState,
+    incoming: PfRedisGlobalVolumeAccumulator,
+) -> Result<(), redis::RedisError> {
+    let mut redis = state.cache.clone();
+
+    let value = serde_json::to_string(&incoming).map_err(|e| {
+        redis::RedisError::from((
+            redis::ErrorKind::TypeError,
+            "failed to serialize PfRedisGlobalVolumeAccumulator",
+            e.to_string(),
+        ))
+    })?;
+
+    let _: i64 = Script::new(SET_IF_NOT_OLDER_SCRIPT)
+        .key(PF_GLOBAL_VOLUME_ACCUMULATOR_KEY)
+        .arg(value)
+        .arg(incoming.slot)
+        .invoke_async(&mut redis)
+        .await?;
+
+    Ok(())
+}
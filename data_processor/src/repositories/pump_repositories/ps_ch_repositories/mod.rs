@@ -0,0 +1,2 @@
+pub mod ps_ch_pool_prices;
+pub mod ps_ch_trade;
@@ -0,0 +1,17 @@
+use crate::models::pump_models::ps_models::ps_ch_dto::PsChPoolPriceDto;
+use crate::state::AppState;
+use log::debug;
+
+pub async fn insert_ps_ch_pool_price(
+    state: &AppState,
+    price_dto: &PsChPoolPriceDto,
+) -> anyhow::Result<()> {
+    state.ch_writer.write(price_dto).await?;
+
+    debug!(
+        "Buffered ps_pool_price for {} at slot {}",
+        price_dto.pool, price_dto.slot
+    );
+
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use crate::models::pump_models::ps_models::ps_ch_dto::PsChTradeDto;
+use crate::state::AppState;
+use log::debug;
+
+pub async fn insert_ps_ch_trade(state: &AppState, trade_dto: &PsChTradeDto) -> anyhow::Result<()> {
+    state.ch_writer.write(trade_dto).await?;
+
+    debug!("Buffered ps_trade: {}", trade_dto.signature);
+
+    Ok(())
+}
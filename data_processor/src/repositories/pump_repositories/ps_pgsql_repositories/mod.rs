@@ -0,0 +1 @@
+pub mod ps_pgsql_prices;
@@ -0,0 +1,60 @@
+use crate::models::pump_models::ps_models::ps_pgsql_dto::PsPgsqlPriceDto;
+use chrono::Utc;
+use sqlx::PgPool;
+
+/// Insert or update the latest price of a pool.
+/// Writes older than the stored `(slot, write_version)` are ignored.
+pub async fn upsert_ps_pgsql_price(pool: &PgPool, dto: PsPgsqlPriceDto) -> Result<(), sqlx::Error> {
+    let now = Utc::now();
+
+    sqlx::query(
+        r#"
+        INSERT INTO ps_prices (
+            pool,
+            base_mint,
+            quote_mint,
+            price,
+            base_reserves,
+            quote_reserves,
+            slot,
+            write_version,
+            ts,
+            created_at,
+            updated_at
+        )
+        VALUES (
+            $1,$2,$3,
+            $4,$5,$6,
+            $7,$8,
+            $9,$10,$11
+        )
+        ON CONFLICT (pool)
+        DO UPDATE SET
+            base_mint      = COALESCE(EXCLUDED.base_mint, ps_prices.base_mint),
+            quote_mint     = COALESCE(EXCLUDED.quote_mint, ps_prices.quote_mint),
+            price          = EXCLUDED.price,
+            base_reserves  = EXCLUDED.base_reserves,
+            quote_reserves = EXCLUDED.quote_reserves,
+            slot           = EXCLUDED.slot,
+            write_version  = EXCLUDED.write_version,
+            ts             = EXCLUDED.ts,
+            updated_at     = EXCLUDED.updated_at
+        WHERE (ps_prices.slot, ps_prices.write_version) <= (EXCLUDED.slot, EXCLUDED.write_version)
+        "#,
+    )
+    .bind(&dto.pool)
+    .bind(&dto.base_mint)
+    .bind(&dto.quote_mint)
+    .bind(dto.price)
+    .bind(dto.base_reserves)
+    .bind(dto.quote_reserves)
+    .bind(dto.slot as i64)
+    .bind(dto.write_version as i64)
+    .bind(dto.ts)
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1 @@
+pub mod ps_redis_prices;
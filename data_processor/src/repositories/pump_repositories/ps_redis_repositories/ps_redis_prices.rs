@@ -0,0 +1,95 @@
+use crate::models::pump_models::ps_models::ps_redis::PsRedisPrice;
+use crate::state::AppState;
+use chrono::Utc;
+use redis::AsyncCommands;
+
+/// Upsert `PsRedisPrice` into Redis without clobbering metadata fields.
+/// Writes older than the cached `(slot, write_version)` are ignored.
+pub async fn upsert_ps_redis_price(
+    state: &AppState,
+    incoming: PsRedisPrice,
+) -> Result<(), redis::RedisError> {
+    let mut redis = state.cache.clone();
+    let existing = get_ps_redis_price(&mut redis, &incoming.pool).await?;
+
+    let merged = if let Some(mut existing) = existing {
+        if (existing.slot, existing.write_version) > (incoming.slot, incoming.write_version) {
+            return Ok(());
+        }
+
+        // ===== overwrite vault-derived fields =====
+        existing.base_mint = incoming.base_mint.or(existing.base_mint);
+        existing.quote_mint = incoming.quote_mint.or(existing.quote_mint);
+        existing.price = incoming.price;
+        existing.base_reserves = incoming.base_reserves;
+        existing.quote_reserves = incoming.quote_reserves;
+        existing.slot = incoming.slot;
+        existing.write_version = incoming.write_version;
+        existing.ts = incoming.ts;
+        existing.updated_at = Utc::now();
+
+        // ===== preserve metadata unless explicitly provided =====
+        existing.metadata.merge(incoming.metadata);
+
+        existing
+    } else {
+        incoming
+    };
+
+    set_ps_redis_price(&mut redis, &merged).await
+}
+
+/// Merge metadata into `ps_prices`, leaving the price fields alone.
+/// `incoming` is stored as-is when the pool has no entry yet.
+pub async fn merge_ps_redis_price_metadata(
+    state: &AppState,
+    incoming: PsRedisPrice,
+) -> Result<(), redis::RedisError> {
+    let mut redis = state.cache.clone();
+    let existing = get_ps_redis_price(&mut redis, &incoming.pool).await?;
+
+    let merged = if let Some(mut existing) = existing {
+        existing.base_mint = existing.base_mint.or(incoming.base_mint);
+        existing.metadata.merge(incoming.metadata);
+        existing.updated_at = Utc::now();
+        existing
+    } else {
+        incoming
+    };
+
+    set_ps_redis_price(&mut redis, &merged).await
+}
+
+async fn get_ps_redis_price(
+    redis: &mut redis::aio::ConnectionManager,
+    pool: &str,
+) -> Result<Option<PsRedisPrice>, redis::RedisError> {
+    let json: Option<String> = redis.hget("ps_prices", pool).await?;
+
+    json.map(|json| {
+        serde_json::from_str(&json).map_err(|e| {
+            redis::RedisError::from((
+                redis::ErrorKind::TypeError,
+                "failed to deserialize PsRedisPrice",
+                e.to_string(),
+            ))
+        })
+    })
+    .transpose()
+}
+
+async fn set_ps_redis_price(
+    redis: &mut redis::aio::ConnectionManager,
+    price: &PsRedisPrice,
+) -> Result<(), redis::RedisError> {
+    let value = serde_json::to_string(price).map_err(|e| {
+        redis::RedisError::from((
+            redis::ErrorKind::TypeError,
+            "failed to serialize PsRedisPrice",
+            e.to_string(),
+        ))
+    })?;
+
+    let _: () = redis.hset("ps_prices", &price.pool, value).await?;
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod rll_ch_repositories;
+pub mod rll_pgsql_repositories;
+pub mod rll_redis_repositories;
@@ -0,0 +1 @@
+pub mod rll_ch_pool_prices;
@@ -0,0 +1,17 @@
+use crate::models::raydium_models::rll_models::rll_ch_dto::RllChPoolPriceDto;
+use crate::state::AppState;
+use log::debug;
+
+pub async fn insert_rll_ch_pool_price(
+    state: &AppState,
+    price_dto: &RllChPoolPriceDto,
+) -> anyhow::Result<()> {
+    state.ch_writer.write(price_dto).await?;
+
+    debug!(
+        "Buffered rll_pool_price for {} at slot {}",
+        price_dto.pool_state, price_dto.slot
+    );
+
+    Ok(())
+}
@@ -0,0 +1 @@
+pub mod rll_pgsql_prices;
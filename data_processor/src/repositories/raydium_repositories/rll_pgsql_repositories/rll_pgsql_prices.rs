@@ -0,0 +1,94 @@
+use crate::models::raydium_models::rll_models::rll_pgsql_dto::RllPgsqlPriceDto;
+use chrono::Utc;
+use sqlx::PgPool;
+
+/// Insert or update the latest curve state of a pool state.
+/// Writes older than the stored `(slot, write_version)` are ignored, so a late
+/// creation event never overwrites account updates.
+pub async fn upsert_rll_pgsql_price(
+    pool: &PgPool,
+    dto: RllPgsqlPriceDto,
+) -> Result<(), sqlx::Error> {
+    let now = Utc::now();
+
+    sqlx::query(
+        r#"
+        INSERT INTO rll_prices (
+            pool_state,
+            base_mint,
+            creator,
+            status,
+
+            price,
+            base_decimals,
+            quote_decimals,
+
+            virtual_base,
+            virtual_quote,
+            real_base,
+            real_quote,
+            total_quote_fund_raising,
+            progress_bps,
+
+            slot,
+            write_version,
+
+            ts,
+            created_at,
+            updated_at
+        )
+        VALUES (
+            $1,$2,$3,$4,
+            $5,$6,$7,
+            $8,$9,$10,$11,$12,$13,
+            $14,$15,
+            $16,$17,$18
+        )
+        ON CONFLICT (pool_state)
+        DO UPDATE SET
+            base_mint                = COALESCE(EXCLUDED.base_mint, rll_prices.base_mint),
+            creator                  = COALESCE(EXCLUDED.creator, rll_prices.creator),
+            status                   = EXCLUDED.status,
+
+            price                    = EXCLUDED.price,
+            base_decimals            = EXCLUDED.base_decimals,
+            quote_decimals           = EXCLUDED.quote_decimals,
+
+            virtual_base             = EXCLUDED.virtual_base,
+            virtual_quote            = EXCLUDED.virtual_quote,
+            real_base                = EXCLUDED.real_base,
+            real_quote               = EXCLUDED.real_quote,
+            total_quote_fund_raising = EXCLUDED.total_quote_fund_raising,
+            progress_bps             = EXCLUDED.progress_bps,
+
+            slot                     = EXCLUDED.slot,
+            write_version            = EXCLUDED.write_version,
+
+            ts                       = EXCLUDED.ts,
+            updated_at               = EXCLUDED.updated_at
+        WHERE (rll_prices.slot, rll_prices.write_version) <= (EXCLUDED.slot, EXCLUDED.write_version)
+        "#,
+    )
+    .bind(&dto.pool_state)
+    .bind(&dto.base_mint)
+    .bind(&dto.creator)
+    .bind(dto.status)
+    .bind(dto.price)
+    .bind(dto.base_decimals)
+    .bind(dto.quote_decimals)
+    .bind(dto.virtual_base)
+    .bind(dto.virtual_quote)
+    .bind(dto.real_base)
+    .bind(dto.real_quote)
+    .bind(dto.total_quote_fund_raising)
+    .bind(dto.progress_bps)
+    .bind(dto.slot as i64)
+    .bind(dto.write_version as i64)
+    .bind(dto.ts)
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use crate::models::raydium_models::rll_models::rll_redis::RllRedisPrice;
+use crate::state::AppState;
+use chrono::Utc;
+use redis::AsyncCommands;
+
+/// Upsert `RllRedisPrice` into Redis without clobbering metadata fields.
+/// Writes older than the cached `(slot, write_version)` are ignored.
+pub async fn upsert_rll_redis_price(
+    state: &AppState,
+    incoming: RllRedisPrice,
+) -> Result<(), redis::RedisError> {
+    let mut redis = state.cache.clone();
+    let existing = get_rll_redis_price(&mut redis, &incoming.pool_state).await?;
+
+    let merged = if let Some(mut existing) = existing {
+        if (existing.slot, existing.write_version) > (incoming.slot, incoming.write_version) {
+            return Ok(());
+        }
+
+        // ===== overwrite pool-state-derived fields =====
+        existing.base_mint = incoming.base_mint.or(existing.base_mint);
+        existing.creator = incoming.creator.or(existing.creator);
+        existing.status = incoming.status;
+        existing.price = incoming.price;
+        existing.base_decimals = incoming.base_decimals;
+        existing.quote_decimals = incoming.quote_decimals;
+        existing.virtual_base = incoming.virtual_base;
+        existing.virtual_quote = incoming.virtual_quote;
+        existing.real_base = incoming.real_base;
+        existing.real_quote = incoming.real_quote;
+        existing.progress_bps = incoming.progress_bps;
+        existing.slot = incoming.slot;
+        existing.write_version = incoming.write_version;
+        existing.ts = incoming.ts;
+        existing.updated_at = Utc::now();
+
+        // ===== preserve metadata unless explicitly provided =====
+        existing.metadata.merge(incoming.metadata);
+
+        existing
+    } else {
+        incoming
+    };
+
+    set_rll_redis_price(&mut redis, &merged).await
+}
+
+/// Merge metadata into `rll_prices`, leaving the price fields alone.
+/// `incoming` is stored as-is when the pool state has no entry yet.
+pub async fn merge_rll_redis_price_metadata(
+    state: &AppState,
+    incoming: RllRedisPrice,
+) -> Result<(), redis::RedisError> {
+    let mut redis = state.cache.clone();
+    let existing = get_rll_redis_price(&mut redis, &incoming.pool_state).await?;
+
+    let merged = if let Some(mut existing) = existing {
+        existing.base_mint = existing.base_mint.or(incoming.base_mint);
+        existing.metadata.merge(incoming.metadata);
+        existing.updated_at = Utc::now();
+        existing
+    } else {
+        incoming
+    };
+
+    set_rll_redis_price(&mut redis, &merged).await
+}
+
+async fn get_rll_redis_price(
+    redis: &mut redis::aio::ConnectionManager,
+    pool_state: &str,
+) -> Result<Option<RllRedisPrice>, redis::RedisError> {
+    let json: Option<String> = redis.hget("rll_prices", pool_state).await?;
+
+    json.map(|json| {
+        serde_json::from_str(&json).map_err(|e| {
+            redis::RedisError::from((
+                redis::ErrorKind::TypeError,
+                "failed to deserialize RllRedisPrice",
+                e.to_string(),
+            ))
+        })
+    })
+    .transpose()
+}
+
+async fn set_rll_redis_price(
+    redis: &mut redis::aio::ConnectionManager,
+    price: &RllRedisPrice,
+) -> Result<(), redis::RedisError> {
+    let value = serde_json::to_string(price).map_err(|e| {
+        redis::RedisError::from((
+            redis::ErrorKind::TypeError,
+            "failed to serialize RllRedisPrice",
+            e.to_string(),
+        ))
+    })?;
+
+    let _: () = redis.hset("rll_prices", &price.pool_state, value).await?;
+    Ok(())
+}
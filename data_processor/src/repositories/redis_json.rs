@@ -0,0 +1,108 @@
+use crate::state::AppState;
+use redis::{AsyncCommands, Script};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Write `ARGV[3]` to field `ARGV[1]` of hash `KEYS[1]`, atomically. The field is only written
+/// if it still holds what the caller read (`ARGV[2]`, empty when absent), else -1 asks for a
+/// re-read; it is skipped with 0 when the stored `(slot, tx_index)` is newer than
+/// `(ARGV[4], ARGV[5])`.
+const MERGE_WRITE_SCRIPT: &str = r#"
+local current = redis.call('HGET', KEYS[1], ARGV[1]) or ''
+if current ~= ARGV[2] then
+    return -1
+end
+if current ~= '' then
+    local stored = cjson.decode(current)
+    local stored_slot, stored_tx_index = stored.slot or 0, stored.tx_index or 0
+    local slot, tx_index = tonumber(ARGV[4]), tonumber(ARGV[5])
+    if stored_slot > slot or (stored_slot == slot and stored_tx_index > tx_index) then
+        return 0
+    end
+end
+redis.call('HSET', KEYS[1], ARGV[1], ARGV[3])
+return 1
+"#;
+
+/// Replace the JSON cached in `field` of hash `key` with `merge` of it, atomically.
+/// `merge` gets `None` when the field is absent and returns `None` to leave it alone.
+/// `position` is the `(slot, tx_index)` of a value; a merged value older than the stored
+/// one is dropped. When another writer changes the field between the read and the write,
+/// the field is read and merged again, so no concurrent update is lost.
+pub async fn merge_redis_json<T>(
+    state: &AppState,
+    key: &str,
+    field: &str,
+    position: impl Fn(&T) -> (i64, i64),
+    merge: impl Fn(Option<T>) -> Option<T>,
+) -> Result<(), redis::RedisError>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let mut redis = state.cache.clone();
+    let script = Script::new(MERGE_WRITE_SCRIPT);
+
+    loop {
+        let existing_json: Option<String> = redis.hget(key, field).await?;
+
+        let existing = existing_json
+            .as_deref()
+            .map(serde_json::from_str::<T>)
+            .transpose()
+            .map_err(|e| {
+                redis::RedisError::from((
+                    redis::ErrorKind::TypeError,
+                    "failed to deserialize cached value",
+                    format!("{key}/{field}: {e}"),
+                ))
+            })?;
+
+        let Some(merged) = merge(existing) else {
+            return Ok(());
+        };
+
+        let value = serde_json::to_string(&merged).map_err(|e| {
+            redis::RedisError::from((
+                redis::ErrorKind::TypeError,
+                "failed to serialize cached value",
+                format!("{key}/{field}: {e}"),
+            ))
+        })?;
+        let (slot, tx_index) = position(&merged);
+
+        let written: i64 = script
+            .key(key)
+            .arg(field)
+            .arg(existing_json.unwrap_or_default())
+            .arg(value)
+            .arg(slot)
+            .arg(tx_index)
+            .invoke_async(&mut redis)
+            .await?;
+
+        if written >= 0 {
+            return Ok(());
+        }
+    }
+}
+
+/// Read the JSON cached in `field` of hash `key`
+pub async fn get_redis_json<T: DeserializeOwned>(
+    state: &AppState,
+    key: &str,
+    field: &str,
+) -> Result<Option<T>, redis::RedisError> {
+    let mut redis = state.cache.clone();
+    let json: Option<String> = redis.hget(key, field).await?;
+
+    json.as_deref()
+        .map(serde_json::from_str::<T>)
+        .transpose()
+        .map_err(|e| {
+            redis::RedisError::from((
+                redis::ErrorKind::TypeError,
+                "failed to deserialize cached value",
+                format!("{key}/{field}: {e}"),
+            ))
+        })
+}
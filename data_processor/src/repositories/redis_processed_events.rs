@@ -0,0 +1,45 @@
+use crate::models::kafka_event::KEventType;
+use crate::state::AppState;
+use redis::AsyncCommands;
+
+/// Key marking an event as processed. One instruction can emit several events
+/// (a trade and a claim, or two trades), so the type and the event's ordinal in
+/// the transaction are part of the key.
+pub fn processed_event_key(
+    event_type: KEventType,
+    signature: &str,
+    ix_index: u32,
+    event_ordinal: u32,
+) -> String {
+    format!(
+        "processed:{}:{signature}:{ix_index}:{event_ordinal}",
+        event_type.as_str()
+    )
+}
+
+pub async fn is_redis_event_processed(
+    state: &AppState,
+    key: &str,
+) -> Result<bool, redis::RedisError> {
+    let mut redis = state.cache.clone();
+
+    redis.exists(key).await
+}
+
+/// Mark events as processed for `processed_window_secs`. Redeliveries within the
+/// window are skipped; older ones fall through to the idempotent sinks.
+pub async fn mark_redis_events_processed(
+    state: &AppState,
+    keys: &[String],
+) -> Result<(), redis::RedisError> {
+    let mut redis = state.cache.clone();
+
+    let mut pipe = redis::pipe();
+    for key in keys {
+        pipe.set_ex(key, 1, state.config.processed_window_secs)
+            .ignore();
+    }
+    let _: () = pipe.query_async(&mut redis).await?;
+
+    Ok(())
+}
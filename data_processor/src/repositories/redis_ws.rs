@@ -0,0 +1,23 @@
+use crate::state::AppState;
+use redis::AsyncCommands;
+use serde::Serialize;
+
+/// Publish `message` as JSON on `channel` for pub_api's websocket streams
+pub async fn publish_redis_ws<T: Serialize>(
+    state: &AppState,
+    channel: &str,
+    message: &T,
+) -> Result<(), redis::RedisError> {
+    let payload = serde_json::to_string(message).map_err(|e| {
+        redis::RedisError::from((
+            redis::ErrorKind::TypeError,
+            "failed to serialize websocket message",
+            format!("{channel}: {e}"),
+        ))
+    })?;
+
+    let mut redis = state.cache.clone();
+    let _: () = redis.publish(channel, payload).await?;
+
+    Ok(())
+}
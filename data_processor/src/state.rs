@@ -1,7 +1,9 @@
+use crate::clickhouse::ChWriter;
 use crate::config::AppConfig;
 use clickhouse::Client;
 use redis::aio::ConnectionManager;
 use sqlx::PgPool;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct AppState {
@@ -9,18 +11,21 @@ pub struct AppState {
     pub pg_pool: sqlx::PgPool,
     pub cache: ConnectionManager,
     pub clickhouse: Client,
+    pub ch_writer: Arc<ChWriter>,
 }
 
 pub fn init_state(
     config: AppConfig,
     pg_pool: PgPool,
     clickhouse: Client,
+    ch_writer: ChWriter,
     cache: ConnectionManager,
 ) -> AppState {
     AppState {
         config,
         pg_pool,
         clickhouse,
+        ch_writer: Arc::new(ch_writer),
         cache,
     }
 }
@@ -0,0 +1,112 @@
+use chrono::Utc;
+use clickhouse::Client;
+use clickhouse::test::{Mock, handlers, status};
+use data_processor::clickhouse::{ChWriter, Offsets};
+use data_processor::config::AppConfig;
+use data_processor::models::pump_models::ps_models::ps_ch_dto::PsChPoolPriceDto;
+use data_processor::models::raydium_models::rll_models::rll_ch_dto::RllChPoolPriceDto;
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+
+const TOPIC: &str = "pool-prices";
+
+fn test_config() -> AppConfig {
+    AppConfig {
+        pg_url: String::new(),
+        redis_url: String::new(),
+        clickhouse_url: String::new(),
+        clickhouse_user: String::new(),
+        clickhouse_password: String::new(),
+        clickhouse_database: String::new(),
+        clickhouse_batch_max_rows: 2,
+        clickhouse_batch_period_ms: 60_000,
+        kafka_brokers: String::new(),
+        kafka_group_id: String::new(),
+        kafka_dlq_topic: String::new(),
+        max_handle_attempts: 1,
+        schema_registry_url: None,
+        processed_window_secs: 0,
+        new_accounts_limit: 0,
+        new_accounts_key: String::new(),
+    }
+}
+
+fn ps_price() -> PsChPoolPriceDto {
+    PsChPoolPriceDto {
+        pool: "pool".to_string(),
+        base_mint: "mint".to_string(),
+        quote_mint: "quote".to_string(),
+        slot: 1,
+        write_version: 1,
+        source: "geyser_account_subscriber".to_string(),
+        price: 1,
+        base_reserves: 1,
+        quote_reserves: 1,
+        timestamp: Utc::now(),
+    }
+}
+
+fn rll_price() -> RllChPoolPriceDto {
+    RllChPoolPriceDto {
+        pool_state: "pool_state".to_string(),
+        base_mint: "mint".to_string(),
+        slot: 1,
+        write_version: 1,
+        source: "geyser_account_subscriber".to_string(),
+        status: 0,
+        price: 1,
+        virtual_base: 1,
+        virtual_quote: 1,
+        real_base: 1,
+        real_quote: 1,
+        progress_bps: 0,
+        timestamp: Utc::now(),
+    }
+}
+
+/// Write one row per table for the message at `offset`. The pause keeps the order
+/// in which the `INSERT`s reach the mock, which serves its handlers in order.
+async fn write_message(writer: &ChWriter, offset: i64) {
+    writer.write(&ps_price()).await.unwrap();
+    sleep(Duration::from_millis(100)).await;
+    writer.write(&rll_price()).await.unwrap();
+    writer.mark_processed(TOPIC, 0, offset).await;
+}
+
+/// `ps_pool_prices` is committed before `rll_pool_prices`. When it fails, the
+/// `rll_pool_prices` row must still be committed with its batch rather than linger
+/// in an open `INSERT` that the replay would append to.
+#[tokio::test]
+async fn partial_flush_failure_replays_the_whole_batch() {
+    let mock = Mock::new();
+    let client = Client::default().with_url(mock.url());
+    let writer = ChWriter::new(&client, &test_config()).unwrap();
+
+    mock.add(handlers::failure(status::INTERNAL_SERVER_ERROR));
+    let first_rll = mock.add(handlers::record());
+
+    write_message(&writer, 10).await;
+    let error = writer.flush_if_due().await.unwrap_err();
+    assert_eq!(error.rewind, Offsets::from([((TOPIC.to_string(), 0), 10)]));
+
+    let first_rll: Vec<RllChPoolPriceDto> = first_rll.collect().await;
+    assert_eq!(first_rll.len(), 1);
+
+    // Replay from the rewound offset
+    let replayed_ps = mock.add(handlers::record());
+    let replayed_rll = mock.add(handlers::record());
+
+    write_message(&writer, 10).await;
+    let offsets = writer.flush_if_due().await.unwrap().unwrap();
+    assert_eq!(offsets, Offsets::from([((TOPIC.to_string(), 0), 11)]));
+
+    let replayed_ps: Vec<PsChPoolPriceDto> = timeout(Duration::from_secs(5), replayed_ps.collect())
+        .await
+        .unwrap();
+    let replayed_rll: Vec<RllChPoolPriceDto> =
+        timeout(Duration::from_secs(5), replayed_rll.collect())
+            .await
+            .unwrap();
+    assert_eq!(replayed_ps.len(), 1);
+    assert_eq!(replayed_rll.len(), 1);
+}
@@ -0,0 +1,69 @@
+use data_processor::config::AppConfig;
+use data_processor::models::kafka_event::KEventType;
+use data_processor::repositories::pgsql_ledger_events::{
+    CREATOR_FEE_LEDGER, claim_pgsql_ledger_event,
+};
+use data_processor::repositories::redis_processed_events::processed_event_key;
+use sqlx::postgres::PgPoolOptions;
+
+const SIGNATURE: &str =
+    "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW";
+
+/// A trade accrual and a creator fee claim emitted by the same instruction
+#[test]
+fn trade_and_claim_in_one_tx_get_distinct_processed_keys() {
+    let trade = processed_event_key(KEventType::PfChTradeOccurred, SIGNATURE, 2, 0);
+    let claim = processed_event_key(KEventType::PfCreatorFeeClaimed, SIGNATURE, 2, 1);
+    let second_trade = processed_event_key(KEventType::PfChTradeOccurred, SIGNATURE, 2, 2);
+
+    assert_ne!(trade, claim);
+    assert_ne!(trade, second_trade);
+    assert_eq!(
+        trade,
+        processed_event_key(KEventType::PfChTradeOccurred, SIGNATURE, 2, 0)
+    );
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres at PG_URL with migrations applied"]
+async fn trade_and_claim_in_one_tx_are_both_applied_once() {
+    let config = AppConfig::from_env();
+    let pool = PgPoolOptions::new().connect(&config.pg_url).await.unwrap();
+    let signature = format!("test-{}", chrono::Utc::now().timestamp_nanos_opt().unwrap());
+
+    let events = [
+        (KEventType::PfChTradeOccurred, 0),
+        (KEventType::PfCreatorFeeClaimed, 1),
+    ];
+
+    let mut tx = pool.begin().await.unwrap();
+    for (event_type, ordinal) in events {
+        let applied = claim_pgsql_ledger_event(
+            &mut tx,
+            CREATOR_FEE_LEDGER,
+            event_type,
+            &signature,
+            2,
+            ordinal,
+            1,
+        )
+        .await
+        .unwrap();
+        assert!(applied, "{event_type:?} shadowed by an earlier event");
+    }
+    for (event_type, ordinal) in events {
+        let applied = claim_pgsql_ledger_event(
+            &mut tx,
+            CREATOR_FEE_LEDGER,
+            event_type,
+            &signature,
+            2,
+            ordinal,
+            1,
+        )
+        .await
+        .unwrap();
+        assert!(!applied, "{event_type:?} redelivery applied twice");
+    }
+    tx.rollback().await.unwrap();
+}
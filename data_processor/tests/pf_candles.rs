@@ -0,0 +1,97 @@
+use chrono::{DateTime, Utc};
+use data_processor::models::pump_models::pf_models::pf_ch::PfChCandle;
+use data_processor::repositories::pump_repositories::pf_ch_repositories::pf_ch_candles::{
+    fill_pf_candle_gaps, pf_candle_buckets,
+};
+
+const STEP: i64 = 60;
+const START: i64 = 1_700_000_040; // a whole minute
+
+fn at(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap()
+}
+
+fn candle(bucket: i64, open: f64, close: f64) -> PfChCandle {
+    PfChCandle {
+        bucket: at(bucket),
+        open,
+        high: open.max(close),
+        low: open.min(close),
+        close,
+        sol_volume: 1_000,
+        token_volume: 2_000,
+        buy_count: 1,
+        sell_count: 1,
+        unique_traders: 2,
+    }
+}
+
+fn shape(candles: &[PfChCandle]) -> Vec<(i64, f64, f64, u64)> {
+    candles
+        .iter()
+        .map(|c| (c.bucket.timestamp(), c.open, c.close, c.sol_volume))
+        .collect()
+}
+
+#[test]
+fn buckets_before_the_first_trade_are_left_out() {
+    let candles = vec![candle(START + 2 * STEP, 1.0, 2.0)];
+
+    let filled = fill_pf_candle_gaps(candles, None, START, 4, STEP);
+
+    assert_eq!(
+        shape(&filled),
+        vec![
+            (START + 2 * STEP, 1.0, 2.0, 1_000),
+            (START + 3 * STEP, 2.0, 2.0, 0)
+        ]
+    );
+}
+
+#[test]
+fn gaps_carry_the_last_close() {
+    let candles = vec![candle(START + STEP, 4.0, 6.0)];
+
+    let filled = fill_pf_candle_gaps(candles, Some(3.0), START, 3, STEP);
+
+    assert_eq!(
+        shape(&filled),
+        vec![
+            (START, 3.0, 3.0, 0),
+            (START + STEP, 4.0, 6.0, 1_000),
+            (START + 2 * STEP, 6.0, 6.0, 0),
+        ]
+    );
+    let carried = &filled[2];
+    assert_eq!((carried.high, carried.low), (6.0, 6.0));
+    assert_eq!((carried.buy_count, carried.sell_count), (0, 0));
+}
+
+#[test]
+fn to_mid_bucket_includes_the_partial_bucket() {
+    let (first_bucket, buckets) =
+        pf_candle_buckets(STEP, at(START + 10), at(START + 2 * STEP + 30));
+
+    assert_eq!((first_bucket, buckets), (START, 3));
+
+    let filled = fill_pf_candle_gaps(
+        vec![candle(START + 2 * STEP, 5.0, 7.0)],
+        Some(5.0),
+        first_bucket,
+        buckets,
+        STEP,
+    );
+    assert_eq!(
+        filled.last().map(|c| c.bucket.timestamp()),
+        Some(START + 2 * STEP)
+    );
+}
+
+#[test]
+fn to_on_a_bucket_edge_excludes_that_bucket() {
+    assert_eq!(
+        pf_candle_buckets(STEP, at(START), at(START + 2 * STEP)),
+        (START, 2)
+    );
+    assert_eq!(pf_candle_buckets(STEP, at(START), at(START)), (START, 0));
+}
@@ -0,0 +1,48 @@
+use chrono::Utc;
+use data_processor::models::enums::PriceSource;
+use data_processor::models::pump_models::pf_models::pf_kafka_event::KPfPrice;
+use data_processor::models::pump_models::pf_models::pf_pgsql::PfPgsqlCurveToken;
+use data_processor::models::pump_models::pf_models::pf_pgsql_dto::build_pf_pgsql_price_from_account;
+
+const SLOT: u64 = 100;
+
+fn account_update(slot: u64) -> KPfPrice {
+    KPfPrice {
+        bonding_curve: "curve".to_string(),
+        source: PriceSource::PumpFunBondingCurve,
+        ts: Utc::now(),
+        virtual_token_reserves: 1_073_000_000_000_000,
+        virtual_sol_reserves: 30_000_000_000,
+        real_token_reserves: 793_100_000_000_000,
+        real_sol_reserves: 0,
+        token_total_supply: 1_000_000_000_000_000,
+        complete: false,
+        creator: None,
+        is_mayhem_mode: false,
+        slot,
+        write_version: 7,
+    }
+}
+
+fn curve() -> PfPgsqlCurveToken {
+    PfPgsqlCurveToken {
+        mint: "mint".to_string(),
+        decimals: 6,
+        virtual_sol_reserves: 30_000_000_000,
+        is_buy: true,
+    }
+}
+
+/// Without its position in the slot, an account write must neither outrank a trade of its
+/// slot (which may come after it) nor fall behind the trades of earlier slots.
+#[test]
+fn account_price_ranks_between_the_previous_slot_and_its_slots_trades() {
+    let price = build_pf_pgsql_price_from_account(&account_update(SLOT), &curve()).unwrap();
+    let position = (price.slot, price.tx_index);
+
+    let first_trade_of_slot = (SLOT as i64, i64::from(u32::MIN));
+    let last_trade_of_previous_slot = (SLOT as i64 - 1, i64::from(u32::MAX));
+
+    assert!(position < first_trade_of_slot);
+    assert!(position > last_trade_of_previous_slot);
+}
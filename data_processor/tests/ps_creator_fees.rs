@@ -0,0 +1,61 @@
+use chrono::Utc;
+use data_processor::models::enums::TradeDirection;
+use data_processor::models::pump_models::pf_models::pf_pgsql_dto::{
+    build_ps_pgsql_creator_fee_accrual, build_ps_pgsql_creator_fee_claim,
+};
+use data_processor::models::pump_models::ps_models::ps_kafka_event::{
+    KPsCreatorFeeClaim, KPsTrade,
+};
+use data_processor::models::token_registry::WSOL_MINT;
+
+const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qJxAd5x8vG8nUWhZXG1uAzTuQ";
+
+fn trade(quote_mint: Option<&str>) -> KPsTrade {
+    KPsTrade {
+        signature: "sig".into(),
+        mint: "mint".into(),
+        pool: "pool".into(),
+        direction: TradeDirection::Buy,
+        sol_amount: 1_000_000,
+        token_amount: 2_000_000,
+        user_pubkey: "user".into(),
+        ts: Utc::now(),
+        coin_creator: Some("creator".into()),
+        coin_creator_fee: Some(500),
+        quote_mint: quote_mint.map(str::to_string),
+        slot: 42,
+        ix_index: 1,
+        event_ordinal: 0,
+    }
+}
+
+fn claim(quote_mint: &str) -> KPsCreatorFeeClaim {
+    KPsCreatorFeeClaim {
+        signature: "sig".into(),
+        slot: 42,
+        coin_creator: "creator".into(),
+        quote_mint: quote_mint.into(),
+        coin_creator_fee: 500,
+        coin_creator_vault_ata: "vault".into(),
+        coin_creator_token_account: "destination".into(),
+        ts: Utc::now(),
+        ix_index: 1,
+        event_ordinal: 0,
+    }
+}
+
+#[test]
+fn wsol_quoted_fees_are_recorded_in_lamports() {
+    let accrual = build_ps_pgsql_creator_fee_accrual(&trade(Some(WSOL_MINT)), 42).unwrap();
+    assert_eq!(accrual.amount_lamports, 500);
+
+    let claim = build_ps_pgsql_creator_fee_claim(claim(WSOL_MINT)).unwrap();
+    assert_eq!(claim.amount_lamports, 500);
+}
+
+#[test]
+fn other_quote_mints_are_not_mixed_into_lamport_balances() {
+    assert!(build_ps_pgsql_creator_fee_accrual(&trade(Some(USDC_MINT)), 42).is_err());
+    assert!(build_ps_pgsql_creator_fee_accrual(&trade(None), 42).is_err());
+    assert!(build_ps_pgsql_creator_fee_claim(claim(USDC_MINT)).is_err());
+}
@@ -0,0 +1,280 @@
+use chrono::Utc;
+use data_processor::cache::init_cache;
+use data_processor::clickhouse::{ChWriter, init_clickhouse_client};
+use data_processor::config::AppConfig;
+use data_processor::models::pump_models::pf_models::pf_enums::{PfPriceSource, PfTradeDirection};
+use data_processor::models::pump_models::pf_models::pf_pgsql_dto::START_OF_SLOT_TX_INDEX;
+use data_processor::models::pump_models::pf_models::pf_redis::{
+    PfRedisBondingCurveProgress, PfRedisGlobalVolumeAccumulator, PfRedisPrice,
+};
+use data_processor::repositories::pump_repositories::pf_redis_repositories::pf_redis_prices::{
+    merge_pf_redis_price_metadata, migrate_pf_redis_bonding_curve,
+    upsert_pf_redis_bonding_curve_progress, upsert_pf_redis_price,
+};
+use data_processor::repositories::pump_repositories::pf_redis_repositories::pf_redis_token_incentives::{
+    PF_GLOBAL_VOLUME_ACCUMULATOR_KEY, upsert_pf_redis_global_volume_accumulator,
+};
+use data_processor::state::{AppState, init_state};
+use redis::AsyncCommands;
+use sqlx::postgres::PgPoolOptions;
+use tokio::task::JoinSet;
+
+const WRITERS: u64 = 8;
+const WRITES_PER_WRITER: u64 = 50;
+
+/// State against the Redis of `REDIS_URL`; Postgres and ClickHouse are never reached.
+async fn test_state() -> AppState {
+    let config = AppConfig::from_env();
+    let cache = init_cache(config.clone()).await.unwrap();
+    let pg_pool = PgPoolOptions::new().connect_lazy(&config.pg_url).unwrap();
+    let clickhouse = init_clickhouse_client(&config).unwrap();
+    let ch_writer = ChWriter::new(&clickhouse, &config).unwrap();
+
+    init_state(config, pg_pool, clickhouse, ch_writer, cache)
+}
+
+fn test_mint(name: &str) -> String {
+    format!("test-{name}-{}", Utc::now().timestamp_nanos_opt().unwrap())
+}
+
+fn price(mint: &str, slot: i64, tx_index: i64) -> PfRedisPrice {
+    let now = Utc::now();
+
+    PfRedisPrice {
+        mint: mint.to_string(),
+        bonding_curve: "curve".to_string(),
+        name: None,
+        symbol: None,
+        price: slot * 1_000 + tx_index,
+        source: PfPriceSource::PfTrade,
+        direction: PfTradeDirection::Buy,
+        decimals: 6,
+        virtual_token_reserves: 1_073_000_000_000_000,
+        virtual_sol_reserves: 30_000_000_000,
+        real_token_reserves: 793_100_000_000_000,
+        real_sol_reserves: 0,
+        uri: None,
+        description: None,
+        twitter: None,
+        telegram: None,
+        website: None,
+        image: None,
+        slot,
+        tx_index,
+        ts: now,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+fn progress(mint: &str, slot: u64) -> PfRedisBondingCurveProgress {
+    let now = Utc::now();
+
+    PfRedisBondingCurveProgress {
+        mint: mint.to_string(),
+        bonding_curve: "curve".to_string(),
+        virtual_sol_reserves: 30_000_000_000 + slot,
+        virtual_token_reserves: 1_073_000_000_000_000,
+        real_sol_reserves: slot,
+        real_token_reserves: 793_100_000_000_000,
+        progress_bps: 0,
+        progress_pct: 0.0,
+        price_lamports: 28,
+        market_cap_lamports: 28_000_000_000,
+        is_pre_migration: true,
+        is_migrated: false,
+        is_tradeable: true,
+        slot,
+        last_trade_slot: slot,
+        last_update_ts: now.timestamp(),
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+async fn cached<T: serde::de::DeserializeOwned>(state: &AppState, key: &str, mint: &str) -> T {
+    let mut redis = state.cache.clone();
+    let json: String = redis.hget(key, mint).await.unwrap();
+    let _: () = redis.hdel(key, mint).await.unwrap();
+
+    serde_json::from_str(&json).unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+#[ignore = "needs Redis at REDIS_URL"]
+async fn concurrent_price_writes_keep_newest_price_and_all_metadata() {
+    let state = test_state().await;
+    let mint = test_mint("price");
+
+    let mut tasks = JoinSet::new();
+    for writer in 0..WRITERS {
+        let state = state.clone();
+        let mint = mint.clone();
+
+        tasks.spawn(async move {
+            for write in 0..WRITES_PER_WRITER {
+                // Writers interleave and each one also goes backwards half of the time
+                let tx_index = if write % 2 == 0 {
+                    write
+                } else {
+                    WRITES_PER_WRITER - write
+                };
+                let update = price(&mint, 100, (tx_index * WRITERS + writer) as i64);
+                upsert_pf_redis_price(&state, update).await.unwrap();
+            }
+
+            // Every writer contributes one metadata field
+            let mut metadata = price(&mint, 0, 0);
+            let value = Some(format!("writer-{writer}"));
+            match writer {
+                0 => metadata.name = value,
+                1 => metadata.symbol = value,
+                2 => metadata.uri = value,
+                3 => metadata.description = value,
+                4 => metadata.twitter = value,
+                5 => metadata.telegram = value,
+                6 => metadata.website = value,
+                _ => metadata.image = value,
+            }
+            merge_pf_redis_price_metadata(&state, metadata)
+                .await
+                .unwrap();
+        });
+    }
+    while let Some(task) = tasks.join_next().await {
+        task.unwrap();
+    }
+
+    let stored: PfRedisPrice = cached(&state, "pf_prices", &mint).await;
+    let newest = ((WRITES_PER_WRITER - 1) * WRITERS + WRITERS - 1) as i64;
+
+    assert_eq!((stored.slot, stored.tx_index), (100, newest));
+    assert_eq!(stored.price, 100 * 1_000 + newest);
+    assert_eq!(stored.name.as_deref(), Some("writer-0"));
+    assert_eq!(stored.symbol.as_deref(), Some("writer-1"));
+    assert_eq!(stored.uri.as_deref(), Some("writer-2"));
+    assert_eq!(stored.description.as_deref(), Some("writer-3"));
+    assert_eq!(stored.twitter.as_deref(), Some("writer-4"));
+    assert_eq!(stored.telegram.as_deref(), Some("writer-5"));
+    assert_eq!(stored.website.as_deref(), Some("writer-6"));
+    assert_eq!(stored.image.as_deref(), Some("writer-7"));
+}
+
+#[tokio::test]
+#[ignore = "needs Redis at REDIS_URL"]
+async fn account_price_does_not_outrank_trades_of_its_slot() {
+    let state = test_state().await;
+    let mint = test_mint("account-price");
+
+    upsert_pf_redis_price(&state, price(&mint, 100, 5))
+        .await
+        .unwrap();
+    upsert_pf_redis_price(&state, price(&mint, 100, START_OF_SLOT_TX_INDEX))
+        .await
+        .unwrap();
+
+    let stored: PfRedisPrice = cached(&state, "pf_prices", &mint).await;
+    assert_eq!((stored.slot, stored.tx_index), (100, 5));
+
+    upsert_pf_redis_price(&state, price(&mint, 100, 5))
+        .await
+        .unwrap();
+    upsert_pf_redis_price(&state, price(&mint, 101, START_OF_SLOT_TX_INDEX))
+        .await
+        .unwrap();
+
+    let stored: PfRedisPrice = cached(&state, "pf_prices", &mint).await;
+    assert_eq!(
+        (stored.slot, stored.tx_index),
+        (101, START_OF_SLOT_TX_INDEX)
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+#[ignore = "needs Redis at REDIS_URL"]
+async fn concurrent_progress_writes_keep_newest_slot_and_migration() {
+    let state = test_state().await;
+    let mint = test_mint("progress");
+
+    upsert_pf_redis_bonding_curve_progress(&state, progress(&mint, 1))
+        .await
+        .unwrap();
+
+    let mut tasks = JoinSet::new();
+    for writer in 0..WRITERS {
+        let state = state.clone();
+        let mint = mint.clone();
+
+        tasks.spawn(async move {
+            for write in 0..WRITES_PER_WRITER {
+                let slot = 2 + write * WRITERS + writer;
+                upsert_pf_redis_bonding_curve_progress(&state, progress(&mint, slot))
+                    .await
+                    .unwrap();
+
+                if writer == 0 && write == WRITES_PER_WRITER / 2 {
+                    migrate_pf_redis_bonding_curve(&state, &mint).await.unwrap();
+                }
+            }
+        });
+    }
+    while let Some(task) = tasks.join_next().await {
+        task.unwrap();
+    }
+
+    let stored: PfRedisBondingCurveProgress =
+        cached(&state, "pf_bonding_curve_progress", &mint).await;
+    let newest = 2 + (WRITES_PER_WRITER - 1) * WRITERS + WRITERS - 1;
+
+    assert_eq!(stored.slot, newest);
+    assert_eq!(stored.real_sol_reserves, newest);
+    assert!(stored.is_migrated);
+    assert!(!stored.is_pre_migration);
+    assert!(!stored.is_tradeable);
+}
+
+fn global_accumulator(slot: u64) -> PfRedisGlobalVolumeAccumulator {
+    PfRedisGlobalVolumeAccumulator {
+        mint: "mint".to_string(),
+        start_time: 0,
+        end_time: 0,
+        seconds_in_a_day: 86_400,
+        total_token_supply: vec![slot],
+        sol_volumes: vec![slot],
+        slot,
+        updated_at: Utc::now(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+#[ignore = "needs Redis at REDIS_URL"]
+async fn concurrent_global_accumulator_writes_keep_newest_slot() {
+    let state = test_state().await;
+    let mut redis = state.cache.clone();
+    let _: () = redis.del(PF_GLOBAL_VOLUME_ACCUMULATOR_KEY).await.unwrap();
+
+    let mut tasks = JoinSet::new();
+    for writer in 0..WRITERS {
+        let state = state.clone();
+
+        tasks.spawn(async move {
+            // Each writer goes newest first, so every later write is stale
+            for write in (0..WRITES_PER_WRITER).rev() {
+                let slot = write * WRITERS + writer;
+                upsert_pf_redis_global_volume_accumulator(&state, global_accumulator(slot))
+                    .await
+                    .unwrap();
+            }
+        });
+    }
+    while let Some(task) = tasks.join_next().await {
+        task.unwrap();
+    }
+
+    let stored: String = redis.get(PF_GLOBAL_VOLUME_ACCUMULATOR_KEY).await.unwrap();
+    let stored: PfRedisGlobalVolumeAccumulator = serde_json::from_str(&stored).unwrap();
+    let newest = (WRITES_PER_WRITER - 1) * WRITERS + WRITERS - 1;
+
+    assert_eq!(stored.slot, newest);
+    assert_eq!(stored.sol_volumes, vec![newest]);
+}
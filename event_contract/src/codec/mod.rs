@@ -0,0 +1,141 @@
+//! Pluggable wire encoding for `KEvent`.
+//!
+//! Producers pick a [`ContentType`] and advertise it in the `content-type`
+//! Kafka header; consumers read the header and decode accordingly. Messages
+//! without the header are JSON, which is what every producer wrote before.
+//!
+//! Protobuf payloads use the Confluent wire format
+//! (`0x00 | schema id (u32 BE) | message index 0 | protobuf bytes`) so they
+//! can be checked against a schema registry. A schema id of `0` means the
+//! producer ran without a registry.
+
+pub mod proto;
+#[cfg(feature = "registry")]
+pub mod registry;
+
+use crate::codec::proto::PbKEvent;
+use crate::models::kafka_event::KEvent;
+use prost::Message;
+use std::fmt;
+use std::str::FromStr;
+
+/// Kafka header carrying the payload encoding.
+pub const CONTENT_TYPE_HEADER: &str = "content-type";
+
+const CONFLUENT_MAGIC_BYTE: u8 = 0;
+const CONFLUENT_HEADER_LEN: usize = 6; // magic + schema id + message index
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContentType {
+    #[default]
+    Json,
+    Protobuf,
+}
+
+impl ContentType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentType::Json => "application/json",
+            ContentType::Protobuf => "application/x-protobuf",
+        }
+    }
+
+    /// Resolves the encoding from a raw header value. A missing header means JSON.
+    pub fn from_header(value: Option<&[u8]>) -> Result<Self, String> {
+        match value {
+            None => Ok(ContentType::Json),
+            Some(v) => std::str::from_utf8(v)
+                .map_err(|_| "ContentType::from_header: header is not utf-8".to_string())?
+                .parse(),
+        }
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Accepts both the header value (`application/json`) and the short form used in env config (`json`).
+impl FromStr for ContentType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "json" | "application/json" => Ok(ContentType::Json),
+            "protobuf" | "proto" | "application/x-protobuf" | "application/protobuf" => {
+                Ok(ContentType::Protobuf)
+            }
+            other => Err(format!(
+                "ContentType::from_str: unsupported content type {other}"
+            )),
+        }
+    }
+}
+
+/// Encodes an event. `schema_id` is the registry id of `proto::K_EVENT_PROTO`
+/// and is only written for protobuf payloads.
+pub fn encode(
+    event: &KEvent,
+    content_type: ContentType,
+    schema_id: Option<u32>,
+) -> Result<Vec<u8>, String> {
+    match content_type {
+        ContentType::Json => {
+            serde_json::to_vec(event).map_err(|e| format!("encode: Failed to serialize event: {e}"))
+        }
+        ContentType::Protobuf => {
+            let message = PbKEvent::from(event);
+            let mut buf = Vec::with_capacity(CONFLUENT_HEADER_LEN + message.encoded_len());
+
+            buf.push(CONFLUENT_MAGIC_BYTE);
+            buf.extend_from_slice(&schema_id.unwrap_or(0).to_be_bytes());
+            buf.push(0); // message index: first message in the schema (`KEvent`)
+            message
+                .encode(&mut buf)
+                .map_err(|e| format!("encode: Failed to encode protobuf: {e}"))?;
+
+            Ok(buf)
+        }
+    }
+}
+
+pub fn decode(payload: &[u8], content_type: ContentType) -> Result<KEvent, String> {
+    match content_type {
+        ContentType::Json => serde_json::from_slice(payload)
+            .map_err(|e| format!("decode: Failed to deserialize event: {e}")),
+        ContentType::Protobuf => {
+            let (_, body) = unframe(payload)?;
+            let message = PbKEvent::decode(body)
+                .map_err(|e| format!("decode: Failed to decode protobuf: {e}"))?;
+
+            KEvent::try_from(message)
+        }
+    }
+}
+
+/// Registry schema id of a protobuf payload; `None` for JSON, malformed frames, or id `0`.
+pub fn schema_id(payload: &[u8], content_type: ContentType) -> Option<u32> {
+    match content_type {
+        ContentType::Json => None,
+        ContentType::Protobuf => unframe(payload)
+            .ok()
+            .map(|(id, _)| id)
+            .filter(|id| *id != 0),
+    }
+}
+
+fn unframe(payload: &[u8]) -> Result<(u32, &[u8]), String> {
+    if payload.len() < CONFLUENT_HEADER_LEN || payload[0] != CONFLUENT_MAGIC_BYTE {
+        return Err("unframe: missing Confluent wire header".into());
+    }
+
+    let schema_id = u32::from_be_bytes(payload[1..5].try_into().unwrap());
+
+    if payload[5] != 0 {
+        return Err("unframe: unsupported message index".into());
+    }
+
+    Ok((schema_id, &payload[CONFLUENT_HEADER_LEN..]))
+}
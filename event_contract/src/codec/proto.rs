@@ -0,0 +1,1728 @@
+//! Hand-written prost mirror of `proto/k_event.proto`. `tests/proto_schema.rs`
+//! checks it against the parsed schema, so drift fails the build.
+//!
+//! Contract types stay serde-only; these `Pb*` types exist just for the wire
+//! and are converted to / from the contract types at the codec boundary.
+
+use crate::models::enums::{EventSource, Platform, PriceSource, TokenStatus, TradeDirection};
+use crate::models::kafka_event::{KEvent, KEventData};
+use crate::models::pump_models::pf_kafka_event::{
+    KPfBondingCurveCompleted, KPfChTrade, KPfCreatorFeeClaim, KPfFeeConfig, KPfFeeTier, KPfFees,
+    KPfGlobalConfig, KPfGlobalVolumeAccumulator, KPfPrice, KPfToken, KPfTokenIncentiveClaim,
+    KPfTokenLifecycle, KPfTrade, KPfUserVolumeAccumulator,
+};
+use crate::models::pump_models::ps_kafka_event::{
+    KPsCreatorFeeClaim, KPsPrice, KPsToken, KPsTrade,
+};
+use crate::models::raydium_models::rll_kafka_event::{
+    KRllPrice, KRllToken, KRllTokenLifecycle, KRllTrade, KRllVesting,
+};
+use chrono::{DateTime, Utc};
+use prost::{Enumeration, Message, Oneof};
+
+/// Schema text registered with the schema registry.
+pub const K_EVENT_PROTO: &str = include_str!("../../proto/k_event.proto");
+
+/* ========= Envelope ========= */
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKEvent {
+    #[prost(string, tag = "1")]
+    pub event_id: String,
+    #[prost(uint32, tag = "2")]
+    pub schema_version: u32,
+    #[prost(enumeration = "PbEventSource", tag = "3")]
+    pub source: i32,
+    #[prost(int64, tag = "4")]
+    pub produced_at_ns: i64,
+    #[prost(uint64, tag = "5")]
+    pub slot: u64,
+    #[prost(
+        oneof = "PbKEventData",
+        tags = "10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29"
+    )]
+    pub data: Option<PbKEventData>,
+}
+
+#[derive(Clone, PartialEq, Oneof)]
+pub enum PbKEventData {
+    #[prost(message, tag = "10")]
+    PfChTradeOccurred(PbKPfChTrade),
+
+    #[prost(message, tag = "11")]
+    PfTradeOccurred(PbKPfTrade),
+    #[prost(message, tag = "12")]
+    PfTokenCreated(PbKPfToken),
+    #[prost(message, tag = "13")]
+    PfTokenMigrated(PbKPfTokenLifecycle),
+    #[prost(message, tag = "14")]
+    PfPriceUpdated(PbKPfPrice),
+
+    #[prost(message, tag = "15")]
+    PsTradeOccurred(PbKPsTrade),
+    #[prost(message, tag = "16")]
+    PsTokenCreated(PbKPsToken),
+    #[prost(message, tag = "17")]
+    PsPriceUpdated(PbKPsPrice),
+
+    #[prost(message, tag = "18")]
+    RllTradeOccurred(PbKRllTrade),
+    #[prost(message, tag = "19")]
+    RllTokenCreated(PbKRllToken),
+    #[prost(message, tag = "20")]
+    RllTokenMigrated(PbKRllTokenLifecycle),
+    #[prost(message, tag = "21")]
+    RllPriceUpdated(PbKRllPrice),
+
+    #[prost(message, tag = "22")]
+    PfCreatorFeeClaimed(PbKPfCreatorFeeClaim),
+    #[prost(message, tag = "23")]
+    PsCreatorFeeClaimed(PbKPsCreatorFeeClaim),
+
+    #[prost(message, tag = "24")]
+    PfTokenIncentivesClaimed(PbKPfTokenIncentiveClaim),
+    #[prost(message, tag = "25")]
+    PfUserVolumeAccumulatorUpdated(PbKPfUserVolumeAccumulator),
+    #[prost(message, tag = "26")]
+    PfGlobalVolumeAccumulatorUpdated(PbKPfGlobalVolumeAccumulator),
+
+    #[prost(message, tag = "27")]
+    PfGlobalConfigUpdated(PbKPfGlobalConfig),
+    #[prost(message, tag = "28")]
+    PfFeeConfigUpdated(PbKPfFeeConfig),
+
+    #[prost(message, tag = "29")]
+    PfBondingCurveCompleted(PbKPfBondingCurveCompleted),
+}
+
+/* ========= Enums ========= */
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Enumeration)]
+#[repr(i32)]
+pub enum PbEventSource {
+    Unknown = 0,
+    GeyserTxStreamer = 1,
+    GeyserAccountSubscriber = 2,
+    GapFiller = 3,
+    Historian = 4,
+    DataProcessor = 5,
+    Snapshot = 6,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Enumeration)]
+#[repr(i32)]
+pub enum PbPlatform {
+    Unspecified = 0,
+    PumpFun = 1,
+    PumpSwap = 2,
+    RaydiumLaunchLab = 3,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Enumeration)]
+#[repr(i32)]
+pub enum PbTokenStatus {
+    Unspecified = 0,
+    Created = 1,
+    Migrated = 2,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Enumeration)]
+#[repr(i32)]
+pub enum PbTradeDirection {
+    Unspecified = 0,
+    Buy = 1,
+    Sell = 2,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Enumeration)]
+#[repr(i32)]
+pub enum PbPriceSource {
+    Unspecified = 0,
+    PumpFunBondingCurve = 1,
+    PumpSwapPool = 2,
+    RaydiumLaunchLabPoolState = 3,
+}
+
+/* ========= Pump.fun ========= */
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfChTrade {
+    #[prost(string, tag = "1")]
+    pub signature: String,
+    #[prost(uint64, tag = "2")]
+    pub slot: u64,
+    #[prost(string, tag = "3")]
+    pub blockhash: String,
+
+    #[prost(string, tag = "4")]
+    pub signer: String,
+    #[prost(string, tag = "5")]
+    pub fee_payer: String,
+    #[prost(string, tag = "6")]
+    pub user: String,
+    #[prost(string, tag = "7")]
+    pub creator: String,
+    #[prost(string, tag = "8")]
+    pub fee_recipient: String,
+
+    #[prost(string, tag = "9")]
+    pub mint: String,
+    #[prost(string, tag = "10")]
+    pub bonding_curve: String,
+    #[prost(bool, tag = "11")]
+    pub is_pump_pool: bool,
+
+    #[prost(string, tag = "12")]
+    pub ix_name: String,
+    #[prost(bool, tag = "13")]
+    pub is_buy: bool,
+
+    #[prost(uint64, tag = "14")]
+    pub sol_amount: u64,
+    #[prost(uint64, tag = "15")]
+    pub token_amount: u64,
+    #[prost(uint64, tag = "16")]
+    pub trade_size_lamports: u64,
+
+    #[prost(uint64, tag = "17")]
+    pub transaction_fee: u64,
+    #[prost(uint64, tag = "18")]
+    pub fee_lamports: u64,
+    #[prost(uint64, tag = "19")]
+    pub fee_basis_points: u64,
+    #[prost(uint64, tag = "20")]
+    pub creator_fee_lamports: u64,
+    #[prost(uint64, tag = "21")]
+    pub creator_fee_basis_points: u64,
+
+    #[prost(uint32, tag = "22")]
+    pub decimals: u32,
+    #[prost(uint64, tag = "23")]
+    pub virtual_sol_reserves: u64,
+    #[prost(uint64, tag = "24")]
+    pub virtual_token_reserves: u64,
+    #[prost(uint64, tag = "25")]
+    pub real_sol_reserves: u64,
+    #[prost(uint64, tag = "26")]
+    pub real_token_reserves: u64,
+    #[prost(uint64, tag = "27")]
+    pub market_cap_lamports: u64,
+
+    #[prost(bool, tag = "28")]
+    pub track_volume: bool,
+    #[prost(uint64, tag = "29")]
+    pub total_unclaimed_tokens: u64,
+    #[prost(uint64, tag = "30")]
+    pub total_claimed_tokens: u64,
+    #[prost(uint64, tag = "31")]
+    pub current_sol_volume: u64,
+    #[prost(int64, tag = "32")]
+    pub last_update_timestamp: i64,
+
+    #[prost(int64, tag = "33")]
+    pub ts_ns: i64,
+    #[prost(uint32, tag = "34")]
+    pub ix_index: u32,
+    #[prost(uint64, tag = "35")]
+    pub tx_index: u64,
+    #[prost(uint32, tag = "36")]
+    pub event_ordinal: u32,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfToken {
+    #[prost(string, tag = "1")]
+    pub mint: String,
+    #[prost(string, tag = "2")]
+    pub bonding_curve: String,
+    #[prost(string, tag = "3")]
+    pub name: String,
+    #[prost(string, tag = "4")]
+    pub symbol: String,
+    #[prost(string, optional, tag = "5")]
+    pub uri: Option<String>,
+    #[prost(string, tag = "6")]
+    pub creator: String,
+    #[prost(string, tag = "7")]
+    pub user_address: String,
+    #[prost(int32, tag = "8")]
+    pub decimals: i32,
+    #[prost(int64, tag = "9")]
+    pub ts_ns: i64,
+    #[prost(string, tag = "10")]
+    pub description: String,
+    #[prost(string, tag = "11")]
+    pub twitter: String,
+    #[prost(string, tag = "12")]
+    pub telegram: String,
+    #[prost(string, tag = "13")]
+    pub website: String,
+    #[prost(string, tag = "14")]
+    pub image: String,
+    #[prost(int64, tag = "15")]
+    pub virtual_token_reserves: i64,
+    #[prost(int64, tag = "16")]
+    pub virtual_sol_reserves: i64,
+    #[prost(int64, tag = "17")]
+    pub real_token_reserves: i64,
+    #[prost(int64, tag = "18")]
+    pub token_total_supply: i64,
+    #[prost(uint64, tag = "19")]
+    pub tx_index: u64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfTrade {
+    #[prost(string, tag = "1")]
+    pub signature: String,
+    #[prost(string, tag = "2")]
+    pub mint: String,
+    #[prost(string, tag = "3")]
+    pub bonding_curve: String,
+    #[prost(enumeration = "PbPlatform", tag = "4")]
+    pub platform: i32,
+    #[prost(enumeration = "PbTradeDirection", tag = "5")]
+    pub direction: i32,
+    #[prost(int64, tag = "6")]
+    pub sol_amount: i64,
+    #[prost(int64, tag = "7")]
+    pub token_amount: i64,
+    #[prost(string, tag = "8")]
+    pub user_pubkey: String,
+    #[prost(int64, tag = "9")]
+    pub ts_ns: i64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfTokenLifecycle {
+    #[prost(string, tag = "1")]
+    pub mint: String,
+    #[prost(enumeration = "PbTokenStatus", tag = "2")]
+    pub status: i32,
+    #[prost(enumeration = "PbPlatform", tag = "3")]
+    pub platform: i32,
+    #[prost(int64, optional, tag = "4")]
+    pub ts_created_ns: Option<i64>,
+    #[prost(int64, optional, tag = "5")]
+    pub ts_migrated_ns: Option<i64>,
+    #[prost(string, optional, tag = "6")]
+    pub bonding_curve: Option<String>,
+    #[prost(string, optional, tag = "7")]
+    pub pool: Option<String>,
+    #[prost(int64, optional, tag = "8")]
+    pub sol_amount_migrated: Option<i64>,
+    #[prost(int64, optional, tag = "9")]
+    pub token_amount_migrated: Option<i64>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfPrice {
+    #[prost(string, tag = "1")]
+    pub bonding_curve: String,
+    #[prost(enumeration = "PbPriceSource", tag = "2")]
+    pub source: i32,
+    #[prost(int64, tag = "3")]
+    pub ts_ns: i64,
+    #[prost(uint64, tag = "4")]
+    pub virtual_token_reserves: u64,
+    #[prost(uint64, tag = "5")]
+    pub virtual_sol_reserves: u64,
+    #[prost(uint64, tag = "6")]
+    pub real_token_reserves: u64,
+    #[prost(uint64, tag = "7")]
+    pub real_sol_reserves: u64,
+    #[prost(uint64, tag = "8")]
+    pub token_total_supply: u64,
+    #[prost(bool, tag = "9")]
+    pub complete: bool,
+    #[prost(string, optional, tag = "10")]
+    pub creator: Option<String>,
+    #[prost(bool, tag = "11")]
+    pub is_mayhem_mode: bool,
+    #[prost(uint64, tag = "12")]
+    pub slot: u64,
+    #[prost(uint64, tag = "13")]
+    pub write_version: u64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfBondingCurveCompleted {
+    #[prost(string, tag = "1")]
+    pub bonding_curve: String,
+    #[prost(string, optional, tag = "2")]
+    pub creator: Option<String>,
+    #[prost(uint64, tag = "3")]
+    pub token_total_supply: u64,
+    #[prost(uint64, tag = "4")]
+    pub virtual_token_reserves: u64,
+    #[prost(uint64, tag = "5")]
+    pub virtual_sol_reserves: u64,
+    #[prost(uint64, tag = "6")]
+    pub real_sol_reserves: u64,
+    #[prost(bool, tag = "7")]
+    pub is_mayhem_mode: bool,
+    #[prost(int64, tag = "8")]
+    pub ts_ns: i64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfCreatorFeeClaim {
+    #[prost(string, tag = "1")]
+    pub signature: String,
+    #[prost(uint64, tag = "2")]
+    pub slot: u64,
+    #[prost(string, tag = "3")]
+    pub creator: String,
+    #[prost(string, tag = "4")]
+    pub creator_vault: String,
+    #[prost(uint64, tag = "5")]
+    pub creator_fee: u64,
+    #[prost(int64, tag = "6")]
+    pub ts_ns: i64,
+    #[prost(uint32, tag = "7")]
+    pub ix_index: u32,
+    #[prost(uint32, tag = "8")]
+    pub event_ordinal: u32,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfTokenIncentiveClaim {
+    #[prost(string, tag = "1")]
+    pub signature: String,
+    #[prost(uint64, tag = "2")]
+    pub slot: u64,
+    #[prost(string, tag = "3")]
+    pub user: String,
+    #[prost(string, tag = "4")]
+    pub mint: String,
+    #[prost(uint64, tag = "5")]
+    pub amount: u64,
+    #[prost(uint64, tag = "6")]
+    pub total_claimed_tokens: u64,
+    #[prost(uint64, tag = "7")]
+    pub current_sol_volume: u64,
+    #[prost(int64, tag = "8")]
+    pub ts_ns: i64,
+    #[prost(uint32, tag = "9")]
+    pub ix_index: u32,
+    #[prost(uint32, tag = "10")]
+    pub event_ordinal: u32,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfUserVolumeAccumulator {
+    #[prost(string, tag = "1")]
+    pub user_volume_accumulator: String,
+    #[prost(string, tag = "2")]
+    pub user: String,
+    #[prost(bool, tag = "3")]
+    pub needs_claim: bool,
+    #[prost(uint64, tag = "4")]
+    pub total_unclaimed_tokens: u64,
+    #[prost(uint64, tag = "5")]
+    pub total_claimed_tokens: u64,
+    #[prost(uint64, tag = "6")]
+    pub current_sol_volume: u64,
+    #[prost(int64, tag = "7")]
+    pub last_update_timestamp: i64,
+    #[prost(int64, tag = "8")]
+    pub ts_ns: i64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfGlobalVolumeAccumulator {
+    #[prost(string, tag = "1")]
+    pub global_volume_accumulator: String,
+    #[prost(int64, tag = "2")]
+    pub start_time: i64,
+    #[prost(int64, tag = "3")]
+    pub end_time: i64,
+    #[prost(int64, tag = "4")]
+    pub seconds_in_a_day: i64,
+    #[prost(string, tag = "5")]
+    pub mint: String,
+    #[prost(uint64, repeated, tag = "6")]
+    pub total_token_supply: Vec<u64>,
+    #[prost(uint64, repeated, tag = "7")]
+    pub sol_volumes: Vec<u64>,
+    #[prost(int64, tag = "8")]
+    pub ts_ns: i64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfGlobalConfig {
+    #[prost(string, tag = "1")]
+    pub global: String,
+    #[prost(string, tag = "2")]
+    pub fee_recipient: String,
+    #[prost(uint64, tag = "3")]
+    pub initial_virtual_token_reserves: u64,
+    #[prost(uint64, tag = "4")]
+    pub initial_virtual_sol_reserves: u64,
+    #[prost(uint64, tag = "5")]
+    pub initial_real_token_reserves: u64,
+    #[prost(uint64, tag = "6")]
+    pub token_total_supply: u64,
+    #[prost(uint64, tag = "7")]
+    pub fee_basis_points: u64,
+    #[prost(uint64, tag = "8")]
+    pub creator_fee_basis_points: u64,
+    #[prost(bool, tag = "9")]
+    pub enable_migrate: bool,
+    #[prost(uint64, tag = "10")]
+    pub pool_migration_fee: u64,
+    #[prost(int64, tag = "11")]
+    pub ts_ns: i64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfFees {
+    #[prost(uint64, tag = "1")]
+    pub lp_fee_bps: u64,
+    #[prost(uint64, tag = "2")]
+    pub protocol_fee_bps: u64,
+    #[prost(uint64, tag = "3")]
+    pub creator_fee_bps: u64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfFeeTier {
+    #[prost(uint64, tag = "1")]
+    pub market_cap_lamports_threshold: u64,
+    #[prost(message, optional, tag = "2")]
+    pub fees: Option<PbKPfFees>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPfFeeConfig {
+    #[prost(string, tag = "1")]
+    pub fee_config: String,
+    #[prost(message, optional, tag = "2")]
+    pub flat_fees: Option<PbKPfFees>,
+    #[prost(message, repeated, tag = "3")]
+    pub fee_tiers: Vec<PbKPfFeeTier>,
+    #[prost(int64, tag = "4")]
+    pub ts_ns: i64,
+}
+
+/* ========= PumpSwap ========= */
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPsTrade {
+    #[prost(string, tag = "1")]
+    pub signature: String,
+    #[prost(string, tag = "2")]
+    pub mint: String,
+    #[prost(string, tag = "3")]
+    pub pool: String,
+    #[prost(enumeration = "PbTradeDirection", tag = "4")]
+    pub direction: i32,
+    #[prost(int64, tag = "5")]
+    pub sol_amount: i64,
+    #[prost(int64, tag = "6")]
+    pub token_amount: i64,
+    #[prost(string, tag = "7")]
+    pub user_pubkey: String,
+    #[prost(int64, tag = "8")]
+    pub ts_ns: i64,
+    #[prost(string, optional, tag = "9")]
+    pub coin_creator: Option<String>,
+    #[prost(uint64, optional, tag = "10")]
+    pub coin_creator_fee: Option<u64>,
+    #[prost(uint64, tag = "11")]
+    pub slot: u64,
+    #[prost(uint32, tag = "12")]
+    pub ix_index: u32,
+    #[prost(uint32, tag = "13")]
+    pub event_ordinal: u32,
+    #[prost(string, optional, tag = "14")]
+    pub quote_mint: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPsToken {
+    #[prost(string, tag = "1")]
+    pub mint: String,
+    #[prost(string, tag = "2")]
+    pub pool: String,
+    #[prost(string, tag = "3")]
+    pub name: String,
+    #[prost(string, tag = "4")]
+    pub symbol: String,
+    #[prost(string, tag = "5")]
+    pub creator: String,
+    #[prost(string, tag = "6")]
+    pub user_address: String,
+    #[prost(int32, tag = "7")]
+    pub decimals: i32,
+    #[prost(int64, tag = "8")]
+    pub ts_ns: i64,
+    #[prost(string, optional, tag = "9")]
+    pub uri: Option<String>,
+    #[prost(string, tag = "10")]
+    pub description: String,
+    #[prost(string, tag = "11")]
+    pub twitter: String,
+    #[prost(string, tag = "12")]
+    pub telegram: String,
+    #[prost(string, tag = "13")]
+    pub website: String,
+    #[prost(string, tag = "14")]
+    pub image: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPsPrice {
+    #[prost(string, tag = "1")]
+    pub pool: String,
+    #[prost(enumeration = "PbPriceSource", tag = "2")]
+    pub source: i32,
+    #[prost(int64, tag = "3")]
+    pub ts_ns: i64,
+    #[prost(uint64, tag = "4")]
+    pub token_a_reserves: u64,
+    #[prost(uint64, tag = "5")]
+    pub token_b_reserves: u64,
+    #[prost(string, optional, tag = "6")]
+    pub base_mint: Option<String>,
+    #[prost(string, optional, tag = "7")]
+    pub quote_mint: Option<String>,
+    #[prost(uint64, tag = "8")]
+    pub slot: u64,
+    #[prost(uint64, tag = "9")]
+    pub write_version: u64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKPsCreatorFeeClaim {
+    #[prost(string, tag = "1")]
+    pub signature: String,
+    #[prost(uint64, tag = "2")]
+    pub slot: u64,
+    #[prost(string, tag = "3")]
+    pub coin_creator: String,
+    #[prost(string, tag = "4")]
+    pub quote_mint: String,
+    #[prost(uint64, tag = "5")]
+    pub coin_creator_fee: u64,
+    #[prost(string, tag = "6")]
+    pub coin_creator_vault_ata: String,
+    #[prost(string, tag = "7")]
+    pub coin_creator_token_account: String,
+    #[prost(int64, tag = "8")]
+    pub ts_ns: i64,
+    #[prost(uint32, tag = "9")]
+    pub ix_index: u32,
+    #[prost(uint32, tag = "10")]
+    pub event_ordinal: u32,
+}
+
+/* ========= Raydium LaunchLab ========= */
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKRllToken {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(string, tag = "2")]
+    pub symbol: String,
+    #[prost(string, optional, tag = "3")]
+    pub uri: Option<String>,
+    #[prost(string, tag = "4")]
+    pub creator: String,
+    #[prost(string, tag = "5")]
+    pub mint: String,
+    #[prost(enumeration = "PbPlatform", tag = "6")]
+    pub platform: i32,
+    #[prost(string, tag = "7")]
+    pub pool_state: String,
+    #[prost(string, tag = "8")]
+    pub user_address: String,
+    #[prost(int64, tag = "9")]
+    pub ts_ns: i64,
+    #[prost(uint32, tag = "10")]
+    pub base_decimals: u32,
+    #[prost(uint32, tag = "11")]
+    pub quote_decimals: u32,
+    #[prost(uint64, tag = "12")]
+    pub virtual_base: u64,
+    #[prost(uint64, tag = "13")]
+    pub virtual_quote: u64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKRllTrade {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKRllTokenLifecycle {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKRllPrice {
+    #[prost(string, tag = "1")]
+    pub pool_state: String,
+    #[prost(enumeration = "PbPriceSource", tag = "2")]
+    pub source: i32,
+    #[prost(int64, tag = "3")]
+    pub ts_ns: i64,
+    #[prost(uint32, tag = "4")]
+    pub base_decimals: u32,
+    #[prost(uint32, tag = "5")]
+    pub quote_decimals: u32,
+    #[prost(uint64, tag = "6")]
+    pub virtual_base: u64,
+    #[prost(uint64, tag = "7")]
+    pub virtual_quote: u64,
+    #[prost(uint32, tag = "8")]
+    pub status: u32,
+    #[prost(uint64, tag = "9")]
+    pub supply: u64,
+    #[prost(uint64, tag = "10")]
+    pub total_base_sell: u64,
+    #[prost(uint64, tag = "11")]
+    pub real_base: u64,
+    #[prost(uint64, tag = "12")]
+    pub real_quote: u64,
+    #[prost(uint64, tag = "13")]
+    pub total_quote_fund_raising: u64,
+    #[prost(message, optional, tag = "14")]
+    pub vesting: Option<PbKRllVesting>,
+    #[prost(string, optional, tag = "15")]
+    pub base_mint: Option<String>,
+    #[prost(string, optional, tag = "16")]
+    pub creator: Option<String>,
+    #[prost(uint64, tag = "17")]
+    pub price_quote: u64,
+    #[prost(uint64, tag = "18")]
+    pub progress_bps: u64,
+    #[prost(uint64, tag = "19")]
+    pub slot: u64,
+    #[prost(uint64, tag = "20")]
+    pub write_version: u64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PbKRllVesting {
+    #[prost(uint64, tag = "1")]
+    pub total_locked_amount: u64,
+    #[prost(uint64, tag = "2")]
+    pub cliff_period: u64,
+    #[prost(uint64, tag = "3")]
+    pub unlock_period: u64,
+    #[prost(uint64, tag = "4")]
+    pub start_time: u64,
+    #[prost(uint64, tag = "5")]
+    pub allocated_share_amount: u64,
+}
+
+/* ========= Contract -> wire ========= */
+
+// Timestamps travel as nanoseconds since epoch, which covers 1677..2262.
+fn to_ns(ts: &DateTime<Utc>) -> i64 {
+    ts.timestamp_nanos_opt().unwrap_or_default()
+}
+
+fn from_ns(ns: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp_nanos(ns)
+}
+
+impl From<&KEvent> for PbKEvent {
+    fn from(src: &KEvent) -> Self {
+        PbKEvent {
+            event_id: src.event_id.clone(),
+            schema_version: u32::from(src.schema_version),
+            source: PbEventSource::from(src.source) as i32,
+            produced_at_ns: to_ns(&src.produced_at),
+            slot: src.slot,
+            data: Some(PbKEventData::from(&src.data)),
+        }
+    }
+}
+
+impl From<&KEventData> for PbKEventData {
+    fn from(src: &KEventData) -> Self {
+        match src {
+            KEventData::PfChTradeOccurred(t) => PbKEventData::PfChTradeOccurred(t.into()),
+            KEventData::PfTradeOccurred(t) => PbKEventData::PfTradeOccurred(t.into()),
+            KEventData::PfTokenCreated(t) => PbKEventData::PfTokenCreated(t.into()),
+            KEventData::PfTokenMigrated(t) => PbKEventData::PfTokenMigrated(t.into()),
+            KEventData::PfPriceUpdated(p) => PbKEventData::PfPriceUpdated(p.into()),
+            KEventData::PsTradeOccurred(t) => PbKEventData::PsTradeOccurred(t.into()),
+            KEventData::PsTokenCreated(t) => PbKEventData::PsTokenCreated(t.into()),
+            KEventData::PsPriceUpdated(p) => PbKEventData::PsPriceUpdated(p.into()),
+            KEventData::RllTradeOccurred(_) => PbKEventData::RllTradeOccurred(PbKRllTrade {}),
+            KEventData::RllTokenCreated(t) => PbKEventData::RllTokenCreated(t.into()),
+            KEventData::RllTokenMigrated(_) => {
+                PbKEventData::RllTokenMigrated(PbKRllTokenLifecycle {})
+            }
+            KEventData::RllPriceUpdated(p) => PbKEventData::RllPriceUpdated(p.into()),
+            KEventData::PfCreatorFeeClaimed(c) => PbKEventData::PfCreatorFeeClaimed(c.into()),
+            KEventData::PsCreatorFeeClaimed(c) => PbKEventData::PsCreatorFeeClaimed(c.into()),
+            KEventData::PfTokenIncentivesClaimed(c) => {
+                PbKEventData::PfTokenIncentivesClaimed(c.into())
+            }
+            KEventData::PfUserVolumeAccumulatorUpdated(a) => {
+                PbKEventData::PfUserVolumeAccumulatorUpdated(a.into())
+            }
+            KEventData::PfGlobalVolumeAccumulatorUpdated(a) => {
+                PbKEventData::PfGlobalVolumeAccumulatorUpdated(a.into())
+            }
+            KEventData::PfGlobalConfigUpdated(g) => PbKEventData::PfGlobalConfigUpdated(g.into()),
+            KEventData::PfFeeConfigUpdated(f) => PbKEventData::PfFeeConfigUpdated(f.into()),
+            KEventData::PfBondingCurveCompleted(c) => {
+                PbKEventData::PfBondingCurveCompleted(c.into())
+            }
+        }
+    }
+}
+
+impl From<EventSource> for PbEventSource {
+    fn from(src: EventSource) -> Self {
+        match src {
+            EventSource::GeyserTxStreamer => PbEventSource::GeyserTxStreamer,
+            EventSource::GeyserAccountSubscriber => PbEventSource::GeyserAccountSubscriber,
+            EventSource::GapFiller => PbEventSource::GapFiller,
+            EventSource::Historian => PbEventSource::Historian,
+            EventSource::DataProcessor => PbEventSource::DataProcessor,
+            EventSource::Snapshot => PbEventSource::Snapshot,
+            EventSource::Unknown => PbEventSource::Unknown,
+        }
+    }
+}
+
+impl From<Platform> for PbPlatform {
+    fn from(src: Platform) -> Self {
+        match src {
+            Platform::PumpFun => PbPlatform::PumpFun,
+            Platform::PumpSwap => PbPlatform::PumpSwap,
+            Platform::RaydiumLaunchLab => PbPlatform::RaydiumLaunchLab,
+        }
+    }
+}
+
+impl From<TokenStatus> for PbTokenStatus {
+    fn from(src: TokenStatus) -> Self {
+        match src {
+            TokenStatus::Created => PbTokenStatus::Created,
+            TokenStatus::Migrated => PbTokenStatus::Migrated,
+        }
+    }
+}
+
+impl From<TradeDirection> for PbTradeDirection {
+    fn from(src: TradeDirection) -> Self {
+        match src {
+            TradeDirection::Buy => PbTradeDirection::Buy,
+            TradeDirection::Sell => PbTradeDirection::Sell,
+        }
+    }
+}
+
+impl From<PriceSource> for PbPriceSource {
+    fn from(src: PriceSource) -> Self {
+        match src {
+            PriceSource::PumpFunBondingCurve => PbPriceSource::PumpFunBondingCurve,
+            PriceSource::PumpSwapPool => PbPriceSource::PumpSwapPool,
+            PriceSource::RaydiumLaunchLabPoolState => PbPriceSource::RaydiumLaunchLabPoolState,
+        }
+    }
+}
+
+impl From<&KPfChTrade> for PbKPfChTrade {
+    fn from(src: &KPfChTrade) -> Self {
+        PbKPfChTrade {
+            signature: src.signature.clone(),
+            slot: src.slot,
+            blockhash: src.blockhash.clone(),
+            signer: src.signer.clone(),
+            fee_payer: src.fee_payer.clone(),
+            user: src.user.clone(),
+            creator: src.creator.clone(),
+            fee_recipient: src.fee_recipient.clone(),
+            mint: src.mint.clone(),
+            bonding_curve: src.bonding_curve.clone(),
+            is_pump_pool: src.is_pump_pool,
+            ix_name: src.ix_name.clone(),
+            is_buy: src.is_buy,
+            sol_amount: src.sol_amount,
+            token_amount: src.token_amount,
+            trade_size_lamports: src.trade_size_lamports,
+            transaction_fee: src.transaction_fee,
+            fee_lamports: src.fee_lamports,
+            fee_basis_points: src.fee_basis_points,
+            creator_fee_lamports: src.creator_fee_lamports,
+            creator_fee_basis_points: src.creator_fee_basis_points,
+            decimals: u32::from(src.decimals),
+            virtual_sol_reserves: src.virtual_sol_reserves,
+            virtual_token_reserves: src.virtual_token_reserves,
+            real_sol_reserves: src.real_sol_reserves,
+            real_token_reserves: src.real_token_reserves,
+            market_cap_lamports: src.market_cap_lamports,
+            track_volume: src.track_volume,
+            total_unclaimed_tokens: src.total_unclaimed_tokens,
+            total_claimed_tokens: src.total_claimed_tokens,
+            current_sol_volume: src.current_sol_volume,
+            last_update_timestamp: src.last_update_timestamp,
+            ts_ns: to_ns(&src.ts),
+            ix_index: src.ix_index,
+            event_ordinal: src.event_ordinal,
+            tx_index: src.tx_index,
+        }
+    }
+}
+
+impl From<&KPfToken> for PbKPfToken {
+    fn from(src: &KPfToken) -> Self {
+        PbKPfToken {
+            mint: src.mint.clone(),
+            bonding_curve: src.bonding_curve.clone(),
+            name: src.name.clone(),
+            symbol: src.symbol.clone(),
+            uri: src.uri.clone(),
+            creator: src.creator.clone(),
+            user_address: src.user_address.clone(),
+            decimals: i32::from(src.decimals),
+            ts_ns: to_ns(&src.ts),
+            description: src.description.clone(),
+            twitter: src.twitter.clone(),
+            telegram: src.telegram.clone(),
+            website: src.website.clone(),
+            image: src.image.clone(),
+            virtual_token_reserves: src.virtual_token_reserves,
+            virtual_sol_reserves: src.virtual_sol_reserves,
+            real_token_reserves: src.real_token_reserves,
+            token_total_supply: src.token_total_supply,
+            tx_index: src.tx_index,
+        }
+    }
+}
+
+impl From<&KPfTrade> for PbKPfTrade {
+    fn from(src: &KPfTrade) -> Self {
+        PbKPfTrade {
+            signature: src.signature.clone(),
+            mint: src.mint.clone(),
+            bonding_curve: src.bonding_curve.clone(),
+            platform: PbPlatform::from(src.platform) as i32,
+            direction: PbTradeDirection::from(src.direction) as i32,
+            sol_amount: src.sol_amount,
+            token_amount: src.token_amount,
+            user_pubkey: src.user_pubkey.clone(),
+            ts_ns: to_ns(&src.ts),
+        }
+    }
+}
+
+impl From<&KPfTokenLifecycle> for PbKPfTokenLifecycle {
+    fn from(src: &KPfTokenLifecycle) -> Self {
+        PbKPfTokenLifecycle {
+            mint: src.mint.clone(),
+            status: PbTokenStatus::from(src.status) as i32,
+            platform: PbPlatform::from(src.platform) as i32,
+            ts_created_ns: src.ts_created.as_ref().map(to_ns),
+            ts_migrated_ns: src.ts_migrated.as_ref().map(to_ns),
+            bonding_curve: src.bonding_curve.clone(),
+            pool: src.pool.clone(),
+            sol_amount_migrated: src.sol_amount_migrated,
+            token_amount_migrated: src.token_amount_migrated,
+        }
+    }
+}
+
+impl From<&KPfPrice> for PbKPfPrice {
+    fn from(src: &KPfPrice) -> Self {
+        PbKPfPrice {
+            bonding_curve: src.bonding_curve.clone(),
+            source: PbPriceSource::from(src.source) as i32,
+            ts_ns: to_ns(&src.ts),
+            virtual_token_reserves: src.virtual_token_reserves,
+            virtual_sol_reserves: src.virtual_sol_reserves,
+            real_token_reserves: src.real_token_reserves,
+            real_sol_reserves: src.real_sol_reserves,
+            token_total_supply: src.token_total_supply,
+            complete: src.complete,
+            creator: src.creator.clone(),
+            is_mayhem_mode: src.is_mayhem_mode,
+            slot: src.slot,
+            write_version: src.write_version,
+        }
+    }
+}
+
+impl From<&KPfBondingCurveCompleted> for PbKPfBondingCurveCompleted {
+    fn from(src: &KPfBondingCurveCompleted) -> Self {
+        PbKPfBondingCurveCompleted {
+            bonding_curve: src.bonding_curve.clone(),
+            creator: src.creator.clone(),
+            token_total_supply: src.token_total_supply,
+            virtual_token_reserves: src.virtual_token_reserves,
+            virtual_sol_reserves: src.virtual_sol_reserves,
+            real_sol_reserves: src.real_sol_reserves,
+            is_mayhem_mode: src.is_mayhem_mode,
+            ts_ns: to_ns(&src.ts),
+        }
+    }
+}
+
+impl From<&KPfCreatorFeeClaim> for PbKPfCreatorFeeClaim {
+    fn from(src: &KPfCreatorFeeClaim) -> Self {
+        PbKPfCreatorFeeClaim {
+            signature: src.signature.clone(),
+            slot: src.slot,
+            creator: src.creator.clone(),
+            creator_vault: src.creator_vault.clone(),
+            creator_fee: src.creator_fee,
+            ts_ns: to_ns(&src.ts),
+            ix_index: src.ix_index,
+            event_ordinal: src.event_ordinal,
+        }
+    }
+}
+
+impl From<&KPfTokenIncentiveClaim> for PbKPfTokenIncentiveClaim {
+    fn from(src: &KPfTokenIncentiveClaim) -> Self {
+        PbKPfTokenIncentiveClaim {
+            signature: src.signature.clone(),
+            slot: src.slot,
+            user: src.user.clone(),
+            mint: src.mint.clone(),
+            amount: src.amount,
+            total_claimed_tokens: src.total_claimed_tokens,
+            current_sol_volume: src.current_sol_volume,
+            ts_ns: to_ns(&src.ts),
+            ix_index: src.ix_index,
+            event_ordinal: src.event_ordinal,
+        }
+    }
+}
+
+impl From<&KPfUserVolumeAccumulator> for PbKPfUserVolumeAccumulator {
+    fn from(src: &KPfUserVolumeAccumulator) -> Self {
+        PbKPfUserVolumeAccumulator {
+            user_volume_accumulator: src.user_volume_accumulator.clone(),
+            user: src.user.clone(),
+            needs_claim: src.needs_claim,
+            total_unclaimed_tokens: src.total_unclaimed_tokens,
+            total_claimed_tokens: src.total_claimed_tokens,
+            current_sol_volume: src.current_sol_volume,
+            last_update_timestamp: src.last_update_timestamp,
+            ts_ns: to_ns(&src.ts),
+        }
+    }
+}
+
+impl From<&KPfGlobalVolumeAccumulator> for PbKPfGlobalVolumeAccumulator {
+    fn from(src: &KPfGlobalVolumeAccumulator) -> Self {
+        PbKPfGlobalVolumeAccumulator {
+            global_volume_accumulator: src.global_volume_accumulator.clone(),
+            start_time: src.start_time,
+            end_time: src.end_time,
+            seconds_in_a_day: src.seconds_in_a_day,
+            mint: src.mint.clone(),
+            total_token_supply: src.total_token_supply.clone(),
+            sol_volumes: src.sol_volumes.clone(),
+            ts_ns: to_ns(&src.ts),
+        }
+    }
+}
+
+impl From<&KPfGlobalConfig> for PbKPfGlobalConfig {
+    fn from(src: &KPfGlobalConfig) -> Self {
+        PbKPfGlobalConfig {
+            global: src.global.clone(),
+            fee_recipient: src.fee_recipient.clone(),
+            initial_virtual_token_reserves: src.initial_virtual_token_reserves,
+            initial_virtual_sol_reserves: src.initial_virtual_sol_reserves,
+            initial_real_token_reserves: src.initial_real_token_reserves,
+            token_total_supply: src.token_total_supply,
+            fee_basis_points: src.fee_basis_points,
+            creator_fee_basis_points: src.creator_fee_basis_points,
+            enable_migrate: src.enable_migrate,
+            pool_migration_fee: src.pool_migration_fee,
+            ts_ns: to_ns(&src.ts),
+        }
+    }
+}
+
+impl From<&KPfFees> for PbKPfFees {
+    fn from(src: &KPfFees) -> Self {
+        PbKPfFees {
+            lp_fee_bps: src.lp_fee_bps,
+            protocol_fee_bps: src.protocol_fee_bps,
+            creator_fee_bps: src.creator_fee_bps,
+        }
+    }
+}
+
+impl From<&KPfFeeConfig> for PbKPfFeeConfig {
+    fn from(src: &KPfFeeConfig) -> Self {
+        PbKPfFeeConfig {
+            fee_config: src.fee_config.clone(),
+            flat_fees: Some((&src.flat_fees).into()),
+            fee_tiers: src
+                .fee_tiers
+                .iter()
+                .map(|t| PbKPfFeeTier {
+                    market_cap_lamports_threshold: t.market_cap_lamports_threshold,
+                    fees: Some((&t.fees).into()),
+                })
+                .collect(),
+            ts_ns: to_ns(&src.ts),
+        }
+    }
+}
+
+impl From<&KPsTrade> for PbKPsTrade {
+    fn from(src: &KPsTrade) -> Self {
+        PbKPsTrade {
+            signature: src.signature.clone(),
+            mint: src.mint.clone(),
+            pool: src.pool.clone(),
+            direction: PbTradeDirection::from(src.direction) as i32,
+            sol_amount: src.sol_amount,
+            token_amount: src.token_amount,
+            user_pubkey: src.user_pubkey.clone(),
+            ts_ns: to_ns(&src.ts),
+            coin_creator: src.coin_creator.clone(),
+            coin_creator_fee: src.coin_creator_fee,
+            slot: src.slot,
+            ix_index: src.ix_index,
+            event_ordinal: src.event_ordinal,
+            quote_mint: src.quote_mint.clone(),
+        }
+    }
+}
+
+impl From<&KPsToken> for PbKPsToken {
+    fn from(src: &KPsToken) -> Self {
+        PbKPsToken {
+            mint: src.mint.clone(),
+            pool: src.pool.clone(),
+            name: src.name.clone(),
+            symbol: src.symbol.clone(),
+            creator: src.creator.clone(),
+            user_address: src.user_address.clone(),
+            decimals: i32::from(src.decimals),
+            ts_ns: to_ns(&src.ts),
+            uri: src.uri.clone(),
+            description: src.description.clone(),
+            twitter: src.twitter.clone(),
+            telegram: src.telegram.clone(),
+            website: src.website.clone(),
+            image: src.image.clone(),
+        }
+    }
+}
+
+impl From<&KPsPrice> for PbKPsPrice {
+    fn from(src: &KPsPrice) -> Self {
+        PbKPsPrice {
+            pool: src.pool.clone(),
+            source: PbPriceSource::from(src.source) as i32,
+            ts_ns: to_ns(&src.ts),
+            token_a_reserves: src.token_a_reserves,
+            token_b_reserves: src.token_b_reserves,
+            base_mint: src.base_mint.clone(),
+            quote_mint: src.quote_mint.clone(),
+            slot: src.slot,
+            write_version: src.write_version,
+        }
+    }
+}
+
+impl From<&KPsCreatorFeeClaim> for PbKPsCreatorFeeClaim {
+    fn from(src: &KPsCreatorFeeClaim) -> Self {
+        PbKPsCreatorFeeClaim {
+            signature: src.signature.clone(),
+            slot: src.slot,
+            coin_creator: src.coin_creator.clone(),
+            quote_mint: src.quote_mint.clone(),
+            coin_creator_fee: src.coin_creator_fee,
+            coin_creator_vault_ata: src.coin_creator_vault_ata.clone(),
+            coin_creator_token_account: src.coin_creator_token_account.clone(),
+            ts_ns: to_ns(&src.ts),
+            ix_index: src.ix_index,
+            event_ordinal: src.event_ordinal,
+        }
+    }
+}
+
+impl From<&KRllToken> for PbKRllToken {
+    fn from(src: &KRllToken) -> Self {
+        PbKRllToken {
+            name: src.name.clone(),
+            symbol: src.symbol.clone(),
+            uri: src.uri.clone(),
+            creator: src.creator.clone(),
+            mint: src.mint.clone(),
+            platform: PbPlatform::from(src.platform) as i32,
+            pool_state: src.pool_state.clone(),
+            user_address: src.user_address.clone(),
+            ts_ns: to_ns(&src.ts),
+            base_decimals: u32::from(src.base_decimals),
+            quote_decimals: u32::from(src.quote_decimals),
+            virtual_base: src.virtual_base,
+            virtual_quote: src.virtual_quote,
+        }
+    }
+}
+
+impl From<&KRllPrice> for PbKRllPrice {
+    fn from(src: &KRllPrice) -> Self {
+        PbKRllPrice {
+            pool_state: src.pool_state.clone(),
+            source: PbPriceSource::from(src.source) as i32,
+            ts_ns: to_ns(&src.ts),
+            base_decimals: u32::from(src.base_decimals),
+            quote_decimals: u32::from(src.quote_decimals),
+            virtual_base: src.virtual_base,
+            virtual_quote: src.virtual_quote,
+            status: u32::from(src.status),
+            supply: src.supply,
+            total_base_sell: src.total_base_sell,
+            real_base: src.real_base,
+            real_quote: src.real_quote,
+            total_quote_fund_raising: src.total_quote_fund_raising,
+            vesting: src.vesting.as_ref().map(Into::into),
+            base_mint: src.base_mint.clone(),
+            creator: src.creator.clone(),
+            price_quote: src.price_quote,
+            progress_bps: src.progress_bps,
+            slot: src.slot,
+            write_version: src.write_version,
+        }
+    }
+}
+
+impl From<&KRllVesting> for PbKRllVesting {
+    fn from(src: &KRllVesting) -> Self {
+        PbKRllVesting {
+            total_locked_amount: src.total_locked_amount,
+            cliff_period: src.cliff_period,
+            unlock_period: src.unlock_period,
+            start_time: src.start_time,
+            allocated_share_amount: src.allocated_share_amount,
+        }
+    }
+}
+
+/* ========= Wire -> contract ========= */
+
+fn source_from_pb(v: i32) -> EventSource {
+    // Unknown producers decode as `Unknown` instead of failing the whole event.
+    match PbEventSource::try_from(v).unwrap_or(PbEventSource::Unknown) {
+        PbEventSource::GeyserTxStreamer => EventSource::GeyserTxStreamer,
+        PbEventSource::GeyserAccountSubscriber => EventSource::GeyserAccountSubscriber,
+        PbEventSource::GapFiller => EventSource::GapFiller,
+        PbEventSource::Historian => EventSource::Historian,
+        PbEventSource::DataProcessor => EventSource::DataProcessor,
+        PbEventSource::Snapshot => EventSource::Snapshot,
+        PbEventSource::Unknown => EventSource::Unknown,
+    }
+}
+
+fn platform_from_pb(v: i32) -> Result<Platform, String> {
+    match PbPlatform::try_from(v) {
+        Ok(PbPlatform::PumpFun) => Ok(Platform::PumpFun),
+        Ok(PbPlatform::PumpSwap) => Ok(Platform::PumpSwap),
+        Ok(PbPlatform::RaydiumLaunchLab) => Ok(Platform::RaydiumLaunchLab),
+        _ => Err(format!("platform_from_pb: invalid platform {v}")),
+    }
+}
+
+fn token_status_from_pb(v: i32) -> Result<TokenStatus, String> {
+    match PbTokenStatus::try_from(v) {
+        Ok(PbTokenStatus::Created) => Ok(TokenStatus::Created),
+        Ok(PbTokenStatus::Migrated) => Ok(TokenStatus::Migrated),
+        _ => Err(format!("token_status_from_pb: invalid token status {v}")),
+    }
+}
+
+fn direction_from_pb(v: i32) -> Result<TradeDirection, String> {
+    match PbTradeDirection::try_from(v) {
+        Ok(PbTradeDirection::Buy) => Ok(TradeDirection::Buy),
+        Ok(PbTradeDirection::Sell) => Ok(TradeDirection::Sell),
+        _ => Err(format!("direction_from_pb: invalid trade direction {v}")),
+    }
+}
+
+fn price_source_from_pb(v: i32) -> Result<PriceSource, String> {
+    match PbPriceSource::try_from(v) {
+        Ok(PbPriceSource::PumpFunBondingCurve) => Ok(PriceSource::PumpFunBondingCurve),
+        Ok(PbPriceSource::PumpSwapPool) => Ok(PriceSource::PumpSwapPool),
+        Ok(PbPriceSource::RaydiumLaunchLabPoolState) => Ok(PriceSource::RaydiumLaunchLabPoolState),
+        _ => Err(format!("price_source_from_pb: invalid price source {v}")),
+    }
+}
+
+fn u8_from_pb(v: u32, field: &str) -> Result<u8, String> {
+    u8::try_from(v).map_err(|_| format!("u8_from_pb: {field} out of range ({v})"))
+}
+
+fn i16_from_pb(v: i32, field: &str) -> Result<i16, String> {
+    i16::try_from(v).map_err(|_| format!("i16_from_pb: {field} out of range ({v})"))
+}
+
+impl TryFrom<PbKEvent> for KEvent {
+    type Error = String;
+
+    fn try_from(src: PbKEvent) -> Result<Self, Self::Error> {
+        let data = KEventData::try_from(src.data.ok_or("KEvent::try_from: data missing")?)?;
+
+        Ok(KEvent {
+            event_id: src.event_id,
+            schema_version: u16::try_from(src.schema_version)
+                .map_err(|_| "KEvent::try_from: schema_version out of range")?,
+            source: source_from_pb(src.source),
+            produced_at: from_ns(src.produced_at_ns),
+            slot: src.slot,
+            event_type: data.event_type(),
+            data,
+        })
+    }
+}
+
+impl TryFrom<PbKEventData> for KEventData {
+    type Error = String;
+
+    fn try_from(src: PbKEventData) -> Result<Self, Self::Error> {
+        Ok(match src {
+            PbKEventData::PfChTradeOccurred(t) => KEventData::PfChTradeOccurred(t.try_into()?),
+            PbKEventData::PfTradeOccurred(t) => KEventData::PfTradeOccurred(t.try_into()?),
+            PbKEventData::PfTokenCreated(t) => KEventData::PfTokenCreated(t.try_into()?),
+            PbKEventData::PfTokenMigrated(t) => KEventData::PfTokenMigrated(t.try_into()?),
+            PbKEventData::PfPriceUpdated(p) => KEventData::PfPriceUpdated(p.try_into()?),
+            PbKEventData::PsTradeOccurred(t) => KEventData::PsTradeOccurred(t.try_into()?),
+            PbKEventData::PsTokenCreated(t) => KEventData::PsTokenCreated(t.try_into()?),
+            PbKEventData::PsPriceUpdated(p) => KEventData::PsPriceUpdated(p.try_into()?),
+            PbKEventData::RllTradeOccurred(_) => KEventData::RllTradeOccurred(KRllTrade {}),
+            PbKEventData::RllTokenCreated(t) => KEventData::RllTokenCreated(t.try_into()?),
+            PbKEventData::RllTokenMigrated(_) => {
+                KEventData::RllTokenMigrated(KRllTokenLifecycle {})
+            }
+            PbKEventData::RllPriceUpdated(p) => KEventData::RllPriceUpdated(p.try_into()?),
+            PbKEventData::PfCreatorFeeClaimed(c) => KEventData::PfCreatorFeeClaimed(c.into()),
+            PbKEventData::PsCreatorFeeClaimed(c) => KEventData::PsCreatorFeeClaimed(c.into()),
+            PbKEventData::PfTokenIncentivesClaimed(c) => {
+                KEventData::PfTokenIncentivesClaimed(c.into())
+            }
+            PbKEventData::PfUserVolumeAccumulatorUpdated(a) => {
+                KEventData::PfUserVolumeAccumulatorUpdated(a.into())
+            }
+            PbKEventData::PfGlobalVolumeAccumulatorUpdated(a) => {
+                KEventData::PfGlobalVolumeAccumulatorUpdated(a.into())
+            }
+            PbKEventData::PfGlobalConfigUpdated(g) => KEventData::PfGlobalConfigUpdated(g.into()),
+            PbKEventData::PfFeeConfigUpdated(f) => KEventData::PfFeeConfigUpdated(f.try_into()?),
+            PbKEventData::PfBondingCurveCompleted(c) => {
+                KEventData::PfBondingCurveCompleted(c.into())
+            }
+        })
+    }
+}
+
+impl TryFrom<PbKPfChTrade> for KPfChTrade {
+    type Error = String;
+
+    fn try_from(src: PbKPfChTrade) -> Result<Self, Self::Error> {
+        Ok(KPfChTrade {
+            signature: src.signature,
+            slot: src.slot,
+            blockhash: src.blockhash,
+            signer: src.signer,
+            fee_payer: src.fee_payer,
+            user: src.user,
+            creator: src.creator,
+            fee_recipient: src.fee_recipient,
+            mint: src.mint,
+            bonding_curve: src.bonding_curve,
+            is_pump_pool: src.is_pump_pool,
+            ix_name: src.ix_name,
+            is_buy: src.is_buy,
+            sol_amount: src.sol_amount,
+            token_amount: src.token_amount,
+            trade_size_lamports: src.trade_size_lamports,
+            transaction_fee: src.transaction_fee,
+            fee_lamports: src.fee_lamports,
+            fee_basis_points: src.fee_basis_points,
+            creator_fee_lamports: src.creator_fee_lamports,
+            creator_fee_basis_points: src.creator_fee_basis_points,
+            decimals: u8_from_pb(src.decimals, "decimals")?,
+            virtual_sol_reserves: src.virtual_sol_reserves,
+            virtual_token_reserves: src.virtual_token_reserves,
+            real_sol_reserves: src.real_sol_reserves,
+            real_token_reserves: src.real_token_reserves,
+            market_cap_lamports: src.market_cap_lamports,
+            track_volume: src.track_volume,
+            total_unclaimed_tokens: src.total_unclaimed_tokens,
+            total_claimed_tokens: src.total_claimed_tokens,
+            current_sol_volume: src.current_sol_volume,
+            last_update_timestamp: src.last_update_timestamp,
+            ts: from_ns(src.ts_ns),
+            ix_index: src.ix_index,
+            event_ordinal: src.event_ordinal,
+            tx_index: src.tx_index,
+        })
+    }
+}
+
+impl TryFrom<PbKPfToken> for KPfToken {
+    type Error = String;
+
+    fn try_from(src: PbKPfToken) -> Result<Self, Self::Error> {
+        Ok(KPfToken {
+            mint: src.mint,
+            bonding_curve: src.bonding_curve,
+            name: src.name,
+            symbol: src.symbol,
+            uri: src.uri,
+            creator: src.creator,
+            user_address: src.user_address,
+            decimals: i16_from_pb(src.decimals, "decimals")?,
+            ts: from_ns(src.ts_ns),
+            description: src.description,
+            twitter: src.twitter,
+            telegram: src.telegram,
+            website: src.website,
+            image: src.image,
+            virtual_token_reserves: src.virtual_token_reserves,
+            virtual_sol_reserves: src.virtual_sol_reserves,
+            real_token_reserves: src.real_token_reserves,
+            token_total_supply: src.token_total_supply,
+            tx_index: src.tx_index,
+        })
+    }
+}
+
+impl TryFrom<PbKPfTrade> for KPfTrade {
+    type Error = String;
+
+    fn try_from(src: PbKPfTrade) -> Result<Self, Self::Error> {
+        Ok(KPfTrade {
+            signature: src.signature,
+            mint: src.mint,
+            bonding_curve: src.bonding_curve,
+            platform: platform_from_pb(src.platform)?,
+            direction: direction_from_pb(src.direction)?,
+            sol_amount: src.sol_amount,
+            token_amount: src.token_amount,
+            user_pubkey: src.user_pubkey,
+            ts: from_ns(src.ts_ns),
+        })
+    }
+}
+
+impl TryFrom<PbKPfTokenLifecycle> for KPfTokenLifecycle {
+    type Error = String;
+
+    fn try_from(src: PbKPfTokenLifecycle) -> Result<Self, Self::Error> {
+        Ok(KPfTokenLifecycle {
+            mint: src.mint,
+            status: token_status_from_pb(src.status)?,
+            platform: platform_from_pb(src.platform)?,
+            ts_created: src.ts_created_ns.map(from_ns),
+            ts_migrated: src.ts_migrated_ns.map(from_ns),
+            bonding_curve: src.bonding_curve,
+            pool: src.pool,
+            sol_amount_migrated: src.sol_amount_migrated,
+            token_amount_migrated: src.token_amount_migrated,
+        })
+    }
+}
+
+impl TryFrom<PbKPfPrice> for KPfPrice {
+    type Error = String;
+
+    fn try_from(src: PbKPfPrice) -> Result<Self, Self::Error> {
+        Ok(KPfPrice {
+            bonding_curve: src.bonding_curve,
+            source: price_source_from_pb(src.source)?,
+            ts: from_ns(src.ts_ns),
+            virtual_token_reserves: src.virtual_token_reserves,
+            virtual_sol_reserves: src.virtual_sol_reserves,
+            real_token_reserves: src.real_token_reserves,
+            real_sol_reserves: src.real_sol_reserves,
+            token_total_supply: src.token_total_supply,
+            complete: src.complete,
+            creator: src.creator,
+            is_mayhem_mode: src.is_mayhem_mode,
+            slot: src.slot,
+            write_version: src.write_version,
+        })
+    }
+}
+
+impl From<PbKPfBondingCurveCompleted> for KPfBondingCurveCompleted {
+    fn from(src: PbKPfBondingCurveCompleted) -> Self {
+        KPfBondingCurveCompleted {
+            bonding_curve: src.bonding_curve,
+            creator: src.creator,
+            token_total_supply: src.token_total_supply,
+            virtual_token_reserves: src.virtual_token_reserves,
+            virtual_sol_reserves: src.virtual_sol_reserves,
+            real_sol_reserves: src.real_sol_reserves,
+            is_mayhem_mode: src.is_mayhem_mode,
+            ts: from_ns(src.ts_ns),
+        }
+    }
+}
+
+// Claims carry no enums or narrowed integers, so decoding can't fail.
+impl From<PbKPfCreatorFeeClaim> for KPfCreatorFeeClaim {
+    fn from(src: PbKPfCreatorFeeClaim) -> Self {
+        KPfCreatorFeeClaim {
+            signature: src.signature,
+            slot: src.slot,
+            creator: src.creator,
+            creator_vault: src.creator_vault,
+            creator_fee: src.creator_fee,
+            ts: from_ns(src.ts_ns),
+            ix_index: src.ix_index,
+            event_ordinal: src.event_ordinal,
+        }
+    }
+}
+
+impl From<PbKPfTokenIncentiveClaim> for KPfTokenIncentiveClaim {
+    fn from(src: PbKPfTokenIncentiveClaim) -> Self {
+        KPfTokenIncentiveClaim {
+            signature: src.signature,
+            slot: src.slot,
+            user: src.user,
+            mint: src.mint,
+            amount: src.amount,
+            total_claimed_tokens: src.total_claimed_tokens,
+            current_sol_volume: src.current_sol_volume,
+            ts: from_ns(src.ts_ns),
+            ix_index: src.ix_index,
+            event_ordinal: src.event_ordinal,
+        }
+    }
+}
+
+impl From<PbKPfUserVolumeAccumulator> for KPfUserVolumeAccumulator {
+    fn from(src: PbKPfUserVolumeAccumulator) -> Self {
+        KPfUserVolumeAccumulator {
+            user_volume_accumulator: src.user_volume_accumulator,
+            user: src.user,
+            needs_claim: src.needs_claim,
+            total_unclaimed_tokens: src.total_unclaimed_tokens,
+            total_claimed_tokens: src.total_claimed_tokens,
+            current_sol_volume: src.current_sol_volume,
+            last_update_timestamp: src.last_update_timestamp,
+            ts: from_ns(src.ts_ns),
+        }
+    }
+}
+
+impl From<PbKPfGlobalVolumeAccumulator> for KPfGlobalVolumeAccumulator {
+    fn from(src: PbKPfGlobalVolumeAccumulator) -> Self {
+        KPfGlobalVolumeAccumulator {
+            global_volume_accumulator: src.global_volume_accumulator,
+            start_time: src.start_time,
+            end_time: src.end_time,
+            seconds_in_a_day: src.seconds_in_a_day,
+            mint: src.mint,
+            total_token_supply: src.total_token_supply,
+            sol_volumes: src.sol_volumes,
+            ts: from_ns(src.ts_ns),
+        }
+    }
+}
+
+impl From<PbKPfGlobalConfig> for KPfGlobalConfig {
+    fn from(src: PbKPfGlobalConfig) -> Self {
+        KPfGlobalConfig {
+            global: src.global,
+            fee_recipient: src.fee_recipient,
+            initial_virtual_token_reserves: src.initial_virtual_token_reserves,
+            initial_virtual_sol_reserves: src.initial_virtual_sol_reserves,
+            initial_real_token_reserves: src.initial_real_token_reserves,
+            token_total_supply: src.token_total_supply,
+            fee_basis_points: src.fee_basis_points,
+            creator_fee_basis_points: src.creator_fee_basis_points,
+            enable_migrate: src.enable_migrate,
+            pool_migration_fee: src.pool_migration_fee,
+            ts: from_ns(src.ts_ns),
+        }
+    }
+}
+
+impl From<PbKPfFees> for KPfFees {
+    fn from(src: PbKPfFees) -> Self {
+        KPfFees {
+            lp_fee_bps: src.lp_fee_bps,
+            protocol_fee_bps: src.protocol_fee_bps,
+            creator_fee_bps: src.creator_fee_bps,
+        }
+    }
+}
+
+impl TryFrom<PbKPfFeeConfig> for KPfFeeConfig {
+    type Error = String;
+
+    fn try_from(src: PbKPfFeeConfig) -> Result<Self, Self::Error> {
+        let fee_tiers = src
+            .fee_tiers
+            .into_iter()
+            .map(|t| {
+                Ok(KPfFeeTier {
+                    market_cap_lamports_threshold: t.market_cap_lamports_threshold,
+                    fees: t.fees.ok_or("KPfFeeTier: missing fees")?.into(),
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(KPfFeeConfig {
+            fee_config: src.fee_config,
+            flat_fees: src
+                .flat_fees
+                .ok_or("KPfFeeConfig: missing flat_fees")?
+                .into(),
+            fee_tiers,
+            ts: from_ns(src.ts_ns),
+        })
+    }
+}
+
+impl TryFrom<PbKPsTrade> for KPsTrade {
+    type Error = String;
+
+    fn try_from(src: PbKPsTrade) -> Result<Self, Self::Error> {
+        Ok(KPsTrade {
+            signature: src.signature,
+            mint: src.mint,
+            pool: src.pool,
+            direction: direction_from_pb(src.direction)?,
+            sol_amount: src.sol_amount,
+            token_amount: src.token_amount,
+            user_pubkey: src.user_pubkey,
+            ts: from_ns(src.ts_ns),
+            coin_creator: src.coin_creator,
+            coin_creator_fee: src.coin_creator_fee,
+            quote_mint: src.quote_mint,
+            slot: src.slot,
+            ix_index: src.ix_index,
+            event_ordinal: src.event_ordinal,
+        })
+    }
+}
+
+impl TryFrom<PbKPsToken> for KPsToken {
+    type Error = String;
+
+    fn try_from(src: PbKPsToken) -> Result<Self, Self::Error> {
+        Ok(KPsToken {
+            mint: src.mint,
+            pool: src.pool,
+            name: src.name,
+            symbol: src.symbol,
+            creator: src.creator,
+            user_address: src.user_address,
+            decimals: i16_from_pb(src.decimals, "decimals")?,
+            ts: from_ns(src.ts_ns),
+            uri: src.uri,
+            description: src.description,
+            twitter: src.twitter,
+            telegram: src.telegram,
+            website: src.website,
+            image: src.image,
+        })
+    }
+}
+
+impl TryFrom<PbKPsPrice> for KPsPrice {
+    type Error = String;
+
+    fn try_from(src: PbKPsPrice) -> Result<Self, Self::Error> {
+        Ok(KPsPrice {
+            pool: src.pool,
+            source: price_source_from_pb(src.source)?,
+            ts: from_ns(src.ts_ns),
+            token_a_reserves: src.token_a_reserves,
+            token_b_reserves: src.token_b_reserves,
+            base_mint: src.base_mint,
+            quote_mint: src.quote_mint,
+            slot: src.slot,
+            write_version: src.write_version,
+        })
+    }
+}
+
+impl From<PbKPsCreatorFeeClaim> for KPsCreatorFeeClaim {
+    fn from(src: PbKPsCreatorFeeClaim) -> Self {
+        KPsCreatorFeeClaim {
+            signature: src.signature,
+            slot: src.slot,
+            coin_creator: src.coin_creator,
+            quote_mint: src.quote_mint,
+            coin_creator_fee: src.coin_creator_fee,
+            coin_creator_vault_ata: src.coin_creator_vault_ata,
+            coin_creator_token_account: src.coin_creator_token_account,
+            ts: from_ns(src.ts_ns),
+            ix_index: src.ix_index,
+            event_ordinal: src.event_ordinal,
+        }
+    }
+}
+
+impl TryFrom<PbKRllToken> for KRllToken {
+    type Error = String;
+
+    fn try_from(src: PbKRllToken) -> Result<Self, Self::Error> {
+        Ok(KRllToken {
+            name: src.name,
+            symbol: src.symbol,
+            uri: src.uri,
+            creator: src.creator,
+            mint: src.mint,
+            platform: platform_from_pb(src.platform)?,
+            pool_state: src.pool_state,
+            user_address: src.user_address,
+            ts: from_ns(src.ts_ns),
+            base_decimals: u8_from_pb(src.base_decimals, "base_decimals")?,
+            quote_decimals: u8_from_pb(src.quote_decimals, "quote_decimals")?,
+            virtual_base: src.virtual_base,
+            virtual_quote: src.virtual_quote,
+        })
+    }
+}
+
+impl TryFrom<PbKRllPrice> for KRllPrice {
+    type Error = String;
+
+    fn try_from(src: PbKRllPrice) -> Result<Self, Self::Error> {
+        Ok(KRllPrice {
+            pool_state: src.pool_state,
+            source: price_source_from_pb(src.source)?,
+            ts: from_ns(src.ts_ns),
+            base_decimals: u8_from_pb(src.base_decimals, "base_decimals")?,
+            quote_decimals: u8_from_pb(src.quote_decimals, "quote_decimals")?,
+            virtual_base: src.virtual_base,
+            virtual_quote: src.virtual_quote,
+            status: u8_from_pb(src.status, "status")?,
+            supply: src.supply,
+            total_base_sell: src.total_base_sell,
+            real_base: src.real_base,
+            real_quote: src.real_quote,
+            total_quote_fund_raising: src.total_quote_fund_raising,
+            vesting: src.vesting.map(Into::into),
+            base_mint: src.base_mint,
+            creator: src.creator,
+            price_quote: src.price_quote,
+            progress_bps: src.progress_bps,
+            slot: src.slot,
+            write_version: src.write_version,
+        })
+    }
+}
+
+impl From<PbKRllVesting> for KRllVesting {
+    fn from(src: PbKRllVesting) -> Self {
+        KRllVesting {
+            total_locked_amount: src.total_locked_amount,
+            cliff_period: src.cliff_period,
+            unlock_period: src.unlock_period,
+            start_time: src.start_time,
+            allocated_share_amount: src.allocated_share_amount,
+        }
+    }
+}
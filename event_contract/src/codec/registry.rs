@@ -0,0 +1,233 @@
+//! Minimal client for a Confluent-compatible schema registry.
+//!
+//! Subjects follow the registry's default `TopicNameStrategy` (`<topic>-value`).
+//! Producers register `K_EVENT_PROTO` once per subject; consumers check that the
+//! schema id carried by a payload is registered under the topic's subject.
+
+use crate::codec::proto::K_EVENT_PROTO;
+use crate::codec::{ContentType, decode, encode, schema_id};
+use crate::models::kafka_event::KEvent;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+const REGISTRY_CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RegisterRequest<'a> {
+    schema_type: &'a str,
+    schema: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct RegisterResponse {
+    id: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct SubjectVersion {
+    subject: String,
+}
+
+/// Why a payload could not be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The registry could not answer; the same payload may decode later.
+    Unavailable(String),
+    /// The payload is malformed, or its schema id is not registered for the topic.
+    Rejected(String),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Unavailable(e) | DecodeError::Rejected(e) => f.write_str(e),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+#[derive(Debug, Clone)]
+pub struct SchemaRegistryClient {
+    url: String,
+    http: reqwest::Client,
+    registered: Arc<Mutex<HashMap<String, u32>>>, // subject -> schema id
+    validated: Arc<Mutex<HashSet<(String, u32)>>>, // (subject, schema id) known to be valid
+}
+
+impl SchemaRegistryClient {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into().trim_end_matches('/').to_string(),
+            http: reqwest::Client::new(),
+            registered: Arc::new(Mutex::new(HashMap::new())),
+            validated: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    pub fn subject_for_topic(topic: &str) -> String {
+        format!("{topic}-value")
+    }
+
+    /// Registers the `KEvent` protobuf schema under `subject` and returns its id.
+    /// Registering an identical schema is idempotent on the registry side; ids are cached locally.
+    pub async fn register(&self, subject: &str) -> Result<u32, String> {
+        if let Some(id) = self.registered.lock().unwrap().get(subject) {
+            return Ok(*id);
+        }
+
+        let resp = self
+            .http
+            .post(format!("{}/subjects/{subject}/versions", self.url))
+            .header(reqwest::header::CONTENT_TYPE, REGISTRY_CONTENT_TYPE)
+            .json(&RegisterRequest {
+                schema_type: "PROTOBUF",
+                schema: K_EVENT_PROTO,
+            })
+            .send()
+            .await
+            .map_err(|e| format!("register: Failed to reach schema registry: {e}"))?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            return Err(format!(
+                "register: Registry rejected {subject}: {status} {body}"
+            ));
+        }
+
+        let id = resp
+            .json::<RegisterResponse>()
+            .await
+            .map_err(|e| format!("register: Invalid registry response: {e}"))?
+            .id;
+
+        self.registered
+            .lock()
+            .unwrap()
+            .insert(subject.to_string(), id);
+        self.validated
+            .lock()
+            .unwrap()
+            .insert((subject.to_string(), id));
+
+        Ok(id)
+    }
+
+    /// Checks that `schema_id` is registered under `subject`.
+    pub async fn validate(&self, subject: &str, schema_id: u32) -> Result<(), DecodeError> {
+        let key = (subject.to_string(), schema_id);
+
+        if self.validated.lock().unwrap().contains(&key) {
+            return Ok(());
+        }
+
+        let resp = self
+            .http
+            .get(format!("{}/schemas/ids/{schema_id}/versions", self.url))
+            .header(reqwest::header::ACCEPT, REGISTRY_CONTENT_TYPE)
+            .send()
+            .await
+            .map_err(|e| {
+                DecodeError::Unavailable(format!("validate: Failed to reach schema registry: {e}"))
+            })?;
+
+        let status = resp.status();
+        if status.is_client_error() {
+            return Err(DecodeError::Rejected(format!(
+                "validate: Unknown schema id {schema_id}: {status}"
+            )));
+        }
+        if !status.is_success() {
+            return Err(DecodeError::Unavailable(format!(
+                "validate: Registry failed to look up schema id {schema_id}: {status}"
+            )));
+        }
+
+        let versions = resp.json::<Vec<SubjectVersion>>().await.map_err(|e| {
+            DecodeError::Unavailable(format!("validate: Invalid registry response: {e}"))
+        })?;
+
+        if !versions.iter().any(|v| v.subject == subject) {
+            return Err(DecodeError::Rejected(format!(
+                "validate: Schema id {schema_id} is not registered under {subject}"
+            )));
+        }
+
+        self.validated.lock().unwrap().insert(key);
+
+        Ok(())
+    }
+}
+
+/// Producer side: encodes events with the configured content type, registering
+/// the schema for each topic first when a registry is configured.
+#[derive(Debug, Clone)]
+pub struct EventEncoder {
+    content_type: ContentType,
+    registry: Option<SchemaRegistryClient>,
+}
+
+impl EventEncoder {
+    /// The registry is only used for protobuf; JSON payloads carry no schema id.
+    pub fn new(content_type: ContentType, registry_url: Option<String>) -> Self {
+        let registry = registry_url
+            .filter(|_| content_type == ContentType::Protobuf)
+            .map(SchemaRegistryClient::new);
+
+        Self {
+            content_type,
+            registry,
+        }
+    }
+
+    pub fn content_type(&self) -> ContentType {
+        self.content_type
+    }
+
+    pub async fn encode(&self, topic: &str, event: &KEvent) -> Result<Vec<u8>, String> {
+        let schema_id = match &self.registry {
+            Some(registry) => Some(
+                registry
+                    .register(&SchemaRegistryClient::subject_for_topic(topic))
+                    .await?,
+            ),
+            None => None,
+        };
+
+        encode(event, self.content_type, schema_id)
+    }
+}
+
+/// Consumer side: decodes events and, when a registry is configured, rejects
+/// protobuf payloads whose schema id isn't registered for the topic.
+#[derive(Debug, Clone)]
+pub struct EventDecoder {
+    registry: Option<SchemaRegistryClient>,
+}
+
+impl EventDecoder {
+    pub fn new(registry_url: Option<String>) -> Self {
+        Self {
+            registry: registry_url.map(SchemaRegistryClient::new),
+        }
+    }
+
+    pub async fn decode(
+        &self,
+        topic: &str,
+        payload: &[u8],
+        content_type: ContentType,
+    ) -> Result<KEvent, DecodeError> {
+        if let (Some(registry), Some(id)) = (&self.registry, schema_id(payload, content_type)) {
+            registry
+                .validate(&SchemaRegistryClient::subject_for_topic(topic), id)
+                .await?;
+        }
+
+        decode(payload, content_type).map_err(DecodeError::Rejected)
+    }
+}
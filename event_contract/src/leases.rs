@@ -0,0 +1,31 @@
+//! Redis layout of subscription leases. geyser_account_subscriber sweeps them and
+//! subscribes to every leased account; any service may take leases.
+
+use crate::models::enums::Platform;
+
+/// Sorted set of `{account}|{holder}` leases scored by expiry (unix ms)
+pub const LEASES_KEY: &str = "subscriptions:leases";
+/// Hash of leased account -> `Platform` (JSON)
+pub const LEASE_PLATFORMS_KEY: &str = "subscriptions:lease_platforms";
+
+/// Lease lifetime when `LEASE_TTL_SECS` is unset
+pub const DEFAULT_LEASE_TTL_SECS: u64 = 90;
+
+/// Member of `LEASES_KEY` for `holder`'s lease on `account`
+pub fn lease_member(account: &str, holder: &str) -> String {
+    format!("{account}|{holder}")
+}
+
+/// Account a `LEASES_KEY` member leases
+pub fn lease_member_account(member: &str) -> Option<&str> {
+    member.rsplit_once('|').map(|(account, _)| account)
+}
+
+/// Value of `LEASE_PLATFORMS_KEY` for `platform`
+pub fn encode_lease_platform(platform: Platform) -> serde_json::Result<String> {
+    serde_json::to_string(&platform)
+}
+
+pub fn decode_lease_platform(value: &str) -> serde_json::Result<Platform> {
+    serde_json::from_str(value)
+}
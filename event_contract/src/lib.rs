@@ -0,0 +1,3 @@
+pub mod codec;
+pub mod leases;
+pub mod models;
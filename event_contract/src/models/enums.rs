@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Platform {
+    PumpFun,
+    PumpSwap,
+    RaydiumLaunchLab,
+}
+
+/// Represents the lifecycle state of a token.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TokenStatus {
+    Created,
+    Migrated,
+}
+
+/// Represents the direction of a swap
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TradeDirection {
+    Buy,
+    Sell,
+}
+
+/// Represents the origin of a specific price data point.
+/// This corresponds to the event source that generated the price.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PriceSource {
+    PumpFunBondingCurve,
+    PumpSwapPool,
+    #[serde(alias = "RaydiunmLaunchLabPoolState")]
+    RaydiumLaunchLabPoolState,
+}
+
+/// Service that produced an event.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventSource {
+    GeyserTxStreamer,
+    GeyserAccountSubscriber,
+    GapFiller,
+    Historian,
+    DataProcessor,
+    /// Synthetic event built from a point-in-time account fetch, not a live write
+    Snapshot,
+    #[default]
+    Unknown,
+}
+
+impl EventSource {
+    /// Serialized (snake_case) name, for sinks that store the source as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventSource::GeyserTxStreamer => "geyser_tx_streamer",
+            EventSource::GeyserAccountSubscriber => "geyser_account_subscriber",
+            EventSource::GapFiller => "gap_filler",
+            EventSource::Historian => "historian",
+            EventSource::DataProcessor => "data_processor",
+            EventSource::Snapshot => "snapshot",
+            EventSource::Unknown => "unknown",
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::models::enums::EventSource;
+use crate::models::pump_models::pf_kafka_event::{
+    KPfBondingCurveCompleted, KPfChTrade, KPfCreatorFeeClaim, KPfFeeConfig, KPfGlobalConfig,
+    KPfGlobalVolumeAccumulator, KPfPrice, KPfToken, KPfTokenIncentiveClaim, KPfTokenLifecycle,
+    KPfTrade, KPfUserVolumeAccumulator,
+};
+use crate::models::pump_models::ps_kafka_event::{
+    KPsCreatorFeeClaim, KPsPrice, KPsToken, KPsTrade,
+};
+use crate::models::raydium_models::rll_kafka_event::{
+    KRllPrice, KRllToken, KRllTokenLifecycle, KRllTrade,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Version of the envelope and payload contract produced by this crate.
+/// Bumped only on breaking changes (see `event_contract/README.md`).
+pub const SCHEMA_VERSION: u16 = 1;
+
+/// Enumerates the different types of events that can be sent over Kafka.
+/// This acts as a routing key for the consumer.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum KEventType {
+    PfChTradeOccurred,
+
+    PfTradeOccurred,
+    PfTokenCreated,
+    PfTokenMigrated,
+    PfPriceUpdated,
+
+    PsTradeOccurred,
+    PsTokenCreated,
+    PsPriceUpdated,
+
+    RllTradeOccurred,
+    RllTokenCreated,
+    RllTokenMigrated,
+    RllPriceUpdated,
+
+    PfCreatorFeeClaimed,
+    PsCreatorFeeClaimed,
+
+    PfTokenIncentivesClaimed,
+    PfUserVolumeAccumulatorUpdated,
+    PfGlobalVolumeAccumulatorUpdated,
+
+    PfGlobalConfigUpdated,
+    PfFeeConfigUpdated,
+
+    PfBondingCurveCompleted,
+}
+
+impl KEventType {
+    /// Serialized (SCREAMING_SNAKE_CASE) name, for keys and sinks that store the type as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KEventType::PfChTradeOccurred => "PF_CH_TRADE_OCCURRED",
+            KEventType::PfTradeOccurred => "PF_TRADE_OCCURRED",
+            KEventType::PfTokenCreated => "PF_TOKEN_CREATED",
+            KEventType::PfTokenMigrated => "PF_TOKEN_MIGRATED",
+            KEventType::PfPriceUpdated => "PF_PRICE_UPDATED",
+            KEventType::PsTradeOccurred => "PS_TRADE_OCCURRED",
+            KEventType::PsTokenCreated => "PS_TOKEN_CREATED",
+            KEventType::PsPriceUpdated => "PS_PRICE_UPDATED",
+            KEventType::RllTradeOccurred => "RLL_TRADE_OCCURRED",
+            KEventType::RllTokenCreated => "RLL_TOKEN_CREATED",
+            KEventType::RllTokenMigrated => "RLL_TOKEN_MIGRATED",
+            KEventType::RllPriceUpdated => "RLL_PRICE_UPDATED",
+            KEventType::PfCreatorFeeClaimed => "PF_CREATOR_FEE_CLAIMED",
+            KEventType::PsCreatorFeeClaimed => "PS_CREATOR_FEE_CLAIMED",
+            KEventType::PfTokenIncentivesClaimed => "PF_TOKEN_INCENTIVES_CLAIMED",
+            KEventType::PfUserVolumeAccumulatorUpdated => "PF_USER_VOLUME_ACCUMULATOR_UPDATED",
+            KEventType::PfGlobalVolumeAccumulatorUpdated => "PF_GLOBAL_VOLUME_ACCUMULATOR_UPDATED",
+            KEventType::PfGlobalConfigUpdated => "PF_GLOBAL_CONFIG_UPDATED",
+            KEventType::PfFeeConfigUpdated => "PF_FEE_CONFIG_UPDATED",
+            KEventType::PfBondingCurveCompleted => "PF_BONDING_CURVE_COMPLETED",
+        }
+    }
+}
+
+/// Represents the data payload for a specific Kafka event.
+/// Each variant holds the struct corresponding to its event type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum KEventData {
+    PfChTradeOccurred(KPfChTrade),
+
+    PfTradeOccurred(KPfTrade),
+    PfTokenCreated(KPfToken),
+    PfTokenMigrated(KPfTokenLifecycle),
+    PfPriceUpdated(KPfPrice),
+
+    PsTradeOccurred(KPsTrade),
+    PsTokenCreated(KPsToken),
+    PsPriceUpdated(KPsPrice),
+
+    RllTradeOccurred(KRllTrade),
+    RllTokenCreated(KRllToken),
+    RllTokenMigrated(KRllTokenLifecycle),
+    RllPriceUpdated(KRllPrice),
+
+    PfCreatorFeeClaimed(KPfCreatorFeeClaim),
+    PsCreatorFeeClaimed(KPsCreatorFeeClaim),
+
+    PfTokenIncentivesClaimed(KPfTokenIncentiveClaim),
+    PfUserVolumeAccumulatorUpdated(KPfUserVolumeAccumulator),
+    PfGlobalVolumeAccumulatorUpdated(KPfGlobalVolumeAccumulator),
+
+    PfGlobalConfigUpdated(KPfGlobalConfig),
+    PfFeeConfigUpdated(KPfFeeConfig),
+
+    PfBondingCurveCompleted(KPfBondingCurveCompleted),
+}
+
+impl KEventData {
+    /// Routing key matching this payload.
+    pub fn event_type(&self) -> KEventType {
+        match self {
+            KEventData::PfChTradeOccurred(_) => KEventType::PfChTradeOccurred,
+            KEventData::PfTradeOccurred(_) => KEventType::PfTradeOccurred,
+            KEventData::PfTokenCreated(_) => KEventType::PfTokenCreated,
+            KEventData::PfTokenMigrated(_) => KEventType::PfTokenMigrated,
+            KEventData::PfPriceUpdated(_) => KEventType::PfPriceUpdated,
+            KEventData::PsTradeOccurred(_) => KEventType::PsTradeOccurred,
+            KEventData::PsTokenCreated(_) => KEventType::PsTokenCreated,
+            KEventData::PsPriceUpdated(_) => KEventType::PsPriceUpdated,
+            KEventData::RllTradeOccurred(_) => KEventType::RllTradeOccurred,
+            KEventData::RllTokenCreated(_) => KEventType::RllTokenCreated,
+            KEventData::RllTokenMigrated(_) => KEventType::RllTokenMigrated,
+            KEventData::RllPriceUpdated(_) => KEventType::RllPriceUpdated,
+            KEventData::PfCreatorFeeClaimed(_) => KEventType::PfCreatorFeeClaimed,
+            KEventData::PsCreatorFeeClaimed(_) => KEventType::PsCreatorFeeClaimed,
+            KEventData::PfTokenIncentivesClaimed(_) => KEventType::PfTokenIncentivesClaimed,
+            KEventData::PfUserVolumeAccumulatorUpdated(_) => {
+                KEventType::PfUserVolumeAccumulatorUpdated
+            }
+            KEventData::PfGlobalVolumeAccumulatorUpdated(_) => {
+                KEventType::PfGlobalVolumeAccumulatorUpdated
+            }
+            KEventData::PfGlobalConfigUpdated(_) => KEventType::PfGlobalConfigUpdated,
+            KEventData::PfFeeConfigUpdated(_) => KEventType::PfFeeConfigUpdated,
+            KEventData::PfBondingCurveCompleted(_) => KEventType::PfBondingCurveCompleted,
+        }
+    }
+}
+
+/// The top-level structure for a message sent over Kafka.
+/// It wraps the payload in a versioned envelope shared by every producer.
+///
+/// Envelope fields default when absent, so payloads written before the
+/// envelope existed still decode (with `schema_version == 0`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KEvent {
+    /// Unique id of this event, assigned by the producer.
+    #[serde(default)]
+    pub event_id: String,
+
+    /// Contract version the producer was built against.
+    #[serde(default)]
+    pub schema_version: u16,
+
+    /// Service that produced the event.
+    #[serde(default)]
+    pub source: EventSource,
+
+    /// Wall-clock time the producer emitted the event.
+    #[serde(default)]
+    pub produced_at: DateTime<Utc>,
+
+    /// Solana slot the event was observed at (0 when unknown).
+    #[serde(default)]
+    pub slot: u64,
+
+    /// The type of event, used by the consumer to determine how to process the payload.
+    pub event_type: KEventType,
+
+    /// The actual data payload, which varies depending on the `event_type`.
+    pub data: KEventData,
+}
+
+impl KEvent {
+    pub fn new(source: EventSource, slot: u64, data: KEventData) -> Self {
+        Self {
+            event_id: Uuid::new_v4().to_string(),
+            schema_version: SCHEMA_VERSION,
+            source,
+            produced_at: Utc::now(),
+            slot,
+            event_type: data.event_type(),
+            data,
+        }
+    }
+
+    /// Whether this consumer understands the event's contract version.
+    pub fn is_supported(&self) -> bool {
+        self.schema_version <= SCHEMA_VERSION
+    }
+}
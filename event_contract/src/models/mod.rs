@@ -0,0 +1,5 @@
+pub mod enums;
+pub mod kafka_event;
+
+pub mod pump_models;
+pub mod raydium_models;
@@ -0,0 +1,2 @@
+pub mod pf_kafka_event;
+pub mod ps_kafka_event;
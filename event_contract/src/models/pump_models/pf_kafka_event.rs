@@ -0,0 +1,269 @@
+use crate::models::enums::{Platform, PriceSource, TokenStatus, TradeDirection};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Kafka payload carrying the full Pump.fun trade record (tx + Anchor trade event + indexing metadata).
+/// Purpose: To feed the ClickHouse trade log and the derived price / bonding curve state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfChTrade {
+    /* ========= Transaction identity ========= */
+    pub signature: String, // Transaction signature (base58)
+    pub slot: u64,         // Solana slot
+    pub blockhash: String,
+
+    /* ========= Actors ========= */
+    pub signer: String,        // Primary transaction signer
+    pub fee_payer: String,     // Fee payer (account_keys[0])
+    pub user: String,          // Trader (from trade event)
+    pub creator: String,       // Token / bonding curve creator
+    pub fee_recipient: String, // Protocol fee recipient
+
+    /* ========= Token / market ========= */
+    pub mint: String,          // Token mint
+    pub bonding_curve: String, // Bonding curve account
+    pub is_pump_pool: bool,
+
+    /* ========= Instruction semantics ========= */
+    pub ix_name: String, // buy | sell | buy_exact_sol_in
+    pub is_buy: bool,    // Direction flag
+
+    /* ========= Trade amounts ========= */
+    pub sol_amount: u64,          // SOL exchanged (lamports)
+    pub token_amount: u64,        // Tokens exchanged (raw units)
+    pub trade_size_lamports: u64, // Trade size used for fee calculation
+
+    /* ========= Fees ========= */
+    pub transaction_fee: u64,  // SOL fee paid for the transaction (lamports)
+    pub fee_lamports: u64,     // Protocol fee paid
+    pub fee_basis_points: u64, // Protocol fee bps
+    pub creator_fee_lamports: u64, // Creator fee paid
+    pub creator_fee_basis_points: u64, // Creator fee bps
+
+    /* ========= Market / bonding curve state (post-trade) ========= */
+    pub decimals: u8,                // Mint decimals
+    pub virtual_sol_reserves: u64,   // Virtual SOL reserves
+    pub virtual_token_reserves: u64, // Virtual token reserves
+    pub real_sol_reserves: u64,      // Real SOL reserves
+    pub real_token_reserves: u64,    // Real token reserves
+    pub market_cap_lamports: u64,    // Market cap estimate
+
+    /* ========= Volume & tracking ========= */
+    pub track_volume: bool,          // Whether volume is tracked
+    pub total_unclaimed_tokens: u64, // Total unclaimed tokens
+    pub total_claimed_tokens: u64,   // Total claimed tokens
+    pub current_sol_volume: u64,     // Current SOL volume
+    pub last_update_timestamp: i64,  // Last update timestamp
+
+    /* ========= Timestamp ========= */
+    pub ts: DateTime<Utc>,
+
+    #[serde(default)]
+    pub ix_index: u32, // top-level instruction that emitted the trade
+    #[serde(default)]
+    pub tx_index: u64, // position of the transaction within its slot
+    #[serde(default)]
+    pub event_ordinal: u32, // position of the event among those the transaction emitted
+}
+
+/// Kafka payload containing the foundational metadata of a newly created token.
+/// Purpose: To register a new token in the system, captured from its "Create" event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfToken {
+    pub mint: String,          // token mint
+    pub bonding_curve: String, // bonding curve PDA
+    pub name: String,          // token name
+    pub symbol: String,        // token symbol
+    pub uri: Option<String>,   // metadata URI
+    pub creator: String,       // wallet that deployed the token
+    pub user_address: String,  // wallet that executed the creator
+    pub decimals: i16,
+    pub ts: DateTime<Utc>, // block timestamp
+    pub description: String,
+    pub twitter: String,
+    pub telegram: String,
+    pub website: String,
+    pub image: String,
+    pub virtual_token_reserves: i64, // bonding curve virtual token reserves
+    pub virtual_sol_reserves: i64,   // bonding curve virtual SOL reserves
+    pub real_token_reserves: i64,    // actual token reserves
+    pub token_total_supply: i64,     // total token supply
+    #[serde(default)]
+    pub tx_index: u64, // position of the create transaction within its slot
+}
+
+/// Kafka payload for an individual swap event (a buy or sell).
+/// Purpose: To log a single trade, which is used to feed all downstream aggregations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfTrade {
+    pub signature: String,         // transaction signature
+    pub mint: String,              // token mint being traded
+    pub bonding_curve: String,     // bonding curve PDA
+    pub platform: Platform,        // PumpSwap / Raydium / Meteora
+    pub direction: TradeDirection, //`Buy` or `Sell`
+    pub sol_amount: i64,           // amount of SOL (in lamports) exchanged
+    pub token_amount: i64,         // amount of token (in smallest unit) exchanged
+    pub user_pubkey: String,       // wallet that executed the swap
+    pub ts: DateTime<Utc>,         // transaction time
+}
+
+/// Kafka payload representing a change in a token's lifecycle state.
+/// Purpose: To either log the initial creation or (more often) update a token to "Migrated".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfTokenLifecycle {
+    pub mint: String,                       // wallet that created token
+    pub status: TokenStatus,                // CREATED / MIGRATED
+    pub platform: Platform,                 // PumpSwap / Raydium / Meteora
+    pub ts_created: Option<DateTime<Utc>>,  // time token was created
+    pub ts_migrated: Option<DateTime<Utc>>, // time token migrated
+    pub bonding_curve: Option<String>,      // bonding_curve
+    pub pool: Option<String>,               // destination pool after migration (from Migrate event)
+    pub sol_amount_migrated: Option<i64>, // SOL transferred during migration (lamports, from Migrate event)
+    pub token_amount_migrated: Option<i64>, // tokens transferred during migration (from Migrate event)
+}
+
+/// Kafka payload for a single, discrete price event.
+/// Purpose: To log a new price point for a token from any monitored source.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfPrice {
+    pub bonding_curve: String, // token bonding curve
+    pub source: PriceSource,   // source of this specific price event
+    pub ts: DateTime<Utc>,     // on-chain timestamp (block time) of the event
+
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+
+    #[serde(default)]
+    pub token_total_supply: u64, // mint supply the curve was created with
+    #[serde(default)]
+    pub complete: bool, // curve is sold out and awaiting migration
+    #[serde(default)]
+    pub creator: Option<String>, // absent on curves created before creator fees
+    #[serde(default)]
+    pub is_mayhem_mode: bool,
+
+    #[serde(default)]
+    pub slot: u64, // slot of the account write
+    #[serde(default)]
+    pub write_version: u64, // orders writes within a slot; 0 for snapshots
+}
+
+/// Kafka payload for a Pump.fun bonding curve whose `complete` flag just turned true.
+/// Purpose: To signal graduation as early as possible, before the migration transaction lands.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfBondingCurveCompleted {
+    pub bonding_curve: String,
+    pub creator: Option<String>,
+    pub token_total_supply: u64,
+    pub virtual_token_reserves: u64, // reserves at completion
+    pub virtual_sol_reserves: u64,
+    pub real_sol_reserves: u64, // SOL raised, to be migrated
+    pub is_mayhem_mode: bool,
+    pub ts: DateTime<Utc>, // time the completed state was observed
+}
+
+/// Kafka payload for a Pump.fun `collect_creator_fee` instruction.
+/// Purpose: To record a creator withdrawing accrued trade fees from their creator vault.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfCreatorFeeClaim {
+    pub signature: String,     // transaction signature
+    pub slot: u64,             // Solana slot
+    pub creator: String,       // creator wallet receiving the fees
+    pub creator_vault: String, // creator vault PDA the fees were collected from
+    pub creator_fee: u64,      // lamports claimed
+    pub ts: DateTime<Utc>,     // on-chain timestamp of the claim
+
+    #[serde(default)]
+    pub ix_index: u32, // top-level instruction of the claim
+    #[serde(default)]
+    pub event_ordinal: u32, // position of the event among those the transaction emitted
+}
+
+/// Kafka payload for a Pump.fun `claim_token_incentives` instruction.
+/// Purpose: To record a wallet claiming the tokens earned through the volume incentive program.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfTokenIncentiveClaim {
+    pub signature: String,         // transaction signature
+    pub slot: u64,                 // Solana slot
+    pub user: String,              // wallet claiming the incentives
+    pub mint: String,              // incentive token mint
+    pub amount: u64,               // tokens claimed (raw units)
+    pub total_claimed_tokens: u64, // lifetime claimed tokens after this claim
+    pub current_sol_volume: u64,   // user SOL volume in the current period (lamports)
+    pub ts: DateTime<Utc>,         // on-chain timestamp of the claim
+
+    #[serde(default)]
+    pub ix_index: u32, // top-level instruction of the claim
+    #[serde(default)]
+    pub event_ordinal: u32, // position of the event among those the transaction emitted
+}
+
+/// Kafka payload for an update of a Pump.fun `UserVolumeAccumulator` account.
+/// Purpose: To keep the per-wallet incentive balance in sync with on-chain state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfUserVolumeAccumulator {
+    pub user_volume_accumulator: String, // accumulator PDA
+    pub user: String,                    // wallet the accumulator belongs to
+    pub needs_claim: bool,               // whether unclaimed tokens are pending
+    pub total_unclaimed_tokens: u64,     // tokens earned but not yet claimed
+    pub total_claimed_tokens: u64,       // lifetime claimed tokens
+    pub current_sol_volume: u64,         // SOL volume in the current period (lamports)
+    pub last_update_timestamp: i64,      // on-chain unix time of the last accumulator sync
+    pub ts: DateTime<Utc>,               // time the update was observed
+}
+
+/// Kafka payload for an update of the Pump.fun `GlobalVolumeAccumulator` account.
+/// Purpose: To track the daily incentive supply and SOL volume used to compute pending rewards.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfGlobalVolumeAccumulator {
+    pub global_volume_accumulator: String, // accumulator PDA
+    pub start_time: i64,                   // unix time the incentive program started
+    pub end_time: i64,                     // unix time the incentive program ends
+    pub seconds_in_a_day: i64,             // length of one incentive period
+    pub mint: String,                      // incentive token mint
+    pub total_token_supply: Vec<u64>,      // tokens distributed per period
+    pub sol_volumes: Vec<u64>,             // total SOL volume per period (lamports)
+    pub ts: DateTime<Utc>,                 // time the update was observed
+}
+
+/// Kafka payload for an update of the Pump.fun `Global` account (changed via `set_params`).
+/// Purpose: To version the protocol parameters that bonding curve math depends on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfGlobalConfig {
+    pub global: String,                      // Global PDA
+    pub fee_recipient: String,               // primary protocol fee recipient
+    pub initial_virtual_token_reserves: u64, // virtual token reserves of a new curve
+    pub initial_virtual_sol_reserves: u64,   // virtual SOL reserves of a new curve (lamports)
+    pub initial_real_token_reserves: u64,    // tokens sold along the curve before migration
+    pub token_total_supply: u64,             // total supply minted per token
+    pub fee_basis_points: u64,               // protocol fee bps
+    pub creator_fee_basis_points: u64,       // creator fee bps
+    pub enable_migrate: bool,                // whether completed curves can migrate
+    pub pool_migration_fee: u64,             // fee charged on migration (lamports)
+    pub ts: DateTime<Utc>,                   // time the update was observed
+}
+
+/// Fee split charged at one market cap tier of the Pump Fees program.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfFees {
+    pub lp_fee_bps: u64,
+    pub protocol_fee_bps: u64,
+    pub creator_fee_bps: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfFeeTier {
+    pub market_cap_lamports_threshold: u64, // on-chain u128, saturated to u64
+    pub fees: KPfFees,
+}
+
+/// Kafka payload for an update of the Pump Fees program `FeeConfig` account for Pump.fun.
+/// Purpose: To version the market cap dependent fee schedule.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPfFeeConfig {
+    pub fee_config: String,         // FeeConfig PDA
+    pub flat_fees: KPfFees,         // fees applied when no tier matches
+    pub fee_tiers: Vec<KPfFeeTier>, // ascending by threshold
+    pub ts: DateTime<Utc>,          // time the update was observed
+}
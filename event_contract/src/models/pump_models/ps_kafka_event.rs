@@ -0,0 +1,92 @@
+use crate::models::enums::{PriceSource, TradeDirection};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Kafka payload for an individual swap event (a buy or sell).
+/// Purpose: To log a single trade, which is used to feed all downstream aggregations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPsTrade {
+    pub signature: String,         // transaction signature
+    pub mint: String,              // token mint being traded
+    pub pool: String,              // bonding curve PDA
+    pub direction: TradeDirection, //`Buy` or `Sell`
+    pub sol_amount: i64,           // amount of SOL (in lamports) exchanged
+    pub token_amount: i64,         // amount of token (in smallest unit) exchanged
+    pub user_pubkey: String,       // wallet that executed the swap
+    pub ts: DateTime<Utc>,         // transaction time
+
+    #[serde(default)]
+    pub coin_creator: Option<String>, // pool coin creator (from Buy/SellEvent)
+    #[serde(default)]
+    pub coin_creator_fee: Option<u64>, // coin creator fee accrued by this swap (quote units)
+    #[serde(default)]
+    pub quote_mint: Option<String>, // mint the quote amounts and fees are in (WSOL for pump pools)
+
+    #[serde(default)]
+    pub slot: u64, // Solana slot
+    #[serde(default)]
+    pub ix_index: u32, // top-level instruction that emitted the swap
+    #[serde(default)]
+    pub event_ordinal: u32, // position of the event among those the transaction emitted
+}
+
+/// Kafka payload containing the foundational metadata of a newly created token.
+/// Purpose: To register a new token in the system, captured from its "Create" event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPsToken {
+    pub mint: String,         // token mint
+    pub pool: String,         // pool PDA
+    pub name: String,         // token name
+    pub symbol: String,       // token symbol
+    pub creator: String,      // wallet that deployed the token
+    pub user_address: String, // wallet that executed the creator
+    pub decimals: i16,
+    pub ts: DateTime<Utc>,   // block timestamp
+    pub uri: Option<String>, // metadata URI
+    pub description: String,
+    pub twitter: String,
+    pub telegram: String,
+    pub website: String,
+    pub image: String,
+}
+
+/// Kafka payload for a single, discrete price event.
+/// Purpose: To log a new price point for a token from any monitored source.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPsPrice {
+    pub pool: String,        // token pool
+    pub source: PriceSource, // source of this specific price event
+    pub ts: DateTime<Utc>,   // on-chain timestamp (block time) of the event
+
+    pub token_a_reserves: u64, // base vault balance
+    pub token_b_reserves: u64, // quote vault balance
+
+    #[serde(default)]
+    pub base_mint: Option<String>,
+    #[serde(default)]
+    pub quote_mint: Option<String>,
+
+    #[serde(default)]
+    pub slot: u64, // slot of the account write
+    #[serde(default)]
+    pub write_version: u64, // orders writes within a slot; 0 for snapshots
+}
+
+/// Kafka payload for a PumpSwap `collect_coin_creator_fee` instruction.
+/// Purpose: To record a coin creator withdrawing accrued fees from their creator vault.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KPsCreatorFeeClaim {
+    pub signature: String,                  // transaction signature
+    pub slot: u64,                          // Solana slot
+    pub coin_creator: String,               // wallet receiving the fees
+    pub quote_mint: String,                 // mint the fees are paid in (WSOL for pump pools)
+    pub coin_creator_fee: u64,              // amount claimed (quote units)
+    pub coin_creator_vault_ata: String,     // vault the fees were collected from
+    pub coin_creator_token_account: String, // destination token account
+    pub ts: DateTime<Utc>,                  // on-chain timestamp of the claim
+
+    #[serde(default)]
+    pub ix_index: u32, // top-level instruction of the claim
+    #[serde(default)]
+    pub event_ordinal: u32, // position of the event among those the transaction emitted
+}
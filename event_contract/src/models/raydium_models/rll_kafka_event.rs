@@ -0,0 +1,89 @@
+use crate::models::enums::{Platform, PriceSource};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Kafka payload containing the foundational metadata of a newly created token.
+/// Purpose: To register a new token in the system, captured from its "Create" event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KRllToken {
+    pub name: String,        // token name
+    pub symbol: String,      // token symbol
+    pub uri: Option<String>, // metadata URI
+    pub creator: String,     // wallet that deployed the token
+    pub mint: String,        // token mint
+    pub platform: Platform,
+
+    pub pool_state: String, // bonding curve PDA
+
+    pub user_address: String, // wallet that executed the creator
+
+    pub ts: DateTime<Utc>, // block timestamp
+
+    pub base_decimals: u8,
+    pub quote_decimals: u8,
+    pub virtual_base: u64,
+    pub virtual_quote: u64,
+}
+
+/// Kafka payload for an individual swap event (a buy or sell).
+/// Purpose: To log a single trade, which is used to feed all downstream aggregations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KRllTrade {}
+
+/// Kafka payload representing a change in a token's lifecycle state.
+/// Purpose: To either log the initial creation or (more often) update a token to "Migrated".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KRllTokenLifecycle {}
+
+/// Kafka payload for a single, discrete price event.
+/// Purpose: To log a new price point for a token from any monitored source.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KRllPrice {
+    pub pool_state: String,  // token pool state
+    pub source: PriceSource, // source of this specific price event
+    pub ts: DateTime<Utc>,   // on-chain timestamp (block time) of the event
+
+    pub base_decimals: u8,
+    pub quote_decimals: u8,
+    pub virtual_base: u64,
+    pub virtual_quote: u64,
+
+    #[serde(default)]
+    pub status: u8, // 0 fund-raising, 1 migrating, 2 trading on the AMM
+    #[serde(default)]
+    pub supply: u64,
+    #[serde(default)]
+    pub total_base_sell: u64, // base tokens sellable on the curve
+    #[serde(default)]
+    pub real_base: u64, // base tokens sold so far
+    #[serde(default)]
+    pub real_quote: u64, // quote raised so far
+    #[serde(default)]
+    pub total_quote_fund_raising: u64, // fundraising goal in quote
+    #[serde(default)]
+    pub vesting: Option<KRllVesting>,
+    #[serde(default)]
+    pub base_mint: Option<String>,
+    #[serde(default)]
+    pub creator: Option<String>,
+
+    #[serde(default)]
+    pub price_quote: u64, // quote smallest units per whole base token
+    #[serde(default)]
+    pub progress_bps: u64, // real_quote / total_quote_fund_raising, capped at 10_000
+
+    #[serde(default)]
+    pub slot: u64, // slot of the account write
+    #[serde(default)]
+    pub write_version: u64, // orders writes within a slot; 0 for snapshots
+}
+
+/// Vesting schedule of a LaunchLab pool's locked base tokens.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KRllVesting {
+    pub total_locked_amount: u64,
+    pub cliff_period: u64,  // seconds after migration before unlocking starts
+    pub unlock_period: u64, // seconds over which locked tokens unlock linearly
+    pub start_time: u64,    // unix seconds, 0 until the pool migrates
+    pub allocated_share_amount: u64,
+}
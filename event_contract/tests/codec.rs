@@ -0,0 +1,96 @@
+mod common;
+
+use common::{all_payloads, pf_ch_trade};
+use event_contract::codec::{self, ContentType};
+use event_contract::models::enums::EventSource;
+use event_contract::models::kafka_event::{KEvent, KEventData};
+
+#[test]
+fn every_payload_round_trips_through_both_codecs() {
+    for content_type in [ContentType::Json, ContentType::Protobuf] {
+        for data in all_payloads() {
+            let event = KEvent::new(EventSource::GeyserTxStreamer, 42, data);
+
+            let bytes = codec::encode(&event, content_type, Some(7)).unwrap();
+            let decoded = codec::decode(&bytes, content_type).unwrap();
+
+            assert_eq!(decoded, event, "{content_type}");
+        }
+    }
+}
+
+#[test]
+fn protobuf_is_smaller_than_json() {
+    let event = KEvent::new(
+        EventSource::GeyserTxStreamer,
+        42,
+        KEventData::PfChTradeOccurred(pf_ch_trade()),
+    );
+
+    let json = codec::encode(&event, ContentType::Json, None).unwrap();
+    let protobuf = codec::encode(&event, ContentType::Protobuf, None).unwrap();
+
+    assert!(protobuf.len() * 2 < json.len());
+}
+
+#[test]
+fn protobuf_payload_carries_schema_id() {
+    let event = KEvent::new(
+        EventSource::GeyserTxStreamer,
+        42,
+        KEventData::PfChTradeOccurred(pf_ch_trade()),
+    );
+
+    let registered = codec::encode(&event, ContentType::Protobuf, Some(12)).unwrap();
+    let unregistered = codec::encode(&event, ContentType::Protobuf, None).unwrap();
+    let json = codec::encode(&event, ContentType::Json, Some(12)).unwrap();
+
+    assert_eq!(&registered[..6], &[0, 0, 0, 0, 12, 0]);
+    assert_eq!(
+        codec::schema_id(&registered, ContentType::Protobuf),
+        Some(12)
+    );
+    assert_eq!(codec::schema_id(&unregistered, ContentType::Protobuf), None);
+    assert_eq!(codec::schema_id(&json, ContentType::Json), None);
+}
+
+#[test]
+fn missing_header_means_json() {
+    assert_eq!(ContentType::from_header(None).unwrap(), ContentType::Json);
+    assert_eq!(
+        ContentType::from_header(Some(b"application/x-protobuf")).unwrap(),
+        ContentType::Protobuf
+    );
+    assert!(ContentType::from_header(Some(b"application/avro")).is_err());
+}
+
+#[test]
+fn content_type_parses_env_and_header_forms() {
+    assert_eq!("json".parse::<ContentType>().unwrap(), ContentType::Json);
+    assert_eq!(
+        "PROTOBUF".parse::<ContentType>().unwrap(),
+        ContentType::Protobuf
+    );
+
+    for content_type in [ContentType::Json, ContentType::Protobuf] {
+        assert_eq!(
+            content_type.as_str().parse::<ContentType>().unwrap(),
+            content_type
+        );
+    }
+}
+
+#[test]
+fn decoding_with_wrong_content_type_fails() {
+    let event = KEvent::new(
+        EventSource::GeyserTxStreamer,
+        42,
+        KEventData::PfChTradeOccurred(pf_ch_trade()),
+    );
+
+    let json = codec::encode(&event, ContentType::Json, None).unwrap();
+    let protobuf = codec::encode(&event, ContentType::Protobuf, None).unwrap();
+
+    assert!(codec::decode(&json, ContentType::Protobuf).is_err());
+    assert!(codec::decode(&protobuf, ContentType::Json).is_err());
+}
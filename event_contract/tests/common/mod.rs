@@ -0,0 +1,303 @@
+use chrono::{TimeZone, Utc};
+use event_contract::models::enums::{Platform, PriceSource, TokenStatus, TradeDirection};
+use event_contract::models::kafka_event::KEventData;
+use event_contract::models::pump_models::pf_kafka_event::{
+    KPfBondingCurveCompleted, KPfChTrade, KPfCreatorFeeClaim, KPfFeeConfig, KPfFeeTier, KPfFees,
+    KPfGlobalConfig, KPfGlobalVolumeAccumulator, KPfPrice, KPfToken, KPfTokenIncentiveClaim,
+    KPfTokenLifecycle, KPfTrade, KPfUserVolumeAccumulator,
+};
+use event_contract::models::pump_models::ps_kafka_event::{
+    KPsCreatorFeeClaim, KPsPrice, KPsToken, KPsTrade,
+};
+use event_contract::models::raydium_models::rll_kafka_event::{
+    KRllPrice, KRllToken, KRllTokenLifecycle, KRllTrade, KRllVesting,
+};
+
+pub fn pf_ch_trade() -> KPfChTrade {
+    KPfChTrade {
+        signature: "sig".into(),
+        slot: 42,
+        blockhash: "hash".into(),
+        signer: "signer".into(),
+        fee_payer: "payer".into(),
+        user: "user".into(),
+        creator: "creator".into(),
+        fee_recipient: "recipient".into(),
+        mint: "mint".into(),
+        bonding_curve: "curve".into(),
+        is_pump_pool: false,
+        ix_name: "buy".into(),
+        is_buy: true,
+        sol_amount: 1_000_000,
+        token_amount: 2_000_000,
+        trade_size_lamports: 1_000_000,
+        transaction_fee: 5_000,
+        fee_lamports: 10_000,
+        fee_basis_points: 95,
+        creator_fee_lamports: 3_000,
+        creator_fee_basis_points: 30,
+        decimals: 6,
+        virtual_sol_reserves: 30_000_000_000,
+        virtual_token_reserves: 1_073_000_000_000_000,
+        real_sol_reserves: 0,
+        real_token_reserves: 793_100_000_000_000,
+        market_cap_lamports: 27_959_000_000,
+        track_volume: true,
+        total_unclaimed_tokens: 0,
+        total_claimed_tokens: 0,
+        current_sol_volume: 0,
+        last_update_timestamp: 1_700_000_000,
+        ts: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+        ix_index: 2,
+        tx_index: 17,
+        event_ordinal: 4,
+    }
+}
+
+pub fn all_payloads() -> Vec<KEventData> {
+    let ts = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+
+    vec![
+        KEventData::PfChTradeOccurred(pf_ch_trade()),
+        KEventData::PfTradeOccurred(KPfTrade {
+            signature: "sig".into(),
+            mint: "mint".into(),
+            bonding_curve: "curve".into(),
+            platform: Platform::PumpFun,
+            direction: TradeDirection::Sell,
+            sol_amount: 1,
+            token_amount: 2,
+            user_pubkey: "user".into(),
+            ts,
+        }),
+        KEventData::PfTokenCreated(KPfToken {
+            mint: "mint".into(),
+            bonding_curve: "curve".into(),
+            name: "Token".into(),
+            symbol: "TKN".into(),
+            uri: Some("https://example.com/meta.json".into()),
+            creator: "creator".into(),
+            user_address: "user".into(),
+            decimals: 6,
+            ts,
+            description: String::new(),
+            twitter: String::new(),
+            telegram: String::new(),
+            website: String::new(),
+            image: String::new(),
+            virtual_token_reserves: 1_073_000_000_000_000,
+            virtual_sol_reserves: 30_000_000_000,
+            real_token_reserves: 793_100_000_000_000,
+            token_total_supply: 1_000_000_000_000_000,
+            tx_index: 5,
+        }),
+        KEventData::PfTokenMigrated(KPfTokenLifecycle {
+            mint: "mint".into(),
+            status: TokenStatus::Migrated,
+            platform: Platform::PumpSwap,
+            ts_created: None,
+            ts_migrated: Some(ts),
+            bonding_curve: Some("curve".into()),
+            pool: Some("pool".into()),
+            sol_amount_migrated: Some(85_000_000_000),
+            token_amount_migrated: None,
+        }),
+        KEventData::PfPriceUpdated(KPfPrice {
+            bonding_curve: "curve".into(),
+            source: PriceSource::PumpFunBondingCurve,
+            ts,
+            virtual_token_reserves: u64::MAX,
+            virtual_sol_reserves: 1,
+            real_token_reserves: 2,
+            real_sol_reserves: 3,
+            token_total_supply: 1_000_000_000_000_000,
+            complete: false,
+            creator: Some("creator".into()),
+            is_mayhem_mode: true,
+            slot: 42,
+            write_version: 7,
+        }),
+        KEventData::PsTradeOccurred(KPsTrade {
+            signature: "sig".into(),
+            mint: "mint".into(),
+            pool: "pool".into(),
+            direction: TradeDirection::Buy,
+            sol_amount: 1,
+            token_amount: 2,
+            user_pubkey: "user".into(),
+            ts,
+            coin_creator: Some("creator".into()),
+            coin_creator_fee: Some(500),
+            quote_mint: Some("So11111111111111111111111111111111111111112".into()),
+            slot: 42,
+            ix_index: 3,
+            event_ordinal: 1,
+        }),
+        KEventData::PsTokenCreated(KPsToken {
+            mint: "mint".into(),
+            pool: "pool".into(),
+            name: "Token".into(),
+            symbol: "TKN".into(),
+            creator: "creator".into(),
+            user_address: "user".into(),
+            decimals: 6,
+            ts,
+            uri: None,
+            description: String::new(),
+            twitter: String::new(),
+            telegram: String::new(),
+            website: String::new(),
+            image: String::new(),
+        }),
+        KEventData::PsPriceUpdated(KPsPrice {
+            pool: "pool".into(),
+            source: PriceSource::PumpSwapPool,
+            ts,
+            token_a_reserves: 10,
+            token_b_reserves: 20,
+            base_mint: Some("mint".into()),
+            quote_mint: Some("So11111111111111111111111111111111111111112".into()),
+            slot: 42,
+            write_version: 7,
+        }),
+        KEventData::RllTradeOccurred(KRllTrade {}),
+        KEventData::RllTokenCreated(KRllToken {
+            name: "Token".into(),
+            symbol: "TKN".into(),
+            uri: None,
+            creator: "creator".into(),
+            mint: "mint".into(),
+            platform: Platform::RaydiumLaunchLab,
+            pool_state: "pool_state".into(),
+            user_address: "user".into(),
+            ts,
+            base_decimals: 6,
+            quote_decimals: 9,
+            virtual_base: 1_073_025_605_596_382,
+            virtual_quote: 30_000_852_951,
+        }),
+        KEventData::RllTokenMigrated(KRllTokenLifecycle {}),
+        KEventData::RllPriceUpdated(KRllPrice {
+            pool_state: "pool_state".into(),
+            source: PriceSource::RaydiumLaunchLabPoolState,
+            ts,
+            base_decimals: 6,
+            quote_decimals: 9,
+            virtual_base: 1_073_025_605_596_382,
+            virtual_quote: 30_000_852_951,
+            status: 0,
+            supply: 1_000_000_000_000_000,
+            total_base_sell: 793_100_000_000_000,
+            real_base: 100_000_000_000_000,
+            real_quote: 3_200_000_000,
+            total_quote_fund_raising: 85_000_000_000,
+            vesting: Some(KRllVesting {
+                total_locked_amount: 0,
+                cliff_period: 0,
+                unlock_period: 0,
+                start_time: 0,
+                allocated_share_amount: 0,
+            }),
+            base_mint: Some("mint".into()),
+            creator: None,
+            price_quote: 34,
+            progress_bps: 376,
+            slot: 42,
+            write_version: 7,
+        }),
+        KEventData::PfCreatorFeeClaimed(KPfCreatorFeeClaim {
+            signature: "sig".into(),
+            slot: 42,
+            creator: "creator".into(),
+            creator_vault: "vault".into(),
+            creator_fee: 12_345_678,
+            ts,
+            ix_index: 1,
+            event_ordinal: 0,
+        }),
+        KEventData::PsCreatorFeeClaimed(KPsCreatorFeeClaim {
+            signature: "sig".into(),
+            slot: 42,
+            coin_creator: "creator".into(),
+            quote_mint: "So11111111111111111111111111111111111111112".into(),
+            coin_creator_fee: 9_876_543,
+            coin_creator_vault_ata: "vault_ata".into(),
+            coin_creator_token_account: "creator_ata".into(),
+            ts,
+            ix_index: 1,
+            event_ordinal: 2,
+        }),
+        KEventData::PfTokenIncentivesClaimed(KPfTokenIncentiveClaim {
+            signature: "sig".into(),
+            slot: 42,
+            user: "user".into(),
+            mint: "pump_mint".into(),
+            amount: 1_500_000,
+            total_claimed_tokens: 3_000_000,
+            current_sol_volume: 0,
+            ts,
+            ix_index: 4,
+            event_ordinal: 3,
+        }),
+        KEventData::PfUserVolumeAccumulatorUpdated(KPfUserVolumeAccumulator {
+            user_volume_accumulator: "uva".into(),
+            user: "user".into(),
+            needs_claim: true,
+            total_unclaimed_tokens: 250_000,
+            total_claimed_tokens: 3_000_000,
+            current_sol_volume: 2_000_000_000,
+            last_update_timestamp: 1_700_000_000,
+            ts,
+        }),
+        KEventData::PfGlobalVolumeAccumulatorUpdated(KPfGlobalVolumeAccumulator {
+            global_volume_accumulator: "gva".into(),
+            start_time: 1_700_000_000,
+            end_time: 1_702_592_000,
+            seconds_in_a_day: 86_400,
+            mint: "pump_mint".into(),
+            total_token_supply: vec![1_000_000; 30],
+            sol_volumes: vec![0; 30],
+            ts,
+        }),
+        KEventData::PfGlobalConfigUpdated(KPfGlobalConfig {
+            global: "global".into(),
+            fee_recipient: "fee_recipient".into(),
+            initial_virtual_token_reserves: 1_073_000_000_000_000,
+            initial_virtual_sol_reserves: 30_000_000_000,
+            initial_real_token_reserves: 793_100_000_000_000,
+            token_total_supply: 1_000_000_000_000_000,
+            fee_basis_points: 95,
+            creator_fee_basis_points: 5,
+            enable_migrate: true,
+            pool_migration_fee: 15_000_001,
+            ts,
+        }),
+        KEventData::PfFeeConfigUpdated(KPfFeeConfig {
+            fee_config: "fee_config".into(),
+            flat_fees: KPfFees {
+                lp_fee_bps: 0,
+                protocol_fee_bps: 95,
+                creator_fee_bps: 30,
+            },
+            fee_tiers: vec![KPfFeeTier {
+                market_cap_lamports_threshold: 420_000_000_000,
+                fees: KPfFees {
+                    lp_fee_bps: 0,
+                    protocol_fee_bps: 93,
+                    creator_fee_bps: 95,
+                },
+            }],
+            ts,
+        }),
+        KEventData::PfBondingCurveCompleted(KPfBondingCurveCompleted {
+            bonding_curve: "curve".into(),
+            creator: None,
+            token_total_supply: 1_000_000_000_000_000,
+            virtual_token_reserves: 279_900_000_000_000,
+            virtual_sol_reserves: 115_005_359_056,
+            real_sol_reserves: 85_005_359_056,
+            is_mayhem_mode: false,
+            ts,
+        }),
+    ]
+}
@@ -0,0 +1,37 @@
+use event_contract::leases::{
+    decode_lease_platform, encode_lease_platform, lease_member, lease_member_account,
+};
+use event_contract::models::enums::Platform;
+
+#[test]
+fn lease_member_keeps_the_account() {
+    let member = lease_member("So11111111111111111111111111111111111111112", "ws:session");
+
+    assert_eq!(
+        lease_member_account(&member),
+        Some("So11111111111111111111111111111111111111112")
+    );
+}
+
+#[test]
+fn lease_platform_round_trips() {
+    for platform in [
+        Platform::PumpFun,
+        Platform::PumpSwap,
+        Platform::RaydiumLaunchLab,
+    ] {
+        let encoded = encode_lease_platform(platform).unwrap();
+
+        assert_eq!(decode_lease_platform(&encoded).unwrap(), platform);
+    }
+}
+
+#[test]
+fn lease_platform_stays_json_encoded() {
+    // Leases written before the layout moved here must still decode
+    assert_eq!(
+        decode_lease_platform("\"PumpFun\"").unwrap(),
+        Platform::PumpFun
+    );
+    assert!(decode_lease_platform("\"RaydiumAmmV4\"").is_err());
+}
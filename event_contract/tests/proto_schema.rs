@@ -0,0 +1,163 @@
+//! Checks that the hand-written `Pb*` mirror agrees with `proto/k_event.proto`
+//! on the wire: messages built from the parsed schema must survive a round
+//! trip through `PbKEvent`, and every payload we encode must decode against
+//! the schema without unknown fields.
+
+mod common;
+
+use common::all_payloads;
+use event_contract::codec::proto::PbKEvent;
+use event_contract::codec::{self, ContentType};
+use event_contract::models::enums::EventSource;
+use event_contract::models::kafka_event::KEvent;
+use prost::Message;
+use prost_reflect::{
+    DescriptorPool, DynamicMessage, FieldDescriptor, Kind, MessageDescriptor, ReflectMessage, Value,
+};
+use std::path::Path;
+
+const K_EVENT_MESSAGE: &str = "dex_indexer.event_contract.v1.KEvent";
+const CONFLUENT_HEADER_LEN: usize = 6;
+
+fn k_event_descriptor() -> MessageDescriptor {
+    let proto_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("proto");
+    let file_set = protobuf_parse::Parser::new()
+        .pure()
+        .include(&proto_dir)
+        .input(proto_dir.join("k_event.proto"))
+        .file_descriptor_set()
+        .unwrap();
+    let bytes = protobuf::Message::write_to_bytes(&file_set).unwrap();
+
+    DescriptorPool::decode(bytes.as_slice())
+        .unwrap()
+        .get_message_by_name(K_EVENT_MESSAGE)
+        .unwrap()
+}
+
+/// How `fill` picks field values
+#[derive(Clone, Copy)]
+enum Fill {
+    /// A value unique to each field, so swapped tags are caught
+    Distinct,
+    /// The default value, so lost `optional` presence is caught
+    Zero,
+}
+
+/// Sets every field of `message`, taking `oneof_field` for its oneof (the others stay unset)
+fn fill(
+    message: &mut DynamicMessage,
+    oneof_field: Option<&FieldDescriptor>,
+    mode: Fill,
+    next: &mut i64,
+) {
+    for field in message.descriptor().fields() {
+        let in_oneof = field
+            .containing_oneof()
+            .is_some_and(|oneof| !oneof.is_synthetic());
+        if in_oneof && oneof_field != Some(&field) {
+            continue;
+        }
+
+        let value = if field.is_list() {
+            Value::List(vec![
+                value_of(&field.kind(), mode, next),
+                value_of(&field.kind(), mode, next),
+            ])
+        } else {
+            value_of(&field.kind(), mode, next)
+        };
+        message.set_field(&field, value);
+    }
+}
+
+fn value_of(kind: &Kind, mode: Fill, next: &mut i64) -> Value {
+    *next += 1;
+    let n = match mode {
+        Fill::Distinct => *next,
+        Fill::Zero => 0,
+    };
+
+    match kind {
+        Kind::Int32 | Kind::Sint32 | Kind::Sfixed32 => Value::I32(-(n as i32)),
+        Kind::Int64 | Kind::Sint64 | Kind::Sfixed64 => Value::I64(-n),
+        Kind::Uint32 | Kind::Fixed32 => Value::U32(n as u32),
+        Kind::Uint64 | Kind::Fixed64 => Value::U64(n as u64),
+        Kind::Float => Value::F32(n as f32),
+        Kind::Double => Value::F64(n as f64),
+        Kind::Bool => Value::Bool(n != 0),
+        Kind::String => Value::String(match mode {
+            Fill::Distinct => format!("s{n}"),
+            Fill::Zero => String::new(),
+        }),
+        Kind::Bytes => Value::Bytes(n.to_be_bytes().to_vec().into()),
+        Kind::Enum(e) => Value::EnumNumber(match mode {
+            Fill::Distinct => e.values().last().unwrap().number(),
+            Fill::Zero => 0,
+        }),
+        Kind::Message(m) => {
+            let mut nested = DynamicMessage::new(m.clone());
+            fill(&mut nested, None, mode, next);
+            Value::Message(nested)
+        }
+    }
+}
+
+fn assert_no_unknown_fields(message: &DynamicMessage, path: &str) {
+    let unknown: Vec<u32> = message.unknown_fields().map(|f| f.number()).collect();
+    assert!(unknown.is_empty(), "{path}: unknown tags {unknown:?}");
+
+    for (field, value) in message.fields() {
+        let path = format!("{path}.{}", field.name());
+        match value {
+            Value::Message(nested) => assert_no_unknown_fields(nested, &path),
+            Value::List(items) => items.iter().for_each(|item| {
+                if let Value::Message(nested) = item {
+                    assert_no_unknown_fields(nested, &path);
+                }
+            }),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn schema_messages_round_trip_through_pb_types() {
+    let descriptor = k_event_descriptor();
+    let payload = descriptor.oneofs().find(|o| o.name() == "data").unwrap();
+
+    for variant in payload.fields() {
+        for mode in [Fill::Distinct, Fill::Zero] {
+            let mut message = DynamicMessage::new(descriptor.clone());
+            fill(&mut message, Some(&variant), mode, &mut 0);
+
+            // Normalise through the wire so both sides compare decoded messages
+            let expected =
+                DynamicMessage::decode(descriptor.clone(), message.encode_to_vec().as_slice())
+                    .unwrap();
+            let pb = PbKEvent::decode(expected.encode_to_vec().as_slice()).unwrap();
+            let actual =
+                DynamicMessage::decode(descriptor.clone(), pb.encode_to_vec().as_slice()).unwrap();
+
+            assert_eq!(actual, expected, "{}", variant.name());
+        }
+    }
+}
+
+#[test]
+fn pb_types_have_no_fields_missing_from_schema() {
+    let descriptor = k_event_descriptor();
+
+    for data in all_payloads() {
+        let event = KEvent::new(EventSource::GeyserTxStreamer, 42, data);
+        let bytes = codec::encode(&event, ContentType::Protobuf, None).unwrap();
+        let body = &bytes[CONFLUENT_HEADER_LEN..];
+
+        let message = DynamicMessage::decode(descriptor.clone(), body).unwrap();
+        assert_no_unknown_fields(&message, "KEvent");
+
+        let pb = PbKEvent::decode(body).unwrap();
+        let reencoded = PbKEvent::decode(message.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(reencoded, pb);
+    }
+}
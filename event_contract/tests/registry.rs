@@ -0,0 +1,69 @@
+#![cfg(feature = "registry")]
+
+mod common;
+
+use common::{all_payloads, pf_ch_trade};
+use event_contract::codec::registry::{DecodeError, EventDecoder};
+use event_contract::codec::{self, ContentType};
+use event_contract::models::enums::EventSource;
+use event_contract::models::kafka_event::{KEvent, KEventData};
+
+/// Nothing listens here, so every registry request fails to connect
+const UNREACHABLE_REGISTRY: &str = "http://127.0.0.1:1";
+
+fn protobuf_event(schema_id: Option<u32>) -> Vec<u8> {
+    let event = KEvent::new(
+        EventSource::GeyserTxStreamer,
+        42,
+        KEventData::PfChTradeOccurred(pf_ch_trade()),
+    );
+
+    codec::encode(&event, ContentType::Protobuf, schema_id).unwrap()
+}
+
+#[tokio::test]
+async fn malformed_payload_is_rejected() {
+    let decoder = EventDecoder::new(None);
+    let mut payload = protobuf_event(None);
+    payload.truncate(payload.len() / 2);
+
+    let error = decoder
+        .decode("pf_trade_event", &payload, ContentType::Protobuf)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DecodeError::Rejected(_)), "{error:?}");
+}
+
+#[tokio::test]
+async fn unreachable_registry_is_unavailable() {
+    let decoder = EventDecoder::new(Some(UNREACHABLE_REGISTRY.to_string()));
+
+    let error = decoder
+        .decode(
+            "pf_trade_event",
+            &protobuf_event(Some(7)),
+            ContentType::Protobuf,
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DecodeError::Unavailable(_)), "{error:?}");
+}
+
+#[tokio::test]
+async fn unregistered_payloads_skip_the_registry() {
+    let decoder = EventDecoder::new(Some(UNREACHABLE_REGISTRY.to_string()));
+
+    for data in all_payloads() {
+        let event = KEvent::new(EventSource::GeyserTxStreamer, 42, data);
+        let payload = codec::encode(&event, ContentType::Protobuf, None).unwrap();
+
+        let decoded = decoder
+            .decode("pf_trade_event", &payload, ContentType::Protobuf)
+            .await
+            .unwrap();
+
+        assert_eq!(decoded, event);
+    }
+}
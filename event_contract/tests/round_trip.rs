@@ -0,0 +1,111 @@
+mod common;
+
+use common::{all_payloads, pf_ch_trade};
+use event_contract::models::enums::{EventSource, PriceSource};
+use event_contract::models::kafka_event::{KEvent, KEventData, KEventType, SCHEMA_VERSION};
+
+#[test]
+fn every_payload_round_trips_through_json() {
+    for data in all_payloads() {
+        let event = KEvent::new(EventSource::GeyserTxStreamer, 42, data);
+
+        let json = serde_json::to_string(&event).unwrap();
+        let decoded: KEvent = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, event);
+        assert_eq!(decoded.event_type, decoded.data.event_type());
+    }
+}
+
+#[test]
+fn event_type_name_matches_json() {
+    for data in all_payloads() {
+        let event_type = data.event_type();
+
+        assert_eq!(
+            serde_json::to_value(event_type).unwrap(),
+            event_type.as_str()
+        );
+    }
+}
+
+#[test]
+fn new_fills_envelope() {
+    let event = KEvent::new(
+        EventSource::GeyserAccountSubscriber,
+        7,
+        KEventData::PfChTradeOccurred(pf_ch_trade()),
+    );
+
+    assert_eq!(event.schema_version, SCHEMA_VERSION);
+    assert_eq!(event.source, EventSource::GeyserAccountSubscriber);
+    assert_eq!(event.slot, 7);
+    assert_eq!(event.event_type, KEventType::PfChTradeOccurred);
+    assert!(uuid::Uuid::parse_str(&event.event_id).is_ok());
+    assert!(event.is_supported());
+}
+
+#[test]
+fn legacy_payload_without_envelope_decodes() {
+    let legacy = r#"{
+        "event_type": "PF_PRICE_UPDATED",
+        "data": {
+            "PfPriceUpdated": {
+                "bonding_curve": "curve",
+                "source": "PumpFunBondingCurve",
+                "ts": "2023-11-14T22:13:20Z",
+                "virtual_token_reserves": 1,
+                "virtual_sol_reserves": 2,
+                "real_token_reserves": 3,
+                "real_sol_reserves": 4
+            }
+        }
+    }"#;
+
+    let event: KEvent = serde_json::from_str(legacy).unwrap();
+
+    assert_eq!(event.schema_version, 0);
+    assert_eq!(event.source, EventSource::Unknown);
+    assert_eq!(event.slot, 0);
+    assert!(event.event_id.is_empty());
+    assert!(event.is_supported());
+}
+
+#[test]
+fn misspelled_raydium_price_source_still_decodes() {
+    let source: PriceSource = serde_json::from_str(r#""RaydiunmLaunchLabPoolState""#).unwrap();
+
+    assert_eq!(source, PriceSource::RaydiumLaunchLabPoolState);
+}
+
+#[test]
+fn unknown_fields_are_ignored() {
+    let event = KEvent::new(
+        EventSource::GeyserTxStreamer,
+        1,
+        KEventData::PfChTradeOccurred(pf_ch_trade()),
+    );
+
+    let mut value = serde_json::to_value(&event).unwrap();
+    value["trace_id"] = "abc".into();
+    value["data"]["PfChTradeOccurred"]["new_field"] = 1.into();
+
+    let decoded: KEvent = serde_json::from_value(value).unwrap();
+
+    assert_eq!(decoded, event);
+}
+
+#[test]
+fn newer_schema_version_is_not_supported() {
+    let mut event = KEvent::new(
+        EventSource::GeyserTxStreamer,
+        1,
+        KEventData::PfChTradeOccurred(pf_ch_trade()),
+    );
+    event.schema_version = SCHEMA_VERSION + 1;
+
+    let json = serde_json::to_string(&event).unwrap();
+    let decoded: KEvent = serde_json::from_str(&json).unwrap();
+
+    assert!(!decoded.is_supported());
+}
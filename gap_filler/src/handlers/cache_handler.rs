@@ -61,9 +61,6 @@ pub async fn handle_price_req(
                     cache_token(&mut cache, &token, token_str.to_string())
                         .await
                         .unwrap(); // TODO re-do the unwrap()
-                    cache_stream(&mut cache, &token, token_str.to_string())
-                        .await
-                        .unwrap(); // TODO re-do the unwrap()
                     send_token_for_price_stream(&mut cache, token.clone(), req_tx.clone()).await;
                     send_token_for_fulfill(token.clone(), req_tx.clone()).await;
                 }
@@ -80,42 +77,6 @@ pub async fn handle_price_req(
     }
 }
 
-pub async fn cache_stream(
-    cache: &mut ConnectionManager,
-    token: &EnrichedResolvedToken,
-    token_str: String,
-) -> Result<(), redis::RedisError> {
-    if !token.mint.is_empty() {
-        let key = format!("subscriptions:by_mint:{}", token.mint);
-        cache
-            .set_ex::<_, _, ()>(&key, token_str.clone(), 72000)
-            .await?;
-    }
-
-    if let Some(bonding_curve) = &token.bonding_curve {
-        let key = format!("subscriptions:by_bonding_curve:{bonding_curve}");
-        cache
-            .set_ex::<_, _, ()>(&key, &token_str.clone(), 72000)
-            .await?;
-    }
-
-    if let Some(pool) = &token.pool {
-        let key = format!("subscriptions:by_pool:{pool}");
-        cache
-            .set_ex::<_, _, ()>(&key, token_str.clone(), 72000)
-            .await?;
-    }
-
-    if let Some(pool_state) = &token.pool_state {
-        let key = format!("subscriptions:by_pool_state:{pool_state}");
-        cache
-            .set_ex::<_, _, ()>(&key, token_str.clone(), 72000)
-            .await?;
-    }
-
-    Ok(())
-}
-
 pub async fn cache_token(
     cache: &mut ConnectionManager,
     token: &EnrichedResolvedToken,
@@ -1,58 +1,324 @@
-use crate::handlers::price_update_handler::handle_price_update;
+use crate::api::geyser::account_versions::AccountVersions;
+use crate::handlers::price_update_handler::{get_platform, handle_price_update};
+use crate::handlers::ps_price_update_handler::PsPoolTracker;
+use crate::metrics::ShardHealth;
 use crate::models::enums::Platform;
-use crate::models::kafka_event::KEvent;
-use futures::StreamExt;
-use log::{error, info, warn};
-use std::collections::HashMap;
+use crate::models::kafka_event::{KEvent, KEventType};
+use crate::snapshot::AccountFetcher;
+use futures::{SinkExt, StreamExt};
+use log::{debug, error, info, warn};
+use redis::aio::ConnectionManager;
+use solana_sdk::bs58;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::mpsc::Sender;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::{Receiver, Sender};
 use tokio::sync::oneshot;
+use tokio::time::MissedTickBehavior;
 use tokio_util::sync::CancellationToken;
 use yellowstone_grpc_client::GeyserGrpcClient;
-use yellowstone_grpc_proto::geyser::{SubscribeRequest, SubscribeRequestFilterAccounts};
+use yellowstone_grpc_proto::geyser::subscribe_request_filter_accounts_filter::Filter;
+use yellowstone_grpc_proto::geyser::subscribe_request_filter_accounts_filter_memcmp::Data;
+use yellowstone_grpc_proto::geyser::subscribe_update::UpdateOneof;
+use yellowstone_grpc_proto::geyser::{
+    SubscribeRequest, SubscribeRequestFilterAccounts, SubscribeRequestFilterAccountsFilter,
+    SubscribeRequestFilterAccountsFilterMemcmp, SubscribeRequestPing, SubscribeUpdateAccountInfo,
+};
 
 const MAX_BACKOFF: u64 = 30;
+const DEFAULT_COALESCE_MS: u64 = 250;
 
+/// Change to the watched account set, pushed to a running `AccountConsumer`.
+/// `applied` fires once Geyser acknowledged the new filter (pong for the request's ping).
+pub struct SubscriptionDiff {
+    pub added: HashMap<String, Platform>,
+    pub removed: Vec<String>,
+    pub applied: oneshot::Sender<()>,
+}
+
+/// PumpSwap pools fetched by a discovery task; `requested` may hold pools that do not exist.
+struct PsPoolDiscovery {
+    requested: Vec<String>,
+    accounts: Vec<SubscribeUpdateAccountInfo>,
+}
+
+/// Every account owned by `owner` whose data starts with `discriminator`.
+#[derive(Debug, Clone)]
+pub struct ProgramFilter {
+    pub owner: String,
+    pub discriminator: Vec<u8>,
+    pub platform: Platform,
+}
+
+/// Long-lived Geyser account stream. The filter is updated in place by sending a new
+/// `SubscribeRequest` over the open stream; a reconnect resubscribes with the current set.
 pub struct AccountConsumer {
     pub geyser_url: String,
     pub geyser_token: Option<String>,
     pub event_tx: Sender<KEvent>,
+    pub cache: ConnectionManager,
     pub tracked_accounts: HashMap<String, Platform>,
+    pub diff_rx: Receiver<SubscriptionDiff>,
+    pub shard_id: usize,
+    pub health: Arc<ShardHealth>,
+    /// Program-wide mode: watch every account matching one of these on top of `tracked_accounts`
+    pub program_filters: Vec<ProgramFilter>,
+    /// Forward only the latest event per account once per window instead of every write
+    pub coalesce_window: Option<Duration>,
+    /// Vaults of tracked PumpSwap pools, watched on top of `tracked_accounts`
+    pub ps_pools: PsPoolTracker,
+    /// Latest write per account across all shards; older writes are dropped
+    pub versions: Arc<AccountVersions>,
+    /// Fetches PumpSwap pools to learn their vaults; without it they wait for a pool write
+    pub snapshot_fetcher: Option<Arc<dyn AccountFetcher>>,
 }
 
 impl AccountConsumer {
-    // pub async fn update_subscriptions(&self, new_subscriptions: HashMap<String, Platform>) {
-    //     let mut subs = self.subscriptions.lock().await;
-    //     *subs = new_subscriptions;
-    // }
-    //
-    // pub async fn get_platform(&self, key: &str) -> Option<Platform> {
-    //     let subs = self.subscriptions.lock().await;
-    //     subs.get(key).cloned()
-    // }
-
-    pub fn spawn(
-        self,
-        cancel_token: CancellationToken,
-    ) -> (tokio::task::JoinHandle<()>, oneshot::Receiver<()>) {
-        let (stability_tx, stability_rx) = oneshot::channel();
-        let handle = tokio::spawn(async move {
-            self.run(cancel_token, Some(stability_tx)).await;
-        });
-        (handle, stability_rx)
+    pub fn spawn(self, cancel_token: CancellationToken) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            self.run(cancel_token).await;
+        })
+    }
+
+    /// Geyser replaces the whole filter on every request, so it always carries the full set.
+    /// An empty `account` list would mean "every account", hence no filter at all when empty.
+    fn build_request(&self, ping_id: i32) -> SubscribeRequest {
+        let mut account_filters = HashMap::new();
+        if !self.tracked_accounts.is_empty() {
+            account_filters.insert(
+                "tracked-accounts".to_string(),
+                SubscribeRequestFilterAccounts {
+                    account: self
+                        .tracked_accounts
+                        .keys()
+                        .chain(self.ps_pools.vault_accounts())
+                        .cloned()
+                        .collect(),
+                    ..Default::default()
+                },
+            );
+        }
+
+        for (i, program) in self.program_filters.iter().enumerate() {
+            account_filters.insert(
+                format!("program-accounts-{i}"),
+                SubscribeRequestFilterAccounts {
+                    owner: vec![program.owner.clone()],
+                    filters: vec![SubscribeRequestFilterAccountsFilter {
+                        filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
+                            offset: 0,
+                            data: Some(Data::Bytes(program.discriminator.clone())),
+                        })),
+                    }],
+                    ..Default::default()
+                },
+            );
+        }
+
+        SubscribeRequest {
+            accounts: account_filters,
+            ping: Some(SubscribeRequestPing { id: ping_id }),
+            ..Default::default()
+        }
+    }
+
+    /// Explicitly tracked accounts win; anything else can only come from the program filters.
+    fn platform_for(&self, pubkey: &[u8], owner: &[u8]) -> Option<Platform> {
+        let account = bs58::encode(pubkey).into_string();
+        get_platform(&account, &self.tracked_accounts).or_else(|| {
+            let owner = bs58::encode(owner).into_string();
+            self.program_filters
+                .iter()
+                .find(|program| program.owner == owner)
+                .map(|program| program.platform)
+        })
+    }
+
+    async fn forward(&self, kevent: KEvent) {
+        if let Err(e) = self.event_tx.send(kevent).await {
+            error!("Failed to forward account event: {e}");
+        }
+    }
+
+    fn apply_diff(&mut self, added: HashMap<String, Platform>, removed: Vec<String>) {
+        for account in removed {
+            self.ps_pools.untrack_pool(&account);
+            self.versions.forget(&account);
+            self.tracked_accounts.remove(&account);
+        }
+        self.tracked_accounts.extend(added);
     }
 
-    async fn run(
-        self,
-        cancel_token: CancellationToken,
-        mut stability_tx: Option<oneshot::Sender<()>>,
+    /// Geyser only streams changes and swaps never write the pool account, so a quiet pool
+    /// would never reveal its vaults: fetch the PumpSwap pools not decoded yet.
+    /// The fetch runs in its own task, retried with backoff until it succeeds, so a slow RPC
+    /// never stalls the stream; results come back through `discovered_tx`.
+    fn discover_ps_pools(
+        &self,
+        in_flight: &mut HashSet<String>,
+        discovered_tx: &mpsc::UnboundedSender<PsPoolDiscovery>,
+        cancel_token: &CancellationToken,
     ) {
+        let Some(fetcher) = self.snapshot_fetcher.clone() else {
+            return;
+        };
+        let missing: Vec<String> = self
+            .tracked_accounts
+            .iter()
+            .filter(|(account, platform)| {
+                **platform == Platform::PumpSwap
+                    && !self.ps_pools.is_pool(account)
+                    && !in_flight.contains(*account)
+            })
+            .map(|(account, _)| account.clone())
+            .collect();
+        if missing.is_empty() {
+            return;
+        }
+        in_flight.extend(missing.iter().cloned());
+
+        let shard_id = self.shard_id;
+        let discovered_tx = discovered_tx.clone();
+        let cancel_token = cancel_token.clone();
+
+        tokio::spawn(async move {
+            let mut backoff = 1;
+            loop {
+                match fetcher.get_multiple_accounts(&missing).await {
+                    Ok(snapshot) => {
+                        let _ = discovered_tx.send(PsPoolDiscovery {
+                            requested: missing,
+                            accounts: snapshot.accounts,
+                        });
+                        return;
+                    }
+                    Err(e) => {
+                        warn!(
+                            "[shard {shard_id}] Failed to fetch PumpSwap pools, retrying in {backoff}s: {e}"
+                        );
+                    }
+                }
+
+                tokio::select! {
+                    _ = cancel_token.cancelled() => return,
+                    _ = tokio::time::sleep(Duration::from_secs(backoff)) => {}
+                }
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+        });
+    }
+
+    /// Returns whether the filter changed and must be resent (new PumpSwap vaults).
+    fn on_ps_pools_discovered(
+        &mut self,
+        discovery: PsPoolDiscovery,
+        in_flight: &mut HashSet<String>,
+    ) -> bool {
+        for pool in &discovery.requested {
+            in_flight.remove(pool);
+        }
+
+        let mut vaults_changed = false;
+        for info in &discovery.accounts {
+            let pool = bs58::encode(&info.pubkey).into_string();
+            // Untracked while the fetch was in flight
+            if self.tracked_accounts.get(&pool) != Some(&Platform::PumpSwap) {
+                continue;
+            }
+            match self.ps_pools.track_pool(&pool, info) {
+                Ok(changed) => vaults_changed |= changed,
+                Err(e) => warn!(
+                    "[shard {}] Skipping PumpSwap pool {pool}: {e}",
+                    self.shard_id
+                ),
+            }
+        }
+
+        vaults_changed
+    }
+
+    /// Returns whether the filter changed and must be resent (new PumpSwap vaults).
+    async fn on_account_update(
+        &mut self,
+        info: &SubscribeUpdateAccountInfo,
+        slot: u64,
+        coalesced: &mut HashMap<Vec<u8>, KEvent>,
+    ) -> bool {
+        // Vault writes of earlier slots are complete once a later slot shows up
+        for kevent in self.ps_pools.flush(Some(slot)) {
+            self.forward(kevent).await;
+        }
+
+        let account = bs58::encode(&info.pubkey).into_string();
+        if !self.versions.observe(&account, slot, info.write_version) {
+            debug!(
+                "[shard {}] Dropping stale write of {account} at slot {slot}",
+                self.shard_id
+            );
+            return false;
+        }
+
+        if self.ps_pools.is_vault(&account) {
+            if let Err(e) = self.ps_pools.on_vault_update(&account, info, slot) {
+                error!("Failed to handle PumpSwap vault update: {e}");
+            }
+            return false;
+        }
+
+        let platform = self.platform_for(&info.pubkey, &info.owner);
+        if platform == Some(Platform::PumpSwap) {
+            return match self.ps_pools.track_pool(&account, info) {
+                Ok(vaults_changed) => vaults_changed,
+                Err(e) => {
+                    error!("Failed to handle PumpSwap pool update: {e}");
+                    false
+                }
+            };
+        }
+
+        match handle_price_update(info, slot, platform, &mut self.cache).await {
+            Ok(kevents) => {
+                for kevent in kevents {
+                    // Completion is a one-off signal, never sampled away
+                    let coalesce = self.coalesce_window.is_some()
+                        && kevent.event_type != KEventType::PfBondingCurveCompleted;
+                    if !coalesce {
+                        self.forward(kevent).await;
+                        continue;
+                    }
+
+                    // Stale writes never get here, so the latest one is the newest state
+                    coalesced.insert(info.pubkey.clone(), kevent);
+                }
+            }
+            Err(e) => {
+                error!("Failed to handle_price_update: {e}");
+            }
+        }
+
+        false
+    }
+
+    async fn run(mut self, cancel_token: CancellationToken) {
         let mut backoff = 1;
-        let mut stability_sent = false;
+        let mut next_ping_id: i32 = 1;
+        // Diffs sent but not yet acknowledged by a pong, oldest first
+        let mut pending: Vec<(i32, oneshot::Sender<()>)> = Vec::new();
+        // Latest event per account, flushed every `coalesce_window`
+        let mut coalesced: HashMap<Vec<u8>, KEvent> = HashMap::new();
+        // PumpSwap pools being fetched, and the fetch results
+        let mut discovering: HashSet<String> = HashSet::new();
+        let (discovered_tx, mut discovered_rx) = mpsc::unbounded_channel();
+        let mut coalesce_tick = tokio::time::interval(
+            self.coalesce_window
+                .unwrap_or(Duration::from_millis(DEFAULT_COALESCE_MS)),
+        );
+        coalesce_tick.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
-        loop {
+        'connection: loop {
             if cancel_token.is_cancelled() {
-                info!("AccountConsumer cancelled");
+                info!("[shard {}] AccountConsumer cancelled", self.shard_id);
                 break;
             }
 
@@ -66,77 +332,106 @@ impl AccountConsumer {
             }) {
                 Ok(fut) => match fut.await {
                     Ok(mut client) => {
-                        info!("Connected to Geyser Account stream");
-                        backoff = 1;
-
-                        let tracked_accounts = self.tracked_accounts.clone();
-                        let items: Vec<String> = tracked_accounts.keys().cloned().collect();
-
-                        let mut account_filters = std::collections::HashMap::new();
-                        account_filters.insert(
-                            "tracked-accounts".to_string(),
-                            SubscribeRequestFilterAccounts {
-                                account: items,
-                                ..Default::default()
-                            },
+                        info!(
+                            "[shard {}] Connected to Geyser Account stream",
+                            self.shard_id
                         );
 
-                        let request = SubscribeRequest {
-                            accounts: account_filters,
-                            ..Default::default()
-                        };
-
-                        match client.subscribe_once(request).await {
-                            Ok(mut stream) => {
-                                if !stability_sent {
-                                    if let Some(tx) = stability_tx.take() {
-                                        let _ = tx.send(());
-                                        info!("Stability reached - subscription active");
-                                    }
-                                    stability_sent = true;
-                                }
+                        self.discover_ps_pools(&mut discovering, &discovered_tx, &cancel_token);
+                        let request = self.build_request(next_ping_id);
+                        next_ping_id += 1;
+
+                        match client.subscribe_with_request(Some(request)).await {
+                            Ok((mut sink, mut stream)) => {
+                                self.health.set_connected(true);
+                                info!(
+                                    "[shard {}] Subscribed to {} accounts",
+                                    self.shard_id,
+                                    self.tracked_accounts.len()
+                                );
+                                backoff = 1;
 
                                 loop {
                                     tokio::select! {
                                         _ = cancel_token.cancelled() => {
-                                            info!("Stream cancelled, shutting down");
-                                            break;
+                                            info!("[shard {}] Stream cancelled, shutting down", self.shard_id);
+                                            break 'connection;
                                         }
-                                        msg = stream.next() => {
-                                            match msg {
-                                                Some(Ok(update)) => {
-
-                                                    if let Some(oneof) = update.update_oneof {
+                                        diff = self.diff_rx.recv() => {
+                                            let Some(diff) = diff else {
+                                                info!("Subscription manager gone, shutting down");
+                                                break 'connection;
+                                            };
 
-                                                        if let yellowstone_grpc_proto::geyser::subscribe_update::UpdateOneof::Account(acc_update) = oneof {
+                                            self.apply_diff(diff.added, diff.removed);
+                                            self.discover_ps_pools(&mut discovering, &discovered_tx, &cancel_token);
 
-                                                            if let Some(info) = acc_update.account {
+                                            let ping_id = next_ping_id;
+                                            next_ping_id += 1;
+                                            pending.push((ping_id, diff.applied));
 
-                                                                match handle_price_update(&info, tracked_accounts.clone()).await {
-                                                                    Ok(kevent) => {
-                                                                        if let Err(e) = self.event_tx.send(kevent).await {
-                                                                            error!("Failed to connect Geyser client: {e}");
-                                                                        }
-                                                                    },
-                                                                    Err(e) => {
-                                                                        error!("Failed to handle_price_update: {e}");
-                                                                    }
+                                            // On failure the reconnect resubscribes with the updated set
+                                            if let Err(e) = sink.send(self.build_request(ping_id)).await {
+                                                warn!("Failed to send subscription update: {e}");
+                                                break;
+                                            }
+                                        }
+                                        Some(discovery) = discovered_rx.recv() => {
+                                            if self.on_ps_pools_discovered(discovery, &mut discovering) {
+                                                let ping_id = next_ping_id;
+                                                next_ping_id += 1;
+                                                if let Err(e) = sink.send(self.build_request(ping_id)).await {
+                                                    warn!("Failed to subscribe to PumpSwap vaults: {e}");
+                                                    break;
+                                                }
+                                            }
+                                        }
+                                        _ = coalesce_tick.tick(), if !coalesced.is_empty() || self.ps_pools.has_pending() => {
+                                            for (_, kevent) in coalesced.drain() {
+                                                self.forward(kevent).await;
+                                            }
+                                            // Quiet stream: no later slot will flush the last vault writes
+                                            for kevent in self.ps_pools.flush(None) {
+                                                self.forward(kevent).await;
+                                            }
+                                        }
+                                        msg = stream.next() => {
+                                            match msg {
+                                                Some(Ok(update)) => match update.update_oneof {
+                                                    Some(UpdateOneof::Account(acc_update)) => {
+                                                        if let Some(info) = acc_update.account {
+                                                            self.health.record_update();
+                                                            if self.on_account_update(&info, acc_update.slot, &mut coalesced).await {
+                                                                let ping_id = next_ping_id;
+                                                                next_ping_id += 1;
+                                                                if let Err(e) = sink.send(self.build_request(ping_id)).await {
+                                                                    warn!("Failed to subscribe to PumpSwap vaults: {e}");
+                                                                    break;
                                                                 }
-                                                            } else {
-                                                                warn!("Invalid info");
                                                             }
                                                         } else {
-                                                            warn!("Invalid update");
+                                                            warn!("Invalid info");
                                                         }
-                                                    } else {
-                                                        warn!("Invalid oneof");
                                                     }
-                                                }
-                                                Some(Err(e)) => {
-                                                    warn!("Stream error: {e:?}");
+                                                    // Each request carries the full set, so a pong acks every earlier diff too
+                                                    Some(UpdateOneof::Pong(pong)) => {
+                                                        let acked = pending.partition_point(|(id, _)| *id <= pong.id);
+                                                        for (_, applied) in pending.drain(..acked) {
+                                                            let _ = applied.send(());
+                                                        }
+                                                    }
+                                                    Some(UpdateOneof::Ping(_)) => {
+                                                        debug!("Geyser keepalive ping");
+                                                    }
+                                                    _ => {
+                                                        warn!("Invalid update");
+                                                    }
                                                 },
+                                                Some(Err(e)) => {
+                                                    warn!("[shard {}] Stream error: {e:?}", self.shard_id);
+                                                }
                                                 None => {
-                                                    info!("Stream ended");
+                                                    info!("[shard {}] Stream ended", self.shard_id);
                                                     break;
                                                 }
                                             }
@@ -159,11 +454,13 @@ impl AccountConsumer {
             }
 
             if cancel_token.is_cancelled() {
-                error!("is_cancelled");
                 break;
             }
 
-            warn!("Reconnecting in {backoff}s...");
+            // The resubscribe after reconnecting carries every pending diff
+            self.health.set_connected(false);
+            self.health.record_reconnect();
+            warn!("[shard {}] Reconnecting in {backoff}s...", self.shard_id);
             tokio::time::sleep(Duration::from_secs(backoff)).await;
             backoff = (backoff * 2).min(MAX_BACKOFF);
         }
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+#[derive(Debug)]
+struct AccountActivity {
+    slot: u64,
+    write_version: u64,
+    updates: u64,
+    first_update: Instant,
+}
+
+/// Accounts without a write for this many slots (about an hour) are dropped. Program-wide
+/// subscriptions see every account of the program and never `forget` them, so without this
+/// the map would only grow. A reconnect replays far fewer slots, so no stale write gets
+/// through once an account is dropped.
+pub const ACCOUNT_IDLE_SLOTS: u64 = 9_000;
+
+/// Idle accounts are swept whenever the newest slot has moved this far since the last sweep
+const SWEEP_EVERY_SLOTS: u64 = 150;
+
+#[derive(Debug, Default)]
+struct Activity {
+    accounts: HashMap<String, AccountActivity>,
+    newest_slot: u64,
+    swept_at_slot: u64,
+}
+
+impl Activity {
+    fn sweep(&mut self, slot: u64) {
+        self.newest_slot = self.newest_slot.max(slot);
+        if self.newest_slot < self.swept_at_slot + SWEEP_EVERY_SLOTS {
+            return;
+        }
+
+        let horizon = self.newest_slot.saturating_sub(ACCOUNT_IDLE_SLOTS);
+        self.accounts.retain(|_, seen| seen.slot >= horizon);
+        self.swept_at_slot = self.newest_slot;
+    }
+}
+
+/// Forwarded writes of one account since it was first seen
+#[derive(Debug, Clone, Copy)]
+pub struct AccountStats {
+    pub last_slot: u64,
+    pub updates: u64,
+    pub updates_per_min: f64,
+}
+
+/// Latest `(slot, write_version)` forwarded per account. Shared by every shard, so an
+/// account replayed by a reconnect or moved to another stream can never go backwards.
+/// Accounts idle for [`ACCOUNT_IDLE_SLOTS`] are dropped.
+#[derive(Debug, Default)]
+pub struct AccountVersions {
+    latest: Mutex<Activity>,
+}
+
+impl AccountVersions {
+    /// Record the write and return whether it is newer than anything seen for `account`.
+    pub fn observe(&self, account: &str, slot: u64, write_version: u64) -> bool {
+        let mut latest = self.latest.lock().unwrap_or_else(|e| e.into_inner());
+
+        let newer = match latest.accounts.get_mut(account) {
+            Some(seen) if (seen.slot, seen.write_version) >= (slot, write_version) => false,
+            Some(seen) => {
+                seen.slot = slot;
+                seen.write_version = write_version;
+                seen.updates += 1;
+                true
+            }
+            None => {
+                latest.accounts.insert(
+                    account.to_string(),
+                    AccountActivity {
+                        slot,
+                        write_version,
+                        updates: 1,
+                        first_update: Instant::now(),
+                    },
+                );
+                true
+            }
+        };
+        latest.sweep(slot);
+        newer
+    }
+
+    /// Drop an account that is no longer watched.
+    pub fn forget(&self, account: &str) {
+        self.latest
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .accounts
+            .remove(account);
+    }
+
+    pub fn stats(&self, account: &str) -> Option<AccountStats> {
+        let latest = self.latest.lock().unwrap_or_else(|e| e.into_inner());
+        let activity = latest.accounts.get(account)?;
+
+        // At least a second, so a fresh account does not report a huge rate
+        let minutes = activity.first_update.elapsed().as_secs_f64().max(1.0) / 60.0;
+        Some(AccountStats {
+            last_slot: activity.slot,
+            updates: activity.updates,
+            updates_per_min: activity.updates as f64 / minutes,
+        })
+    }
+}
@@ -1,2 +1,3 @@
 pub mod account_consumer;
+pub mod account_versions;
 pub mod subscription_manager;
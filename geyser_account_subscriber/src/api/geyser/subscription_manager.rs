@@ -1,56 +1,150 @@
-use crate::api::geyser::account_consumer::AccountConsumer;
+use crate::api::geyser::account_consumer::{AccountConsumer, ProgramFilter, SubscriptionDiff};
+use crate::api::geyser::account_versions::AccountVersions;
+use crate::cache::{load_subscriptions, save_subscription_changes};
+use crate::handlers::ps_price_update_handler::PsPoolTracker;
+use crate::metrics::{
+    ShardHealth, ShardHealthSnapshot, SubscriptionMetrics, TrackedAccountSnapshot,
+};
 use crate::models::enums::Platform;
 use crate::models::kafka_event::KEvent;
-use log::info;
+use crate::snapshot::{AccountFetcher, emit_snapshots};
+use futures::future::join_all;
+use log::{error, info, warn};
+use redis::aio::ConnectionManager;
 use std::collections::HashMap;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, oneshot};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
+const HEALTH_REPORT_INTERVAL: Duration = Duration::from_secs(60);
+/// How long an update waits for every shard to confirm Geyser applied its new filter
+const ACK_TIMEOUT: Duration = Duration::from_secs(10);
+
 pub enum SubscriptionCommand {
     Update {
         tracked_accounts: HashMap<String, Platform>,
         response: oneshot::Sender<Result<(), String>>,
     },
+    Health {
+        response: oneshot::Sender<Vec<ShardHealthSnapshot>>,
+    },
+    Accounts {
+        response: oneshot::Sender<Vec<TrackedAccountSnapshot>>,
+    },
+    Resubscribe {
+        response: oneshot::Sender<()>,
+    },
     Shutdown,
 }
 
+/// Program-wide mode: one extra stream over every account matching one of `filters`, coalesced
+#[derive(Debug, Clone)]
+pub struct ProgramWideConfig {
+    pub filters: Vec<ProgramFilter>,
+    pub coalesce_window: Duration,
+}
+
 pub struct SubscriptionManager {
     geyser_url: String,
     geyser_token: Option<String>,
     event_tx: mpsc::Sender<KEvent>,
-    current_task: Option<(JoinHandle<()>, CancellationToken)>,
+    cache: ConnectionManager,
+    /// Accounts watched regardless of requests (protocol accounts); never persisted
+    pinned_accounts: HashMap<String, Platform>,
+    /// Requested accounts as last written to the registry, so only changes are persisted
+    persisted_accounts: HashMap<String, Platform>,
+    /// Max accounts per gRPC stream
+    shard_size: usize,
+    shards: Vec<Shard>,
+    next_shard_id: usize,
+    program_wide: Option<ProgramWideConfig>,
+    /// Program-wide stream, kept out of `shards` so rebalancing never touches it
+    program_shard: Option<Shard>,
+    /// Fetches the current state of newly added accounts; no snapshots when unset
+    snapshot_fetcher: Option<Arc<dyn AccountFetcher>>,
+    versions: Arc<AccountVersions>,
     command_rx: mpsc::Receiver<SubscriptionCommand>,
+    metrics: Arc<SubscriptionMetrics>,
+}
+
+/// One `AccountConsumer` stream and the accounts it is filtered on
+struct Shard {
+    id: usize,
+    accounts: HashMap<String, Platform>,
+    handle: JoinHandle<()>,
+    cancel_token: CancellationToken,
+    diff_tx: mpsc::Sender<SubscriptionDiff>,
+    health: Arc<ShardHealth>,
+}
+
+#[derive(Default)]
+struct ShardDiff {
+    added: HashMap<String, Platform>,
+    removed: Vec<String>,
 }
 
 impl SubscriptionManager {
+    #[allow(clippy::too_many_arguments)]
     pub fn spawn(
         geyser_url: String,
         geyser_token: Option<String>,
         event_tx: mpsc::Sender<KEvent>,
+        cache: ConnectionManager,
+        pinned_accounts: HashMap<String, Platform>,
+        shard_size: usize,
+        program_wide: Option<ProgramWideConfig>,
+        snapshot_fetcher: Option<Arc<dyn AccountFetcher>>,
     ) -> (JoinHandle<()>, SubscriptionManagerHandle) {
         let (cmd_tx, cmd_rx) = mpsc::channel(32);
+        let metrics = Arc::new(SubscriptionMetrics::default());
 
         let mut manager = Self {
             geyser_url,
             geyser_token,
             event_tx,
-            current_task: None,
+            cache,
+            pinned_accounts,
+            persisted_accounts: HashMap::new(),
+            shard_size: shard_size.max(1),
+            shards: Vec::new(),
+            next_shard_id: 0,
+            program_wide,
+            program_shard: None,
+            snapshot_fetcher,
+            versions: Arc::new(AccountVersions::default()),
             command_rx: cmd_rx,
+            metrics: metrics.clone(),
         };
 
         let handle = tokio::spawn(async move {
             manager.run().await;
         });
 
-        let manager_handle = SubscriptionManagerHandle { cmd_tx };
+        let manager_handle = SubscriptionManagerHandle { cmd_tx, metrics };
 
         (handle, manager_handle)
     }
 
     async fn run(&mut self) {
-        while let Some(cmd) = self.command_rx.recv().await {
+        self.start_program_shard();
+        self.restore().await;
+
+        let mut health_report = tokio::time::interval(HEALTH_REPORT_INTERVAL);
+
+        loop {
+            let cmd = tokio::select! {
+                cmd = self.command_rx.recv() => cmd,
+                _ = health_report.tick() => {
+                    self.report_health();
+                    continue;
+                }
+            };
+            let Some(cmd) = cmd else {
+                break;
+            };
+
             match cmd {
                 SubscriptionCommand::Update {
                     tracked_accounts,
@@ -59,6 +153,16 @@ impl SubscriptionManager {
                     let result = self.update_subscription(tracked_accounts).await;
                     let _ = response.send(result);
                 }
+                SubscriptionCommand::Health { response } => {
+                    let _ = response.send(self.shard_health());
+                }
+                SubscriptionCommand::Accounts { response } => {
+                    let _ = response.send(self.tracked_accounts());
+                }
+                SubscriptionCommand::Resubscribe { response } => {
+                    self.resubscribe().await;
+                    let _ = response.send(());
+                }
                 SubscriptionCommand::Shutdown => {
                     self.shutdown().await;
                     break;
@@ -67,53 +171,435 @@ impl SubscriptionManager {
         }
     }
 
+    /// Resume the accounts persisted by the previous run (plus the pinned ones).
+    /// The first lease sweep then narrows them to the accounts still leased.
+    async fn restore(&mut self) {
+        let tracked_accounts: HashMap<String, Platform> =
+            match load_subscriptions(&mut self.cache).await {
+                Ok(entries) => entries
+                    .into_iter()
+                    .map(|(account, entry)| (account, entry.platform))
+                    .collect(),
+                Err(e) => {
+                    error!("Failed to load persisted subscriptions: {e}");
+                    HashMap::new()
+                }
+            };
+
+        if tracked_accounts.is_empty() && self.pinned_accounts.is_empty() {
+            return;
+        }
+
+        info!(
+            "Restoring {} persisted subscriptions",
+            tracked_accounts.len()
+        );
+        self.persisted_accounts = tracked_accounts.clone();
+
+        if let Err(e) = self.update_subscription(tracked_accounts).await {
+            error!("Failed to restore subscriptions: {e}");
+        }
+    }
+
     async fn update_subscription(
         &mut self,
         tracked_accounts: HashMap<String, Platform>,
     ) -> Result<(), String> {
-        info!(
-            "Starting new subscription with {} accounts",
-            tracked_accounts.len()
-        );
+        let started = Instant::now();
+        self.respawn_stopped_shards();
+
+        let mut watched_accounts = self.pinned_accounts.clone();
+        watched_accounts.extend(tracked_accounts.clone());
+
+        let mut diffs: HashMap<usize, ShardDiff> = HashMap::new();
+
+        // Drop accounts no longer watched and pick up platform changes in place
+        for shard in &mut self.shards {
+            let diff = diffs.entry(shard.id).or_default();
+            shard
+                .accounts
+                .retain(|account, platform| match watched_accounts.get(account) {
+                    None => {
+                        diff.removed.push(account.clone());
+                        false
+                    }
+                    Some(watched) => {
+                        if watched != platform {
+                            *platform = *watched;
+                            diff.added.insert(account.clone(), *watched);
+                        }
+                        true
+                    }
+                });
+        }
+
+        // New accounts fill existing shards first, opening new ones when all are full
+        let unassigned: Vec<(String, Platform)> = watched_accounts
+            .into_iter()
+            .filter(|(account, _)| !self.shards.iter().any(|s| s.accounts.contains_key(account)))
+            .collect();
+        let newly_added: HashMap<String, Platform> = unassigned.iter().cloned().collect();
+        for (account, platform) in unassigned {
+            let idx = match self.shard_with_room() {
+                Some(idx) => idx,
+                None => self.open_shard(HashMap::new()),
+            };
+            let shard = &mut self.shards[idx];
+            shard.accounts.insert(account.clone(), platform);
+            diffs
+                .entry(shard.id)
+                .or_default()
+                .added
+                .insert(account, platform);
+        }
+
+        // Rebalance: retire the emptiest shards while the others can absorb their accounts
+        let total: usize = self.shards.iter().map(|s| s.accounts.len()).sum();
+        let required = total.div_ceil(self.shard_size);
+        let mut retired = Vec::new();
+        while self.shards.len() > required {
+            let idx = self
+                .shards
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, s)| s.accounts.len())
+                .map(|(idx, _)| idx)
+                .expect("shards is not empty");
+            let shard = self.shards.remove(idx);
+            diffs.remove(&shard.id);
+
+            for (account, platform) in &shard.accounts {
+                let target = self
+                    .shard_with_room()
+                    .expect("remaining shards hold every account");
+                let target = &mut self.shards[target];
+                target.accounts.insert(account.clone(), *platform);
+                diffs
+                    .entry(target.id)
+                    .or_default()
+                    .added
+                    .insert(account.clone(), *platform);
+            }
+            retired.push(shard);
+        }
+
+        let mut acks = Vec::new();
+        for shard in &self.shards {
+            let Some(diff) = diffs.remove(&shard.id) else {
+                continue;
+            };
+            if diff.added.is_empty() && diff.removed.is_empty() {
+                continue;
+            }
+
+            info!(
+                "Updating shard {}: +{} -{} ({} accounts)",
+                shard.id,
+                diff.added.len(),
+                diff.removed.len(),
+                shard.accounts.len()
+            );
+
+            // Only accounts new to the watched set need a snapshot; moved ones are already current
+            let snapshot: HashMap<String, Platform> = diff
+                .added
+                .iter()
+                .filter(|(account, _)| newly_added.contains_key(*account))
+                .map(|(account, platform)| (account.clone(), *platform))
+                .collect();
+
+            let (applied_tx, applied_rx) = oneshot::channel();
+            // A closed channel means the consumer died; the next update respawns it with its set
+            if shard
+                .diff_tx
+                .send(SubscriptionDiff {
+                    added: diff.added,
+                    removed: diff.removed,
+                    applied: applied_tx,
+                })
+                .await
+                .is_err()
+            {
+                warn!("Shard {} consumer stopped", shard.id);
+            }
+            acks.push((shard.id, snapshot, applied_rx));
+        }
+
+        let deadline = tokio::time::Instant::now() + ACK_TIMEOUT;
+        let waits = acks
+            .into_iter()
+            .map(|(shard_id, snapshot, mut applied_rx)| async move {
+                let acked = tokio::time::timeout_at(deadline, &mut applied_rx).await;
+                (shard_id, snapshot, applied_rx, acked)
+            });
+        let acks = join_all(waits).await;
+
+        // Snapshots wait for the shard's ack, so no write can fall between snapshot and stream
+        let mut acked_snapshots: HashMap<String, Platform> = HashMap::new();
+        let mut late_snapshots = Vec::new();
+        let mut all_acked = true;
+        let had_acks = !acks.is_empty();
+        for (shard_id, snapshot, applied_rx, acked) in acks {
+            match acked {
+                Ok(Ok(())) => acked_snapshots.extend(snapshot),
+                Ok(Err(_)) => {
+                    all_acked = false;
+                    warn!("Shard {shard_id} stopped before Geyser acknowledged the update");
+                }
+                Err(_) => {
+                    all_acked = false;
+                    warn!("Shard {shard_id} did not acknowledge the update within {ACK_TIMEOUT:?}");
+                    late_snapshots.push((snapshot, applied_rx));
+                }
+            }
+        }
+
+        let result = if !had_acks {
+            Ok(())
+        } else if all_acked {
+            let latency = started.elapsed();
+            self.metrics.record_applied(latency);
+            info!(
+                "Subscription update applied in {latency:?} across {} shards",
+                self.shards.len()
+            );
+            Ok(())
+        } else {
+            self.metrics.record_failed();
+            Err(
+                "SubscriptionManager::update_subscription::ERROR::Update not acknowledged by Geyser"
+                    .to_string(),
+            )
+        };
+
+        // Retired shards keep streaming until their accounts are live elsewhere
+        for shard in retired {
+            info!("Retiring shard {}", shard.id);
+            shard.cancel_token.cancel();
+            let _ = shard.handle.await;
+        }
+
+        if let Some(fetcher) = &self.snapshot_fetcher {
+            if !acked_snapshots.is_empty() {
+                tokio::spawn(emit_snapshots(
+                    fetcher.clone(),
+                    acked_snapshots,
+                    self.event_tx.clone(),
+                    self.cache.clone(),
+                ));
+            }
+
+            // A late ack still gets its snapshot; accounts of a shard that stopped wait for a write
+            for (snapshot, applied_rx) in late_snapshots {
+                if snapshot.is_empty() {
+                    continue;
+                }
+                let fetcher = fetcher.clone();
+                let event_tx = self.event_tx.clone();
+                let cache = self.cache.clone();
+                tokio::spawn(async move {
+                    if applied_rx.await.is_ok() {
+                        emit_snapshots(fetcher, snapshot, event_tx, cache).await;
+                    }
+                });
+            }
+        }
+
+        // Persist only what was requested, and only when it changed, so a restart resumes it
+        if tracked_accounts != self.persisted_accounts {
+            let removed: Vec<String> = self
+                .persisted_accounts
+                .keys()
+                .filter(|account| !tracked_accounts.contains_key(*account))
+                .cloned()
+                .collect();
+            let upserted: HashMap<String, Platform> = tracked_accounts
+                .iter()
+                .filter(|(account, platform)| {
+                    self.persisted_accounts.get(*account) != Some(platform)
+                })
+                .map(|(account, platform)| (account.clone(), *platform))
+                .collect();
+
+            match save_subscription_changes(&mut self.cache, &upserted, &removed).await {
+                Ok(()) => self.persisted_accounts = tracked_accounts,
+                Err(e) => warn!("Failed to persist subscriptions: {e}"),
+            }
+        }
+
+        result
+    }
 
-        let new_cancel_token = CancellationToken::new();
+    fn shard_with_room(&self) -> Option<usize> {
+        self.shards
+            .iter()
+            .position(|s| s.accounts.len() < self.shard_size)
+    }
+
+    /// Start a consumer streaming `accounts` and return its index in `shards`.
+    fn open_shard(&mut self, accounts: HashMap<String, Platform>) -> usize {
+        let shard = self.spawn_shard(accounts, None);
+        self.shards.push(shard);
+
+        self.shards.len() - 1
+    }
+
+    fn start_program_shard(&mut self) {
+        if let Some(program_wide) = self.program_wide.clone() {
+            let owners: Vec<&str> = program_wide
+                .filters
+                .iter()
+                .map(|f| f.owner.as_str())
+                .collect();
+            info!(
+                "Program-wide tracking of {owners:?} accounts, coalesced every {:?}",
+                program_wide.coalesce_window
+            );
+            self.program_shard = Some(self.spawn_shard(HashMap::new(), Some(program_wide)));
+        }
+    }
+
+    fn spawn_shard(
+        &mut self,
+        accounts: HashMap<String, Platform>,
+        program_wide: Option<ProgramWideConfig>,
+    ) -> Shard {
+        let id = self.next_shard_id;
+        self.next_shard_id += 1;
+
+        let (diff_tx, diff_rx) = mpsc::channel(32);
+        let cancel_token = CancellationToken::new();
+        let health = Arc::new(ShardHealth::default());
         let consumer = AccountConsumer {
             geyser_url: self.geyser_url.clone(),
             geyser_token: self.geyser_token.clone(),
             event_tx: self.event_tx.clone(),
-            tracked_accounts,
+            cache: self.cache.clone(),
+            tracked_accounts: accounts.clone(),
+            diff_rx,
+            shard_id: id,
+            health: health.clone(),
+            program_filters: program_wide
+                .as_ref()
+                .map(|p| p.filters.clone())
+                .unwrap_or_default(),
+            coalesce_window: program_wide.map(|p| p.coalesce_window),
+            ps_pools: PsPoolTracker::default(),
+            versions: self.versions.clone(),
+            snapshot_fetcher: self.snapshot_fetcher.clone(),
         };
 
-        let (new_handle, stability_rx) = consumer.spawn(new_cancel_token.clone());
+        info!("Opening shard {id}");
+        let handle = consumer.spawn(cancel_token.clone());
+        Shard {
+            id,
+            accounts,
+            handle,
+            cancel_token,
+            diff_tx,
+            health,
+        }
+    }
 
-        match tokio::time::timeout(Duration::from_secs(10), stability_rx).await {
-            Ok(_) => {
-                info!("New subscription stable, shutting down old subscription");
+    /// Consumers only exit when cancelled; anything else (a panic) gets a fresh stream.
+    fn respawn_stopped_shards(&mut self) {
+        let stopped: Vec<usize> = self
+            .shards
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.handle.is_finished())
+            .map(|(idx, _)| idx)
+            .collect();
 
-                if let Some((old_handle, old_token)) = self.current_task.take() {
-                    old_token.cancel();
-                    let _ = old_handle.await;
-                }
+        for idx in stopped.into_iter().rev() {
+            let shard = self.shards.remove(idx);
+            error!("Shard {} consumer stopped, respawning", shard.id);
+            self.open_shard(shard.accounts);
+        }
 
-                self.current_task = Some((new_handle, new_cancel_token));
-                info!("Subscription swap complete");
-                Ok(())
-            }
-            // TODO re-review the error handling. Make it more insigthful
-            Err(_) => {
-                info!("New subscription failed to stabilize, cancelling");
-                new_cancel_token.cancel();
-                let _ = new_handle.await;
-                Err("Failed to reach stability".to_string())
+        if let Some(shard) = &self.program_shard
+            && shard.handle.is_finished()
+        {
+            error!(
+                "Program-wide shard {} consumer stopped, respawning",
+                shard.id
+            );
+            self.start_program_shard();
+        }
+    }
+
+    fn report_health(&self) {
+        for health in self.shard_health() {
+            if health.connected {
+                info!(
+                    "Shard {}: {} accounts, {} updates, {} reconnects, last update {:?}",
+                    health.shard_id,
+                    health.accounts,
+                    health.updates_received,
+                    health.reconnects,
+                    health.last_update_at
+                );
+            } else {
+                warn!(
+                    "Shard {} disconnected: {} accounts, {} reconnects",
+                    health.shard_id, health.accounts, health.reconnects
+                );
             }
         }
     }
 
+    fn shard_health(&self) -> Vec<ShardHealthSnapshot> {
+        self.shards
+            .iter()
+            .chain(self.program_shard.as_ref())
+            .map(|s| s.health.snapshot(s.id, s.accounts.len()))
+            .collect()
+    }
+
+    fn tracked_accounts(&self) -> Vec<TrackedAccountSnapshot> {
+        self.shards
+            .iter()
+            .flat_map(|shard| {
+                shard.accounts.iter().map(|(account, platform)| {
+                    let stats = self.versions.stats(account);
+                    TrackedAccountSnapshot {
+                        account: account.clone(),
+                        platform: *platform,
+                        shard_id: shard.id,
+                        pinned: self.pinned_accounts.contains_key(account),
+                        last_update_slot: stats.map(|s| s.last_slot),
+                        updates_received: stats.map_or(0, |s| s.updates),
+                        updates_per_min: stats.map_or(0.0, |s| s.updates_per_min),
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Tear down every stream and open fresh ones on the same accounts.
+    async fn resubscribe(&mut self) {
+        info!("Resubscribing {} shards", self.shards.len());
+
+        let shards = std::mem::take(&mut self.shards);
+        for shard in shards {
+            shard.cancel_token.cancel();
+            let _ = shard.handle.await;
+            self.open_shard(shard.accounts);
+        }
+
+        if let Some(shard) = self.program_shard.take() {
+            shard.cancel_token.cancel();
+            let _ = shard.handle.await;
+            self.start_program_shard();
+        }
+    }
+
     async fn shutdown(&mut self) {
-        if let Some((handle, token)) = self.current_task.take() {
-            info!("Shutting down subscription");
-            token.cancel();
-            let _ = handle.await;
+        info!("Shutting down {} subscription shards", self.shards.len());
+        let program_shard = self.program_shard.take();
+        for shard in self.shards.drain(..).chain(program_shard) {
+            shard.cancel_token.cancel();
+            let _ = shard.handle.await;
         }
     }
 }
@@ -121,9 +607,14 @@ impl SubscriptionManager {
 #[derive(Clone)]
 pub struct SubscriptionManagerHandle {
     cmd_tx: mpsc::Sender<SubscriptionCommand>,
+    metrics: Arc<SubscriptionMetrics>,
 }
 
 impl SubscriptionManagerHandle {
+    pub fn metrics(&self) -> Arc<SubscriptionMetrics> {
+        self.metrics.clone()
+    }
+
     pub async fn update_subscription(
         &self,
         tracked_accounts: HashMap<String, Platform>,
@@ -147,6 +638,59 @@ impl SubscriptionManagerHandle {
         })?
     }
 
+    pub async fn shard_health(&self) -> Result<Vec<ShardHealthSnapshot>, String> {
+        let (response_tx, response_rx) = oneshot::channel();
+
+        self.cmd_tx
+            .send(SubscriptionCommand::Health {
+                response: response_tx,
+            })
+            .await
+            .map_err(|_| {
+                "SubscriptionManagerHandle::shard_health::ERROR::Manager disconnected".to_string()
+            })?;
+
+        response_rx.await.map_err(|_| {
+            "SubscriptionManagerHandle::shard_health::ERROR::Response channel closed".to_string()
+        })
+    }
+
+    pub async fn tracked_accounts(&self) -> Result<Vec<TrackedAccountSnapshot>, String> {
+        let (response_tx, response_rx) = oneshot::channel();
+
+        self.cmd_tx
+            .send(SubscriptionCommand::Accounts {
+                response: response_tx,
+            })
+            .await
+            .map_err(|_| {
+                "SubscriptionManagerHandle::tracked_accounts::ERROR::Manager disconnected"
+                    .to_string()
+            })?;
+
+        response_rx.await.map_err(|_| {
+            "SubscriptionManagerHandle::tracked_accounts::ERROR::Response channel closed"
+                .to_string()
+        })
+    }
+
+    pub async fn resubscribe(&self) -> Result<(), String> {
+        let (response_tx, response_rx) = oneshot::channel();
+
+        self.cmd_tx
+            .send(SubscriptionCommand::Resubscribe {
+                response: response_tx,
+            })
+            .await
+            .map_err(|_| {
+                "SubscriptionManagerHandle::resubscribe::ERROR::Manager disconnected".to_string()
+            })?;
+
+        response_rx.await.map_err(|_| {
+            "SubscriptionManagerHandle::resubscribe::ERROR::Response channel closed".to_string()
+        })
+    }
+
     pub async fn shutdown(&self) -> Result<(), String> {
         self.cmd_tx
             .send(SubscriptionCommand::Shutdown)
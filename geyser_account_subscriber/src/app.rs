@@ -0,0 +1,68 @@
+use crate::routes::v1;
+use crate::state::AppState;
+use axum::extract::{Request, State};
+use axum::http::header::AUTHORIZATION;
+use axum::http::{HeaderMap, Method, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::{Router, serve};
+use tokio::net::TcpListener;
+
+pub fn app(state: AppState) -> Router {
+    Router::new()
+        .nest("/v1", v1::routes())
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_admin_token,
+        ))
+        .with_state(state)
+}
+
+/// Admin API for operators. Without `ADMIN_TOKEN` it only listens on a loopback address.
+pub async fn server(state: AppState) {
+    let addr = state.config.admin_bind_addr;
+    let port = state.config.admin_port;
+    if !addr.is_loopback() && state.config.admin_token.is_none() {
+        panic!("ADMIN_TOKEN must be set to serve the admin API on {addr}");
+    }
+
+    let listener = TcpListener::bind((addr, port))
+        .await
+        .unwrap_or_else(|e| panic!("Cannot bind {addr}:{port}: {e}"));
+    serve(listener, app(state)).await.expect("Cannot serve");
+}
+
+async fn require_admin_token(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let token = state.config.admin_token.as_deref();
+    if !is_authorized(request.method(), request.headers(), token) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    next.run(request).await
+}
+
+/// Reads are open; anything else (lease and subscription changes) needs
+/// `Authorization: Bearer <token>` once a token is configured.
+pub fn is_authorized(method: &Method, headers: &HeaderMap, token: Option<&str>) -> bool {
+    let Some(token) = token else {
+        return true;
+    };
+    if matches!(*method, Method::GET | Method::HEAD) {
+        return true;
+    }
+
+    headers
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .is_some_and(|presented| constant_time_eq(presented.as_bytes(), token.as_bytes()))
+}
+
+/// Compare without returning early, so response time does not reveal the matching prefix
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -1,10 +1,16 @@
 use crate::config::AppConfig;
 use crate::models::enums::Platform;
-use log::{error, info};
-use redis::AsyncCommands;
+use crate::models::subscription::SubscriptionEntry;
+use chrono::Utc;
+use event_contract::leases::{
+    LEASE_PLATFORMS_KEY, LEASES_KEY, decode_lease_platform, encode_lease_platform, lease_member,
+};
+use log::{error, info, warn};
 use redis::Client;
 use redis::aio::ConnectionManager;
+use redis::{AsyncCommands, Script};
 use std::collections::HashMap;
+use std::time::Duration;
 
 pub async fn init_cache(config: AppConfig) -> Result<ConnectionManager, redis::RedisError> {
     let client = Client::open(config.redis_url).map_err(|e| {
@@ -19,24 +25,239 @@ pub async fn init_cache(config: AppConfig) -> Result<ConnectionManager, redis::R
     Ok(conn)
 }
 
-pub async fn get_subscriptions(
+/// Redis hash of tracked account -> `SubscriptionEntry` (JSON). Leases decide what is
+/// subscribed; this only mirrors the last applied set, so a restart resumes streaming
+/// before the first lease sweep.
+pub const SUBSCRIPTIONS_KEY: &str = "subscriptions:accounts";
+
+/// Load the subscription registry. Entries that fail to decode are skipped.
+pub async fn load_subscriptions(
+    conn: &mut ConnectionManager,
+) -> redis::RedisResult<HashMap<String, SubscriptionEntry>> {
+    let raw: HashMap<String, String> = conn.hgetall(SUBSCRIPTIONS_KEY).await?;
+
+    let entries = raw
+        .into_iter()
+        .filter_map(
+            |(account, value)| match serde_json::from_str::<SubscriptionEntry>(&value) {
+                Ok(entry) => Some((account, entry)),
+                Err(e) => {
+                    warn!("load_subscriptions: skipping {account}: {e}");
+                    None
+                }
+            },
+        )
+        .collect();
+
+    Ok(entries)
+}
+
+/// Apply a change to the registry: write `upserted` and drop `removed`, keeping `added_at`
+/// of accounts already tracked. Untouched entries are left alone.
+pub async fn save_subscription_changes(
+    conn: &mut ConnectionManager,
+    upserted: &HashMap<String, Platform>,
+    removed: &[String],
+) -> redis::RedisResult<()> {
+    if upserted.is_empty() && removed.is_empty() {
+        return Ok(());
+    }
+
+    let accounts: Vec<&String> = upserted.keys().collect();
+    let existing: Vec<Option<String>> = if accounts.is_empty() {
+        Vec::new()
+    } else {
+        redis::cmd("HMGET")
+            .arg(SUBSCRIPTIONS_KEY)
+            .arg(&accounts)
+            .query_async(conn)
+            .await?
+    };
+    let now = Utc::now();
+
+    let mut fields = Vec::with_capacity(upserted.len());
+    for (account, existing) in accounts.into_iter().zip(existing) {
+        let added_at = existing
+            .and_then(|value| serde_json::from_str::<SubscriptionEntry>(&value).ok())
+            .map(|entry| entry.added_at)
+            .unwrap_or(now);
+        let entry = SubscriptionEntry {
+            platform: upserted[account],
+            added_at,
+            updated_at: now,
+        };
+        let value = serde_json::to_string(&entry).map_err(|e| {
+            redis::RedisError::from((
+                redis::ErrorKind::TypeError,
+                "failed to serialize SubscriptionEntry",
+                e.to_string(),
+            ))
+        })?;
+        fields.push((account.clone(), value));
+    }
+
+    let mut pipe = redis::pipe();
+    pipe.atomic();
+    if !removed.is_empty() {
+        pipe.hdel(SUBSCRIPTIONS_KEY, removed).ignore();
+    }
+    if !fields.is_empty() {
+        pipe.hset_multiple(SUBSCRIPTIONS_KEY, &fields).ignore();
+    }
+    pipe.query_async::<()>(conn).await
+}
+
+// Completed curves are migrated within minutes; a week easily outlives any replay
+const COMPLETED_CURVE_TTL_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Take or renew `holder`'s lease on every account for `ttl`.
+pub async fn acquire_leases(
+    conn: &mut ConnectionManager,
+    holder: &str,
+    accounts: &HashMap<String, Platform>,
+    ttl: Duration,
+) -> redis::RedisResult<()> {
+    let expires_at = Utc::now().timestamp_millis() + ttl.as_millis() as i64;
+    set_leases(conn, holder, accounts, expires_at).await
+}
+
+/// Lease that never expires, held until `release_leases` (operator-added accounts).
+pub async fn acquire_permanent_leases(
+    conn: &mut ConnectionManager,
+    holder: &str,
+    accounts: &HashMap<String, Platform>,
+) -> redis::RedisResult<()> {
+    set_leases(conn, holder, accounts, "+inf").await
+}
+
+async fn set_leases<S: redis::ToRedisArgs + Copy>(
+    conn: &mut ConnectionManager,
+    holder: &str,
+    accounts: &HashMap<String, Platform>,
+    expires_at: S,
+) -> redis::RedisResult<()> {
+    if accounts.is_empty() {
+        return Ok(());
+    }
+
+    let mut pipe = redis::pipe();
+    for (account, platform) in accounts {
+        let platform = encode_lease_platform(*platform).map_err(|e| {
+            redis::RedisError::from((
+                redis::ErrorKind::TypeError,
+                "failed to serialize Platform",
+                e.to_string(),
+            ))
+        })?;
+        pipe.zadd(LEASES_KEY, lease_member(account, holder), expires_at)
+            .ignore()
+            .hset(LEASE_PLATFORMS_KEY, account, platform)
+            .ignore();
+    }
+    pipe.query_async::<()>(conn).await
+}
+
+pub async fn release_leases(
+    conn: &mut ConnectionManager,
+    holder: &str,
+    accounts: &[String],
+) -> redis::RedisResult<()> {
+    if accounts.is_empty() {
+        return Ok(());
+    }
+
+    let members: Vec<String> = accounts
+        .iter()
+        .map(|account| lease_member(account, holder))
+        .collect();
+    conn.zrem(LEASES_KEY, members).await
+}
+
+/// Drop every holder's lease on `account`, e.g. to force it out of the subscription.
+pub async fn release_all_leases(
+    conn: &mut ConnectionManager,
+    account: &str,
+) -> redis::RedisResult<()> {
+    let prefix = lease_member(account, "");
+    let members: Vec<String> = conn.zrange(LEASES_KEY, 0, -1).await?;
+    let held: Vec<String> = members
+        .into_iter()
+        .filter(|member| member.starts_with(&prefix))
+        .collect();
+
+    if held.is_empty() {
+        return Ok(());
+    }
+    conn.zrem(LEASES_KEY, held).await
+}
+
+/// Drop leases expiring at or before `ARGV[1]` from `KEYS[1]` and the platforms of accounts
+/// left without a lease from `KEYS[2]`, then return `[account, platform, ...]` of the leased
+/// ones. One script, so an acquire cannot land between the check and the cleanup.
+const SWEEP_LEASES_SCRIPT: &str = r#"
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', ARGV[1])
+local leased = {}
+for _, member in ipairs(redis.call('ZRANGE', KEYS[1], 0, -1)) do
+    local account = string.match(member, '^(.*)|')
+    if account then
+        leased[account] = true
+    end
+end
+local accounts = {}
+local platforms = redis.call('HGETALL', KEYS[2])
+for i = 1, #platforms, 2 do
+    if leased[platforms[i]] then
+        table.insert(accounts, platforms[i])
+        table.insert(accounts, platforms[i + 1])
+    else
+        redis.call('HDEL', KEYS[2], platforms[i])
+    end
+end
+return accounts
+"#;
+
+/// Drop expired leases and return every account that still has at least one holder.
+pub async fn load_leased_accounts(
     conn: &mut ConnectionManager,
 ) -> redis::RedisResult<HashMap<String, Platform>> {
-    let keys = vec![
-        "subscriptions:by_bonding_curve",
-        "subscriptions:by_pool",
-        "subscriptions:by_pool_state",
-    ];
-
-    let mut map = HashMap::new();
-
-    for key in keys {
-        if let Ok(value) = conn.get::<_, String>(key).await {
-            if let Ok(platform) = serde_json::from_str::<Platform>(&value) {
-                map.insert(key.to_string(), platform);
+    let now = Utc::now().timestamp_millis();
+
+    let platforms: HashMap<String, String> = Script::new(SWEEP_LEASES_SCRIPT)
+        .key(LEASES_KEY)
+        .key(LEASE_PLATFORMS_KEY)
+        .arg(now)
+        .invoke_async(conn)
+        .await?;
+
+    let mut accounts = HashMap::with_capacity(platforms.len());
+    for (account, platform) in platforms {
+        match decode_lease_platform(&platform) {
+            Ok(platform) => {
+                accounts.insert(account, platform);
+            }
+            Err(e) => {
+                warn!("load_leased_accounts: skipping {account}: {e}");
             }
         }
     }
 
-    Ok(map)
+    Ok(accounts)
+}
+
+/// Claim the completion of a bonding curve; `true` only for the first caller,
+/// so every shard, stream and restart emits `PfBondingCurveCompleted` once.
+pub async fn mark_pf_bonding_curve_completed(
+    conn: &mut ConnectionManager,
+    bonding_curve: &str,
+) -> redis::RedisResult<bool> {
+    let key = format!("pf_bonding_curve_completed:{bonding_curve}");
+    let claimed: Option<String> = redis::cmd("SET")
+        .arg(&key)
+        .arg(1)
+        .arg("NX")
+        .arg("EX")
+        .arg(COMPLETED_CURVE_TTL_SECS)
+        .query_async(conn)
+        .await?;
+    Ok(claimed.is_some())
 }
@@ -1,4 +1,7 @@
+use event_contract::codec::ContentType;
+use event_contract::leases::DEFAULT_LEASE_TTL_SECS;
 use std::env;
+use std::net::{IpAddr, Ipv4Addr};
 
 #[derive(Debug, Clone)]
 pub struct AppConfig {
@@ -6,7 +9,19 @@ pub struct AppConfig {
     pub geyser_token: Option<String>,
     pub kafka_brokers: String,
     pub kafka_group_id: String,
+    pub kafka_codec: ContentType,
+    pub schema_registry_url: Option<String>,
     pub redis_url: String,
+    pub subscription_shard_size: usize,
+    pub lease_ttl_secs: u64,
+    pub lease_sweep_interval_secs: u64,
+    pub program_wide_tracking: bool,
+    pub coalesce_window_ms: u64,
+    pub rpc_http_url: Option<String>,
+    pub admin_port: u16,
+    pub admin_bind_addr: IpAddr,
+    /// Required for changes through the admin API; without it the API stays on loopback
+    pub admin_token: Option<String>,
 }
 
 impl AppConfig {
@@ -19,14 +34,68 @@ impl AppConfig {
         let kafka_brokers = env::var("KAFKA_BROKERS").unwrap_or_else(|_| "kafka:9092".to_string());
         let kafka_group_id =
             env::var("KAFKA_GROUP_ID").unwrap_or_else(|_| "pump_data_group".to_string());
+        let kafka_codec = env::var("KAFKA_CODEC")
+            .map(|v| v.parse().expect("KAFKA_CODEC must be json or protobuf"))
+            .unwrap_or_default();
+        let schema_registry_url = env::var("SCHEMA_REGISTRY_URL").ok();
         let redis_url = env::var("REDIS_URL").expect("REDIS_URL must be set in .env");
+        let subscription_shard_size = env::var("SUBSCRIPTION_SHARD_SIZE")
+            .map(|v| {
+                v.parse()
+                    .expect("SUBSCRIPTION_SHARD_SIZE must be a positive integer")
+            })
+            .unwrap_or(500);
+        let lease_ttl_secs = env::var("LEASE_TTL_SECS")
+            .map(|v| {
+                v.parse()
+                    .expect("LEASE_TTL_SECS must be a positive integer")
+            })
+            .unwrap_or(DEFAULT_LEASE_TTL_SECS);
+        let lease_sweep_interval_secs = env::var("LEASE_SWEEP_INTERVAL_SECS")
+            .map(|v| {
+                v.parse()
+                    .expect("LEASE_SWEEP_INTERVAL_SECS must be a positive integer")
+            })
+            .unwrap_or(5);
+        let program_wide_tracking = env::var("PROGRAM_WIDE_TRACKING")
+            .map(|v| {
+                v.parse()
+                    .expect("PROGRAM_WIDE_TRACKING must be true or false")
+            })
+            .unwrap_or(false);
+        let coalesce_window_ms = env::var("COALESCE_WINDOW_MS")
+            .map(|v| {
+                v.parse()
+                    .expect("COALESCE_WINDOW_MS must be a positive integer")
+            })
+            .unwrap_or(250);
+        // Snapshots of newly subscribed accounts are only taken with an RPC endpoint
+        let rpc_http_url = env::var("RPC_HTTP_URL").ok();
+        let admin_port = env::var("ADMIN_PORT")
+            .map(|v| v.parse().expect("ADMIN_PORT must be a port number"))
+            .unwrap_or(8004);
+        let admin_bind_addr = env::var("ADMIN_BIND_ADDR")
+            .map(|v| v.parse().expect("ADMIN_BIND_ADDR must be an IP address"))
+            .unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        let admin_token = env::var("ADMIN_TOKEN").ok().filter(|t| !t.is_empty());
 
         Self {
             geyser_url,
             geyser_token: Some(geyser_token),
             kafka_brokers,
             kafka_group_id,
+            kafka_codec,
+            schema_registry_url,
             redis_url,
+            subscription_shard_size,
+            lease_ttl_secs,
+            lease_sweep_interval_secs,
+            program_wide_tracking,
+            coalesce_window_ms,
+            rpc_http_url,
+            admin_port,
+            admin_bind_addr,
+            admin_token,
         }
     }
 
@@ -0,0 +1,139 @@
+use crate::cache::{acquire_permanent_leases, release_all_leases, release_leases};
+use crate::leases::{ADMIN_HOLDER, sync_leased_subscriptions};
+use crate::models::kafka_req::KReq;
+use crate::state::AppState;
+use axum::http::StatusCode;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    response::IntoResponse,
+};
+use log::{error, info};
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+pub struct RemoveAccountParams {
+    /// Also drop leases held by clients and `price_req`, not just the admin one
+    #[serde(default)]
+    pub force: bool,
+}
+
+pub async fn list_accounts(State(state): State<AppState>) -> impl IntoResponse {
+    match state.subscription_handle.tracked_accounts().await {
+        Ok(accounts) => (
+            StatusCode::OK,
+            Json(json!({ "count": accounts.len(), "accounts": accounts })),
+        ),
+        Err(e) => {
+            error!("list_accounts: {e}");
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(json!({ "error": "subscription manager unavailable" })),
+            )
+        }
+    }
+}
+
+/// Watch accounts until they are removed again; body is a `KReq`.
+pub async fn add_accounts(
+    State(state): State<AppState>,
+    Json(kreq): Json<KReq>,
+) -> impl IntoResponse {
+    if let Err(e) = acquire_permanent_leases(
+        &mut state.cache.clone(),
+        ADMIN_HOLDER,
+        &kreq.tracked_accounts,
+    )
+    .await
+    {
+        error!("add_accounts: Redis error: {e}");
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": "internal error" })),
+        );
+    }
+
+    info!("Admin added {} accounts", kreq.tracked_accounts.len());
+    sync(
+        &state,
+        json!({ "added": kreq.tracked_accounts.keys().collect::<Vec<_>>() }),
+    )
+    .await
+}
+
+pub async fn remove_account(
+    State(state): State<AppState>,
+    Path(account): Path<String>,
+    Query(params): Query<RemoveAccountParams>,
+) -> impl IntoResponse {
+    let mut conn = state.cache.clone();
+    let released = if params.force {
+        release_all_leases(&mut conn, &account).await
+    } else {
+        release_leases(&mut conn, ADMIN_HOLDER, std::slice::from_ref(&account)).await
+    };
+
+    if let Err(e) = released {
+        error!("remove_account: Redis error: {e}");
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "account": account, "error": "internal error" })),
+        );
+    }
+
+    info!("Admin removed {account} (force: {})", params.force);
+    sync(&state, json!({ "removed": account, "force": params.force })).await
+}
+
+pub async fn resubscribe(State(state): State<AppState>) -> impl IntoResponse {
+    match state.subscription_handle.resubscribe().await {
+        Ok(()) => (StatusCode::OK, Json(json!({ "resubscribed": true }))),
+        Err(e) => {
+            error!("resubscribe: {e}");
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(json!({ "error": "subscription manager unavailable" })),
+            )
+        }
+    }
+}
+
+pub async fn get_status(State(state): State<AppState>) -> impl IntoResponse {
+    let metrics = state.subscription_handle.metrics().snapshot();
+
+    match state.subscription_handle.shard_health().await {
+        Ok(shards) => (
+            StatusCode::OK,
+            Json(json!({
+                "connected": shards.iter().all(|s| s.connected),
+                "shards": shards,
+                "subscription": metrics,
+            })),
+        ),
+        Err(e) => {
+            error!("get_status: {e}");
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(
+                    json!({ "error": "subscription manager unavailable", "subscription": metrics }),
+                ),
+            )
+        }
+    }
+}
+
+/// Apply lease changes right away instead of waiting for the sweeper.
+async fn sync(state: &AppState, body: serde_json::Value) -> (StatusCode, Json<serde_json::Value>) {
+    match sync_leased_subscriptions(state).await {
+        Ok(()) => (StatusCode::OK, Json(body)),
+        Err(e) => {
+            error!("Failed to apply admin change: {e}");
+            // The lease is stored; the sweeper retries the subscription update
+            (
+                StatusCode::ACCEPTED,
+                Json(json!({ "result": body, "error": e })),
+            )
+        }
+    }
+}
@@ -1,4 +1,7 @@
-mod pf_price_update_handler;
+pub mod admin_handler;
+pub mod pf_price_update_handler;
+pub mod pf_protocol_params_update_handler;
+pub mod pf_volume_accumulator_update_handler;
 pub mod price_update_handler;
-mod ps_price_update_handler;
+pub mod ps_price_update_handler;
 mod rll_price_update_handler;
@@ -1,48 +1,116 @@
-use crate::models::kafka_event::{KEvent, KEventData, KEventType, PriceSource};
-use crate::models::pump_models::pf_kafka_event::KPfPrice;
+use crate::cache::mark_pf_bonding_curve_completed;
+use crate::models::kafka_event::{EventSource, KEvent, KEventData, PriceSource};
+use crate::models::pump_models::pf_kafka_event::{KPfBondingCurveCompleted, KPfPrice};
 use chrono::Utc;
+use log::error;
+use redis::aio::ConnectionManager;
 use serde::{Deserialize, Serialize};
 use solana_sdk::bs58;
 use yellowstone_grpc_proto::prelude::SubscribeUpdateAccountInfo;
 
+/// Anchor discriminator of the `BondingCurve` account
+pub const BONDING_CURVE_DISC: [u8; 8] = [23, 183, 248, 55, 96, 216, 172, 96];
+
+// Curves grew over time: creator was appended with creator fees, is_mayhem_mode after it
+const COMPLETE_OFFSET: usize = 48;
+const CREATOR_OFFSET: usize = 49;
+const MAYHEM_OFFSET: usize = 81;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct BondingCurveData {
     pub virtual_token_reserves: u64,
     pub virtual_sol_reserves: u64,
     pub real_token_reserves: u64,
     pub real_sol_reserves: u64,
+    pub token_total_supply: u64,
+    pub complete: bool,
+    pub creator: Option<String>,
+    pub is_mayhem_mode: bool,
 }
 
-pub fn handle_pf_price_update(acc_info: &SubscribeUpdateAccountInfo) -> Result<KEvent, String> {
+pub async fn handle_pf_price_update(
+    acc_info: &SubscribeUpdateAccountInfo,
+    slot: u64,
+    cache: &mut ConnectionManager,
+) -> Result<Vec<KEvent>, String> {
     let curve = parse_bonding_curve(acc_info).ok_or("Failed to parse bonding curve")?;
     let bonding_curve = bs58::encode(&acc_info.pubkey).into_string();
+    let ts = Utc::now();
+
+    let mut events = Vec::with_capacity(2);
+
+    if curve.complete {
+        match mark_pf_bonding_curve_completed(cache, &bonding_curve).await {
+            Ok(true) => {
+                let completed = KPfBondingCurveCompleted {
+                    bonding_curve: bonding_curve.clone(),
+                    creator: curve.creator.clone(),
+                    token_total_supply: curve.token_total_supply,
+                    virtual_token_reserves: curve.virtual_token_reserves,
+                    virtual_sol_reserves: curve.virtual_sol_reserves,
+                    real_sol_reserves: curve.real_sol_reserves,
+                    is_mayhem_mode: curve.is_mayhem_mode,
+                    ts,
+                };
+                events.push(KEvent::new(
+                    EventSource::GeyserAccountSubscriber,
+                    slot,
+                    KEventData::PfBondingCurveCompleted(completed),
+                ));
+            }
+            Ok(false) => {}
+            // The price update still goes out; completion is retried on the next write
+            Err(e) => {
+                error!("handle_pf_price_update: failed to mark {bonding_curve} completed: {e}");
+            }
+        }
+    }
+
     let k_pf_price: KPfPrice = KPfPrice {
         bonding_curve,
         source: PriceSource::PumpFunBondingCurve,
-        ts: Utc::now(),
+        ts,
         virtual_token_reserves: curve.virtual_token_reserves,
         virtual_sol_reserves: curve.virtual_sol_reserves,
         real_token_reserves: curve.real_token_reserves,
         real_sol_reserves: curve.real_sol_reserves,
+        token_total_supply: curve.token_total_supply,
+        complete: curve.complete,
+        creator: curve.creator,
+        is_mayhem_mode: curve.is_mayhem_mode,
+        slot,
+        write_version: acc_info.write_version,
     };
 
-    Ok(KEvent {
-        event_type: KEventType::PfPriceUpdated,
-        data: KEventData::PfPriceUpdated(k_pf_price),
-    })
+    events.push(KEvent::new(
+        EventSource::GeyserAccountSubscriber,
+        slot,
+        KEventData::PfPriceUpdated(k_pf_price),
+    ));
+
+    Ok(events)
 }
 
 fn parse_bonding_curve(account_info: &SubscribeUpdateAccountInfo) -> Option<BondingCurveData> {
-    if account_info.data.len() < 40 {
+    let data = &account_info.data;
+
+    if !data.starts_with(&BONDING_CURVE_DISC) || data.len() <= COMPLETE_OFFSET {
         return None;
     }
 
-    let data = &account_info.data;
+    let creator = data
+        .get(CREATOR_OFFSET..CREATOR_OFFSET + 32)
+        .filter(|bytes| bytes.iter().any(|b| *b != 0))
+        .map(|bytes| bs58::encode(bytes).into_string());
 
     Some(BondingCurveData {
         virtual_token_reserves: u64::from_le_bytes(data[8..16].try_into().ok()?),
         virtual_sol_reserves: u64::from_le_bytes(data[16..24].try_into().ok()?),
         real_token_reserves: u64::from_le_bytes(data[24..32].try_into().ok()?),
         real_sol_reserves: u64::from_le_bytes(data[32..40].try_into().ok()?),
+        token_total_supply: u64::from_le_bytes(data[40..48].try_into().ok()?),
+        complete: data[COMPLETE_OFFSET] != 0,
+        creator,
+        is_mayhem_mode: data.get(MAYHEM_OFFSET).is_some_and(|b| *b != 0),
     })
 }
@@ -0,0 +1,125 @@
+use crate::models::kafka_event::{EventSource, KEvent, KEventData};
+use crate::models::pump_models::pf_kafka_event::{
+    KPfFeeConfig, KPfFeeTier, KPfFees, KPfGlobalConfig,
+};
+use chrono::Utc;
+use solana_sdk::bs58;
+use yellowstone_grpc_proto::prelude::SubscribeUpdateAccountInfo;
+
+/// `sha256("account:Global")[..8]`
+const GLOBAL_DISC: [u8; 8] = [167, 232, 232, 177, 200, 108, 114, 127];
+/// `sha256("account:FeeConfig")[..8]`
+const FEE_CONFIG_DISC: [u8; 8] = [143, 52, 146, 187, 219, 123, 76, 155];
+
+/// Pump.fun `Global` PDA (`["global"]`)
+pub const PF_GLOBAL: &str = "4wTV1YmiEkRvAtNtsSGPtUrqRYQMe5SKy2uB4Jjaxnjf";
+/// Pump Fees program `FeeConfig` PDA for Pump.fun (`["fee_config", pump_program_id]`)
+pub const PF_FEE_CONFIG: &str = "8Wf5TiAheLUqBrKXeYg2JtAFFMWtKdG2BSFgqUcPVwTt";
+
+const FEES_LEN: usize = 24; // 3 x u64
+const FEE_TIER_LEN: usize = 16 + FEES_LEN; // u128 threshold + Fees
+
+pub fn is_pf_protocol_params(data: &[u8]) -> bool {
+    data.starts_with(&GLOBAL_DISC) || data.starts_with(&FEE_CONFIG_DISC)
+}
+
+pub fn handle_pf_protocol_params_update(
+    acc_info: &SubscribeUpdateAccountInfo,
+    slot: u64,
+) -> Result<KEvent, String> {
+    let data = if acc_info.data.starts_with(&GLOBAL_DISC) {
+        KEventData::PfGlobalConfigUpdated(parse_global(acc_info)?)
+    } else if acc_info.data.starts_with(&FEE_CONFIG_DISC) {
+        KEventData::PfFeeConfigUpdated(parse_fee_config(acc_info)?)
+    } else {
+        return Err("handle_pf_protocol_params_update: unknown account discriminator".into());
+    };
+
+    Ok(KEvent::new(
+        EventSource::GeyserAccountSubscriber,
+        slot,
+        data,
+    ))
+}
+
+/* Global layout:
+ * disc [0..8] | initialized [8] | authority [9..41] | fee_recipient [41..73]
+ * | initial_virtual_token_reserves [73..81] | initial_virtual_sol_reserves [81..89]
+ * | initial_real_token_reserves [89..97] | token_total_supply [97..105]
+ * | fee_basis_points [105..113] | withdraw_authority [113..145] | enable_migrate [145]
+ * | pool_migration_fee [146..154] | creator_fee_basis_points [154..162] | ...
+ */
+fn parse_global(acc_info: &SubscribeUpdateAccountInfo) -> Result<KPfGlobalConfig, String> {
+    let data = &acc_info.data;
+    if data.len() < 162 {
+        return Err("parse_global: buffer too short".into());
+    }
+
+    Ok(KPfGlobalConfig {
+        global: bs58::encode(&acc_info.pubkey).into_string(),
+        fee_recipient: bs58::encode(&data[41..73]).into_string(),
+        initial_virtual_token_reserves: read_u64(data, 73),
+        initial_virtual_sol_reserves: read_u64(data, 81),
+        initial_real_token_reserves: read_u64(data, 89),
+        token_total_supply: read_u64(data, 97),
+        fee_basis_points: read_u64(data, 105),
+        enable_migrate: data[145] != 0,
+        pool_migration_fee: read_u64(data, 146),
+        creator_fee_basis_points: read_u64(data, 154),
+        ts: Utc::now(),
+    })
+}
+
+/* FeeConfig layout:
+ * disc [0..8] | bump [8] | admin [9..41] | flat_fees [41..65]
+ * | fee_tiers: u32 len [65..69], then len x (market_cap_lamports_threshold u128, fees)
+ */
+fn parse_fee_config(acc_info: &SubscribeUpdateAccountInfo) -> Result<KPfFeeConfig, String> {
+    const TIERS_OFFSET: usize = 69;
+
+    let data = &acc_info.data;
+    if data.len() < TIERS_OFFSET {
+        return Err("parse_fee_config: buffer too short".into());
+    }
+
+    let tier_count = u32::from_le_bytes([data[65], data[66], data[67], data[68]]) as usize;
+    if data.len() < TIERS_OFFSET + tier_count * FEE_TIER_LEN {
+        return Err("parse_fee_config: fee_tiers out of bounds".into());
+    }
+
+    let fee_tiers = (0..tier_count)
+        .map(|i| {
+            let o = TIERS_OFFSET + i * FEE_TIER_LEN;
+            let mut threshold = [0u8; 16];
+            threshold.copy_from_slice(&data[o..o + 16]);
+
+            KPfFeeTier {
+                market_cap_lamports_threshold: u64::try_from(u128::from_le_bytes(threshold))
+                    .unwrap_or(u64::MAX),
+                fees: read_fees(data, o + 16),
+            }
+        })
+        .collect();
+
+    Ok(KPfFeeConfig {
+        fee_config: bs58::encode(&acc_info.pubkey).into_string(),
+        flat_fees: read_fees(data, 41),
+        fee_tiers,
+        ts: Utc::now(),
+    })
+}
+
+fn read_fees(data: &[u8], offset: usize) -> KPfFees {
+    KPfFees {
+        lp_fee_bps: read_u64(data, offset),
+        protocol_fee_bps: read_u64(data, offset + 8),
+        creator_fee_bps: read_u64(data, offset + 16),
+    }
+}
+
+// Callers check the buffer length up front.
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&data[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
@@ -0,0 +1,107 @@
+use crate::models::kafka_event::{EventSource, KEvent, KEventData};
+use crate::models::pump_models::pf_kafka_event::{
+    KPfGlobalVolumeAccumulator, KPfUserVolumeAccumulator,
+};
+use chrono::Utc;
+use solana_sdk::bs58;
+use yellowstone_grpc_proto::prelude::SubscribeUpdateAccountInfo;
+
+/// `sha256("account:UserVolumeAccumulator")[..8]`
+pub const USER_VOLUME_ACCUMULATOR_DISC: [u8; 8] = [86, 255, 112, 14, 102, 53, 154, 250];
+/// `sha256("account:GlobalVolumeAccumulator")[..8]`
+const GLOBAL_VOLUME_ACCUMULATOR_DISC: [u8; 8] = [202, 42, 246, 43, 142, 190, 30, 255];
+
+/// Pump.fun `GlobalVolumeAccumulator` PDA (`["global_volume_accumulator"]`)
+pub const PF_GLOBAL_VOLUME_ACCUMULATOR: &str = "Hq2wp8uJ9jCPsYgNHex8RtqdvMPfVGoYwjvF1ATiwn2Y";
+
+/// Number of daily slots kept by the global accumulator
+const INCENTIVE_DAYS: usize = 30;
+
+pub fn is_pf_volume_accumulator(data: &[u8]) -> bool {
+    data.starts_with(&USER_VOLUME_ACCUMULATOR_DISC)
+        || data.starts_with(&GLOBAL_VOLUME_ACCUMULATOR_DISC)
+}
+
+pub fn handle_pf_volume_accumulator_update(
+    acc_info: &SubscribeUpdateAccountInfo,
+    slot: u64,
+) -> Result<KEvent, String> {
+    let data = if acc_info.data.starts_with(&USER_VOLUME_ACCUMULATOR_DISC) {
+        KEventData::PfUserVolumeAccumulatorUpdated(parse_user_volume_accumulator(acc_info)?)
+    } else if acc_info.data.starts_with(&GLOBAL_VOLUME_ACCUMULATOR_DISC) {
+        KEventData::PfGlobalVolumeAccumulatorUpdated(parse_global_volume_accumulator(acc_info)?)
+    } else {
+        return Err("handle_pf_volume_accumulator_update: unknown account discriminator".into());
+    };
+
+    Ok(KEvent::new(
+        EventSource::GeyserAccountSubscriber,
+        slot,
+        data,
+    ))
+}
+
+/* UserVolumeAccumulator layout:
+ * disc [0..8] | user [8..40] | needs_claim [40] | total_unclaimed_tokens [41..49]
+ * | total_claimed_tokens [49..57] | current_sol_volume [57..65] | last_update_timestamp [65..73]
+ */
+fn parse_user_volume_accumulator(
+    acc_info: &SubscribeUpdateAccountInfo,
+) -> Result<KPfUserVolumeAccumulator, String> {
+    let data = &acc_info.data;
+    if data.len() < 73 {
+        return Err("parse_user_volume_accumulator: buffer too short".into());
+    }
+
+    Ok(KPfUserVolumeAccumulator {
+        user_volume_accumulator: bs58::encode(&acc_info.pubkey).into_string(),
+        user: bs58::encode(&data[8..40]).into_string(),
+        needs_claim: data[40] != 0,
+        total_unclaimed_tokens: read_u64(data, 41),
+        total_claimed_tokens: read_u64(data, 49),
+        current_sol_volume: read_u64(data, 57),
+        last_update_timestamp: read_u64(data, 65) as i64,
+        ts: Utc::now(),
+    })
+}
+
+/* GlobalVolumeAccumulator layout:
+ * disc [0..8] | start_time [8..16] | end_time [16..24] | seconds_in_a_day [24..32]
+ * | mint [32..64] | total_token_supply [64..304] | sol_volumes [304..544]
+ */
+fn parse_global_volume_accumulator(
+    acc_info: &SubscribeUpdateAccountInfo,
+) -> Result<KPfGlobalVolumeAccumulator, String> {
+    const SUPPLY_OFFSET: usize = 64;
+    const VOLUMES_OFFSET: usize = SUPPLY_OFFSET + INCENTIVE_DAYS * 8;
+    const END: usize = VOLUMES_OFFSET + INCENTIVE_DAYS * 8;
+
+    let data = &acc_info.data;
+    if data.len() < END {
+        return Err("parse_global_volume_accumulator: buffer too short".into());
+    }
+
+    let read_days = |offset: usize| -> Vec<u64> {
+        (0..INCENTIVE_DAYS)
+            .map(|day| read_u64(data, offset + day * 8))
+            .collect()
+    };
+
+    Ok(KPfGlobalVolumeAccumulator {
+        global_volume_accumulator: bs58::encode(&acc_info.pubkey).into_string(),
+        start_time: read_u64(data, 8) as i64,
+        end_time: read_u64(data, 16) as i64,
+        seconds_in_a_day: read_u64(data, 24) as i64,
+        mint: bs58::encode(&data[32..64]).into_string(),
+        total_token_supply: read_days(SUPPLY_OFFSET),
+        sol_volumes: read_days(VOLUMES_OFFSET),
+        ts: Utc::now(),
+    })
+}
+
+// Callers check the buffer length up front.
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&data[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
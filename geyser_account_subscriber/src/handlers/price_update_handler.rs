@@ -1,30 +1,40 @@
 use crate::handlers::pf_price_update_handler::handle_pf_price_update;
-use crate::handlers::ps_price_update_handler::handle_ps_price_update;
+use crate::handlers::pf_protocol_params_update_handler::{
+    handle_pf_protocol_params_update, is_pf_protocol_params,
+};
+use crate::handlers::pf_volume_accumulator_update_handler::{
+    handle_pf_volume_accumulator_update, is_pf_volume_accumulator,
+};
 use crate::handlers::rll_price_update_handler::handle_rll_price_update;
 use crate::models::enums::Platform;
 use crate::models::kafka_event::KEvent;
-use solana_sdk::bs58;
+use redis::aio::ConnectionManager;
 use std::collections::HashMap;
 use yellowstone_grpc_proto::prelude::SubscribeUpdateAccountInfo;
 
 pub async fn handle_price_update(
     acc_info: &SubscribeUpdateAccountInfo,
-    tracked_accounts: HashMap<String, Platform>,
-) -> Result<KEvent, String> {
-    let identifier = bs58::encode(&acc_info.pubkey).into_string();
-    let platform = get_platform(&identifier, &tracked_accounts).await;
-
+    slot: u64,
+    platform: Option<Platform>,
+    cache: &mut ConnectionManager,
+) -> Result<Vec<KEvent>, String> {
     match platform {
-        Some(Platform::PumpFun) => handle_pf_price_update(acc_info),
-        Some(Platform::PumpSwap) => handle_ps_price_update(acc_info),
-        Some(Platform::RaydiumLaunchLab) => handle_rll_price_update(acc_info),
+        // Incentive accumulators are Pump.fun accounts too, told apart by discriminator
+        Some(Platform::PumpFun) if is_pf_volume_accumulator(&acc_info.data) => {
+            handle_pf_volume_accumulator_update(acc_info, slot).map(|e| vec![e])
+        }
+        Some(Platform::PumpFun) if is_pf_protocol_params(&acc_info.data) => {
+            handle_pf_protocol_params_update(acc_info, slot).map(|e| vec![e])
+        }
+        Some(Platform::PumpFun) => handle_pf_price_update(acc_info, slot, cache).await,
+        Some(Platform::RaydiumLaunchLab) => {
+            handle_rll_price_update(acc_info, slot).map(|e| vec![e])
+        }
+        // PumpSwap pools are priced from their vaults by the consumer's `PsPoolTracker`
         _ => Err("Unknown or unsupported platform".to_string()),
     }
 }
 
-pub async fn get_platform(
-    key: &str,
-    tracked_accounts: &HashMap<String, Platform>,
-) -> Option<Platform> {
+pub fn get_platform(key: &str, tracked_accounts: &HashMap<String, Platform>) -> Option<Platform> {
     tracked_accounts.get(key).cloned()
 }
@@ -1,42 +1,235 @@
-use crate::models::kafka_event::{KEvent, KEventData, KEventType, PriceSource};
+use crate::models::kafka_event::{EventSource, KEvent, KEventData, PriceSource};
 use crate::models::pump_models::ps_kafka_event::KPsPrice;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use solana_sdk::bs58;
+use std::collections::HashMap;
 use yellowstone_grpc_proto::prelude::SubscribeUpdateAccountInfo;
 
+/// Anchor discriminator of the PumpSwap `Pool` account
+pub const PS_POOL_DISC: [u8; 8] = [241, 154, 109, 4, 17, 177, 109, 188];
+
+// Offsets include the discriminator: pool_bump u8, index u16, creator, then the keys below
+const BASE_MINT_OFFSET: usize = 43;
+const QUOTE_MINT_OFFSET: usize = 75;
+const BASE_VAULT_OFFSET: usize = 139;
+const QUOTE_VAULT_OFFSET: usize = 171;
+const MIN_POOL_LEN: usize = QUOTE_VAULT_OFFSET + 32;
+
+// SPL token account (Token and Token-2022 share the base layout): mint, owner, amount
+const TOKEN_AMOUNT_OFFSET: usize = 64;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct PumpSwapPoolData {
-    pub token_a_reserves: u64,
-    pub token_b_reserves: u64,
+    pub base_mint: String,
+    pub quote_mint: String,
+    pub base_vault: String,
+    pub quote_vault: String,
 }
 
-pub fn handle_ps_price_update(acc_info: &SubscribeUpdateAccountInfo) -> Result<KEvent, String> {
-    let pool_data = parse_pool(acc_info).ok_or("Failed to parse pool")?;
-    let pool = bs58::encode(&acc_info.pubkey).into_string();
-    let k_ps_price: KPsPrice = KPsPrice {
-        pool,
-        source: PriceSource::PumpSwapPool,
-        ts: Utc::now(),
-        token_a_reserves: pool_data.token_a_reserves,
-        token_b_reserves: pool_data.token_b_reserves,
-    };
-
-    Ok(KEvent {
-        event_type: KEventType::PsPriceUpdated,
-        data: KEventData::PsPriceUpdated(k_ps_price),
-    })
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum VaultSide {
+    Base,
+    Quote,
+}
+
+#[derive(Debug)]
+struct TrackedPool {
+    pool: PumpSwapPoolData,
+    base_reserves: Option<u64>,
+    base_slot: u64,
+    base_write_version: u64,
+    quote_reserves: Option<u64>,
+    quote_slot: u64,
+    quote_write_version: u64,
+    /// Slot of the latest vault write not yet published
+    dirty_slot: Option<u64>,
+}
+
+/// PumpSwap reserves live in the pool's vault token accounts, not in the pool account.
+/// Pools are decoded to learn their vaults, which the consumer then watches as well;
+/// vault balances are joined back into one `PsPriceUpdated` per pool and slot.
+#[derive(Debug, Default)]
+pub struct PsPoolTracker {
+    pools: HashMap<String, TrackedPool>,
+    vaults: HashMap<String, (String, VaultSide)>,
+}
+
+impl PsPoolTracker {
+    /// Register (or refresh) a pool from its account data.
+    /// Returns whether the vault set changed, i.e. the Geyser filter must be resent.
+    pub fn track_pool(
+        &mut self,
+        pool: &str,
+        acc_info: &SubscribeUpdateAccountInfo,
+    ) -> Result<bool, String> {
+        let data = parse_pool(acc_info).ok_or("Failed to parse pool")?;
+
+        if let Some(tracked) = self.pools.get(pool)
+            && tracked.pool.base_vault == data.base_vault
+            && tracked.pool.quote_vault == data.quote_vault
+        {
+            return Ok(false);
+        }
+
+        self.untrack_pool(pool);
+        self.vaults
+            .insert(data.base_vault.clone(), (pool.to_string(), VaultSide::Base));
+        self.vaults.insert(
+            data.quote_vault.clone(),
+            (pool.to_string(), VaultSide::Quote),
+        );
+        self.pools.insert(
+            pool.to_string(),
+            TrackedPool {
+                pool: data,
+                base_reserves: None,
+                base_slot: 0,
+                base_write_version: 0,
+                quote_reserves: None,
+                quote_slot: 0,
+                quote_write_version: 0,
+                dirty_slot: None,
+            },
+        );
+
+        Ok(true)
+    }
+
+    /// Forget a pool and its vaults. Returns whether it was tracked.
+    pub fn untrack_pool(&mut self, pool: &str) -> bool {
+        let Some(tracked) = self.pools.remove(pool) else {
+            return false;
+        };
+        self.vaults.remove(&tracked.pool.base_vault);
+        self.vaults.remove(&tracked.pool.quote_vault);
+
+        true
+    }
+
+    pub fn is_pool(&self, account: &str) -> bool {
+        self.pools.contains_key(account)
+    }
+
+    pub fn is_vault(&self, account: &str) -> bool {
+        self.vaults.contains_key(account)
+    }
+
+    pub fn vault_accounts(&self) -> impl Iterator<Item = &String> {
+        self.vaults.keys()
+    }
+
+    pub fn has_pending(&self) -> bool {
+        self.pools.values().any(|p| p.dirty_slot.is_some())
+    }
+
+    /// Record a vault balance. Nothing is published yet: a swap writes both vaults,
+    /// possibly several times within a slot, so the pool is flushed once the slot is over.
+    pub fn on_vault_update(
+        &mut self,
+        vault: &str,
+        acc_info: &SubscribeUpdateAccountInfo,
+        slot: u64,
+    ) -> Result<(), String> {
+        let (pool, side) = self
+            .vaults
+            .get(vault)
+            .ok_or("Unknown PumpSwap vault")?
+            .clone();
+        let tracked = self
+            .pools
+            .get_mut(&pool)
+            .ok_or("Vault of an untracked pool")?;
+        let amount = parse_token_amount(acc_info).ok_or("Failed to parse vault token account")?;
+
+        let (reserves, last_slot, last_write_version) = match side {
+            VaultSide::Base => (
+                &mut tracked.base_reserves,
+                &mut tracked.base_slot,
+                &mut tracked.base_write_version,
+            ),
+            VaultSide::Quote => (
+                &mut tracked.quote_reserves,
+                &mut tracked.quote_slot,
+                &mut tracked.quote_write_version,
+            ),
+        };
+        // Writes can arrive out of order across reconnects
+        if (slot, acc_info.write_version) < (*last_slot, *last_write_version) {
+            return Ok(());
+        }
+        *reserves = Some(amount);
+        *last_slot = slot;
+        *last_write_version = acc_info.write_version;
+        tracked.dirty_slot = tracked.dirty_slot.max(Some(slot));
+
+        Ok(())
+    }
+
+    /// Publish pools whose vault writes all belong to slots before `current_slot`
+    /// (every pending pool when `None`). Pools missing a vault balance keep waiting.
+    pub fn flush(&mut self, current_slot: Option<u64>) -> Vec<KEvent> {
+        let mut events = Vec::new();
+
+        for (pool, tracked) in &mut self.pools {
+            let Some(dirty_slot) = tracked.dirty_slot else {
+                continue;
+            };
+            if current_slot.is_some_and(|current| dirty_slot >= current) {
+                continue;
+            }
+            let (Some(base_reserves), Some(quote_reserves)) =
+                (tracked.base_reserves, tracked.quote_reserves)
+            else {
+                continue;
+            };
+            tracked.dirty_slot = None;
+
+            let k_ps_price: KPsPrice = KPsPrice {
+                pool: pool.clone(),
+                source: PriceSource::PumpSwapPool,
+                ts: Utc::now(),
+                token_a_reserves: base_reserves,
+                token_b_reserves: quote_reserves,
+                base_mint: Some(tracked.pool.base_mint.clone()),
+                quote_mint: Some(tracked.pool.quote_mint.clone()),
+                slot: dirty_slot,
+                // Write versions are global, so the later vault write orders the joined price
+                write_version: tracked.base_write_version.max(tracked.quote_write_version),
+            };
+
+            events.push(KEvent::new(
+                EventSource::GeyserAccountSubscriber,
+                dirty_slot,
+                KEventData::PsPriceUpdated(k_ps_price),
+            ));
+        }
+
+        events
+    }
 }
 
 fn parse_pool(account_info: &SubscribeUpdateAccountInfo) -> Option<PumpSwapPoolData> {
-    if account_info.data.len() < 24 {
+    let data = &account_info.data;
+
+    if !data.starts_with(&PS_POOL_DISC) || data.len() < MIN_POOL_LEN {
         return None;
     }
 
-    let data = &account_info.data;
+    let key = |offset: usize| Some(bs58::encode(data.get(offset..offset + 32)?).into_string());
 
     Some(PumpSwapPoolData {
-        token_a_reserves: u64::from_le_bytes(data[8..16].try_into().ok()?),
-        token_b_reserves: u64::from_le_bytes(data[16..24].try_into().ok()?),
+        base_mint: key(BASE_MINT_OFFSET)?,
+        quote_mint: key(QUOTE_MINT_OFFSET)?,
+        base_vault: key(BASE_VAULT_OFFSET)?,
+        quote_vault: key(QUOTE_VAULT_OFFSET)?,
     })
 }
+
+fn parse_token_amount(account_info: &SubscribeUpdateAccountInfo) -> Option<u64> {
+    let bytes = account_info
+        .data
+        .get(TOKEN_AMOUNT_OFFSET..TOKEN_AMOUNT_OFFSET + 8)?;
+
+    Some(u64::from_le_bytes(bytes.try_into().ok()?))
+}
@@ -1,49 +1,141 @@
-use crate::models::kafka_event::{KEvent, KEventData, KEventType, PriceSource};
-use crate::models::raydium_models::rll_kafka_event::KRllPrice;
+use crate::models::kafka_event::{EventSource, KEvent, KEventData, PriceSource};
+use crate::models::raydium_models::rll_kafka_event::{KRllPrice, KRllVesting};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use solana_sdk::bs58;
 use yellowstone_grpc_proto::prelude::SubscribeUpdateAccountInfo;
 
+/// Anchor discriminator of the LaunchLab `PoolState` account
+pub const POOL_STATE_DISC: [u8; 8] = [247, 237, 227, 245, 215, 195, 222, 70];
+
+// Offsets include the discriminator; layout mirrors `gap_filler::models::rpc::PoolState`
+const STATUS_OFFSET: usize = 17;
+const BASE_DECIMALS_OFFSET: usize = 18;
+const QUOTE_DECIMALS_OFFSET: usize = 19;
+const SUPPLY_OFFSET: usize = 21;
+const TOTAL_BASE_SELL_OFFSET: usize = 29;
+const VIRTUAL_BASE_OFFSET: usize = 37;
+const VIRTUAL_QUOTE_OFFSET: usize = 45;
+const REAL_BASE_OFFSET: usize = 53;
+const REAL_QUOTE_OFFSET: usize = 61;
+const TOTAL_QUOTE_FUND_RAISING_OFFSET: usize = 69;
+const VESTING_OFFSET: usize = 101;
+const BASE_MINT_OFFSET: usize = 205;
+const CREATOR_OFFSET: usize = 333;
+// Everything up to and including `creator`
+const MIN_POOL_STATE_LEN: usize = CREATOR_OFFSET + 32;
+
+const MAX_PROGRESS_BPS: u64 = 10_000;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct PoolStateData {
+    pub status: u8,
     pub base_decimals: u8,
     pub quote_decimals: u8,
+    pub supply: u64,
+    pub total_base_sell: u64,
     pub virtual_base: u64,
     pub virtual_quote: u64,
+    pub real_base: u64,
+    pub real_quote: u64,
+    pub total_quote_fund_raising: u64,
+    pub vesting: KRllVesting,
+    pub base_mint: String,
+    pub creator: String,
 }
 
-pub fn handle_rll_price_update(acc_info: &SubscribeUpdateAccountInfo) -> Result<KEvent, String> {
+pub fn handle_rll_price_update(
+    acc_info: &SubscribeUpdateAccountInfo,
+    slot: u64,
+) -> Result<KEvent, String> {
+    let ps = parse_pool_state(acc_info).ok_or("Failed to parse pool state")?;
     let pool_state = bs58::encode(&acc_info.pubkey).into_string();
-    let ps = extract_price_data(acc_info);
+
     let k_rll_price: KRllPrice = KRllPrice {
         pool_state,
-        source: PriceSource::RaydiunmLaunchLabPoolState,
+        source: PriceSource::RaydiumLaunchLabPoolState,
         ts: Utc::now(),
         base_decimals: ps.base_decimals,
         quote_decimals: ps.quote_decimals,
         virtual_base: ps.virtual_base,
         virtual_quote: ps.virtual_quote,
+        status: ps.status,
+        supply: ps.supply,
+        total_base_sell: ps.total_base_sell,
+        real_base: ps.real_base,
+        real_quote: ps.real_quote,
+        total_quote_fund_raising: ps.total_quote_fund_raising,
+        price_quote: price_quote(&ps),
+        progress_bps: progress_bps(&ps),
+        vesting: Some(ps.vesting),
+        base_mint: Some(ps.base_mint),
+        creator: Some(ps.creator),
+        slot,
+        write_version: acc_info.write_version,
     };
 
-    Ok(KEvent {
-        event_type: KEventType::PfPriceUpdated,
-        data: KEventData::RllPriceUpdated(k_rll_price),
-    })
+    Ok(KEvent::new(
+        EventSource::GeyserAccountSubscriber,
+        slot,
+        KEventData::RllPriceUpdated(k_rll_price),
+    ))
+}
+
+/// Constant-product curve price: quote smallest units per whole base token.
+fn price_quote(ps: &PoolStateData) -> u64 {
+    let base = u128::from(ps.virtual_base.saturating_sub(ps.real_base));
+    if base == 0 {
+        return 0;
+    }
+
+    let quote = u128::from(ps.virtual_quote) + u128::from(ps.real_quote);
+    let price = quote * 10u128.pow(u32::from(ps.base_decimals)) / base;
+    u64::try_from(price).unwrap_or(u64::MAX)
 }
 
-fn extract_price_data(account_info: &SubscribeUpdateAccountInfo) -> PoolStateData {
+/// Share of the fundraising goal already raised, in basis points.
+fn progress_bps(ps: &PoolStateData) -> u64 {
+    if ps.total_quote_fund_raising == 0 {
+        return 0;
+    }
+
+    let progress = u128::from(ps.real_quote) * u128::from(MAX_PROGRESS_BPS)
+        / u128::from(ps.total_quote_fund_raising);
+    (progress as u64).min(MAX_PROGRESS_BPS)
+}
+
+fn parse_pool_state(account_info: &SubscribeUpdateAccountInfo) -> Option<PoolStateData> {
     let data = &account_info.data;
 
-    // TODO properly calculate necessary fields
-    PoolStateData {
-        base_decimals: data[18],
-        quote_decimals: data[19],
-        virtual_base: 1_000_000, /*u64::from_le_bytes([
-                                     data[38], data[39], data[40], data[41], data[42], data[43], data[44], data[45],
-                                 ])*/
-        virtual_quote: 1_000_000, /*u64::from_le_bytes([
-                                      data[46], data[47], data[48], data[49], data[50], data[51], data[52], data[53],
-                                  ])*/
+    if !data.starts_with(&POOL_STATE_DISC) || data.len() < MIN_POOL_STATE_LEN {
+        return None;
     }
+
+    Some(PoolStateData {
+        status: data[STATUS_OFFSET],
+        base_decimals: data[BASE_DECIMALS_OFFSET],
+        quote_decimals: data[QUOTE_DECIMALS_OFFSET],
+        supply: read_u64(data, SUPPLY_OFFSET)?,
+        total_base_sell: read_u64(data, TOTAL_BASE_SELL_OFFSET)?,
+        virtual_base: read_u64(data, VIRTUAL_BASE_OFFSET)?,
+        virtual_quote: read_u64(data, VIRTUAL_QUOTE_OFFSET)?,
+        real_base: read_u64(data, REAL_BASE_OFFSET)?,
+        real_quote: read_u64(data, REAL_QUOTE_OFFSET)?,
+        total_quote_fund_raising: read_u64(data, TOTAL_QUOTE_FUND_RAISING_OFFSET)?,
+        vesting: KRllVesting {
+            total_locked_amount: read_u64(data, VESTING_OFFSET)?,
+            cliff_period: read_u64(data, VESTING_OFFSET + 8)?,
+            unlock_period: read_u64(data, VESTING_OFFSET + 16)?,
+            start_time: read_u64(data, VESTING_OFFSET + 24)?,
+            allocated_share_amount: read_u64(data, VESTING_OFFSET + 32)?,
+        },
+        base_mint: bs58::encode(data.get(BASE_MINT_OFFSET..BASE_MINT_OFFSET + 32)?).into_string(),
+        creator: bs58::encode(data.get(CREATOR_OFFSET..CREATOR_OFFSET + 32)?).into_string(),
+    })
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(
+        data.get(offset..offset + 8)?.try_into().ok()?,
+    ))
 }
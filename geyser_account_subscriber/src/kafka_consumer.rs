@@ -1,4 +1,6 @@
+use crate::cache::acquire_leases;
 use crate::config::AppConfig;
+use crate::leases::{PRICE_REQ_HOLDER, sync_leased_subscriptions};
 use crate::models::kafka_req::KReq;
 use crate::state::AppState;
 use log::{error, info, warn};
@@ -70,9 +72,18 @@ async fn handle_message(topic: &str, payload: &str, state: &AppState) -> anyhow:
     match topic {
         "price_req" => {
             let kreq: KReq = serde_json::from_str(payload)?;
-            let handle = state.subscription_handle.clone();
+            let ttl = Duration::from_secs(state.config.lease_ttl_secs);
+            acquire_leases(
+                &mut state.cache.clone(),
+                PRICE_REQ_HOLDER,
+                &kreq.tracked_accounts,
+                ttl,
+            )
+            .await?;
+
+            let state = state.clone();
             tokio::spawn(async move {
-                match handle.update_subscription(kreq.tracked_accounts).await {
+                match sync_leased_subscriptions(&state).await {
                     Ok(_) => {
                         info!("SUCCESS");
                     }
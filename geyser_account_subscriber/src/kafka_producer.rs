@@ -1,13 +1,19 @@
 use crate::config::AppConfig;
 use crate::models::kafka_event::{KEvent, KEventType};
-use log::{error, info};
+use event_contract::codec::CONTENT_TYPE_HEADER;
+use event_contract::codec::registry::EventEncoder;
+use log::{error, info, warn};
 use rdkafka::config::ClientConfig;
+use rdkafka::message::{Header, OwnedHeaders};
 use rdkafka::producer::{FutureProducer, FutureRecord};
 use std::time::Duration;
 
 const PF_PRICE_UPDATED: &[&str] = &["pf_price_update_event"];
 const PS_PRICE_UPDATED: &[&str] = &["ps_price_update_event"];
 const RLL_PRICE_UPDATED: &[&str] = &["rll_price_update_event"];
+const PF_INCENTIVE_UPDATED: &[&str] = &["pf_incentive_event"];
+const PF_PROTOCOL_PARAMS_UPDATED: &[&str] = &["pf_protocol_params_event"];
+const PF_BONDING_CURVE_COMPLETED: &[&str] = &["pf_migrate_event"];
 
 pub async fn start_kafka_producer(
     config: AppConfig,
@@ -21,26 +27,46 @@ pub async fn start_kafka_producer(
         .create()
         .expect("Failed to create Kafka producer");
 
+    let encoder = EventEncoder::new(config.kafka_codec, config.schema_registry_url.clone());
+
     tokio::spawn(async move {
         while let Some(kevent) = event_rx.recv().await {
             match kevent.event_type {
                 KEventType::PfPriceUpdated => {
-                    broadcast_event(&producer, PF_PRICE_UPDATED, &kevent).await;
+                    broadcast_event(&producer, PF_PRICE_UPDATED, &kevent, &encoder).await;
                 }
                 KEventType::PsPriceUpdated => {
-                    broadcast_event(&producer, PS_PRICE_UPDATED, &kevent).await;
+                    broadcast_event(&producer, PS_PRICE_UPDATED, &kevent, &encoder).await;
                 }
                 KEventType::RllPriceUpdated => {
-                    broadcast_event(&producer, RLL_PRICE_UPDATED, &kevent).await;
+                    broadcast_event(&producer, RLL_PRICE_UPDATED, &kevent, &encoder).await;
+                }
+                KEventType::PfUserVolumeAccumulatorUpdated
+                | KEventType::PfGlobalVolumeAccumulatorUpdated => {
+                    broadcast_event(&producer, PF_INCENTIVE_UPDATED, &kevent, &encoder).await;
+                }
+                KEventType::PfGlobalConfigUpdated | KEventType::PfFeeConfigUpdated => {
+                    broadcast_event(&producer, PF_PROTOCOL_PARAMS_UPDATED, &kevent, &encoder).await;
+                }
+                KEventType::PfBondingCurveCompleted => {
+                    broadcast_event(&producer, PF_BONDING_CURVE_COMPLETED, &kevent, &encoder).await;
+                }
+                other => {
+                    warn!("start_kafka_producer: No topic for event type {other:?}");
                 }
             }
         }
     });
 }
 
-async fn broadcast_event(producer: &FutureProducer, topics: &[&str], event: &KEvent) {
+async fn broadcast_event(
+    producer: &FutureProducer,
+    topics: &[&str],
+    event: &KEvent,
+    encoder: &EventEncoder,
+) {
     for topic in topics {
-        match send_event(producer, topic, event).await {
+        match send_event(producer, topic, event, encoder).await {
             Ok(_) => {
                 info!("Successfully sent an event to {topic}")
             }
@@ -51,13 +77,24 @@ async fn broadcast_event(producer: &FutureProducer, topics: &[&str], event: &KEv
     }
 }
 
-async fn send_event(producer: &FutureProducer, topic: &str, event: &KEvent) -> Result<(), String> {
+async fn send_event(
+    producer: &FutureProducer,
+    topic: &str,
+    event: &KEvent,
+    encoder: &EventEncoder,
+) -> Result<(), String> {
     info!("topic: {topic}; event: {event:?};");
 
-    let payload =
-        serde_json::to_string(event).map_err(|e| format!("Failed to serialize event: {e}"))?;
+    let payload = encoder.encode(topic, event).await?;
+    let headers = OwnedHeaders::new().insert(Header {
+        key: CONTENT_TYPE_HEADER,
+        value: Some(encoder.content_type().as_str()),
+    });
 
-    let record = FutureRecord::to(topic).payload(&payload).key("test_key");
+    let record = FutureRecord::to(topic)
+        .payload(&payload)
+        .key("test_key")
+        .headers(headers);
 
     producer
         .send(record, Duration::from_secs(0))
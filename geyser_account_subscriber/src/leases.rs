@@ -0,0 +1,39 @@
+use crate::cache::load_leased_accounts;
+use crate::config::AppConfig;
+use crate::state::AppState;
+use log::{error, info};
+use std::time::Duration;
+
+/// Holder of leases taken on behalf of `price_req` messages; they lapse unless re-requested
+pub const PRICE_REQ_HOLDER: &str = "price_req";
+/// Holder of accounts added through the admin API; never expires
+pub const ADMIN_HOLDER: &str = "admin";
+
+/// Point the Geyser subscription at exactly the accounts with a live lease.
+/// Leases are the source of truth; the subscription registry only follows them.
+pub async fn sync_leased_subscriptions(state: &AppState) -> Result<(), String> {
+    let leased = load_leased_accounts(&mut state.cache.clone())
+        .await
+        .map_err(|e| format!("sync_leased_subscriptions: {e}"))?;
+
+    state.subscription_handle.update_subscription(leased).await
+}
+
+/// Periodically expire leases and unsubscribe accounts whose last lease is gone.
+pub async fn start_lease_sweeper(config: AppConfig, state: AppState) {
+    info!(
+        "Lease sweeper started: every {}s, lease ttl {}s",
+        config.lease_sweep_interval_secs, config.lease_ttl_secs
+    );
+
+    let mut interval =
+        tokio::time::interval(Duration::from_secs(config.lease_sweep_interval_secs.max(1)));
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = sync_leased_subscriptions(&state).await {
+            error!("Failed to sync leased subscriptions: {e}");
+        }
+    }
+}
@@ -1,8 +1,13 @@
 pub mod api;
+pub mod app;
 pub mod cache;
 pub mod config;
 pub mod handlers;
 pub mod kafka_consumer;
 pub mod kafka_producer;
+pub mod leases;
+pub mod metrics;
 pub mod models;
+pub mod routes;
+pub mod snapshot;
 pub mod state;
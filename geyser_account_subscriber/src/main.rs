@@ -1,13 +1,28 @@
-use geyser_account_subscriber::api::geyser::subscription_manager::SubscriptionManager;
-use geyser_account_subscriber::cache::{get_subscriptions, init_cache};
+use geyser_account_subscriber::api::geyser::account_consumer::ProgramFilter;
+use geyser_account_subscriber::api::geyser::subscription_manager::{
+    ProgramWideConfig, SubscriptionManager,
+};
+use geyser_account_subscriber::app;
+use geyser_account_subscriber::cache::init_cache;
 use geyser_account_subscriber::config::AppConfig;
+use geyser_account_subscriber::handlers::pf_price_update_handler::BONDING_CURVE_DISC;
+use geyser_account_subscriber::handlers::pf_protocol_params_update_handler::{
+    PF_FEE_CONFIG, PF_GLOBAL,
+};
+use geyser_account_subscriber::handlers::pf_volume_accumulator_update_handler::{
+    PF_GLOBAL_VOLUME_ACCUMULATOR, USER_VOLUME_ACCUMULATOR_DISC,
+};
 use geyser_account_subscriber::kafka_consumer::start_kafka_consumer;
 use geyser_account_subscriber::kafka_producer::start_kafka_producer;
+use geyser_account_subscriber::leases::start_lease_sweeper;
+use geyser_account_subscriber::models::consts::PUMPFUN_PROGRAM_ID;
 use geyser_account_subscriber::models::enums::Platform;
 use geyser_account_subscriber::models::kafka_event::KEvent;
+use geyser_account_subscriber::snapshot::{AccountFetcher, RpcAccountFetcher};
 use geyser_account_subscriber::state::init_state;
-use log::{error, info};
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::mpsc;
 
 #[tokio::main]
@@ -17,37 +32,62 @@ async fn main() {
     let config_clone = config.clone();
 
     let cache = init_cache(config.clone()).await.unwrap();
-    let _tracked_accounts = get_subscriptions(&mut cache.clone()).await.unwrap();
+
+    // Protocol accounts are always watched on top of the persisted registry
+    let pinned_accounts = HashMap::from([
+        (PF_GLOBAL_VOLUME_ACCUMULATOR.to_string(), Platform::PumpFun),
+        (PF_GLOBAL.to_string(), Platform::PumpFun),
+        (PF_FEE_CONFIG.to_string(), Platform::PumpFun),
+    ]);
+
+    // Every wallet's incentive accumulator, so claimable balances stay current
+    let mut program_filters = vec![ProgramFilter {
+        owner: PUMPFUN_PROGRAM_ID.to_string(),
+        discriminator: USER_VOLUME_ACCUMULATOR_DISC.to_vec(),
+        platform: Platform::PumpFun,
+    }];
+    // Whole-market view: every Pump.fun bonding curve, sampled per account
+    if config.program_wide_tracking {
+        program_filters.push(ProgramFilter {
+            owner: PUMPFUN_PROGRAM_ID.to_string(),
+            discriminator: BONDING_CURVE_DISC.to_vec(),
+            platform: Platform::PumpFun,
+        });
+    }
+    let program_wide = Some(ProgramWideConfig {
+        filters: program_filters,
+        coalesce_window: Duration::from_millis(config.coalesce_window_ms),
+    });
+
+    let snapshot_fetcher = config
+        .rpc_http_url
+        .clone()
+        .map(|url| Arc::new(RpcAccountFetcher::new(url)) as Arc<dyn AccountFetcher>);
 
     let (event_tx, event_rx) = mpsc::channel::<KEvent>(1000); // TODO review the 1000 buffer size
     let (_manager_task, subscription_handle) = SubscriptionManager::spawn(
         config_clone.geyser_url.clone(),
         config_clone.geyser_token.clone(),
         event_tx,
+        cache.clone(),
+        pinned_accounts,
+        config_clone.subscription_shard_size,
+        program_wide,
+        snapshot_fetcher,
     );
 
     let state = init_state(config_clone.clone(), subscription_handle.clone(), cache);
 
-    let tracked_accounts = HashMap::from([(
-        "8sbZehpLFcjCGk7vQGmBVbVVyih4pQLF6NuYnp61jXMF".to_string(),
-        Platform::PumpFun,
-    )]);
-
-    if !tracked_accounts.is_empty() {
-        tokio::spawn(async move {
-            match subscription_handle
-                .update_subscription(tracked_accounts)
-                .await
-            {
-                Ok(_) => {
-                    info!("SUCCESS");
-                }
-                Err(e) => {
-                    error!("Failed to update subscription: {e}");
-                }
-            }
-        });
-    }
+    let sweeper_config = config.clone();
+    let sweeper_state = state.clone();
+    tokio::spawn(async move {
+        start_lease_sweeper(sweeper_config, sweeper_state).await;
+    });
+
+    let admin_state = state.clone();
+    tokio::spawn(async move {
+        app::server(admin_state).await;
+    });
 
     tokio::spawn(async move {
         start_kafka_producer(config_clone.clone(), event_rx).await;
@@ -0,0 +1,118 @@
+use crate::models::enums::Platform;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Subscription change latency: from the manager receiving an update until Geyser
+/// acknowledged the new filter on the live stream.
+#[derive(Debug, Default)]
+pub struct SubscriptionMetrics {
+    updates_applied: AtomicU64,
+    updates_failed: AtomicU64,
+    last_latency_us: AtomicU64,
+    max_latency_us: AtomicU64,
+    total_latency_us: AtomicU64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SubscriptionMetricsSnapshot {
+    pub updates_applied: u64,
+    pub updates_failed: u64,
+    pub last_latency_ms: f64,
+    pub max_latency_ms: f64,
+    pub avg_latency_ms: f64,
+}
+
+impl SubscriptionMetrics {
+    pub fn record_applied(&self, latency: Duration) {
+        let us = latency.as_micros() as u64;
+        self.updates_applied.fetch_add(1, Ordering::Relaxed);
+        self.last_latency_us.store(us, Ordering::Relaxed);
+        self.max_latency_us.fetch_max(us, Ordering::Relaxed);
+        self.total_latency_us.fetch_add(us, Ordering::Relaxed);
+    }
+
+    pub fn record_failed(&self) {
+        self.updates_failed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> SubscriptionMetricsSnapshot {
+        let applied = self.updates_applied.load(Ordering::Relaxed);
+        let total_us = self.total_latency_us.load(Ordering::Relaxed);
+
+        SubscriptionMetricsSnapshot {
+            updates_applied: applied,
+            updates_failed: self.updates_failed.load(Ordering::Relaxed),
+            last_latency_ms: self.last_latency_us.load(Ordering::Relaxed) as f64 / 1000.0,
+            max_latency_ms: self.max_latency_us.load(Ordering::Relaxed) as f64 / 1000.0,
+            avg_latency_ms: if applied == 0 {
+                0.0
+            } else {
+                total_us as f64 / applied as f64 / 1000.0
+            },
+        }
+    }
+}
+
+/// Liveness of one `AccountConsumer` shard, updated by the consumer itself.
+#[derive(Debug, Default)]
+pub struct ShardHealth {
+    connected: AtomicBool,
+    reconnects: AtomicU64,
+    updates_received: AtomicU64,
+    last_update_ms: AtomicI64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShardHealthSnapshot {
+    pub shard_id: usize,
+    pub accounts: usize,
+    pub connected: bool,
+    pub reconnects: u64,
+    pub updates_received: u64,
+    pub last_update_at: Option<DateTime<Utc>>,
+}
+
+impl ShardHealth {
+    pub fn set_connected(&self, connected: bool) {
+        self.connected.store(connected, Ordering::Relaxed);
+    }
+
+    pub fn record_reconnect(&self) {
+        self.reconnects.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_update(&self) {
+        self.updates_received.fetch_add(1, Ordering::Relaxed);
+        self.last_update_ms
+            .store(Utc::now().timestamp_millis(), Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self, shard_id: usize, accounts: usize) -> ShardHealthSnapshot {
+        let last_update_ms = self.last_update_ms.load(Ordering::Relaxed);
+
+        ShardHealthSnapshot {
+            shard_id,
+            accounts,
+            connected: self.connected.load(Ordering::Relaxed),
+            reconnects: self.reconnects.load(Ordering::Relaxed),
+            updates_received: self.updates_received.load(Ordering::Relaxed),
+            last_update_at: (last_update_ms > 0)
+                .then(|| DateTime::from_timestamp_millis(last_update_ms))
+                .flatten(),
+        }
+    }
+}
+
+/// One explicitly watched account, as reported by the admin API.
+#[derive(Debug, Clone, Serialize)]
+pub struct TrackedAccountSnapshot {
+    pub account: String,
+    pub platform: Platform,
+    pub shard_id: usize,
+    pub pinned: bool,
+    pub last_update_slot: Option<u64>,
+    pub updates_received: u64,
+    pub updates_per_min: f64,
+}
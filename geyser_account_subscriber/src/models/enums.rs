@@ -1,8 +1 @@
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
-pub enum Platform {
-    PumpFun,
-    PumpSwap,
-    RaydiumLaunchLab,
-}
+pub use event_contract::models::enums::Platform;
@@ -1,6 +1,8 @@
+pub mod consts;
 pub mod enums;
 pub mod kafka_event;
 pub mod kafka_req;
+pub mod subscription;
 
 pub mod pump_models;
 pub mod raydium_models;
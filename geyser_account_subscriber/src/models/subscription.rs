@@ -0,0 +1,11 @@
+use crate::models::enums::Platform;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// One tracked account in the durable subscription registry (`subscriptions:accounts`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscriptionEntry {
+    pub platform: Platform,
+    pub added_at: DateTime<Utc>,   // first time the account was requested
+    pub updated_at: DateTime<Utc>, // last `KReq` that kept the account tracked
+}
@@ -0,0 +1,12 @@
+use crate::handlers::admin_handler::{add_accounts, list_accounts, remove_account};
+use crate::state::AppState;
+use axum::{
+    Router,
+    routing::{delete, get},
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/accounts", get(list_accounts).post(add_accounts))
+        .route("/accounts/{account}", delete(remove_account))
+}
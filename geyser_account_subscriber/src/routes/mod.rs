@@ -0,0 +1,3 @@
+mod accounts;
+mod status;
+pub mod v1;
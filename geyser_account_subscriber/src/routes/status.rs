@@ -0,0 +1,12 @@
+use crate::handlers::admin_handler::{get_status, resubscribe};
+use crate::state::AppState;
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/status", get(get_status))
+        .route("/resubscribe", post(resubscribe))
+}
@@ -0,0 +1,9 @@
+use super::{accounts, status};
+use crate::state::AppState;
+use axum::Router;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .merge(accounts::routes())
+        .merge(status::routes())
+}
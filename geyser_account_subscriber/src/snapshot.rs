@@ -0,0 +1,164 @@
+use crate::handlers::price_update_handler::handle_price_update;
+use crate::handlers::ps_price_update_handler::PsPoolTracker;
+use crate::models::enums::Platform;
+use crate::models::kafka_event::{EventSource, KEvent};
+use futures::future::BoxFuture;
+use log::{error, info, warn};
+use redis::aio::ConnectionManager;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::bs58;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use yellowstone_grpc_proto::prelude::SubscribeUpdateAccountInfo;
+
+/// `getMultipleAccounts` accepts at most 100 keys per call
+const MAX_ACCOUNTS_PER_REQUEST: usize = 100;
+
+/// Accounts fetched at one point in time. Accounts that do not exist are left out.
+pub struct AccountsSnapshot {
+    pub slot: u64,
+    pub accounts: Vec<SubscribeUpdateAccountInfo>,
+}
+
+/// Source of current account state outside the live stream, behind a trait so it can be mocked.
+pub trait AccountFetcher: Send + Sync {
+    fn get_multiple_accounts<'a>(
+        &'a self,
+        accounts: &'a [String],
+    ) -> BoxFuture<'a, Result<AccountsSnapshot, String>>;
+}
+
+pub struct RpcAccountFetcher {
+    rpc_client: RpcClient,
+}
+
+impl RpcAccountFetcher {
+    pub fn new(rpc_http_url: String) -> Self {
+        Self {
+            rpc_client: RpcClient::new(rpc_http_url),
+        }
+    }
+}
+
+impl AccountFetcher for RpcAccountFetcher {
+    fn get_multiple_accounts<'a>(
+        &'a self,
+        accounts: &'a [String],
+    ) -> BoxFuture<'a, Result<AccountsSnapshot, String>> {
+        Box::pin(async move {
+            let mut snapshot = AccountsSnapshot {
+                slot: 0,
+                accounts: Vec::with_capacity(accounts.len()),
+            };
+
+            for chunk in accounts.chunks(MAX_ACCOUNTS_PER_REQUEST) {
+                let pubkeys = chunk
+                    .iter()
+                    .map(|account| Pubkey::from_str(account))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| format!("RpcAccountFetcher::get_multiple_accounts: {e}"))?;
+
+                let response = self
+                    .rpc_client
+                    .get_multiple_accounts_with_commitment(&pubkeys, self.rpc_client.commitment())
+                    .await
+                    .map_err(|e| format!("RpcAccountFetcher::get_multiple_accounts: {e}"))?;
+
+                // Chunks can land on different slots; report the oldest
+                snapshot.slot = match snapshot.slot {
+                    0 => response.context.slot,
+                    slot => slot.min(response.context.slot),
+                };
+                for (pubkey, account) in pubkeys.iter().zip(response.value) {
+                    let Some(account) = account else {
+                        continue;
+                    };
+                    snapshot.accounts.push(SubscribeUpdateAccountInfo {
+                        pubkey: pubkey.to_bytes().to_vec(),
+                        lamports: account.lamports,
+                        owner: account.owner.to_bytes().to_vec(),
+                        executable: account.executable,
+                        rent_epoch: account.rent_epoch,
+                        data: account.data,
+                        ..Default::default()
+                    });
+                }
+            }
+
+            Ok(snapshot)
+        })
+    }
+}
+
+/// Publish the current state of newly subscribed accounts, so clients get a price
+/// right away instead of waiting for the next on-chain write.
+/// Events go through the regular handlers and are tagged `EventSource::Snapshot`.
+pub async fn emit_snapshots(
+    fetcher: Arc<dyn AccountFetcher>,
+    accounts: HashMap<String, Platform>,
+    event_tx: mpsc::Sender<KEvent>,
+    mut cache: ConnectionManager,
+) {
+    let keys: Vec<String> = accounts.keys().cloned().collect();
+    let snapshot = match fetcher.get_multiple_accounts(&keys).await {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            error!("Failed to fetch account snapshots: {e}");
+            return;
+        }
+    };
+
+    let mut kevents = Vec::new();
+    // PumpSwap pools are priced from their vaults, which take a second fetch
+    let mut ps_pools = PsPoolTracker::default();
+
+    for info in &snapshot.accounts {
+        let account = bs58::encode(&info.pubkey).into_string();
+        let platform = accounts.get(&account).copied();
+
+        if platform == Some(Platform::PumpSwap) {
+            if let Err(e) = ps_pools.track_pool(&account, info) {
+                warn!("Skipping PumpSwap snapshot of {account}: {e}");
+            }
+            continue;
+        }
+
+        match handle_price_update(info, snapshot.slot, platform, &mut cache).await {
+            Ok(events) => kevents.extend(events),
+            Err(e) => warn!("Skipping snapshot of {account}: {e}"),
+        }
+    }
+
+    let vaults: Vec<String> = ps_pools.vault_accounts().cloned().collect();
+    if !vaults.is_empty() {
+        match fetcher.get_multiple_accounts(&vaults).await {
+            Ok(vault_snapshot) => {
+                for info in &vault_snapshot.accounts {
+                    let vault = bs58::encode(&info.pubkey).into_string();
+                    if let Err(e) = ps_pools.on_vault_update(&vault, info, vault_snapshot.slot) {
+                        warn!("Skipping PumpSwap vault snapshot of {vault}: {e}");
+                    }
+                }
+                kevents.extend(ps_pools.flush(None));
+            }
+            Err(e) => error!("Failed to fetch PumpSwap vault snapshots: {e}"),
+        }
+    }
+
+    info!(
+        "Publishing {} snapshot events for {} new accounts at slot {}",
+        kevents.len(),
+        accounts.len(),
+        snapshot.slot
+    );
+
+    for mut kevent in kevents {
+        kevent.source = EventSource::Snapshot;
+        if let Err(e) = event_tx.send(kevent).await {
+            error!("Failed to forward snapshot event: {e}");
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use geyser_account_subscriber::api::geyser::account_versions::{
+    ACCOUNT_IDLE_SLOTS, AccountVersions,
+};
+
+#[test]
+fn stale_writes_are_dropped() {
+    let versions = AccountVersions::default();
+
+    assert!(versions.observe("pool", 10, 5));
+    assert!(!versions.observe("pool", 10, 5));
+    assert!(!versions.observe("pool", 9, 7));
+    assert!(versions.observe("pool", 10, 6));
+}
+
+#[test]
+fn idle_accounts_are_evicted() {
+    let versions = AccountVersions::default();
+    assert!(versions.observe("quiet", 1, 0));
+    assert!(versions.observe("busy", 1, 0));
+
+    let later = 1 + ACCOUNT_IDLE_SLOTS * 2;
+    assert!(versions.observe("busy", later, 0));
+
+    assert!(versions.stats("quiet").is_none());
+    let busy = versions.stats("busy").unwrap();
+    assert_eq!((busy.last_slot, busy.updates), (later, 2));
+}
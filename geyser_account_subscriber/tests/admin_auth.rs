@@ -0,0 +1,37 @@
+use axum::http::header::AUTHORIZATION;
+use axum::http::{HeaderMap, HeaderValue, Method};
+use geyser_account_subscriber::app::is_authorized;
+
+fn bearer(token: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        AUTHORIZATION,
+        HeaderValue::from_str(&format!("Bearer {token}")).unwrap(),
+    );
+    headers
+}
+
+#[test]
+fn changes_need_the_configured_token() {
+    let token = Some("secret");
+
+    assert!(!is_authorized(&Method::POST, &HeaderMap::new(), token));
+    assert!(!is_authorized(&Method::DELETE, &bearer("wrong"), token));
+    assert!(!is_authorized(&Method::POST, &bearer("secre"), token));
+    assert!(is_authorized(&Method::POST, &bearer("secret"), token));
+    assert!(is_authorized(&Method::DELETE, &bearer("secret"), token));
+}
+
+#[test]
+fn reads_are_open() {
+    assert!(is_authorized(
+        &Method::GET,
+        &HeaderMap::new(),
+        Some("secret")
+    ));
+}
+
+#[test]
+fn no_token_means_loopback_only_and_no_check() {
+    assert!(is_authorized(&Method::POST, &HeaderMap::new(), None));
+}
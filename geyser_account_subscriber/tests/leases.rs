@@ -0,0 +1,55 @@
+use chrono::Utc;
+use event_contract::leases::{LEASE_PLATFORMS_KEY, encode_lease_platform};
+use geyser_account_subscriber::cache::{acquire_leases, load_leased_accounts};
+use geyser_account_subscriber::models::enums::Platform;
+use redis::AsyncCommands;
+use redis::aio::ConnectionManager;
+use std::collections::HashMap;
+use std::time::Duration;
+
+async fn test_cache() -> ConnectionManager {
+    let url = std::env::var("REDIS_URL").expect("REDIS_URL must be set");
+    redis::Client::open(url)
+        .unwrap()
+        .get_connection_manager()
+        .await
+        .unwrap()
+}
+
+fn test_account(name: &str) -> String {
+    format!("test-{name}-{}", Utc::now().timestamp_nanos_opt().unwrap())
+}
+
+#[tokio::test]
+#[ignore = "needs Redis at REDIS_URL"]
+async fn sweep_drops_only_platforms_left_without_a_lease() {
+    let mut cache = test_cache().await;
+    let leased = test_account("leased");
+    let orphaned = test_account("orphaned");
+
+    acquire_leases(
+        &mut cache,
+        "price_req",
+        &HashMap::from([(leased.clone(), Platform::PumpFun)]),
+        Duration::from_secs(60),
+    )
+    .await
+    .unwrap();
+    let _: () = cache
+        .hset(
+            LEASE_PLATFORMS_KEY,
+            &orphaned,
+            encode_lease_platform(Platform::PumpSwap).unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let accounts = load_leased_accounts(&mut cache).await.unwrap();
+
+    assert_eq!(accounts.get(&leased), Some(&Platform::PumpFun));
+    assert!(!accounts.contains_key(&orphaned));
+    let kept: Option<String> = cache.hget(LEASE_PLATFORMS_KEY, &leased).await.unwrap();
+    let dropped: Option<String> = cache.hget(LEASE_PLATFORMS_KEY, &orphaned).await.unwrap();
+    assert!(kept.is_some());
+    assert!(dropped.is_none());
+}
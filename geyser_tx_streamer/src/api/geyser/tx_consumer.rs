@@ -66,17 +66,17 @@ impl TxConsumer {
                                                 let slot = tx_update.slot;
                                                 if let Some(tx_info) = tx_update.transaction {
                                                     match handle_tx(tx_info, slot) {
-                                                        Ok(kevent) => match kevent {
-                                                            Some(ke) => {
+                                                        Ok(kevents) => {
+                                                            if kevents.is_empty() {
+                                                                warn!("Invalid event")
+                                                            }
+                                                            for ke in kevents {
                                                                 self.event_tx
                                                                     .send(ke)
                                                                     .await
                                                                     .unwrap();
                                                             }
-                                                            None => {
-                                                                warn!("Invalid event")
-                                                            }
-                                                        },
+                                                        }
                                                         Err(e) => {
                                                             warn!("Failed to handle tx: {e}")
                                                         }
@@ -1,3 +1,4 @@
+use event_contract::codec::ContentType;
 use std::env;
 
 #[derive(Debug, Clone)]
@@ -6,6 +7,8 @@ pub struct AppConfig {
     pub geyser_token: Option<String>,
     pub kafka_brokers: String,
     pub kafka_group_id: String,
+    pub kafka_codec: ContentType,
+    pub schema_registry_url: Option<String>,
 }
 
 impl AppConfig {
@@ -18,12 +21,18 @@ impl AppConfig {
         let kafka_brokers = env::var("KAFKA_BROKERS").unwrap_or_else(|_| "kafka:9092".to_string());
         let kafka_group_id =
             env::var("KAFKA_GROUP_ID").unwrap_or_else(|_| "pump_data_producer".to_string());
+        let kafka_codec = env::var("KAFKA_CODEC")
+            .map(|v| v.parse().expect("KAFKA_CODEC must be json or protobuf"))
+            .unwrap_or_default();
+        let schema_registry_url = env::var("SCHEMA_REGISTRY_URL").ok();
 
         Self {
             geyser_url,
             geyser_token: Some(geyser_token),
             kafka_brokers,
             kafka_group_id,
+            kafka_codec,
+            schema_registry_url,
         }
     }
 
@@ -0,0 +1,206 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+use solana_sdk::bs58;
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+
+/// Anchor `emit_cpi!` instruction tag (`sha256("anchor:event")[..8]`, little endian)
+const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+pub const PF_TRADE_EVENT_DISC: [u8; 8] = [189, 219, 127, 211, 78, 230, 97, 238];
+pub const PF_CREATE_EVENT_DISC: [u8; 8] = [27, 114, 169, 77, 222, 235, 99, 118];
+pub const PF_COMPLETE_PUMP_AMM_MIGRATION_EVENT_DISC: [u8; 8] =
+    [189, 233, 93, 185, 92, 148, 234, 148];
+pub const PF_COLLECT_CREATOR_FEE_EVENT_DISC: [u8; 8] = [122, 2, 127, 1, 14, 191, 12, 175];
+pub const PS_COLLECT_COIN_CREATOR_FEE_EVENT_DISC: [u8; 8] = [232, 245, 194, 238, 234, 218, 58, 89];
+pub const PS_BUY_EVENT_DISC: [u8; 8] = [103, 244, 82, 31, 44, 245, 119, 119];
+pub const PS_SELL_EVENT_DISC: [u8; 8] = [62, 47, 55, 10, 165, 3, 220, 42];
+pub const PF_CLAIM_TOKEN_INCENTIVES_EVENT_DISC: [u8; 8] = [79, 172, 246, 49, 205, 91, 206, 232];
+
+pub const BUY_IX_DISC: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
+pub const SELL_IX_DISC: [u8; 8] = [51, 230, 133, 164, 1, 127, 131, 173];
+pub const PF_BUY_EXACT_SOL_IN_IX_DISC: [u8; 8] = [56, 252, 116, 8, 158, 223, 205, 95];
+pub const PS_BUY_EXACT_QUOTE_IN_IX_DISC: [u8; 8] = [198, 46, 21, 82, 180, 217, 232, 112];
+pub const PF_COLLECT_CREATOR_FEE_IX_DISC: [u8; 8] = [20, 22, 86, 123, 198, 28, 219, 132];
+pub const PS_COLLECT_COIN_CREATOR_FEE_IX_DISC: [u8; 8] = [160, 57, 89, 42, 181, 139, 43, 66];
+
+/// An Anchor event emitted by a transaction
+pub struct AnchorEvent {
+    /// Event body, discriminator stripped
+    pub data: Vec<u8>,
+    /// Top-level instruction that emitted the event. Together with the signature it
+    /// identifies the event, so redeliveries can be recognised.
+    pub ix_index: u32,
+    /// Position among the events the transaction emitted the same way (`emit!` or `emit_cpi!`),
+    /// telling apart events of one instruction
+    pub ordinal: u32,
+}
+
+/// Find every Anchor event with the given discriminator, in emission order.
+/// Looks at `Program data:` logs (`emit!`) first, then self-CPI inner instructions (`emit_cpi!`).
+pub fn find_anchor_events(tx: &SubscribeUpdateTransactionInfo, disc: &[u8; 8]) -> Vec<AnchorEvent> {
+    let Some(meta) = tx.meta.as_ref() else {
+        return Vec::new();
+    };
+
+    // Every top-level instruction opens with an `invoke [1]` log, whatever the program
+    let mut events = Vec::new();
+    let mut top_level: Option<u32> = None;
+    let mut ordinal = 0;
+    for log in &meta.log_messages {
+        if log.starts_with("Program ") && log.ends_with(" invoke [1]") {
+            top_level = Some(top_level.map_or(0, |i| i + 1));
+        } else if let Some(data) = log.strip_prefix("Program data: ") {
+            if let Some(ix_index) = top_level
+                && let Ok(buf) = STANDARD.decode(data)
+                && buf.starts_with(disc)
+            {
+                events.push(AnchorEvent {
+                    data: buf[8..].to_vec(),
+                    ix_index,
+                    ordinal,
+                });
+            }
+            ordinal += 1;
+        }
+    }
+    if !events.is_empty() {
+        return events;
+    }
+
+    let mut ordinal = 0;
+    for inner in &meta.inner_instructions {
+        for ix in &inner.instructions {
+            if ix.data.len() < 16 || ix.data[..8] != EVENT_IX_TAG {
+                continue;
+            }
+            if ix.data[8..16] == disc[..] {
+                events.push(AnchorEvent {
+                    data: ix.data[16..].to_vec(),
+                    ix_index: inner.index,
+                    ordinal,
+                });
+            }
+            ordinal += 1;
+        }
+    }
+
+    events
+}
+
+/// Full account list as seen by instructions: static keys, then ALT writable, then ALT readonly
+pub fn resolved_account_keys(tx: &SubscribeUpdateTransactionInfo) -> Vec<Vec<u8>> {
+    let mut keys: Vec<Vec<u8>> = tx
+        .transaction
+        .as_ref()
+        .and_then(|t| t.message.as_ref())
+        .map(|m| m.account_keys.clone())
+        .unwrap_or_default();
+
+    if let Some(meta) = tx.meta.as_ref() {
+        keys.extend(meta.loaded_writable_addresses.iter().cloned());
+        keys.extend(meta.loaded_readonly_addresses.iter().cloned());
+    }
+
+    keys
+}
+
+/// An instruction, top-level or inner, calling a given program
+pub struct ProgramInstruction {
+    /// Instruction data, discriminator included
+    pub data: Vec<u8>,
+    /// Accounts (base58)
+    pub accounts: Vec<String>,
+}
+
+/// Instructions, top-level or inner, calling `program_id` with one of `discs`, in execution order.
+/// An instruction emits its events before the next one runs, so the k-th instruction here
+/// pairs with the k-th event it emits.
+pub fn find_instructions(
+    tx: &SubscribeUpdateTransactionInfo,
+    program_id: &str,
+    discs: &[[u8; 8]],
+) -> Vec<ProgramInstruction> {
+    let keys = resolved_account_keys(tx);
+    let to_base58 = |index: u32| {
+        keys.get(index as usize)
+            .map(|k| bs58::encode(k).into_string())
+    };
+
+    let Some(message) = tx.transaction.as_ref().and_then(|t| t.message.as_ref()) else {
+        return Vec::new();
+    };
+    let inner = tx
+        .meta
+        .as_ref()
+        .map(|m| m.inner_instructions.as_slice())
+        .unwrap_or_default();
+
+    let mut ordered = Vec::new();
+    for (index, ix) in message.instructions.iter().enumerate() {
+        ordered.push((ix.program_id_index, &ix.accounts, &ix.data));
+        for ii in inner.iter().filter(|ii| ii.index as usize == index) {
+            ordered.extend(
+                ii.instructions
+                    .iter()
+                    .map(|ix| (ix.program_id_index, &ix.accounts, &ix.data)),
+            );
+        }
+    }
+
+    ordered
+        .into_iter()
+        .filter(|(program_index, _, data)| {
+            discs.iter().any(|disc| data.starts_with(disc))
+                && to_base58(*program_index).as_deref() == Some(program_id)
+        })
+        .filter_map(|(_, accounts, data)| {
+            let accounts = accounts
+                .iter()
+                .map(|i| to_base58(u32::from(*i)))
+                .collect::<Option<Vec<_>>>()?;
+            Some(ProgramInstruction {
+                data: data.clone(),
+                accounts,
+            })
+        })
+        .collect()
+}
+
+pub fn read_pubkey(buf: &[u8], offset: usize) -> Result<String, String> {
+    let bytes = buf
+        .get(offset..offset + 32)
+        .ok_or("read_pubkey: out of bounds")?;
+    Ok(bs58::encode(bytes).into_string())
+}
+
+/// Borsh string at `offset`; returns it with the offset just past it
+pub fn read_string(buf: &[u8], offset: usize) -> Result<(String, usize), String> {
+    let len_bytes: [u8; 4] = buf
+        .get(offset..offset + 4)
+        .ok_or("read_string: length out of bounds")?
+        .try_into()
+        .map_err(|_| "read_string: invalid length slice")?;
+    let end = offset + 4 + u32::from_le_bytes(len_bytes) as usize;
+    let bytes = buf
+        .get(offset + 4..end)
+        .ok_or("read_string: out of bounds")?;
+    let s = String::from_utf8(bytes.to_vec()).map_err(|_| "read_string: invalid UTF-8")?;
+    Ok((s, end))
+}
+
+pub fn read_u64(buf: &[u8], offset: usize) -> Result<u64, String> {
+    let bytes: [u8; 8] = buf
+        .get(offset..offset + 8)
+        .ok_or("read_u64: out of bounds")?
+        .try_into()
+        .map_err(|_| "read_u64: invalid slice")?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+pub fn read_i64(buf: &[u8], offset: usize) -> Result<i64, String> {
+    let bytes: [u8; 8] = buf
+        .get(offset..offset + 8)
+        .ok_or("read_i64: out of bounds")?
+        .try_into()
+        .map_err(|_| "read_i64: invalid slice")?;
+    Ok(i64::from_le_bytes(bytes))
+}
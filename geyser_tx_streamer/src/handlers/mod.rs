@@ -1,3 +1,4 @@
+pub mod anchor_event;
 pub mod pumpfun;
 pub mod pumpswap;
 pub mod raydium_launchlab;
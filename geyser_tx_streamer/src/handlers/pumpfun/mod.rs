@@ -1,5 +1,7 @@
 pub mod pf_ch_trade_occurred_handler;
+pub mod pf_creator_fee_claimed_handler;
 pub mod pf_token_created_handler;
+pub mod pf_token_incentives_claimed_handler;
 pub mod pf_token_migrated_handler;
 pub mod pf_trade_occurred_handler;
 pub mod pf_tx_handler;
@@ -1,69 +1,152 @@
-use crate::models::pump_models::pf_ch_kafka_event::PfChTradeUnified;
-use base64::{Engine, engine::general_purpose::STANDARD};
-use chrono::Utc;
+use crate::handlers::anchor_event::{
+    AnchorEvent, BUY_IX_DISC, PF_BUY_EXACT_SOL_IN_IX_DISC, PF_TRADE_EVENT_DISC, SELL_IX_DISC,
+    find_anchor_events, find_instructions, read_i64, read_pubkey, read_u64,
+};
+use crate::models::consts::PUMPFUN_PROGRAM_ID;
+use crate::models::pump_models::pf_kafka_event::KPfChTrade;
+use chrono::DateTime;
 use solana_sdk::bs58;
 use solana_sdk::hash::Hash;
-use solana_sdk::pubkey::Pubkey;
 use std::convert::TryFrom;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
-pub fn handle_pf_ch_trade(
+/* TradeEvent layout (after discriminator) */
+const MINT_OFFSET: usize = 0; // Pubkey
+const SOL_AMOUNT_OFFSET: usize = 32; // u64
+const TOKEN_AMOUNT_OFFSET: usize = 40; // u64
+const IS_BUY_OFFSET: usize = 48; // bool
+const USER_OFFSET: usize = 49; // Pubkey
+const TIMESTAMP_OFFSET: usize = 81; // i64, block time
+const VIRTUAL_SOL_RESERVES_OFFSET: usize = 89; // u64
+const VIRTUAL_TOKEN_RESERVES_OFFSET: usize = 97; // u64
+const REAL_SOL_RESERVES_OFFSET: usize = 105; // u64
+const REAL_TOKEN_RESERVES_OFFSET: usize = 113; // u64
+const FEE_RECIPIENT_OFFSET: usize = 121; // Pubkey
+const FEE_BASIS_POINTS_OFFSET: usize = 153; // u64
+const FEE_OFFSET: usize = 161; // u64
+const CREATOR_OFFSET: usize = 169; // Pubkey
+const CREATOR_FEE_BASIS_POINTS_OFFSET: usize = 201; // u64
+const CREATOR_FEE_OFFSET: usize = 209; // u64
+const TRACK_VOLUME_OFFSET: usize = 217; // bool
+const TOTAL_UNCLAIMED_TOKENS_OFFSET: usize = 218; // u64
+const TOTAL_CLAIMED_TOKENS_OFFSET: usize = 226; // u64
+const CURRENT_SOL_VOLUME_OFFSET: usize = 234; // u64
+const LAST_UPDATE_TIMESTAMP_OFFSET: usize = 242; // i64
+
+/* buy / sell / buy_exact_sol_in accounts */
+const BONDING_CURVE_ACCOUNT: usize = 3;
+
+/// One trade per TradeEvent, paired with the buy/sell instruction that emitted it
+pub fn handle_pf_ch_trades(
     tx_info: &SubscribeUpdateTransactionInfo,
     slot: u64,
-) -> Result<PfChTradeUnified, String> {
+) -> Result<Vec<KPfChTrade>, String> {
+    let events = find_anchor_events(tx_info, &PF_TRADE_EVENT_DISC);
+    if events.is_empty() {
+        return Err("handle_pf_ch_trades: TradeEvent not found".into());
+    }
+    let instructions = find_instructions(
+        tx_info,
+        PUMPFUN_PROGRAM_ID,
+        &[BUY_IX_DISC, SELL_IX_DISC, PF_BUY_EXACT_SOL_IN_IX_DISC],
+    );
+
+    events
+        .iter()
+        .enumerate()
+        .map(|(k, event)| -> Result<KPfChTrade, String> {
+            let instruction = instructions
+                .get(k)
+                .ok_or("handle_pf_ch_trades: trade instruction not found")?;
+            let ix_name = pf_ix_name(&instruction.data)?;
+            let bonding_curve = instruction
+                .accounts
+                .get(BONDING_CURVE_ACCOUNT)
+                .cloned()
+                .ok_or("handle_pf_ch_trades: bonding_curve account missing")?;
+
+            handle_pf_ch_trade(tx_info, slot, event, k, ix_name, bonding_curve)
+        })
+        .collect()
+}
+
+fn handle_pf_ch_trade(
+    tx_info: &SubscribeUpdateTransactionInfo,
+    slot: u64,
+    event: &AnchorEvent,
+    trade_index: usize,
+    ix_name: String,
+    bonding_curve: String,
+) -> Result<KPfChTrade, String> {
+    let data = &event.data;
+    let field = |name: &str, e: String| format!("handle_pf_ch_trade: {name}: {e}");
+
     /* ========= Transaction identity ========= */
     let signature: String = extract_pf_signature(tx_info)?;
-    let slot: u64 = slot;
     let blockhash: String = extract_blockhash(tx_info)?;
 
     /* ========= Actors ========= */
     let signer = pf_signer(tx_info)?;
     let fee_payer = pf_fee_payer(tx_info)?;
-    let user = pf_user(tx_info)?;
-    let creator = extract_pumpfun_creator(tx_info)?;
-    let fee_recipient = extract_pumpfun_fee_recipient(tx_info)?;
+    let user = read_pubkey(data, USER_OFFSET).map_err(|e| field("user", e))?;
+    let creator = read_pubkey(data, CREATOR_OFFSET).map_err(|e| field("creator", e))?;
+    let fee_recipient =
+        read_pubkey(data, FEE_RECIPIENT_OFFSET).map_err(|e| field("fee_recipient", e))?;
 
     /* ========= Token / market ========= */
-    let mint: String = pf_mint(tx_info)?;
-    let bonding_curve: String = pf_bonding_curve(tx_info)?;
-    let is_pump_pool: bool = extract_is_pump_pool(tx_info)?;
+    let mint = read_pubkey(data, MINT_OFFSET).map_err(|e| field("mint", e))?;
+    let is_pump_pool: bool = get_fees_value(tx_info, "is_pump_pool", trade_index)?;
 
     /* ========= Instruction semantics ========= */
-    let ix_name: String = pf_ix_name(tx_info)?; // buy | sell | buy_exact_sol_in
-    let is_buy: bool = pf_is_buy(tx_info)?; // Direction flag
+    let is_buy = read_bool(data, IS_BUY_OFFSET).map_err(|e| field("is_buy", e))?;
 
     /* ========= Trade amounts ========= */
-    let sol_amount: u64 = pf_sol_amount(tx_info)?; // SOL exchanged (lamports)
-    let token_amount: u64 = pf_token_amount(tx_info)?; // Tokens exchanged (raw units)
-    let trade_size_lamports: u64 = extract_trade_size_lamports(tx_info)?; // Trade size used for fee calculation
+    let sol_amount = read_u64(data, SOL_AMOUNT_OFFSET).map_err(|e| field("sol_amount", e))?;
+    let token_amount = read_u64(data, TOKEN_AMOUNT_OFFSET).map_err(|e| field("token_amount", e))?;
+    let trade_size_lamports: u64 = get_fees_value(tx_info, "trade_size_lamports", trade_index)?;
 
     /* ========= Fees ========= */
-    let transaction_fee: u64 = extract_transaction_fee(tx_info)?; // SOL fee paid for the transaction (lamports)
-    let fee_lamports: u64 = extract_protocol_fee_lamports(tx_info)?; // Protocol fee paid
-    let fee_basis_points: u64 = extract_protocol_fee_bps(tx_info)?; // Protocol fee bps
-    let creator_fee_lamports: u64 = extract_creator_fee_lamports(tx_info)?; // Creator fee paid
-    let creator_fee_basis_points: u64 = extract_creator_fee_bps(tx_info)?; // Creator fee bps
+    let transaction_fee = extract_transaction_fee(tx_info)?;
+    let fee_lamports = read_u64(data, FEE_OFFSET).map_err(|e| field("fee", e))?;
+    let fee_basis_points =
+        read_u64(data, FEE_BASIS_POINTS_OFFSET).map_err(|e| field("fee_basis_points", e))?;
+    let creator_fee_lamports =
+        read_u64(data, CREATOR_FEE_OFFSET).map_err(|e| field("creator_fee", e))?;
+    let creator_fee_basis_points = read_u64(data, CREATOR_FEE_BASIS_POINTS_OFFSET)
+        .map_err(|e| field("creator_fee_basis_points", e))?;
 
     /* ========= Market / bonding curve state (post-trade) ========= */
-    let (
-        decimals,
-        virtual_sol_reserves,
-        virtual_token_reserves,
-        real_sol_reserves,
-        real_token_reserves,
-    ) = build_cc_pumpfun_trade_1(tx_info)?;
-    let market_cap_lamports = extract_market_cap_lamports(tx_info)?;
+    let decimals = extract_token_decimals(tx_info, &mint)?;
+    let virtual_sol_reserves = read_u64(data, VIRTUAL_SOL_RESERVES_OFFSET)
+        .map_err(|e| field("virtual_sol_reserves", e))?;
+    let virtual_token_reserves = read_u64(data, VIRTUAL_TOKEN_RESERVES_OFFSET)
+        .map_err(|e| field("virtual_token_reserves", e))?;
+    let real_sol_reserves =
+        read_u64(data, REAL_SOL_RESERVES_OFFSET).map_err(|e| field("real_sol_reserves", e))?;
+    let real_token_reserves =
+        read_u64(data, REAL_TOKEN_RESERVES_OFFSET).map_err(|e| field("real_token_reserves", e))?;
+    let market_cap_lamports: u64 = get_fees_value(tx_info, "market_cap_lamports", trade_index)?;
 
     /* ========= Volume & tracking ========= */
-    let track_volume: bool = extract_track_volume(tx_info)?;
-    let total_unclaimed_tokens: u64 = extract_total_unclaimed_tokens(tx_info)?;
-    let total_claimed_tokens: u64 = extract_total_claimed_tokens(tx_info)?;
-    let current_sol_volume: u64 = extract_current_sol_volume(tx_info)?;
-    let last_update_timestamp: i64 = extract_last_update_timestamp(tx_info)?;
-
-    let ts = Utc::now();
-
-    let pf_ch_trade_unified = PfChTradeUnified {
+    let track_volume =
+        read_bool(data, TRACK_VOLUME_OFFSET).map_err(|e| field("track_volume", e))?;
+    let total_unclaimed_tokens = read_u64(data, TOTAL_UNCLAIMED_TOKENS_OFFSET)
+        .map_err(|e| field("total_unclaimed_tokens", e))?;
+    let total_claimed_tokens = read_u64(data, TOTAL_CLAIMED_TOKENS_OFFSET)
+        .map_err(|e| field("total_claimed_tokens", e))?;
+    let current_sol_volume =
+        read_u64(data, CURRENT_SOL_VOLUME_OFFSET).map_err(|e| field("current_sol_volume", e))?;
+    let last_update_timestamp = read_i64(data, LAST_UPDATE_TIMESTAMP_OFFSET)
+        .map_err(|e| field("last_update_timestamp", e))?;
+
+    let ts = read_i64(data, TIMESTAMP_OFFSET)
+        .map_err(|e| field("timestamp", e))
+        .and_then(|ts| {
+            DateTime::from_timestamp(ts, 0)
+                .ok_or_else(|| field("timestamp", "invalid timestamp".into()))
+        })?;
+
+    let pf_ch_trade = KPfChTrade {
         /* ========= Transaction identity ========= */
         signature, // Transaction signature (base58)
         slot,      // Solana slot
@@ -116,9 +199,13 @@ pub fn handle_pf_ch_trade(
 
         /* ========= Timestamp ========= */
         ts,
+
+        ix_index: event.ix_index, // Top-level instruction that emitted the trade
+        tx_index: tx_info.index,  // Position of the transaction within its slot
+        event_ordinal: event.ordinal, // Position of the event within the transaction
     };
 
-    Ok(pf_ch_trade_unified)
+    Ok(pf_ch_trade)
 }
 
 /* ========= Transaction identity ========= */
@@ -191,248 +278,54 @@ pub fn pf_fee_payer(tx: &SubscribeUpdateTransactionInfo) -> Result<String, Strin
     Ok(bs58::encode(key).into_string())
 }
 
-pub fn pf_user(tx: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    let meta = tx.meta.as_ref().ok_or("meta missing")?;
-    let tb = meta
-        .post_token_balances
-        .iter()
-        .max_by_key(|b| b.ui_token_amount.as_ref()?.amount.parse::<u64>().ok()) // user ATA
-        .ok_or("user token balance not found")?;
-    Ok(tb.owner.clone())
-}
-
-/// Extract `creator` pubkey from Pump.fun `anchor Self CPI Log` (TradeEvent)
-pub fn extract_pumpfun_creator(tx: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    // 1) find and decode Anchor event bytes
-    let logs = &tx
-        .meta
-        .as_ref()
-        .ok_or("pf_anchor_event_bytes: meta missing")?
-        .log_messages;
-
-    let mut decoded: Option<Vec<u8>> = None;
-
-    for log in logs {
-        if let Some(data) = log.strip_prefix("Program data: ") {
-            decoded = Some(
-                STANDARD
-                    .decode(data)
-                    .map_err(|_| "extract_pumpfun_creator: base64 decode failed")?,
-            );
-            break;
-        }
-    }
-
-    let buf = decoded.ok_or("extract_pumpfun_creator: Program data not found")?;
-
-    // 2) walk Anchor TradeEvent layout up to `creator`
-    const DISC: usize = 8;
-    const PUBKEY: usize = 32;
-    const U64: usize = 8;
-    const BOOL: usize = 1;
-
-    let mut o = DISC;
-
-    o += PUBKEY; // mint
-    o += U64; // sol_amount
-    o += U64; // token_amount
-    o += BOOL; // is_buy
-    o += PUBKEY; // user
-    o += U64; // timestamp
-    o += U64 * 4; // virtual_sol, virtual_token, real_sol, real_token
-    o += PUBKEY; // fee_recipient
-    o += U64; // fee_basis_points
-    o += U64; // fee
-
-    if buf.len() < o + PUBKEY {
-        return Err("extract_pumpfun_creator: buffer too short".into());
-    }
-
-    // 3) read creator pubkey
-    let creator = Pubkey::try_from(&buf[o..o + PUBKEY])
-        .map_err(|_| "extract_pumpfun_creator: invalid pubkey bytes")?;
-
-    Ok(creator.to_string())
-}
-
-/// Extract `feeRecipient` pubkey (base58 String) from Pump.fun Anchor TradeEvent
-pub fn extract_pumpfun_fee_recipient(
-    tx: &SubscribeUpdateTransactionInfo,
-) -> Result<String, String> {
-    // decode Anchor event bytes
-    let logs = &tx
-        .meta
-        .as_ref()
-        .ok_or("pf_anchor_event_bytes: meta missing")?
-        .log_messages;
-
-    let mut buf: Option<Vec<u8>> = None;
-
-    for log in logs {
-        if let Some(data) = log.strip_prefix("Program data: ") {
-            buf = Some(
-                STANDARD
-                    .decode(data)
-                    .map_err(|_| "extract_pumpfun_fee_recipient: base64 decode failed")?,
-            );
-            break;
-        }
-    }
-
-    let buf = buf.ok_or("extract_pumpfun_fee_recipient: Program data not found")?;
-
-    // walk Anchor TradeEvent layout up to `feeRecipient`
-    const DISC: usize = 8;
-    const PUBKEY: usize = 32;
-    const U64: usize = 8;
-    const BOOL: usize = 1;
-
-    let mut o = DISC;
-
-    o += PUBKEY; // mint
-    o += U64; // sol_amount
-    o += U64; // token_amount
-    o += BOOL; // is_buy
-    o += PUBKEY; // user
-    o += U64; // timestamp
-    o += U64 * 4; // virtual_sol, virtual_token, real_sol, real_token
-
-    // feeRecipient starts here
-    if buf.len() < o + PUBKEY {
-        return Err("extract_pumpfun_fee_recipient: buffer too short".into());
-    }
-
-    let fee_recipient = Pubkey::try_from(&buf[o..o + PUBKEY])
-        .map_err(|_| "extract_pumpfun_fee_recipient: invalid pubkey bytes")?;
-
-    Ok(fee_recipient.to_string())
-}
-
-/* ========= Token / market ========= */
-
-pub fn pf_mint(tx: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    tx.meta
-        .as_ref()
-        .and_then(|m| m.pre_token_balances.first())
-        .map(|b| b.mint.clone())
-        .ok_or("mint not found".into())
-}
-
-pub fn pf_bonding_curve(tx: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    let meta = tx.meta.as_ref().ok_or("meta missing")?;
-    let bc = meta
-        .post_token_balances
-        .iter()
-        .max_by_key(|b| b.ui_token_amount.as_ref()?.amount.parse::<u64>().ok())
-        .ok_or("bonding curve not found")?;
-    Ok(bc.owner.clone())
-}
-
-/// Extract `is_pump_pool` from
-/// `Pump Fees Program: get_fees` program log
-pub fn extract_is_pump_pool(tx: &SubscribeUpdateTransactionInfo) -> Result<bool, String> {
-    let logs = &tx
-        .meta
-        .as_ref()
-        .ok_or("pf_anchor_event_bytes: meta missing")?
-        .log_messages;
-
-    for log in logs {
-        if let Some(v) = log.strip_prefix("Program log: Pump Fees Program: get_fees is_pump_pool=")
-        {
-            return match v {
-                "true" => Ok(true),
-                "false" => Ok(false),
-                _ => Err("extract_is_pump_pool: invalid boolean".into()),
-            };
-        }
-    }
-
-    Err("extract_is_pump_pool: not found".into())
-}
-
 /* ========= Instruction semantics ========= */
 
-pub fn pf_ix_name(tx: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    let logs = &tx.meta.as_ref().ok_or("meta missing")?.log_messages;
-    for l in logs {
-        if l.contains("Instruction: BuyExactSolIn") {
-            return Ok("buy_exact_sol_in".into());
-        }
-        if l.contains("Instruction: Buy") {
-            return Ok("buy".into());
-        }
-        if l.contains("Instruction: Sell") {
-            return Ok("sell".into());
-        }
+pub fn pf_ix_name(ix_data: &[u8]) -> Result<String, String> {
+    if ix_data.starts_with(&PF_BUY_EXACT_SOL_IN_IX_DISC) {
+        Ok("buy_exact_sol_in".into())
+    } else if ix_data.starts_with(&BUY_IX_DISC) {
+        Ok("buy".into())
+    } else if ix_data.starts_with(&SELL_IX_DISC) {
+        Ok("sell".into())
+    } else {
+        Err("pf_ix_name: unknown trade instruction".into())
     }
-    Err("ix name not found".into())
 }
 
-pub fn pf_is_buy(tx: &SubscribeUpdateTransactionInfo) -> Result<bool, String> {
-    Ok(pf_ix_name(tx)?.starts_with("buy"))
+fn read_bool(buf: &[u8], offset: usize) -> Result<bool, String> {
+    buf.get(offset)
+        .map(|b| *b != 0)
+        .ok_or("read_bool: out of bounds".into())
 }
 
-/* ========= Trade amounts ========= */
-
-pub fn pf_sol_amount(tx: &SubscribeUpdateTransactionInfo) -> Result<u64, String> {
-    let meta = tx.meta.as_ref().ok_or("meta missing")?;
-    let pre = meta.pre_balances[0];
-    let post = meta.post_balances[0];
-    Ok(((pre as i64 - post as i64) - meta.fee as i64).abs() as u64)
-}
-
-pub fn pf_token_amount(tx: &SubscribeUpdateTransactionInfo) -> Result<u64, String> {
-    let meta = tx.meta.as_ref().ok_or("pf_token_amount: meta missing")?;
-
-    let ii = meta
-        .inner_instructions
-        .first()
-        .ok_or("pf_token_amount: no inner instructions")?;
-
-    for ix in &ii.instructions {
-        if ix.data.first() == Some(&12) {
-            let bytes: [u8; 8] = ix
-                .data
-                .get(1..9)
-                .ok_or("pf_token_amount: TransferChecked data too short")?
-                .try_into()
-                .map_err(|_| "pf_token_amount: invalid amount slice")?;
-
-            return Ok(u64::from_le_bytes(bytes));
-        }
-    }
-
-    Err("pf_token_amount: TransferChecked not found".into())
-}
+/* ========= Fees ========= */
 
-/// Extract `trade_size_lamports` from
-/// `Pump Fees Program: get_fees` program log
-pub fn extract_trade_size_lamports(tx: &SubscribeUpdateTransactionInfo) -> Result<u64, String> {
+/// Value of `key` in the `Pump Fees Program: get_fees` log of the `trade_index`-th trade.
+/// The fee program logs once per trade, in trade order.
+pub fn get_fees_value<T: std::str::FromStr>(
+    tx: &SubscribeUpdateTransactionInfo,
+    key: &str,
+    trade_index: usize,
+) -> Result<T, String> {
     let logs = &tx
         .meta
         .as_ref()
-        .ok_or("pf_anchor_event_bytes: meta missing")?
+        .ok_or("get_fees_value: meta missing")?
         .log_messages;
+    let prefix = format!("Program log: Pump Fees Program: get_fees {key}=");
 
-    for log in logs {
-        // Example log format (as observed on-chain):
-        // "Program log: Pump Fees Program: get_fees trade_size_lamports=123456789"
-        if let Some(rest) = log.strip_prefix("Program log: Pump Fees Program: get_fees ") {
-            if let Some(value) = rest
-                .strip_prefix("trade_size_lamports=")
-                .and_then(|v| v.parse::<u64>().ok())
-            {
-                return Ok(value);
-            }
-        }
-    }
+    let value = logs
+        .iter()
+        .filter_map(|log| log.strip_prefix(&prefix))
+        .nth(trade_index)
+        .ok_or_else(|| format!("get_fees_value: {key} not found"))?;
 
-    Err("extract_trade_size_lamports: trade_size_lamports not found".into())
+    value
+        .parse::<T>()
+        .map_err(|_| format!("get_fees_value: invalid {key}"))
 }
 
-/* ========= Fees ========= */
-/* 1. SOL transaction fee (lamports) */
+/// SOL fee paid for the transaction (lamports)
 pub fn extract_transaction_fee(tx: &SubscribeUpdateTransactionInfo) -> Result<u64, String> {
     tx.meta
         .as_ref()
@@ -440,297 +333,29 @@ pub fn extract_transaction_fee(tx: &SubscribeUpdateTransactionInfo) -> Result<u6
         .ok_or("extract_transaction_fee: fee missing".into())
 }
 
-/* 2. protocol fee (lamports) */
-pub fn extract_protocol_fee_lamports(tx: &SubscribeUpdateTransactionInfo) -> Result<u64, String> {
-    let logs = &tx
-        .meta
-        .as_ref()
-        .ok_or("pf_anchor_event_bytes: meta missing")?
-        .log_messages;
-
-    for log in logs {
-        if let Some(v) = log
-            .strip_prefix("Program log: Pump Fees Program: get_fees fee_lamports=")
-            .and_then(|v| v.parse::<u64>().ok())
-        {
-            return Ok(v);
-        }
-    }
-
-    Err("extract_protocol_fee_lamports: not found".into())
-}
-
-/* 3. protocol fee bps */
-pub fn extract_protocol_fee_bps(tx: &SubscribeUpdateTransactionInfo) -> Result<u64, String> {
-    let logs = &tx
-        .meta
-        .as_ref()
-        .ok_or("pf_anchor_event_bytes: meta missing")?
-        .log_messages;
-
-    for log in logs {
-        if let Some(v) = log
-            .strip_prefix("Program log: Pump Fees Program: get_fees fee_basis_points=")
-            .and_then(|v| v.parse::<u64>().ok())
-        {
-            return Ok(v);
-        }
-    }
-
-    Err("extract_protocol_fee_bps: not found".into())
-}
-
-/* 4. creator fee (lamports) */
-pub fn extract_creator_fee_lamports(tx: &SubscribeUpdateTransactionInfo) -> Result<u64, String> {
-    let logs = &tx
-        .meta
-        .as_ref()
-        .ok_or("pf_anchor_event_bytes: meta missing")?
-        .log_messages;
-
-    for log in logs {
-        if let Some(v) = log
-            .strip_prefix("Program log: Pump Fees Program: get_fees creator_fee_lamports=")
-            .and_then(|v| v.parse::<u64>().ok())
-        {
-            return Ok(v);
-        }
-    }
-
-    Err("extract_creator_fee_lamports: not found".into())
-}
-
-/* 5. creator fee bps */
-pub fn extract_creator_fee_bps(tx: &SubscribeUpdateTransactionInfo) -> Result<u64, String> {
-    let logs = &tx
-        .meta
-        .as_ref()
-        .ok_or("pf_anchor_event_bytes: meta missing")?
-        .log_messages;
-
-    for log in logs {
-        if let Some(v) = log
-            .strip_prefix("Program log: Pump Fees Program: get_fees creator_fee_basis_points=")
-            .and_then(|v| v.parse::<u64>().ok())
-        {
-            return Ok(v);
-        }
-    }
-
-    Err("extract_creator_fee_bps: not found".into())
-}
-
 /* ========= Market / bonding curve state (post-trade) ========= */
 
-pub fn build_cc_pumpfun_trade_1(
+pub fn extract_token_decimals(
     tx: &SubscribeUpdateTransactionInfo,
-) -> Result<(u32, u64, u64, u64, u64), String> {
-    let buf = pf_anchor_event_bytes_1(tx)?;
-    let (vsol, vtok, rsol, rtok) = trade_event_offsets_1();
-    let decimals = extract_token_decimals(tx)?;
-    let virtual_sol_reserves = read_u64_1(&buf, vsol)?;
-    let virtual_token_reserves = read_u64_1(&buf, vtok)?;
-    let real_sol_reserves = read_u64_1(&buf, rsol)?;
-    let real_token_reserves = read_u64_1(&buf, rtok)?;
-
-    Ok((
-        decimals,
-        virtual_sol_reserves,
-        virtual_token_reserves,
-        real_sol_reserves,
-        real_token_reserves,
-    ))
-}
-
-fn trade_event_offsets_1() -> (usize, usize, usize, usize) {
-    const DISC: usize = 8;
-    const PUBKEY: usize = 32;
-    const U64: usize = 8;
-    const BOOL: usize = 1;
-
-    let mut o = DISC;
-
-    o += PUBKEY; // mint
-    o += U64; // sol_amount
-    o += U64; // token_amount
-    o += BOOL; // is_buy
-    o += PUBKEY; // user
-    o += U64; // timestamp
-
-    let virtual_sol = o;
-    o += U64;
-    let virtual_token = o;
-    o += U64;
-    let real_sol = o;
-    o += U64;
-    let real_token = o;
-    o += U64; // TODO review usage
-
-    (virtual_sol, virtual_token, real_sol, real_token)
-}
-
-fn pf_anchor_event_bytes_1(tx: &SubscribeUpdateTransactionInfo) -> Result<Vec<u8>, String> {
-    let logs = &tx
-        .meta
-        .as_ref()
-        .ok_or("pf_anchor_event_bytes: meta missing")?
-        .log_messages;
-
-    for log in logs {
-        if let Some(data) = log.strip_prefix("Program data: ") {
-            let decoded = STANDARD
-                .decode(data)
-                .map_err(|_| "pf_anchor_event_bytes: base64 decode failed")?;
-            return Ok(decoded);
-        }
-    }
-
-    Err("pf_anchor_event_bytes: Program data not found".into())
-}
-
-fn read_u64_1(buf: &[u8], offset: usize) -> Result<u64, String> {
-    let bytes: [u8; 8] = buf
-        .get(offset..offset + 8)
-        .ok_or("read_u64: out of bounds")?
-        .try_into()
-        .map_err(|_| "read_u64: invalid slice")?;
-    Ok(u64::from_le_bytes(bytes))
-}
-
-pub fn extract_token_decimals(tx: &SubscribeUpdateTransactionInfo) -> Result<u32, String> {
+    mint: &str,
+) -> Result<u8, String> {
     let meta = tx
         .meta
         .as_ref()
         .ok_or("extract_token_decimals: meta missing")?;
 
-    let first_balance = meta
-        .pre_token_balances
-        .first()
-        .ok_or("extract_token_decimals: pre_token_balances empty")?;
+    let balance = meta
+        .post_token_balances
+        .iter()
+        .chain(meta.pre_token_balances.iter())
+        .find(|b| b.mint == mint)
+        .ok_or("extract_token_decimals: no token balance for mint")?;
 
-    let ui_amount = first_balance
+    let ui_amount = balance
         .ui_token_amount
         .as_ref()
         .ok_or("extract_token_decimals: ui_token_amount missing")?;
 
-    Ok(ui_amount.decimals)
-}
-
-pub fn extract_market_cap_lamports(tx: &SubscribeUpdateTransactionInfo) -> Result<u64, String> {
-    let logs = &tx
-        .meta
-        .as_ref()
-        .ok_or("pf_anchor_event_bytes: meta missing")?
-        .log_messages;
-
-    for log in logs {
-        if let Some(v) = log
-            .strip_prefix("Program log: Pump Fees Program: get_fees market_cap_lamports=")
-            .and_then(|v| v.parse::<u64>().ok())
-        {
-            return Ok(v);
-        }
-    }
-
-    Err("extract_market_cap_lamports: not found".into())
-}
-
-/* ========= Volume & tracking ========= */
-
-fn pf_anchor_trade_event_bytes(tx: &SubscribeUpdateTransactionInfo) -> Result<Vec<u8>, String> {
-    let logs = &tx
-        .meta
-        .as_ref()
-        .ok_or("pf_anchor_event_bytes: meta missing")?
-        .log_messages;
-
-    for log in logs {
-        if let Some(data) = log.strip_prefix("Program data: ") {
-            return STANDARD
-                .decode(data)
-                .map_err(|_| "pf_anchor_trade_event_bytes: base64 decode failed".into());
-        }
-    }
-
-    Err("pf_anchor_trade_event_bytes: Program data not found".into())
-}
-
-fn volume_tracking_base_offset() -> usize {
-    const DISC: usize = 8;
-    const PUBKEY: usize = 32;
-    const U64: usize = 8;
-    const BOOL: usize = 1;
-
-    let mut o = DISC;
-
-    o += PUBKEY; // mint
-    o += U64; // sol_amount
-    o += U64; // token_amount
-    o += BOOL; // is_buy
-    o += PUBKEY; // user
-    o += U64; // timestamp
-    o += U64 * 4; // virtual_sol, virtual_token, real_sol, real_token
-    o += PUBKEY; // fee_recipient
-    o += U64; // fee_basis_points
-    o += U64; // fee
-    o += PUBKEY; // creator
-    o += U64; // creator_fee_basis_points
-    o += U64; // creator_fee
-
-    o
-}
-
-pub fn extract_track_volume(tx: &SubscribeUpdateTransactionInfo) -> Result<bool, String> {
-    let buf = pf_anchor_trade_event_bytes(tx)?;
-    let o = volume_tracking_base_offset();
-
-    if buf.len() <= o {
-        return Err("extract_track_volume: buffer too short".into());
-    }
-
-    Ok(buf[o] != 0)
-}
-
-pub fn extract_total_unclaimed_tokens(tx: &SubscribeUpdateTransactionInfo) -> Result<u64, String> {
-    let buf = pf_anchor_trade_event_bytes(tx)?;
-    let o = volume_tracking_base_offset() + 1;
-
-    if buf.len() < o + 8 {
-        return Err("extract_total_unclaimed_tokens: buffer too short".into());
-    }
-
-    Ok(u64::from_le_bytes(buf[o..o + 8].try_into().unwrap()))
-}
-
-pub fn extract_total_claimed_tokens(tx: &SubscribeUpdateTransactionInfo) -> Result<u64, String> {
-    let buf = pf_anchor_trade_event_bytes(tx)?;
-    let o = volume_tracking_base_offset() + 1 + 8;
-
-    if buf.len() < o + 8 {
-        return Err("extract_total_claimed_tokens: buffer too short".into());
-    }
-
-    Ok(u64::from_le_bytes(buf[o..o + 8].try_into().unwrap()))
-}
-
-pub fn extract_current_sol_volume(tx: &SubscribeUpdateTransactionInfo) -> Result<u64, String> {
-    let buf = pf_anchor_trade_event_bytes(tx)?;
-    let o = volume_tracking_base_offset() + 1 + 8 + 8;
-
-    if buf.len() < o + 8 {
-        return Err("extract_current_sol_volume: buffer too short".into());
-    }
-
-    Ok(u64::from_le_bytes(buf[o..o + 8].try_into().unwrap()))
-}
-
-pub fn extract_last_update_timestamp(tx: &SubscribeUpdateTransactionInfo) -> Result<i64, String> {
-    let buf = pf_anchor_trade_event_bytes(tx)?;
-    let o = volume_tracking_base_offset() + 1 + 8 + 8 + 8;
-
-    if buf.len() < o + 8 {
-        return Err("extract_last_update_timestamp: buffer too short".into());
-    }
-
-    Ok(i64::from_le_bytes(buf[o..o + 8].try_into().unwrap()))
+    u8::try_from(ui_amount.decimals)
+        .map_err(|_| "extract_token_decimals: decimals out of range".into())
 }
@@ -0,0 +1,72 @@
+use crate::handlers::anchor_event::{
+    PF_COLLECT_CREATOR_FEE_EVENT_DISC, PF_COLLECT_CREATOR_FEE_IX_DISC, find_anchor_events,
+    find_instructions, read_i64, read_pubkey, read_u64,
+};
+use crate::handlers::pumpfun::pf_ch_trade_occurred_handler::extract_pf_signature;
+use crate::models::consts::PUMPFUN_PROGRAM_ID;
+use crate::models::pump_models::pf_kafka_event::KPfCreatorFeeClaim;
+use chrono::{DateTime, Utc};
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+
+/* CollectCreatorFeeEvent layout (after discriminator) */
+const TIMESTAMP_OFFSET: usize = 0; // i64
+const CREATOR_OFFSET: usize = 8; // Pubkey
+const CREATOR_FEE_OFFSET: usize = 40; // u64
+
+/* collect_creator_fee accounts */
+const CREATOR_VAULT_ACCOUNT: usize = 1;
+
+/// One claim per CollectCreatorFeeEvent, paired with the collect_creator_fee instruction that emitted it
+pub fn handle_pf_creator_fee_claims(
+    tx_info: &SubscribeUpdateTransactionInfo,
+    slot: u64,
+) -> Result<Vec<KPfCreatorFeeClaim>, String> {
+    let signature = extract_pf_signature(tx_info)?;
+
+    let events = find_anchor_events(tx_info, &PF_COLLECT_CREATOR_FEE_EVENT_DISC);
+    if events.is_empty() {
+        return Err("handle_pf_creator_fee_claims: CollectCreatorFeeEvent not found".into());
+    }
+    let instructions = find_instructions(
+        tx_info,
+        PUMPFUN_PROGRAM_ID,
+        &[PF_COLLECT_CREATOR_FEE_IX_DISC],
+    );
+
+    events
+        .iter()
+        .enumerate()
+        .map(|(k, event)| -> Result<KPfCreatorFeeClaim, String> {
+            let creator = read_pubkey(&event.data, CREATOR_OFFSET)
+                .map_err(|e| format!("handle_pf_creator_fee_claims: creator: {e}"))?;
+            let creator_fee = read_u64(&event.data, CREATOR_FEE_OFFSET)
+                .map_err(|e| format!("handle_pf_creator_fee_claims: creator_fee: {e}"))?;
+            let ts = extract_claim_timestamp(&event.data)?;
+
+            let creator_vault = instructions
+                .get(k)
+                .and_then(|ix| ix.accounts.get(CREATOR_VAULT_ACCOUNT))
+                .cloned()
+                .ok_or("handle_pf_creator_fee_claims: creator_vault account missing")?;
+
+            Ok(KPfCreatorFeeClaim {
+                signature: signature.clone(),
+                slot,
+                creator,
+                creator_vault,
+                creator_fee,
+                ts,
+                ix_index: event.ix_index,
+                event_ordinal: event.ordinal,
+            })
+        })
+        .collect()
+}
+
+fn extract_claim_timestamp(event: &[u8]) -> Result<DateTime<Utc>, String> {
+    let ts =
+        read_i64(event, TIMESTAMP_OFFSET).map_err(|e| format!("extract_claim_timestamp: {e}"))?;
+
+    DateTime::from_timestamp(ts, 0)
+        .ok_or_else(|| "extract_claim_timestamp: invalid timestamp".into())
+}
@@ -1,794 +1,68 @@
+use crate::handlers::anchor_event::{
+    PF_CREATE_EVENT_DISC, find_anchor_events, read_i64, read_pubkey, read_string, read_u64,
+};
 use crate::models::pump_models::pf_kafka_event::KPfToken;
-use chrono::{DateTime, Utc};
-use solana_sdk::bs58;
+use chrono::DateTime;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
-pub fn extract_name(tx_info: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    let tx = tx_info.transaction.as_ref().ok_or("No transaction")?;
-    let msg = tx.message.as_ref().ok_or("No message")?;
-    let ix = msg.instructions.get(2).ok_or("No create instruction")?;
-    let data = ix.data.as_slice();
-
-    // Need at least 8 bytes for discriminator
-    if data.len() < 8 {
-        return Err("Data too short for discriminator".into());
-    }
-
-    let mut cursor = 8;
-
-    // Need 4 bytes for name_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for name_len".into());
-    }
-
-    let name_len_bytes: [u8; 4] = data[cursor..cursor + 4]
-        .try_into()
-        .map_err(|_| "Failed to read name_len")?;
-    let name_len = u32::from_le_bytes(name_len_bytes) as usize;
-    cursor += 4;
-
-    // Need name_len bytes for name
-    if data.len() < cursor + name_len {
-        return Err("Data too short for name field".into());
-    }
-
-    let name_bytes = &data[cursor..cursor + name_len];
-    let name = String::from_utf8(name_bytes.to_vec()).map_err(|_| "Invalid UTF-8 in name")?;
-
-    Ok(name)
-}
-
-pub fn extract_symbol(tx_info: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    let tx = tx_info.transaction.as_ref().ok_or("No transaction")?;
-    let msg = tx.message.as_ref().ok_or("No message")?;
-    let ix = msg.instructions.get(2).ok_or("No create instruction")?;
-    let data = ix.data.as_slice();
-
-    if data.len() < 8 {
-        return Err("Data too short for discriminator".into());
-    }
-
-    let mut cursor = 8;
-
-    // name_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for name_len".into());
-    }
-    let name_len_bytes: [u8; 4] = data[cursor..cursor + 4]
-        .try_into()
-        .map_err(|_| "Failed to read name_len")?;
-    let name_len = u32::from_le_bytes(name_len_bytes) as usize;
-    cursor += 4;
-
-    // skip name
-    if data.len() < cursor + name_len {
-        return Err("Data too short for name field".into());
-    }
-    cursor += name_len;
-
-    // symbol_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for symbol_len".into());
-    }
-    let symbol_len_bytes: [u8; 4] = data[cursor..cursor + 4]
-        .try_into()
-        .map_err(|_| "Failed to read symbol_len")?;
-    let symbol_len = u32::from_le_bytes(symbol_len_bytes) as usize;
-    cursor += 4;
-
-    // symbol
-    if data.len() < cursor + symbol_len {
-        return Err("Data too short for symbol field".into());
-    }
-    let symbol_bytes = &data[cursor..cursor + symbol_len];
-    let symbol = String::from_utf8(symbol_bytes.to_vec()).map_err(|_| "Invalid UTF-8 in symbol")?;
-
-    Ok(symbol)
-}
-
-pub fn extract_uri(tx_info: &SubscribeUpdateTransactionInfo) -> Result<Option<String>, String> {
-    let tx = tx_info.transaction.as_ref().ok_or("No transaction")?;
-    let msg = tx.message.as_ref().ok_or("No message")?;
-    let ix = msg.instructions.get(2).ok_or("No create instruction")?;
-    let data = ix.data.as_slice();
-
-    if data.len() < 8 {
-        return Err("Data too short for discriminator".into());
-    }
-
-    let mut cursor = 8;
-
-    // name_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for name_len".into());
-    }
-    let name_len_bytes: [u8; 4] = data[cursor..cursor + 4]
-        .try_into()
-        .map_err(|_| "Failed to read name_len")?;
-    let name_len = u32::from_le_bytes(name_len_bytes) as usize;
-    cursor += 4;
-
-    // skip name
-    if data.len() < cursor + name_len {
-        return Err("Data too short for name".into());
-    }
-    cursor += name_len;
-
-    // symbol_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for symbol_len".into());
-    }
-    let symbol_len_bytes: [u8; 4] = data[cursor..cursor + 4]
-        .try_into()
-        .map_err(|_| "Failed to read symbol_len")?;
-    let symbol_len = u32::from_le_bytes(symbol_len_bytes) as usize;
-    cursor += 4;
-
-    // skip symbol
-    if data.len() < cursor + symbol_len {
-        return Err("Data too short for symbol".into());
-    }
-    cursor += symbol_len;
-
-    // uri_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for uri_len".into());
-    }
-    let uri_len_bytes: [u8; 4] = data[cursor..cursor + 4]
-        .try_into()
-        .map_err(|_| "Failed to read uri_len")?;
-    let uri_len = u32::from_le_bytes(uri_len_bytes) as usize;
-    cursor += 4;
-
-    if uri_len == 0 {
-        return Ok(None);
-    }
-
-    // uri
-    if data.len() < cursor + uri_len {
-        return Err("Data too short for uri".into());
-    }
-    let uri_bytes = &data[cursor..cursor + uri_len];
-    let uri = String::from_utf8(uri_bytes.to_vec()).map_err(|_| "Invalid UTF-8 in uri")?;
-
-    Ok(Some(uri))
-}
-
-pub fn extract_creator(tx_info: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    let tx = tx_info.transaction.as_ref().ok_or("No transaction")?;
-    let msg = tx.message.as_ref().ok_or("No message")?;
-    let ix = msg.instructions.get(2).ok_or("No create instruction")?;
-    let data = ix.data.as_slice();
-
-    if data.len() < 8 {
-        return Err("Data too short for discriminator".into());
-    }
-
-    let mut cursor = 8;
-
-    // name_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for name_len".into());
-    }
-    let name_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed to read name_len")?,
-    ) as usize;
-    cursor += 4;
-
-    // skip name
-    if data.len() < cursor + name_len {
-        return Err("Data too short for name".into());
-    }
-    cursor += name_len;
-
-    // symbol_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for symbol_len".into());
-    }
-    let symbol_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed to read symbol_len")?,
-    ) as usize;
-    cursor += 4;
-
-    // skip symbol
-    if data.len() < cursor + symbol_len {
-        return Err("Data too short for symbol".into());
-    }
-    cursor += symbol_len;
-
-    // uri_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for uri_len".into());
-    }
-    let uri_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed to read uri_len")?,
-    ) as usize;
-    cursor += 4;
-
-    // skip uri
-    if data.len() < cursor + uri_len {
-        return Err("Data too short for uri".into());
-    }
-    cursor += uri_len;
-
-    // creator (32 bytes)
-    if data.len() < cursor + 32 {
-        return Err("Data too short for creator pubkey".into());
-    }
-    let creator_bytes = &data[cursor..cursor + 32];
-
-    Ok(bs58::encode(creator_bytes).into_string())
-}
-
-pub fn extract_mint(tx_info: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    let tx = tx_info.transaction.as_ref().ok_or("No transaction")?;
-    let msg = tx.message.as_ref().ok_or("No message")?;
-
-    let mint_bytes = msg
-        .account_keys
-        .get(1)
-        .ok_or("No mint account at index 1")?;
-
-    if mint_bytes.len() != 32 {
-        return Err("Mint pubkey has invalid length".into());
-    }
-
-    Ok(bs58::encode(mint_bytes).into_string())
-}
-
-pub fn extract_bonding_curve(tx_info: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    let tx = tx_info.transaction.as_ref().ok_or("No transaction")?;
-    let msg = tx.message.as_ref().ok_or("No message")?;
-
-    let curve_bytes = msg
-        .account_keys
-        .get(2)
-        .ok_or("No bonding curve account at index 2")?;
-
-    if curve_bytes.len() != 32 {
-        return Err("Bonding curve pubkey has invalid length".into());
-    }
-
-    Ok(bs58::encode(curve_bytes).into_string())
-}
-
-pub fn extract_user_address(tx_info: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    let tx = tx_info.transaction.as_ref().ok_or("No transaction")?;
-    let msg = tx.message.as_ref().ok_or("No message")?;
-
-    let user_bytes = msg
-        .account_keys
-        .first()
-        .ok_or("No user account at index 0")?;
-
-    if user_bytes.len() != 32 {
-        return Err("User pubkey has invalid length".into());
-    }
-
-    Ok(bs58::encode(user_bytes).into_string())
-}
-
-pub fn extract_timestamp(
+/* CreateEvent layout (after discriminator): name, symbol and uri strings, then */
+const MINT_OFFSET: usize = 0; // Pubkey
+const BONDING_CURVE_OFFSET: usize = 32; // Pubkey
+const USER_OFFSET: usize = 64; // Pubkey
+const CREATOR_OFFSET: usize = 96; // Pubkey
+const TIMESTAMP_OFFSET: usize = 128; // i64
+const VIRTUAL_TOKEN_RESERVES_OFFSET: usize = 136; // u64
+const VIRTUAL_SOL_RESERVES_OFFSET: usize = 144; // u64
+const REAL_TOKEN_RESERVES_OFFSET: usize = 152; // u64
+const TOKEN_TOTAL_SUPPLY_OFFSET: usize = 160; // u64
+
+/// Every Pump.fun mint is created with 6 decimals
+const PF_TOKEN_DECIMALS: i16 = 6;
+
+/// One token per CreateEvent (`create` and `create_v2`)
+pub fn handle_pf_token_creations(
     tx_info: &SubscribeUpdateTransactionInfo,
-) -> Result<DateTime<Utc>, String> {
-    let meta = tx_info.meta.as_ref().ok_or("No meta")?;
-    let inner_ix = meta
-        .inner_instructions
-        .first()
-        .ok_or("No inner instructions at index 0")?;
-    let event_ix = inner_ix
-        .instructions
-        .get(14)
-        .ok_or("No event instruction at index 14")?;
-    let data = event_ix.data.as_slice();
-
-    // Need at least 16 bytes to skip discriminators
-    if data.len() < 16 {
-        return Err("Data too short for event discriminators".into());
-    }
-
-    let mut cursor = 16;
-
-    // name_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for name_len".into());
-    }
-    let name_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed reading name_len")?,
-    ) as usize;
-    cursor += 4;
-
-    // skip name
-    if data.len() < cursor + name_len {
-        return Err("Data too short for name".into());
-    }
-    cursor += name_len;
-
-    // symbol_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for symbol_len".into());
-    }
-    let symbol_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed reading symbol_len")?,
-    ) as usize;
-    cursor += 4;
-
-    // skip symbol
-    if data.len() < cursor + symbol_len {
-        return Err("Data too short for symbol".into());
-    }
-    cursor += symbol_len;
-
-    // uri_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for uri_len".into());
-    }
-    let uri_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed reading uri_len")?,
-    ) as usize;
-    cursor += 4;
-
-    // skip uri
-    if data.len() < cursor + uri_len {
-        return Err("Data too short for uri".into());
-    }
-    cursor += uri_len;
-
-    // skip 4 pubkeys (32 bytes each)
-    let skip_pubkeys = 32 * 4;
-    if data.len() < cursor + skip_pubkeys {
-        return Err("Data too short for pubkeys".into());
+) -> Result<Vec<KPfToken>, String> {
+    let events = find_anchor_events(tx_info, &PF_CREATE_EVENT_DISC);
+    if events.is_empty() {
+        return Err("handle_pf_token_creations: CreateEvent not found".into());
     }
-    cursor += skip_pubkeys;
 
-    // timestamp (i64, 8 bytes)
-    if data.len() < cursor + 8 {
-        return Err("Data too short for timestamp".into());
-    }
-    let ts_value = i64::from_le_bytes(
-        data[cursor..cursor + 8]
-            .try_into()
-            .map_err(|_| "Failed reading timestamp")?,
-    );
-
-    let ts = DateTime::from_timestamp(ts_value, 0)
-        .ok_or_else(|| "Invalid timestamp value".to_string())?;
-
-    Ok(ts)
-}
-
-pub fn extract_virtual_token_reserves(
-    tx_info: &SubscribeUpdateTransactionInfo,
-) -> Result<i64, String> {
-    let meta = tx_info.meta.as_ref().ok_or("No meta")?;
-    let inner_ix = meta
-        .inner_instructions
-        .first()
-        .ok_or("No inner instructions at index 0")?;
-    let event_ix = inner_ix
-        .instructions
-        .get(14)
-        .ok_or("No event instruction at index 14")?;
-    let data = event_ix.data.as_slice();
-
-    if data.len() < 16 {
-        return Err("Data too short for event discriminators".into());
-    }
-
-    let mut cursor = 16;
-
-    // name_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for name_len".into());
-    }
-    let name_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed to read name_len")?,
-    ) as usize;
-    cursor += 4;
-
-    if data.len() < cursor + name_len {
-        return Err("Data too short for name".into());
-    }
-    cursor += name_len;
-
-    // symbol_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for symbol_len".into());
-    }
-    let symbol_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed to read symbol_len")?,
-    ) as usize;
-    cursor += 4;
-
-    if data.len() < cursor + symbol_len {
-        return Err("Data too short for symbol".into());
-    }
-    cursor += symbol_len;
-
-    // uri_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for uri_len".into());
-    }
-    let uri_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed to read uri_len")?,
-    ) as usize;
-    cursor += 4;
-
-    if data.len() < cursor + uri_len {
-        return Err("Data too short for uri".into());
-    }
-    cursor += uri_len;
-
-    // skip 4 pubkeys + timestamp = 32*4 + 8
-    let skip_fixed = 32 * 4 + 8;
-    if data.len() < cursor + skip_fixed {
-        return Err("Data too short for pubkeys + timestamp".into());
-    }
-    cursor += skip_fixed;
-
-    // virtual_token_reserves (u64)
-    if data.len() < cursor + 8 {
-        return Err("Data too short for virtual_token_reserves".into());
-    }
-    let reserves = u64::from_le_bytes(
-        data[cursor..cursor + 8]
-            .try_into()
-            .map_err(|_| "Failed to read virtual_token_reserves")?,
-    );
-
-    Ok(reserves as i64)
-}
-
-// Extract virtual_sol_reserves from event log
-pub fn extract_virtual_sol_reserves(
-    tx_info: &SubscribeUpdateTransactionInfo,
-) -> Result<i64, String> {
-    let meta = tx_info.meta.as_ref().ok_or("No meta")?;
-    let inner_ix = meta
-        .inner_instructions
-        .first()
-        .ok_or("No inner instructions at index 0")?;
-    let event_ix = inner_ix
-        .instructions
-        .get(14)
-        .ok_or("No event instruction at index 14")?;
-    let data = event_ix.data.as_slice();
-
-    if data.len() < 16 {
-        return Err("Data too short for event discriminators".into());
-    }
-
-    let mut cursor = 16;
-
-    // name_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for name_len".into());
-    }
-    let name_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed reading name_len")?,
-    ) as usize;
-    cursor += 4;
-
-    if data.len() < cursor + name_len {
-        return Err("Data too short for name".into());
-    }
-    cursor += name_len;
-
-    // symbol_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for symbol_len".into());
-    }
-    let symbol_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed reading symbol_len")?,
-    ) as usize;
-    cursor += 4;
-
-    if data.len() < cursor + symbol_len {
-        return Err("Data too short for symbol".into());
-    }
-    cursor += symbol_len;
-
-    // uri_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for uri_len".into());
-    }
-    let uri_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed reading uri_len")?,
-    ) as usize;
-    cursor += 4;
-
-    if data.len() < cursor + uri_len {
-        return Err("Data too short for uri".into());
-    }
-    cursor += uri_len;
-
-    // skip mint, bonding_curve, user, creator, timestamp, virtual_token_reserves
-    let skip = 32 * 4 + 8 + 8;
-    if data.len() < cursor + skip {
-        return Err("Data too short for fixed fields".into());
-    }
-    cursor += skip;
-
-    // virtual_sol_reserves (u64)
-    if data.len() < cursor + 8 {
-        return Err("Data too short for virtual_sol_reserves".into());
-    }
-    let reserves = u64::from_le_bytes(
-        data[cursor..cursor + 8]
-            .try_into()
-            .map_err(|_| "Failed reading virtual_sol_reserves")?,
-    );
-
-    Ok(reserves as i64)
-}
-
-// Extract real_token_reserves from event log
-pub fn extract_real_token_reserves(
-    tx_info: &SubscribeUpdateTransactionInfo,
-) -> Result<i64, String> {
-    let meta = tx_info.meta.as_ref().ok_or("No meta")?;
-    let inner_ix = meta
-        .inner_instructions
-        .first()
-        .ok_or("No inner instructions at index 0")?;
-    let event_ix = inner_ix
-        .instructions
-        .get(14)
-        .ok_or("No event instruction at index 14")?;
-    let data = event_ix.data.as_slice();
-
-    if data.len() < 16 {
-        return Err("Data too short for event discriminators".into());
-    }
-
-    let mut cursor = 16;
-
-    // name_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for name_len".into());
-    }
-    let name_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed reading name_len")?,
-    ) as usize;
-    cursor += 4;
-
-    if data.len() < cursor + name_len {
-        return Err("Data too short for name".into());
-    }
-    cursor += name_len;
-
-    // symbol_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for symbol_len".into());
-    }
-    let symbol_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed reading symbol_len")?,
-    ) as usize;
-    cursor += 4;
-
-    if data.len() < cursor + symbol_len {
-        return Err("Data too short for symbol".into());
-    }
-    cursor += symbol_len;
-
-    // uri_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for uri_len".into());
-    }
-    let uri_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed reading uri_len")?,
-    ) as usize;
-    cursor += 4;
-
-    if data.len() < cursor + uri_len {
-        return Err("Data too short for uri".into());
-    }
-    cursor += uri_len;
-
-    // skip mint, bonding_curve, user, creator, timestamp, virtual_token_reserves, virtual_sol_reserves
-    let skip = 32 * 4 + 8 + 8 + 8;
-    if data.len() < cursor + skip {
-        return Err("Data too short for fixed fields".into());
-    }
-    cursor += skip;
-
-    // real_token_reserves (u64)
-    if data.len() < cursor + 8 {
-        return Err("Data too short for real_token_reserves".into());
-    }
-
-    let reserves = u64::from_le_bytes(
-        data[cursor..cursor + 8]
-            .try_into()
-            .map_err(|_| "Failed reading real_token_reserves")?,
-    );
-
-    Ok(reserves as i64)
-}
-
-// Extract token_total_supply from event log
-pub fn extract_token_total_supply(tx_info: &SubscribeUpdateTransactionInfo) -> Result<i64, String> {
-    let meta = tx_info.meta.as_ref().ok_or("No meta")?;
-    let inner_ix = meta
-        .inner_instructions
-        .first()
-        .ok_or("No inner instructions at index 0")?;
-    let event_ix = inner_ix
-        .instructions
-        .get(14)
-        .ok_or("No event instruction at index 14")?;
-    let data = event_ix.data.as_slice();
-
-    if data.len() < 16 {
-        return Err("Data too short for event discriminators".into());
-    }
-
-    let mut cursor = 16;
-
-    // name_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for name_len".into());
-    }
-    let name_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed reading name_len")?,
-    ) as usize;
-    cursor += 4;
-    if data.len() < cursor + name_len {
-        return Err("Data too short for name".into());
-    }
-    cursor += name_len;
-
-    // symbol_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for symbol_len".into());
-    }
-    let symbol_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed reading symbol_len")?,
-    ) as usize;
-    cursor += 4;
-    if data.len() < cursor + symbol_len {
-        return Err("Data too short for symbol".into());
-    }
-    cursor += symbol_len;
-
-    // uri_len
-    if data.len() < cursor + 4 {
-        return Err("Data too short for uri_len".into());
-    }
-    let uri_len = u32::from_le_bytes(
-        data[cursor..cursor + 4]
-            .try_into()
-            .map_err(|_| "Failed reading uri_len")?,
-    ) as usize;
-    cursor += 4;
-    if data.len() < cursor + uri_len {
-        return Err("Data too short for uri".into());
-    }
-    cursor += uri_len;
-
-    // skip mint, bonding_curve, user, creator, timestamp, virtual_token_reserves,
-    // virtual_sol_reserves, real_token_reserves
-    let skip = 32 * 4 + 8 + 8 + 8 + 8;
-    if data.len() < cursor + skip {
-        return Err("Data too short for fixed fields".into());
-    }
-    cursor += skip;
-
-    // total_supply (u64)
-    if data.len() < cursor + 8 {
-        return Err("Data too short for token_total_supply".into());
-    }
-    let supply = u64::from_le_bytes(
-        data[cursor..cursor + 8]
-            .try_into()
-            .map_err(|_| "Failed reading token_total_supply")?,
-    );
-
-    Ok(supply as i64)
-}
-
-pub fn extract_decimal(tx_info: &SubscribeUpdateTransactionInfo) -> Result<u8, String> {
-    let meta = tx_info.meta.as_ref().ok_or("No meta")?;
-    let inner_ix = meta
-        .inner_instructions
-        .first()
-        .ok_or("No inner instructions at index 0")?;
-
-    // Find InitializeMint2 instruction (discriminator = 20)
-    let mint_ix = inner_ix
-        .instructions
+    events
         .iter()
-        .find(|ix| ix.data.first() == Some(&20))
-        .ok_or("No InitializeMint2 instruction found")?;
-
-    let data = mint_ix.data.as_slice();
-
-    if data.len() < 2 {
-        return Err("Data too short for decimals".into());
-    }
-
-    // decimals is at byte 1
-    let decimals = data[1];
-
-    Ok(decimals)
+        .map(|event| decode_create_event(tx_info, &event.data))
+        .collect()
 }
 
-// Main handler
-pub fn handle_pf_token_creation(
+fn decode_create_event(
     tx_info: &SubscribeUpdateTransactionInfo,
+    data: &[u8],
 ) -> Result<KPfToken, String> {
-    let name = extract_name(tx_info).map_err(|e| format!("extract_name: {e}"))?;
-
-    let symbol = extract_symbol(tx_info).map_err(|e| format!("extract_symbol: {e}"))?;
-
-    let uri = extract_uri(tx_info).map_err(|e| format!("extract_uri: {e}"))?;
+    let field = |name: &str, e: String| format!("decode_create_event: {name}: {e}");
 
-    let creator = extract_creator(tx_info).map_err(|e| format!("extract_creator: {e}"))?;
-
-    let mint = extract_mint(tx_info).map_err(|e| format!("extract_mint: {e}"))?;
+    let (name, o) = read_string(data, 0).map_err(|e| field("name", e))?;
+    let (symbol, o) = read_string(data, o).map_err(|e| field("symbol", e))?;
+    let (uri, o) = read_string(data, o).map_err(|e| field("uri", e))?;
 
+    let mint = read_pubkey(data, o + MINT_OFFSET).map_err(|e| field("mint", e))?;
     let bonding_curve =
-        extract_bonding_curve(tx_info).map_err(|e| format!("extract_bonding_curve: {e}"))?;
-
-    let user_address =
-        extract_user_address(tx_info).map_err(|e| format!("extract_user_address: {e}"))?;
-
-    let ts = extract_timestamp(tx_info).map_err(|e| format!("extract_timestamp: {e}"))?;
-
-    let virtual_token_reserves = extract_virtual_token_reserves(tx_info)
-        .map_err(|e| format!("extract_virtual_token_reserves: {e}"))?;
-
-    let virtual_sol_reserves = extract_virtual_sol_reserves(tx_info)
-        .map_err(|e| format!("extract_virtual_sol_reserves: {e}"))?;
-
-    let real_token_reserves = extract_real_token_reserves(tx_info)
-        .map_err(|e| format!("extract_real_token_reserves: {e}"))?;
-
-    let token_total_supply = extract_token_total_supply(tx_info)
-        .map_err(|e| format!("extract_token_total_supply: {e}"))?;
-
-    let decimals =
-        extract_decimal(tx_info).map_err(|e| format!("extract_token_total_supply: {e}"))?;
-
-    let description = "".to_string();
-    let twitter = "".to_string();
-    let telegram = "".to_string();
-    let website = "".to_string();
-    let image = "".to_string();
+        read_pubkey(data, o + BONDING_CURVE_OFFSET).map_err(|e| field("bonding_curve", e))?;
+    let user_address = read_pubkey(data, o + USER_OFFSET).map_err(|e| field("user", e))?;
+    let creator = read_pubkey(data, o + CREATOR_OFFSET).map_err(|e| field("creator", e))?;
+    let ts = read_i64(data, o + TIMESTAMP_OFFSET)
+        .map_err(|e| field("timestamp", e))
+        .and_then(|ts| {
+            DateTime::from_timestamp(ts, 0)
+                .ok_or_else(|| field("timestamp", "invalid timestamp".into()))
+        })?;
+    let virtual_token_reserves = read_u64(data, o + VIRTUAL_TOKEN_RESERVES_OFFSET)
+        .map_err(|e| field("virtual_token_reserves", e))?;
+    let virtual_sol_reserves = read_u64(data, o + VIRTUAL_SOL_RESERVES_OFFSET)
+        .map_err(|e| field("virtual_sol_reserves", e))?;
+    let real_token_reserves = read_u64(data, o + REAL_TOKEN_RESERVES_OFFSET)
+        .map_err(|e| field("real_token_reserves", e))?;
+    let token_total_supply = read_u64(data, o + TOKEN_TOTAL_SUPPLY_OFFSET)
+        .map_err(|e| field("token_total_supply", e))?;
 
     Ok(KPfToken {
         mint,
@@ -797,17 +71,18 @@ pub fn handle_pf_token_creation(
         symbol,
         creator,
         user_address,
-        decimals: (decimals as i16),
+        decimals: PF_TOKEN_DECIMALS,
         ts,
-        uri,
-        description,
-        twitter,
-        telegram,
-        website,
-        image,
-        virtual_token_reserves,
-        virtual_sol_reserves,
-        real_token_reserves,
-        token_total_supply,
+        uri: Some(uri),
+        description: "".to_string(),
+        twitter: "".to_string(),
+        telegram: "".to_string(),
+        website: "".to_string(),
+        image: "".to_string(),
+        virtual_token_reserves: virtual_token_reserves as i64,
+        virtual_sol_reserves: virtual_sol_reserves as i64,
+        real_token_reserves: real_token_reserves as i64,
+        token_total_supply: token_total_supply as i64,
+        tx_index: tx_info.index,
     })
 }
@@ -0,0 +1,72 @@
+use crate::handlers::anchor_event::{
+    PF_CLAIM_TOKEN_INCENTIVES_EVENT_DISC, find_anchor_events, read_i64, read_pubkey, read_u64,
+};
+use crate::handlers::pumpfun::pf_ch_trade_occurred_handler::extract_pf_signature;
+use crate::models::pump_models::pf_kafka_event::KPfTokenIncentiveClaim;
+use chrono::{DateTime, Utc};
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+
+/* ClaimTokenIncentivesEvent layout (after discriminator) */
+const USER_OFFSET: usize = 0; // Pubkey
+const MINT_OFFSET: usize = 32; // Pubkey
+const AMOUNT_OFFSET: usize = 64; // u64
+const TIMESTAMP_OFFSET: usize = 72; // i64
+const TOTAL_CLAIMED_TOKENS_OFFSET: usize = 80; // u64
+const CURRENT_SOL_VOLUME_OFFSET: usize = 88; // u64
+
+/// One claim per ClaimTokenIncentivesEvent
+pub fn handle_pf_token_incentives_claims(
+    tx_info: &SubscribeUpdateTransactionInfo,
+    slot: u64,
+) -> Result<Vec<KPfTokenIncentiveClaim>, String> {
+    let signature = extract_pf_signature(tx_info)?;
+
+    let events = find_anchor_events(tx_info, &PF_CLAIM_TOKEN_INCENTIVES_EVENT_DISC);
+    if events.is_empty() {
+        return Err(
+            "handle_pf_token_incentives_claims: ClaimTokenIncentivesEvent not found".into(),
+        );
+    }
+
+    events
+        .iter()
+        .map(|event| -> Result<KPfTokenIncentiveClaim, String> {
+            let user = read_pubkey(&event.data, USER_OFFSET)
+                .map_err(|e| format!("handle_pf_token_incentives_claims: user: {e}"))?;
+            let mint = read_pubkey(&event.data, MINT_OFFSET)
+                .map_err(|e| format!("handle_pf_token_incentives_claims: mint: {e}"))?;
+            let amount = read_u64(&event.data, AMOUNT_OFFSET)
+                .map_err(|e| format!("handle_pf_token_incentives_claims: amount: {e}"))?;
+            let total_claimed_tokens =
+                read_u64(&event.data, TOTAL_CLAIMED_TOKENS_OFFSET).map_err(|e| {
+                    format!("handle_pf_token_incentives_claims: total_claimed_tokens: {e}")
+                })?;
+            let current_sol_volume =
+                read_u64(&event.data, CURRENT_SOL_VOLUME_OFFSET).map_err(|e| {
+                    format!("handle_pf_token_incentives_claims: current_sol_volume: {e}")
+                })?;
+            let ts = extract_claim_timestamp(&event.data)?;
+
+            Ok(KPfTokenIncentiveClaim {
+                signature: signature.clone(),
+                slot,
+                user,
+                mint,
+                amount,
+                total_claimed_tokens,
+                current_sol_volume,
+                ts,
+                ix_index: event.ix_index,
+                event_ordinal: event.ordinal,
+            })
+        })
+        .collect()
+}
+
+fn extract_claim_timestamp(event: &[u8]) -> Result<DateTime<Utc>, String> {
+    let ts =
+        read_i64(event, TIMESTAMP_OFFSET).map_err(|e| format!("extract_claim_timestamp: {e}"))?;
+
+    DateTime::from_timestamp(ts, 0)
+        .ok_or_else(|| "extract_claim_timestamp: invalid timestamp".into())
+}
@@ -1,280 +1,52 @@
+use crate::handlers::anchor_event::{
+    PF_COMPLETE_PUMP_AMM_MIGRATION_EVENT_DISC, find_anchor_events, read_i64, read_pubkey, read_u64,
+};
 use crate::models::enums::Platform;
 use crate::models::kafka_event::TokenStatus;
 use crate::models::pump_models::pf_kafka_event::KPfTokenLifecycle;
-use chrono::{DateTime, Utc};
-use solana_sdk::bs58;
+use chrono::DateTime;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
-pub fn extract_mint(tx_info: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    let meta = tx_info.meta.as_ref().ok_or("No meta")?;
-    let inner_ix = meta
-        .inner_instructions
-        .first()
-        .ok_or("No inner instructions")?;
+/* CompletePumpAmmMigrationEvent layout (after discriminator) */
+const MINT_OFFSET: usize = 32; // Pubkey
+const MINT_AMOUNT_OFFSET: usize = 64; // u64
+const SOL_AMOUNT_OFFSET: usize = 72; // u64
+const BONDING_CURVE_OFFSET: usize = 88; // Pubkey
+const TIMESTAMP_OFFSET: usize = 120; // i64
+const POOL_OFFSET: usize = 128; // Pubkey
 
-    let event_ix = inner_ix.instructions.last().ok_or("No event instruction")?;
-
-    let data = event_ix.data.as_slice();
-
-    if data.len() < 16 {
-        return Err("Data too short for event discriminators".into());
-    }
-
-    let mut cursor = 16;
-
-    // user pubkey (32 bytes)
-    if data.len() < cursor + 32 {
-        return Err("Data too short for user pubkey".into());
-    }
-    cursor += 32;
-
-    // mint pubkey (32 bytes)
-    if data.len() < cursor + 32 {
-        return Err("Data too short for mint pubkey".into());
-    }
-    let mint_bytes = &data[cursor..cursor + 32];
-
-    Ok(bs58::encode(mint_bytes).into_string())
-}
-
-pub fn extract_token_amount_migrated(
-    tx_info: &SubscribeUpdateTransactionInfo,
-) -> Result<i64, String> {
-    let meta = tx_info.meta.as_ref().ok_or("No meta")?;
-    let inner_ix = meta
-        .inner_instructions
-        .first()
-        .ok_or("No inner instructions")?;
-
-    let event_ix = inner_ix.instructions.last().ok_or("No event instruction")?;
-
-    let data = event_ix.data.as_slice();
-
-    if data.len() < 16 {
-        return Err("Data too short for event discriminators".into());
-    }
-
-    let mut cursor = 16;
-
-    // skip user (32 bytes)
-    cursor += 32;
-
-    // skip mint (32 bytes)
-    cursor += 32;
-
-    // mintAmount (u64, 8 bytes)
-    if data.len() < cursor + 8 {
-        return Err("Data too short for mintAmount".into());
-    }
-    let amount = u64::from_le_bytes(
-        data[cursor..cursor + 8]
-            .try_into()
-            .map_err(|_| "Failed reading mintAmount")?,
-    );
-
-    Ok(amount as i64)
-}
-
-pub fn extract_sol_amount_migrated(
-    tx_info: &SubscribeUpdateTransactionInfo,
-) -> Result<i64, String> {
-    let meta = tx_info.meta.as_ref().ok_or("No meta")?;
-    let inner_ix = meta
-        .inner_instructions
-        .first()
-        .ok_or("No inner instructions")?;
-
-    let event_ix = inner_ix.instructions.last().ok_or("No event instruction")?;
-
-    let data = event_ix.data.as_slice();
-
-    if data.len() < 16 {
-        return Err("Data too short for event discriminators".into());
-    }
-
-    let mut cursor = 16;
-
-    // skip user (32 bytes)
-    cursor += 32;
-
-    // skip mint (32 bytes)
-    cursor += 32;
-
-    // skip mintAmount (8 bytes)
-    cursor += 8;
-
-    // solAmount (u64, 8 bytes)
-    if data.len() < cursor + 8 {
-        return Err("Data too short for solAmount".into());
-    }
-    let amount = u64::from_le_bytes(
-        data[cursor..cursor + 8]
-            .try_into()
-            .map_err(|_| "Failed reading solAmount")?,
-    );
-
-    Ok(amount as i64)
-}
-
-pub fn extract_timestamp(
+/// One lifecycle update per CompletePumpAmmMigrationEvent
+pub fn handle_pf_token_migrations(
     tx_info: &SubscribeUpdateTransactionInfo,
-) -> Result<DateTime<Utc>, String> {
-    let meta = tx_info.meta.as_ref().ok_or("No meta")?;
-    let inner_ix = meta
-        .inner_instructions
-        .first()
-        .ok_or("No inner instructions")?;
-
-    let event_ix = inner_ix.instructions.last().ok_or("No event instruction")?;
-
-    let data = event_ix.data.as_slice();
-
-    if data.len() < 16 {
-        return Err("Data too short for event discriminators".into());
-    }
-
-    let mut cursor = 16;
-
-    // skip user (32 bytes)
-    cursor += 32;
-
-    // skip mint (32 bytes)
-    cursor += 32;
-
-    // skip mintAmount (8 bytes)
-    cursor += 8;
-
-    // skip solAmount (8 bytes)
-    cursor += 8;
-
-    // skip poolMigrationFee (8 bytes)
-    cursor += 8;
-
-    // skip bondingCurve (32 bytes)
-    cursor += 32;
-
-    // timestamp (i64, 8 bytes)
-    if data.len() < cursor + 8 {
-        return Err("Data too short for timestamp".into());
-    }
-    let ts_value = i64::from_le_bytes(
-        data[cursor..cursor + 8]
-            .try_into()
-            .map_err(|_| "Failed reading timestamp")?,
-    );
-
-    let ts = DateTime::from_timestamp(ts_value, 0)
-        .ok_or_else(|| "Invalid timestamp value".to_string())?;
-
-    Ok(ts)
-}
-
-pub fn extract_pool_address(tx_info: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    let meta = tx_info.meta.as_ref().ok_or("No meta")?;
-    let inner_ix = meta
-        .inner_instructions
-        .first()
-        .ok_or("No inner instructions")?;
-
-    let event_ix = inner_ix.instructions.last().ok_or("No event instruction")?;
-
-    let data = event_ix.data.as_slice();
-
-    if data.len() < 16 {
-        return Err("Data too short for event discriminators".into());
-    }
-
-    let mut cursor = 16;
-
-    // skip user (32 bytes)
-    cursor += 32;
-
-    // skip mint (32 bytes)
-    cursor += 32;
-
-    // skip mintAmount (8 bytes)
-    cursor += 8;
-
-    // skip solAmount (8 bytes)
-    cursor += 8;
-
-    // skip poolMigrationFee (8 bytes)
-    cursor += 8;
-
-    // skip bondingCurve (32 bytes)
-    cursor += 32;
-
-    // skip timestamp (8 bytes)
-    cursor += 8;
-
-    // pool address (32 bytes)
-    if data.len() < cursor + 32 {
-        return Err("Data too short for pool address".into());
-    }
-    let pool_bytes = &data[cursor..cursor + 32];
-
-    Ok(bs58::encode(pool_bytes).into_string())
-}
-
-pub fn extract_bonding_curve(tx_info: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    let meta = tx_info.meta.as_ref().ok_or("No meta")?;
-    let inner_ix = meta
-        .inner_instructions
-        .first()
-        .ok_or("No inner instructions")?;
-
-    let event_ix = inner_ix.instructions.last().ok_or("No event instruction")?;
-
-    let data = event_ix.data.as_slice();
-
-    if data.len() < 16 {
-        return Err("Data too short for event discriminators".into());
-    }
-
-    let mut cursor = 16;
-
-    // skip user (32 bytes)
-    cursor += 32;
-
-    // skip mint (32 bytes)
-    cursor += 32;
-
-    // skip mintAmount (8 bytes)
-    cursor += 8;
-
-    // skip solAmount (8 bytes)
-    cursor += 8;
-
-    // skip poolMigrationFee (8 bytes)
-    cursor += 8;
-
-    // bondingCurve (32 bytes)
-    if data.len() < cursor + 32 {
-        return Err("Data too short for bonding curve".into());
+) -> Result<Vec<KPfTokenLifecycle>, String> {
+    let events = find_anchor_events(tx_info, &PF_COMPLETE_PUMP_AMM_MIGRATION_EVENT_DISC);
+    if events.is_empty() {
+        return Err("handle_pf_token_migrations: CompletePumpAmmMigrationEvent not found".into());
     }
-    let bonding_curve_bytes = &data[cursor..cursor + 32];
 
-    Ok(bs58::encode(bonding_curve_bytes).into_string())
+    events
+        .iter()
+        .map(|event| decode_migration_event(&event.data))
+        .collect()
 }
 
-pub fn handle_pf_token_migration(
-    tx_info: &SubscribeUpdateTransactionInfo,
-) -> Result<KPfTokenLifecycle, String> {
-    let mint = extract_mint(tx_info).map_err(|e| format!("extract_mint: {e}"))?;
-
-    let token_amount_migrated = extract_token_amount_migrated(tx_info)
-        .map_err(|e| format!("extract_token_amount_migrated: {e}"))?;
-
-    let sol_amount_migrated = extract_sol_amount_migrated(tx_info)
-        .map_err(|e| format!("extract_sol_amount_migrated: {e}"))?;
-
-    let ts_migrated = extract_timestamp(tx_info).map_err(|e| format!("extract_timestamp: {e}"))?;
-
-    let pool_address =
-        extract_pool_address(tx_info).map_err(|e| format!("extract_pool_address: {e}"))?;
+fn decode_migration_event(data: &[u8]) -> Result<KPfTokenLifecycle, String> {
+    let field = |name: &str, e: String| format!("decode_migration_event: {name}: {e}");
 
+    let mint = read_pubkey(data, MINT_OFFSET).map_err(|e| field("mint", e))?;
+    let token_amount_migrated =
+        read_u64(data, MINT_AMOUNT_OFFSET).map_err(|e| field("mint_amount", e))?;
+    let sol_amount_migrated =
+        read_u64(data, SOL_AMOUNT_OFFSET).map_err(|e| field("sol_amount", e))?;
     let bonding_curve =
-        extract_bonding_curve(tx_info).map_err(|e| format!("extract_bonding_curve: {e}"))?;
+        read_pubkey(data, BONDING_CURVE_OFFSET).map_err(|e| field("bonding_curve", e))?;
+    let ts_migrated = read_i64(data, TIMESTAMP_OFFSET)
+        .map_err(|e| field("timestamp", e))
+        .and_then(|ts| {
+            DateTime::from_timestamp(ts, 0)
+                .ok_or_else(|| field("timestamp", "invalid timestamp".into()))
+        })?;
+    let pool = read_pubkey(data, POOL_OFFSET).map_err(|e| field("pool", e))?;
 
     Ok(KPfTokenLifecycle {
         mint,
@@ -283,8 +55,8 @@ pub fn handle_pf_token_migration(
         ts_created: None,
         ts_migrated: Some(ts_migrated),
         bonding_curve: Some(bonding_curve),
-        pool: Some(pool_address),
-        sol_amount_migrated: Some(sol_amount_migrated),
-        token_amount_migrated: Some(token_amount_migrated),
+        pool: Some(pool),
+        sol_amount_migrated: Some(sol_amount_migrated as i64),
+        token_amount_migrated: Some(token_amount_migrated as i64),
     })
 }
@@ -1,66 +1,89 @@
-use crate::handlers::pumpfun::pf_ch_trade_occurred_handler::handle_pf_ch_trade;
-use crate::models::kafka_event::{KEvent, KEventData, KEventType};
+use crate::handlers::pumpfun::pf_ch_trade_occurred_handler::handle_pf_ch_trades;
+use crate::handlers::pumpfun::pf_creator_fee_claimed_handler::handle_pf_creator_fee_claims;
+use crate::handlers::pumpfun::pf_token_created_handler::handle_pf_token_creations;
+use crate::handlers::pumpfun::pf_token_incentives_claimed_handler::handle_pf_token_incentives_claims;
+use crate::handlers::pumpfun::pf_token_migrated_handler::handle_pf_token_migrations;
+use crate::models::kafka_event::{EventSource, KEvent, KEventData};
 use log::error;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
+/// Decode every event of a Pump.fun transaction. A trade can land in the same
+/// transaction as a claim, so each matching decoder runs; one failing does not
+/// drop what the others decoded.
 pub fn handle_pf_tx(
     tx_info: SubscribeUpdateTransactionInfo,
     slot: u64,
-) -> Result<Option<KEvent>, String> {
+) -> Result<Vec<KEvent>, String> {
     let logs = tx_info
         .clone()
         .meta
         .map(|m| m.log_messages)
         .unwrap_or_default();
 
-    if is_pf_trade(&logs) {
-        match handle_pf_ch_trade(&tx_info, slot) {
-            Ok(pf_ch_trade) => Ok(Some(KEvent {
-                event_type: KEventType::PfChTradeOccurred,
-                data: KEventData::PfChTradeOccurred(pf_ch_trade),
-            })),
-            Err(e) => {
-                let err = format!("Failed to handle PF trade: {e}");
-                error!("{err}");
-                Err(err)
-            }
+    let mut events = Vec::new();
+    let mut errors = Vec::new();
+    let mut collect = |decoded: Result<Vec<KEventData>, String>, what: &str| match decoded {
+        Ok(data) => events.extend(
+            data.into_iter()
+                .map(|d| KEvent::new(EventSource::GeyserTxStreamer, slot, d)),
+        ),
+        Err(e) => {
+            let err = format!("Failed to handle {what}: {e}");
+            error!("{err}");
+            errors.push(err);
         }
-    } else if is_pf_create(&logs) {
-        return Ok(None);
-        // match handle_pf_token_creation(&tx_info) {
-        //     Ok(ktoken) => Ok(Some(KEvent {
-        //         event_type: KEventType::PfTokenCreated,
-        //         data: KEventData::PfTokenCreated(ktoken),
-        //     })),
-        //     Err(e) => {
-        //         let err = format!("Failed to handle token creation: {e}");
-        //         error!("{err}");
-        //         Err(err)
-        //     }
-        // }
-    } else if is_pf_migrate(&logs) {
-        return Ok(None);
-        // match handle_pf_token_migration(&tx_info) {
-        //     Ok(ktoken_lifecycle) => Ok(Some(KEvent {
-        //         event_type: KEventType::PfTokenMigrated,
-        //         data: KEventData::PfTokenMigrated(ktoken_lifecycle),
-        //     })),
-        //     Err(e) => {
-        //         let err = format!("Failed to handle migration event: {e}");
-        //         error!("{err}");
-        //         Err(err)
-        //     }
-        // }
-    } else {
-        Ok(None)
+    };
+
+    if is_pf_trade(&logs) {
+        collect(
+            handle_pf_ch_trades(&tx_info, slot)
+                .map(|v| v.into_iter().map(KEventData::PfChTradeOccurred).collect()),
+            "PF trade",
+        );
+    }
+    if is_pf_creator_fee_claim(&logs) {
+        collect(
+            handle_pf_creator_fee_claims(&tx_info, slot)
+                .map(|v| v.into_iter().map(KEventData::PfCreatorFeeClaimed).collect()),
+            "PF creator fee claim",
+        );
+    }
+    if is_pf_token_incentives_claim(&logs) {
+        collect(
+            handle_pf_token_incentives_claims(&tx_info, slot).map(|v| {
+                v.into_iter()
+                    .map(KEventData::PfTokenIncentivesClaimed)
+                    .collect()
+            }),
+            "PF token incentives claim",
+        );
+    }
+    if is_pf_create(&logs) {
+        collect(
+            handle_pf_token_creations(&tx_info)
+                .map(|v| v.into_iter().map(KEventData::PfTokenCreated).collect()),
+            "token creation",
+        );
+    }
+    if is_pf_migrate(&logs) {
+        collect(
+            handle_pf_token_migrations(&tx_info)
+                .map(|v| v.into_iter().map(KEventData::PfTokenMigrated).collect()),
+            "migration event",
+        );
+    }
+
+    if events.is_empty() && !errors.is_empty() {
+        return Err(errors.join("; "));
     }
+    Ok(events)
 }
 
 fn is_pf_create(logs: &[String]) -> bool {
-    if logs
-        .iter()
-        .any(|log| log.contains("Program log: Instruction: CreateV2"))
-    {
+    if logs.iter().any(|log| {
+        log == "Program log: Instruction: Create"
+            || log.contains("Program log: Instruction: CreateV2")
+    }) {
         return true;
     }
 
@@ -78,6 +101,16 @@ fn is_pf_migrate(logs: &[String]) -> bool {
     false
 }
 
+fn is_pf_creator_fee_claim(logs: &[String]) -> bool {
+    logs.iter()
+        .any(|log| log.contains("Program log: Instruction: CollectCreatorFee"))
+}
+
+fn is_pf_token_incentives_claim(logs: &[String]) -> bool {
+    logs.iter()
+        .any(|log| log.contains("Program log: Instruction: ClaimTokenIncentives"))
+}
+
 fn is_pf_trade(logs: &[String]) -> bool {
     if logs.iter().any(|log| {
         log.starts_with("Program log: SwapEvent")
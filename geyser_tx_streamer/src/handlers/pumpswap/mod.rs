@@ -1,2 +1,3 @@
+pub mod ps_creator_fee_claimed_handler;
 pub mod ps_trade_occurred_handler;
 pub mod ps_tx_handler;
@@ -0,0 +1,85 @@
+use crate::handlers::anchor_event::{
+    PS_COLLECT_COIN_CREATOR_FEE_EVENT_DISC, PS_COLLECT_COIN_CREATOR_FEE_IX_DISC,
+    find_anchor_events, find_instructions, read_i64, read_pubkey, read_u64,
+};
+use crate::handlers::pumpswap::ps_trade_occurred_handler::extract_ps_signature;
+use crate::models::consts::PUMPSWAP_PROGRAM_ID;
+use crate::models::pump_models::ps_kafka_event::KPsCreatorFeeClaim;
+use chrono::{DateTime, Utc};
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+
+/* CollectCoinCreatorFeeEvent layout (after discriminator) */
+const TIMESTAMP_OFFSET: usize = 0; // i64
+const COIN_CREATOR_OFFSET: usize = 8; // Pubkey
+const COIN_CREATOR_FEE_OFFSET: usize = 40; // u64
+const COIN_CREATOR_VAULT_ATA_OFFSET: usize = 48; // Pubkey
+const COIN_CREATOR_TOKEN_ACCOUNT_OFFSET: usize = 80; // Pubkey
+
+/* collect_coin_creator_fee accounts */
+const QUOTE_MINT_ACCOUNT: usize = 0;
+
+/// One claim per CollectCoinCreatorFeeEvent, paired with the collect_coin_creator_fee
+/// instruction that emitted it
+pub fn handle_ps_creator_fee_claims(
+    tx_info: &SubscribeUpdateTransactionInfo,
+    slot: u64,
+) -> Result<Vec<KPsCreatorFeeClaim>, String> {
+    let signature = extract_ps_signature(tx_info)?;
+
+    let events = find_anchor_events(tx_info, &PS_COLLECT_COIN_CREATOR_FEE_EVENT_DISC);
+    if events.is_empty() {
+        return Err("handle_ps_creator_fee_claims: CollectCoinCreatorFeeEvent not found".into());
+    }
+    let instructions = find_instructions(
+        tx_info,
+        PUMPSWAP_PROGRAM_ID,
+        &[PS_COLLECT_COIN_CREATOR_FEE_IX_DISC],
+    );
+
+    events
+        .iter()
+        .enumerate()
+        .map(|(k, event)| -> Result<KPsCreatorFeeClaim, String> {
+            let coin_creator = read_pubkey(&event.data, COIN_CREATOR_OFFSET)
+                .map_err(|e| format!("handle_ps_creator_fee_claims: coin_creator: {e}"))?;
+            let coin_creator_fee = read_u64(&event.data, COIN_CREATOR_FEE_OFFSET)
+                .map_err(|e| format!("handle_ps_creator_fee_claims: coin_creator_fee: {e}"))?;
+            let coin_creator_vault_ata = read_pubkey(&event.data, COIN_CREATOR_VAULT_ATA_OFFSET)
+                .map_err(|e| {
+                    format!("handle_ps_creator_fee_claims: coin_creator_vault_ata: {e}")
+                })?;
+            let coin_creator_token_account =
+                read_pubkey(&event.data, COIN_CREATOR_TOKEN_ACCOUNT_OFFSET).map_err(|e| {
+                    format!("handle_ps_creator_fee_claims: coin_creator_token_account: {e}")
+                })?;
+            let ts = extract_claim_timestamp(&event.data)?;
+
+            let quote_mint = instructions
+                .get(k)
+                .and_then(|ix| ix.accounts.get(QUOTE_MINT_ACCOUNT))
+                .cloned()
+                .ok_or("handle_ps_creator_fee_claims: quote_mint account missing")?;
+
+            Ok(KPsCreatorFeeClaim {
+                signature: signature.clone(),
+                slot,
+                coin_creator,
+                quote_mint,
+                coin_creator_fee,
+                coin_creator_vault_ata,
+                coin_creator_token_account,
+                ts,
+                ix_index: event.ix_index,
+                event_ordinal: event.ordinal,
+            })
+        })
+        .collect()
+}
+
+fn extract_claim_timestamp(event: &[u8]) -> Result<DateTime<Utc>, String> {
+    let ts =
+        read_i64(event, TIMESTAMP_OFFSET).map_err(|e| format!("extract_claim_timestamp: {e}"))?;
+
+    DateTime::from_timestamp(ts, 0)
+        .ok_or_else(|| "extract_claim_timestamp: invalid timestamp".into())
+}
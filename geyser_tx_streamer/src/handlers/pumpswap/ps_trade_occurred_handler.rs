@@ -1,29 +1,95 @@
+use crate::handlers::anchor_event::{
+    AnchorEvent, BUY_IX_DISC, PS_BUY_EVENT_DISC, PS_BUY_EXACT_QUOTE_IN_IX_DISC, PS_SELL_EVENT_DISC,
+    SELL_IX_DISC, find_anchor_events, find_instructions, read_pubkey, read_u64,
+};
+use crate::models::consts::PUMPSWAP_PROGRAM_ID;
 use crate::models::kafka_event::TradeDirection;
 use crate::models::pump_models::ps_kafka_event::KPsTrade;
 use chrono::{DateTime, Utc};
 use solana_sdk::bs58;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
-pub fn handle_ps_trade(tx_info: &SubscribeUpdateTransactionInfo) -> Result<KPsTrade, String> {
+/* BuyEvent / SellEvent layout (after discriminator), shared up to the coin creator fee */
+const BASE_AMOUNT_OFFSET: usize = 8; // u64, base_amount_out / base_amount_in
+const USER_QUOTE_AMOUNT_OFFSET: usize = 104; // u64, user_quote_amount_in / user_quote_amount_out
+const POOL_OFFSET: usize = 112; // Pubkey
+const USER_OFFSET: usize = 144; // Pubkey
+
+/* buy / sell / buy_exact_quote_in accounts */
+const BASE_MINT_ACCOUNT: usize = 3;
+const QUOTE_MINT_ACCOUNT: usize = 4;
+
+/// One trade per BuyEvent / SellEvent, paired with the instruction that emitted it
+pub fn handle_ps_trades(
+    tx_info: &SubscribeUpdateTransactionInfo,
+    slot: u64,
+) -> Result<Vec<KPsTrade>, String> {
     let signature = extract_ps_signature(tx_info)?;
-    let mint = extract_ps_mint_from_trade(tx_info)?;
-    let pool = extract_ps_pool_from_trade(tx_info)?;
-    let direction = extract_ps_direction(tx_info)?;
-    let token_amount = extract_ps_token_amount(tx_info, 1)?;
-    let sol_amount = extract_ps_sol_amount(tx_info, direction)?;
-    let user_pubkey = extract_ps_user_pubkey(tx_info)?;
-    let ts = extract_ps_trade_timestamp()?;
 
-    Ok(KPsTrade {
-        signature,
-        mint,
-        pool,
-        direction,
-        sol_amount,
-        token_amount,
-        user_pubkey,
-        ts,
-    })
+    let mut events: Vec<(TradeDirection, AnchorEvent)> =
+        find_anchor_events(tx_info, &PS_BUY_EVENT_DISC)
+            .into_iter()
+            .map(|e| (TradeDirection::Buy, e))
+            .chain(
+                find_anchor_events(tx_info, &PS_SELL_EVENT_DISC)
+                    .into_iter()
+                    .map(|e| (TradeDirection::Sell, e)),
+            )
+            .collect();
+    if events.is_empty() {
+        return Err("handle_ps_trades: trade event not found".into());
+    }
+    events.sort_by_key(|(_, e)| e.ordinal);
+
+    let instructions = find_instructions(
+        tx_info,
+        PUMPSWAP_PROGRAM_ID,
+        &[BUY_IX_DISC, SELL_IX_DISC, PS_BUY_EXACT_QUOTE_IN_IX_DISC],
+    );
+
+    events
+        .iter()
+        .enumerate()
+        .map(|(k, (direction, event))| -> Result<KPsTrade, String> {
+            let field = |name: &str, e: String| format!("handle_ps_trades: {name}: {e}");
+
+            let mint = instructions
+                .get(k)
+                .and_then(|ix| ix.accounts.get(BASE_MINT_ACCOUNT))
+                .cloned()
+                .ok_or("handle_ps_trades: base_mint account missing")?;
+            let quote_mint = instructions
+                .get(k)
+                .and_then(|ix| ix.accounts.get(QUOTE_MINT_ACCOUNT))
+                .cloned();
+            let pool = read_pubkey(&event.data, POOL_OFFSET).map_err(|e| field("pool", e))?;
+            let user_pubkey =
+                read_pubkey(&event.data, USER_OFFSET).map_err(|e| field("user", e))?;
+            let token_amount = read_u64(&event.data, BASE_AMOUNT_OFFSET)
+                .map_err(|e| field("base_amount", e))? as i64;
+            let sol_amount = read_u64(&event.data, USER_QUOTE_AMOUNT_OFFSET)
+                .map_err(|e| field("user_quote_amount", e))? as i64;
+            let ts = extract_ps_trade_timestamp()?;
+            let (coin_creator, coin_creator_fee) = extract_ps_coin_creator_fee(&event.data);
+
+            Ok(KPsTrade {
+                signature: signature.clone(),
+                mint,
+                pool,
+                direction: *direction,
+                sol_amount,
+                token_amount,
+                user_pubkey,
+                ts,
+                coin_creator,
+                coin_creator_fee,
+                quote_mint,
+                slot,
+                ix_index: event.ix_index,
+                event_ordinal: event.ordinal,
+            })
+        })
+        .collect()
 }
 
 pub fn extract_ps_signature(tx_info: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
@@ -33,139 +99,25 @@ pub fn extract_ps_signature(tx_info: &SubscribeUpdateTransactionInfo) -> Result<
     Ok(bs58::encode(&tx_info.signature).into_string())
 }
 
-pub fn extract_ps_mint_from_trade(
-    tx_info: &SubscribeUpdateTransactionInfo,
-) -> Result<String, String> {
-    let meta = tx_info
-        .meta
-        .as_ref()
-        .ok_or("extract_ps_mint_from_trade: No PS meta")?;
-    let token_balance = meta
-        .pre_token_balances
-        .first()
-        .ok_or("extract_ps_mint_from_trade: No PS token balances")?;
-    Ok(token_balance.mint.clone())
-}
-
-pub fn extract_ps_pool_from_trade(
-    tx_info: &SubscribeUpdateTransactionInfo,
-) -> Result<String, String> {
-    let tx = tx_info
-        .transaction
-        .as_ref()
-        .ok_or("extract_ps_pool_from_trade: No PS transaction")?;
-    let msg = tx
-        .message
-        .as_ref()
-        .ok_or("extract_ps_pool_from_trade: No PS message")?;
-
-    // Pool is account_keys[3] for PumpSwap
-    let pool_bytes = msg
-        .account_keys
-        .get(3)
-        .ok_or("extract_ps_pool_from_trade: No PS pool account")?;
-
-    if pool_bytes.len() != 32 {
-        return Err("extract_ps_pool_from_trade: Invalid PS pool pubkey length".into());
-    }
-
-    Ok(bs58::encode(pool_bytes).into_string())
-}
-
-pub fn extract_ps_direction(
-    tx_info: &SubscribeUpdateTransactionInfo,
-) -> Result<TradeDirection, String> {
-    let meta = tx_info.meta.as_ref().ok_or("No meta")?;
-
-    for log in &meta.log_messages {
-        if log.contains("Instruction: Sell") {
-            return Ok(TradeDirection::Sell);
-        } else if log.contains("Instruction: Buy") {
-            return Ok(TradeDirection::Buy);
-        }
-    }
-
-    Err("extract_ps_direction: Could not determine PS trade direction".into())
-}
-
-pub fn extract_ps_token_amount(
-    tx_info: &SubscribeUpdateTransactionInfo,
-    trade_index: usize,
-) -> Result<i64, String> {
-    let meta = tx_info
-        .meta
-        .as_ref()
-        .ok_or("extract_ps_token_amount: No PS meta")?;
-    let inner_ix = meta
-        .inner_instructions
-        .get(trade_index)
-        .ok_or("extract_ps_token_amount: No PS inner instruction at index")?;
-
-    for ix in &inner_ix.instructions {
-        if !ix.data.is_empty() && ix.data[0] == 12 {
-            if ix.data.len() < 9 {
-                return Err("extract_ps_token_amount: TransferChecked PS data too short".into());
-            }
-            let amount = u64::from_le_bytes(
-                ix.data[1..9]
-                    .try_into()
-                    .map_err(|_| "extract_ps_token_amount: Failed to read PS token amount")?,
-            );
-            return Ok(amount as i64);
-        }
-    }
-
-    Err("extract_ps_token_amount: TransferChecked PS instruction not found".into())
-}
-
-pub fn extract_ps_sol_amount(
-    tx_info: &SubscribeUpdateTransactionInfo,
-    direction: TradeDirection,
-) -> Result<i64, String> {
-    let meta = tx_info
-        .meta
-        .as_ref()
-        .ok_or("extract_ps_sol_amount: No PS meta")?;
-
-    let pre_balance = *meta
-        .pre_balances
-        .first()
-        .ok_or("extract_ps_sol_amount: No PS pre_balance")?;
-    let post_balance = *meta
-        .post_balances
-        .first()
-        .ok_or("extract_ps_sol_amount: No PS post_balance")?;
-    let fee = meta.fee;
-
-    let sol_change = match direction {
-        TradeDirection::Buy => (pre_balance as i64) - (post_balance as i64) - (fee as i64),
-        TradeDirection::Sell => (post_balance as i64) - (pre_balance as i64) + (fee as i64),
-    };
-
-    Ok(sol_change.abs())
+pub fn extract_ps_trade_timestamp() -> Result<DateTime<Utc>, String> {
+    Ok(Utc::now())
 }
 
-pub fn extract_ps_user_pubkey(tx_info: &SubscribeUpdateTransactionInfo) -> Result<String, String> {
-    let tx = tx_info
-        .transaction
-        .as_ref()
-        .ok_or("extract_ps_user_pubkey: No PS transaction")?;
-    let msg = tx
-        .message
-        .as_ref()
-        .ok_or("extract_ps_user_pubkey: No PS message")?;
-    let user_bytes = msg
-        .account_keys
-        .first()
-        .ok_or("extract_ps_user_pubkey: No PS user account")?;
+/// Extract `coin_creator` and `coin_creator_fee` from the PumpSwap Buy/SellEvent.
+/// Both events share the same layout up to these fields. Events of pools created
+/// before creator fees existed are too short and yield `None`.
+pub fn extract_ps_coin_creator_fee(event: &[u8]) -> (Option<String>, Option<u64>) {
+    const U64: usize = 8;
+    const PUBKEY: usize = 32;
 
-    if user_bytes.len() != 32 {
-        return Err("extract_ps_user_pubkey: Invalid PS user pubkey length".into());
-    }
+    let mut o = 0;
+    o += U64 * 14; // timestamp .. user_quote_amount_{in,out}
+    o += PUBKEY * 6; // pool .. protocol_fee_recipient_token_account
 
-    Ok(bs58::encode(user_bytes).into_string())
-}
+    let coin_creator = read_pubkey(event, o).ok();
+    o += PUBKEY;
+    o += U64; // coin_creator_fee_basis_points
+    let coin_creator_fee = read_u64(event, o).ok();
 
-pub fn extract_ps_trade_timestamp() -> Result<DateTime<Utc>, String> {
-    Ok(Utc::now())
+    (coin_creator, coin_creator_fee)
 }
@@ -1,30 +1,53 @@
-use crate::handlers::pumpswap::ps_trade_occurred_handler::handle_ps_trade;
-use crate::models::kafka_event::{KEvent, KEventData, KEventType};
+use crate::handlers::pumpswap::ps_creator_fee_claimed_handler::handle_ps_creator_fee_claims;
+use crate::handlers::pumpswap::ps_trade_occurred_handler::handle_ps_trades;
+use crate::models::kafka_event::{EventSource, KEvent, KEventData};
 use log::error;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
-pub fn handle_ps_tx(tx_info: SubscribeUpdateTransactionInfo) -> Result<Option<KEvent>, String> {
+/// Decode every event of a PumpSwap transaction; see `handle_pf_tx`
+pub fn handle_ps_tx(
+    tx_info: SubscribeUpdateTransactionInfo,
+    slot: u64,
+) -> Result<Vec<KEvent>, String> {
     let logs = tx_info
         .clone()
         .meta
         .map(|m| m.log_messages)
         .unwrap_or_default();
 
-    if is_ps_trade(&logs) {
-        match handle_ps_trade(&tx_info) {
-            Ok(ktrade) => Ok(Some(KEvent {
-                event_type: KEventType::PsTradeOccurred,
-                data: KEventData::PsTradeOccurred(ktrade),
-            })),
-            Err(e) => {
-                let err = format!("handle_ps_tx: Failed to handle PS trade: {e}");
-                error!("{err}");
-                Err(err)
-            }
+    let mut events = Vec::new();
+    let mut errors = Vec::new();
+    let mut collect = |decoded: Result<Vec<KEventData>, String>, what: &str| match decoded {
+        Ok(data) => events.extend(
+            data.into_iter()
+                .map(|d| KEvent::new(EventSource::GeyserTxStreamer, slot, d)),
+        ),
+        Err(e) => {
+            let err = format!("handle_ps_tx: Failed to handle {what}: {e}");
+            error!("{err}");
+            errors.push(err);
         }
-    } else {
-        Ok(None)
+    };
+
+    if is_ps_trade(&logs) {
+        collect(
+            handle_ps_trades(&tx_info, slot)
+                .map(|v| v.into_iter().map(KEventData::PsTradeOccurred).collect()),
+            "PS trade",
+        );
+    }
+    if is_ps_creator_fee_claim(&logs) {
+        collect(
+            handle_ps_creator_fee_claims(&tx_info, slot)
+                .map(|v| v.into_iter().map(KEventData::PsCreatorFeeClaimed).collect()),
+            "PS creator fee claim",
+        );
     }
+
+    if events.is_empty() && !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
+    Ok(events)
 }
 
 fn is_ps_trade(logs: &[String]) -> bool {
@@ -37,3 +60,8 @@ fn is_ps_trade(logs: &[String]) -> bool {
     }
     false
 }
+
+fn is_ps_creator_fee_claim(logs: &[String]) -> bool {
+    logs.iter()
+        .any(|log| log.contains("Program log: Instruction: CollectCoinCreatorFee"))
+}
@@ -1,8 +1,9 @@
-use crate::models::pump_models::pf_kafka_event::KPfToken;
+use crate::models::raydium_models::rll_kafka_event::KRllToken;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
+/// LaunchLab creations are not decoded yet
 pub fn handle_rll_token_creation(
     _tx_info: &SubscribeUpdateTransactionInfo,
-) -> Result<KPfToken, String> {
-    todo!();
+) -> Result<KRllToken, String> {
+    Err("handle_rll_token_creation: LaunchLab creations are not decoded yet".into())
 }
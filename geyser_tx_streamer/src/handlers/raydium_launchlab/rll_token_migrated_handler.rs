@@ -1,8 +1,9 @@
-use crate::models::pump_models::pf_kafka_event::KPfTokenLifecycle;
+use crate::models::raydium_models::rll_kafka_event::KRllTokenLifecycle;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
+/// LaunchLab migrations are not decoded yet; `KRllTokenLifecycle` carries no fields
 pub fn handle_rll_token_migration(
     _tx_info: &SubscribeUpdateTransactionInfo,
-) -> Result<KPfTokenLifecycle, String> {
-    todo!();
+) -> Result<KRllTokenLifecycle, String> {
+    Err("handle_rll_token_migration: LaunchLab migrations are not decoded yet".into())
 }
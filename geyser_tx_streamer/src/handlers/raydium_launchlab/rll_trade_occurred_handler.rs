@@ -1,6 +1,7 @@
-use crate::models::pump_models::pf_kafka_event::KPfTrade;
+use crate::models::raydium_models::rll_kafka_event::KRllTrade;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
-pub fn handle_rll_trade(_tx_info: &SubscribeUpdateTransactionInfo) -> Result<KPfTrade, String> {
-    todo!();
+/// LaunchLab trades are not decoded yet; `KRllTrade` carries no fields
+pub fn handle_rll_trade(_tx_info: &SubscribeUpdateTransactionInfo) -> Result<KRllTrade, String> {
+    Err("handle_rll_trade: LaunchLab trades are not decoded yet".into())
 }
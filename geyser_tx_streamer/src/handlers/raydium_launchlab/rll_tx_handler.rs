@@ -1,56 +1,58 @@
 use crate::handlers::raydium_launchlab::rll_token_created_handler::handle_rll_token_creation;
 use crate::handlers::raydium_launchlab::rll_token_migrated_handler::handle_rll_token_migration;
 use crate::handlers::raydium_launchlab::rll_trade_occurred_handler::handle_rll_trade;
-use crate::models::kafka_event::{KEvent, KEventData, KEventType};
+use crate::models::kafka_event::{EventSource, KEvent, KEventData};
 use log::error;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
-pub fn handle_rll_tx(tx_info: SubscribeUpdateTransactionInfo) -> Result<Option<KEvent>, String> {
+/// Decode every event of a Raydium LaunchLab transaction; see `handle_pf_tx`
+pub fn handle_rll_tx(
+    tx_info: SubscribeUpdateTransactionInfo,
+    slot: u64,
+) -> Result<Vec<KEvent>, String> {
     let logs = tx_info
         .clone()
         .meta
         .map(|m| m.log_messages)
         .unwrap_or_default();
 
-    if is_rll_trade(&logs) {
-        match handle_rll_trade(&tx_info) {
-            Ok(ktrade) => Ok(Some(KEvent {
-                event_type: KEventType::PfTradeOccurred,
-                data: KEventData::PfTradeOccurred(ktrade),
-            })),
-            Err(e) => {
-                let err = format!("Failed to handle RLL trade: {e}");
-                error!("{err}");
-                Err(err)
-            }
-        }
-    } else if is_rll_create(&logs) {
-        match handle_rll_token_creation(&tx_info) {
-            Ok(ktoken) => Ok(Some(KEvent {
-                event_type: KEventType::PfTokenCreated,
-                data: KEventData::PfTokenCreated(ktoken),
-            })),
-            Err(e) => {
-                let err = format!("Failed to handle token creation: {e}");
-                error!("{err}");
-                Err(err)
-            }
-        }
-    } else if is_rll_migrate(&logs) {
-        match handle_rll_token_migration(&tx_info) {
-            Ok(ktoken_lifecycle) => Ok(Some(KEvent {
-                event_type: KEventType::PfTokenMigrated,
-                data: KEventData::PfTokenMigrated(ktoken_lifecycle),
-            })),
-            Err(e) => {
-                let err = format!("Failed to handle migration event: {e}");
-                error!("{err}");
-                Err(err)
-            }
+    let mut events = Vec::new();
+    let mut errors = Vec::new();
+    let mut collect = |decoded: Result<Vec<KEventData>, String>, what: &str| match decoded {
+        Ok(data) => events.extend(
+            data.into_iter()
+                .map(|d| KEvent::new(EventSource::GeyserTxStreamer, slot, d)),
+        ),
+        Err(e) => {
+            let err = format!("Failed to handle {what}: {e}");
+            error!("{err}");
+            errors.push(err);
         }
-    } else {
-        Ok(None)
+    };
+
+    if is_rll_trade(&logs) {
+        collect(
+            handle_rll_trade(&tx_info).map(|t| vec![KEventData::RllTradeOccurred(t)]),
+            "RLL trade",
+        );
+    }
+    if is_rll_create(&logs) {
+        collect(
+            handle_rll_token_creation(&tx_info).map(|t| vec![KEventData::RllTokenCreated(t)]),
+            "token creation",
+        );
+    }
+    if is_rll_migrate(&logs) {
+        collect(
+            handle_rll_token_migration(&tx_info).map(|t| vec![KEventData::RllTokenMigrated(t)]),
+            "migration event",
+        );
+    }
+
+    if events.is_empty() && !errors.is_empty() {
+        return Err(errors.join("; "));
     }
+    Ok(events)
 }
 
 fn is_rll_create(logs: &[String]) -> bool {
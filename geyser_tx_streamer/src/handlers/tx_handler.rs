@@ -13,14 +13,14 @@ use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 pub fn handle_tx(
     tx_info: SubscribeUpdateTransactionInfo,
     slot: u64,
-) -> Result<Option<KEvent>, String> {
+) -> Result<Vec<KEvent>, String> {
     let platform = get_platform(&tx_info);
 
     match platform {
         Some(p) => match p {
             Platform::PumpFun => handle_pf_tx(tx_info, slot),
-            Platform::PumpSwap => handle_ps_tx(tx_info),
-            Platform::RaydiumLaunchLab => handle_rll_tx(tx_info),
+            Platform::PumpSwap => handle_ps_tx(tx_info, slot),
+            Platform::RaydiumLaunchLab => handle_rll_tx(tx_info, slot),
         },
         None => {
             let err = "Unknown platform".to_string();
@@ -1,12 +1,16 @@
 use crate::config::AppConfig;
 use crate::models::consts::{
-    PUMPFUN_CREATE_EVENT_TOPICS, PUMPFUN_MIGRATE_EVENT_TOPICS, PUMPFUN_TRADE_EVENT_TOPICS,
+    PUMPFUN_CREATE_EVENT_TOPICS, PUMPFUN_CREATOR_FEE_EVENT_TOPICS, PUMPFUN_INCENTIVE_EVENT_TOPICS,
+    PUMPFUN_MIGRATE_EVENT_TOPICS, PUMPFUN_TRADE_EVENT_TOPICS, PUMPSWAP_CREATOR_FEE_EVENT_TOPICS,
     PUMPSWAP_TRADE_EVENT_TOPICS, RAYDIUM_LAUNCHLAB_CREATE_EVENT_TOPICS,
     RAYDIUM_LAUNCHLAB_MIGRATE_EVENT_TOPICSS, RAYDIUM_LAUNCHLAB_TRADE_EVENT_TOPICSS,
 };
 use crate::models::kafka_event::{KEvent, KEventType};
-use log::{error, info};
+use event_contract::codec::CONTENT_TYPE_HEADER;
+use event_contract::codec::registry::EventEncoder;
+use log::{error, info, warn};
 use rdkafka::config::ClientConfig;
+use rdkafka::message::{Header, OwnedHeaders};
 use rdkafka::producer::{FutureProducer, FutureRecord};
 use std::time::Duration;
 
@@ -20,44 +24,94 @@ pub async fn start_kafka_producer(
         .create()
         .expect("Failed to create Kafka producer");
 
+    let encoder = EventEncoder::new(config.kafka_codec, config.schema_registry_url.clone());
+
     tokio::spawn(async move {
         while let Some(kevent) = event_rx.recv().await {
             match kevent.event_type {
                 KEventType::PfChTradeOccurred => {
-                    broadcast_event(&producer, PUMPFUN_TRADE_EVENT_TOPICS, &kevent).await;
+                    broadcast_event(&producer, PUMPFUN_TRADE_EVENT_TOPICS, &kevent, &encoder).await;
                 }
                 KEventType::PfTradeOccurred => {
-                    broadcast_event(&producer, PUMPFUN_TRADE_EVENT_TOPICS, &kevent).await;
+                    broadcast_event(&producer, PUMPFUN_TRADE_EVENT_TOPICS, &kevent, &encoder).await;
                 }
                 KEventType::PfTokenCreated => {
-                    broadcast_event(&producer, PUMPFUN_CREATE_EVENT_TOPICS, &kevent).await;
+                    broadcast_event(&producer, PUMPFUN_CREATE_EVENT_TOPICS, &kevent, &encoder)
+                        .await;
                 }
                 KEventType::PfTokenMigrated => {
-                    broadcast_event(&producer, PUMPFUN_MIGRATE_EVENT_TOPICS, &kevent).await;
+                    broadcast_event(&producer, PUMPFUN_MIGRATE_EVENT_TOPICS, &kevent, &encoder)
+                        .await;
                 }
                 KEventType::PsTradeOccurred => {
-                    broadcast_event(&producer, PUMPSWAP_TRADE_EVENT_TOPICS, &kevent).await;
+                    broadcast_event(&producer, PUMPSWAP_TRADE_EVENT_TOPICS, &kevent, &encoder)
+                        .await;
                 }
-                KEventType::RllTradeOccurred => {
-                    broadcast_event(&producer, RAYDIUM_LAUNCHLAB_TRADE_EVENT_TOPICSS, &kevent)
+                KEventType::PfCreatorFeeClaimed => {
+                    broadcast_event(
+                        &producer,
+                        PUMPFUN_CREATOR_FEE_EVENT_TOPICS,
+                        &kevent,
+                        &encoder,
+                    )
+                    .await;
+                }
+                KEventType::PfTokenIncentivesClaimed => {
+                    broadcast_event(&producer, PUMPFUN_INCENTIVE_EVENT_TOPICS, &kevent, &encoder)
                         .await;
                 }
+                KEventType::PsCreatorFeeClaimed => {
+                    broadcast_event(
+                        &producer,
+                        PUMPSWAP_CREATOR_FEE_EVENT_TOPICS,
+                        &kevent,
+                        &encoder,
+                    )
+                    .await;
+                }
+                KEventType::RllTradeOccurred => {
+                    broadcast_event(
+                        &producer,
+                        RAYDIUM_LAUNCHLAB_TRADE_EVENT_TOPICSS,
+                        &kevent,
+                        &encoder,
+                    )
+                    .await;
+                }
                 KEventType::RllTokenCreated => {
-                    broadcast_event(&producer, RAYDIUM_LAUNCHLAB_CREATE_EVENT_TOPICS, &kevent)
-                        .await;
+                    broadcast_event(
+                        &producer,
+                        RAYDIUM_LAUNCHLAB_CREATE_EVENT_TOPICS,
+                        &kevent,
+                        &encoder,
+                    )
+                    .await;
                 }
                 KEventType::RllTokenMigrated => {
-                    broadcast_event(&producer, RAYDIUM_LAUNCHLAB_MIGRATE_EVENT_TOPICSS, &kevent)
-                        .await;
+                    broadcast_event(
+                        &producer,
+                        RAYDIUM_LAUNCHLAB_MIGRATE_EVENT_TOPICSS,
+                        &kevent,
+                        &encoder,
+                    )
+                    .await;
+                }
+                other => {
+                    warn!("start_kafka_producer: No topic for event type {other:?}");
                 }
             }
         }
     });
 }
 
-async fn broadcast_event(producer: &FutureProducer, topics: &[&str], event: &KEvent) {
+async fn broadcast_event(
+    producer: &FutureProducer,
+    topics: &[&str],
+    event: &KEvent,
+    encoder: &EventEncoder,
+) {
     for topic in topics {
-        match send_event(producer, topic, event).await {
+        match send_event(producer, topic, event, encoder).await {
             Ok(_) => {
                 info!("Successfully sent an event to {topic}")
             }
@@ -68,11 +122,22 @@ async fn broadcast_event(producer: &FutureProducer, topics: &[&str], event: &KEv
     }
 }
 
-async fn send_event(producer: &FutureProducer, topic: &str, event: &KEvent) -> Result<(), String> {
-    let payload =
-        serde_json::to_string(event).map_err(|e| format!("Failed to serialize event: {e}"))?;
+async fn send_event(
+    producer: &FutureProducer,
+    topic: &str,
+    event: &KEvent,
+    encoder: &EventEncoder,
+) -> Result<(), String> {
+    let payload = encoder.encode(topic, event).await?;
+    let headers = OwnedHeaders::new().insert(Header {
+        key: CONTENT_TYPE_HEADER,
+        value: Some(encoder.content_type().as_str()),
+    });
 
-    let record = FutureRecord::to(topic).payload(&payload).key("test_key");
+    let record = FutureRecord::to(topic)
+        .payload(&payload)
+        .key("test_key")
+        .headers(headers);
 
     producer
         .send(record, Duration::from_secs(0))
@@ -5,8 +5,11 @@ pub const RAYDIUM_LAUNCHLAB_PROGRAM_ID: &str = "LanMV9sAd7wArD4vJFi2qDdfnVhFxYSU
 pub const PUMPFUN_TRADE_EVENT_TOPICS: &[&str] = &["pf_trade_event"];
 pub const PUMPFUN_CREATE_EVENT_TOPICS: &[&str] = &["pf_create_event"];
 pub const PUMPFUN_MIGRATE_EVENT_TOPICS: &[&str] = &["pf_migrate_event"];
+pub const PUMPFUN_CREATOR_FEE_EVENT_TOPICS: &[&str] = &["pf_creator_fee_event"];
+pub const PUMPFUN_INCENTIVE_EVENT_TOPICS: &[&str] = &["pf_incentive_event"];
 
 pub const PUMPSWAP_TRADE_EVENT_TOPICS: &[&str] = &["ps_trade_event"];
+pub const PUMPSWAP_CREATOR_FEE_EVENT_TOPICS: &[&str] = &["ps_creator_fee_event"];
 
 pub const RAYDIUM_LAUNCHLAB_TRADE_EVENT_TOPICSS: &[&str] = &["raydium_launchlab_trade_event"];
 pub const RAYDIUM_LAUNCHLAB_CREATE_EVENT_TOPICS: &[&str] = &["rll_create_event"];
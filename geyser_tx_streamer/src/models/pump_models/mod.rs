@@ -1,3 +1,2 @@
-pub mod pf_ch_kafka_event;
 pub mod pf_kafka_event;
 pub mod ps_kafka_event;
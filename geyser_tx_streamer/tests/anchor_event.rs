@@ -0,0 +1,65 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+use geyser_tx_streamer::handlers::anchor_event::{
+    PF_COLLECT_CREATOR_FEE_EVENT_DISC, PF_TRADE_EVENT_DISC, find_anchor_events,
+};
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+use yellowstone_grpc_proto::prelude::TransactionStatusMeta;
+
+const PUMPFUN: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+
+fn program_data(disc: &[u8; 8], body: &[u8]) -> String {
+    format!(
+        "Program data: {}",
+        STANDARD.encode([&disc[..], body].concat())
+    )
+}
+
+fn tx_with_logs(logs: Vec<String>) -> SubscribeUpdateTransactionInfo {
+    SubscribeUpdateTransactionInfo {
+        meta: Some(TransactionStatusMeta {
+            log_messages: logs,
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Two buys in one transaction, with a creator fee claim between them
+#[test]
+fn finds_every_trade_event_of_a_tx() {
+    let tx = tx_with_logs(vec![
+        format!("Program {PUMPFUN} invoke [1]"),
+        "Program log: Instruction: Buy".into(),
+        program_data(&PF_TRADE_EVENT_DISC, &[1; 4]),
+        format!("Program {PUMPFUN} success"),
+        format!("Program {PUMPFUN} invoke [1]"),
+        "Program log: Instruction: CollectCreatorFee".into(),
+        program_data(&PF_COLLECT_CREATOR_FEE_EVENT_DISC, &[9; 4]),
+        format!("Program {PUMPFUN} success"),
+        format!("Program {PUMPFUN} invoke [1]"),
+        "Program log: Instruction: Buy".into(),
+        program_data(&PF_TRADE_EVENT_DISC, &[2; 4]),
+        format!("Program {PUMPFUN} success"),
+    ]);
+
+    let trades = find_anchor_events(&tx, &PF_TRADE_EVENT_DISC);
+    let keys: Vec<_> = trades
+        .iter()
+        .map(|e| (e.data.clone(), e.ix_index, e.ordinal))
+        .collect();
+    assert_eq!(keys, vec![(vec![1; 4], 0, 0), (vec![2; 4], 2, 2)]);
+
+    let claims = find_anchor_events(&tx, &PF_COLLECT_CREATOR_FEE_EVENT_DISC);
+    assert_eq!(claims.len(), 1);
+    assert_eq!((claims[0].ix_index, claims[0].ordinal), (1, 1));
+}
+
+#[test]
+fn no_event_yields_nothing() {
+    let tx = tx_with_logs(vec![
+        format!("Program {PUMPFUN} invoke [1]"),
+        format!("Program {PUMPFUN} success"),
+    ]);
+
+    assert!(find_anchor_events(&tx, &PF_TRADE_EVENT_DISC).is_empty());
+}
@@ -0,0 +1,64 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+use geyser_tx_streamer::handlers::anchor_event::PF_CREATE_EVENT_DISC;
+use geyser_tx_streamer::handlers::pumpfun::pf_token_created_handler::handle_pf_token_creations;
+use solana_sdk::bs58;
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+use yellowstone_grpc_proto::prelude::TransactionStatusMeta;
+
+const PUMPFUN: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+
+fn borsh_string(s: &str) -> Vec<u8> {
+    [&(s.len() as u32).to_le_bytes()[..], s.as_bytes()].concat()
+}
+
+fn create_event(name: &str, symbol: &str, uri: &str) -> Vec<u8> {
+    let mut body = PF_CREATE_EVENT_DISC.to_vec();
+    body.extend(borsh_string(name));
+    body.extend(borsh_string(symbol));
+    body.extend(borsh_string(uri));
+    for key in 1..=4u8 {
+        body.extend([key; 32]); // mint, bonding_curve, user, creator
+    }
+    body.extend(1_700_000_000i64.to_le_bytes());
+    for amount in [
+        1_073_000_000_000_000u64,
+        30_000_000_000,
+        793_100_000_000_000,
+        1_000_000_000_000_000,
+    ] {
+        body.extend(amount.to_le_bytes());
+    }
+    body
+}
+
+#[test]
+fn decodes_the_create_event() {
+    let tx = SubscribeUpdateTransactionInfo {
+        index: 7,
+        meta: Some(TransactionStatusMeta {
+            log_messages: vec![
+                format!("Program {PUMPFUN} invoke [1]"),
+                "Program log: Instruction: CreateV2".into(),
+                format!(
+                    "Program data: {}",
+                    STANDARD.encode(create_event("Dog", "DOG", "ipfs://dog"))
+                ),
+                format!("Program {PUMPFUN} success"),
+            ],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let tokens = handle_pf_token_creations(&tx).unwrap();
+
+    assert_eq!(tokens.len(), 1);
+    let token = &tokens[0];
+    assert_eq!((token.name.as_str(), token.symbol.as_str()), ("Dog", "DOG"));
+    assert_eq!(token.uri.as_deref(), Some("ipfs://dog"));
+    assert_eq!(token.mint, bs58::encode([1u8; 32]).into_string());
+    assert_eq!(token.creator, bs58::encode([4u8; 32]).into_string());
+    assert_eq!(token.ts.timestamp(), 1_700_000_000);
+    assert_eq!(token.token_total_supply, 1_000_000_000_000_000);
+    assert_eq!(token.tx_index, 7);
+}
@@ -0,0 +1,21 @@
+CREATE TABLE pf_creator_fee_ledger
+(
+    /* ========= Transaction identity ========= */
+    signature                String,
+    slot                     UInt64,
+
+    /* ========= Creator ========= */
+    creator                  String,
+    platform                 LowCardinality(String), -- pump_fun | pump_swap
+    kind                     Enum8('accrual' = 1, 'claim' = 2),
+
+    /* ========= Amounts ========= */
+    mint                     String, -- traded mint for accruals, empty for claims
+    amount_lamports          UInt64,
+
+    /* ========= Timestamp ========= */
+    timestamp                DateTime64(3, 'UTC')
+) ENGINE = MergeTree
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (creator, platform, timestamp, signature)
+SETTINGS index_granularity = 8192;
@@ -0,0 +1,13 @@
+CREATE TABLE pf_creator_fee_balances
+(
+    creator                  String,
+    platform                 LowCardinality(String),
+
+    accrued_lamports         SimpleAggregateFunction(sum, UInt64),
+    claimed_lamports         SimpleAggregateFunction(sum, UInt64),
+    accrual_count            SimpleAggregateFunction(sum, UInt64),
+    claim_count              SimpleAggregateFunction(sum, UInt64),
+
+    updated_at               SimpleAggregateFunction(max, DateTime64(3, 'UTC'))
+) ENGINE = AggregatingMergeTree
+ORDER BY (creator, platform);
@@ -0,0 +1,16 @@
+-- Query balances with:
+--   SELECT creator, platform,
+--          sum(accrued_lamports) AS accrued, sum(claimed_lamports) AS claimed,
+--          greatest(toInt128(accrued) - toInt128(claimed), 0) AS unclaimed
+--   FROM pf_creator_fee_balances WHERE creator = ? GROUP BY creator, platform
+CREATE MATERIALIZED VIEW pf_creator_fee_balances_mv TO pf_creator_fee_balances AS
+SELECT
+    creator,
+    platform,
+    sumIf(amount_lamports, kind = 'accrual') AS accrued_lamports,
+    sumIf(amount_lamports, kind = 'claim')   AS claimed_lamports,
+    countIf(kind = 'accrual')                AS accrual_count,
+    countIf(kind = 'claim')                  AS claim_count,
+    max(timestamp)                           AS updated_at
+FROM pf_creator_fee_ledger
+GROUP BY creator, platform;
@@ -0,0 +1,25 @@
+CREATE TABLE pf_token_incentive_ledger
+(
+    /* ========= Transaction identity ========= */
+    signature                String, -- empty for account updates
+    slot                     UInt64,
+
+    /* ========= Wallet ========= */
+    wallet                   String,
+    source                   Enum8('trade' = 1, 'claim' = 2, 'account_update' = 3),
+
+    /* ========= Amounts (raw token units) ========= */
+    mint                     String, -- incentive mint, empty when unknown
+    amount                   UInt64, -- tokens claimed, 0 for other sources
+
+    /* ========= Accumulator state after the update ========= */
+    total_unclaimed_tokens   UInt64,
+    total_claimed_tokens     UInt64,
+    current_sol_volume       UInt64,
+
+    /* ========= Timestamp ========= */
+    timestamp                DateTime64(3, 'UTC')
+) ENGINE = MergeTree
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (wallet, timestamp, slot, signature)
+SETTINGS index_granularity = 8192;
@@ -0,0 +1,25 @@
+-- Pump.fun bonding curve states from account updates (geyser_account_subscriber),
+-- complementing the trade-derived state in pf_ch_trades.
+CREATE TABLE pf_curve_states
+(
+    /* ========= Identity ========= */
+    bonding_curve            String,
+    mint                     String, -- empty until the curve's token is known
+    slot                     UInt64,
+    write_version            UInt64,
+    source                   LowCardinality(String), -- geyser_account_subscriber | snapshot | ...
+
+    /* ========= Curve state ========= */
+    virtual_token_reserves   UInt64,
+    virtual_sol_reserves     UInt64,
+    real_token_reserves      UInt64,
+    real_sol_reserves        UInt64,
+    token_total_supply       UInt64,
+    complete                 UInt8,
+
+    /* ========= Timestamp ========= */
+    timestamp                DateTime64(3, 'UTC')
+) ENGINE = MergeTree
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (bonding_curve, slot, write_version)
+SETTINGS index_granularity = 8192;
@@ -0,0 +1,21 @@
+-- Token lifecycle transitions across platforms: creation, curve completion, migration.
+CREATE TABLE token_lifecycle_ledger
+(
+    /* ========= Token ========= */
+    platform                 LowCardinality(String), -- pump_fun | pump_swap | raydium_launch_lab
+    mint                     String, -- empty when the event does not carry it
+    account                  String, -- bonding curve / pool state the event refers to
+    kind                     Enum8('created' = 1, 'completed' = 2, 'migrated' = 3),
+
+    /* ========= Migration ========= */
+    pool                     String, -- destination pool, empty unless migrated
+    sol_amount               UInt64, -- SOL raised / migrated (lamports)
+    token_amount             UInt64, -- tokens migrated (raw units)
+
+    /* ========= Origin ========= */
+    slot                     UInt64,
+    timestamp                DateTime64(3, 'UTC')
+) ENGINE = MergeTree
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (platform, mint, account, timestamp)
+SETTINGS index_granularity = 8192;
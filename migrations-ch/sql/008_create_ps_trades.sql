@@ -0,0 +1,26 @@
+CREATE TABLE ps_trades
+(
+    /* ========= Transaction identity ========= */
+    signature                String,
+    slot                     UInt64,
+
+    /* ========= Market ========= */
+    mint                     String,
+    pool                     String,
+    user                     String,
+
+    /* ========= Trade ========= */
+    is_buy                   UInt8,
+    sol_amount               Int64,  -- lamports
+    token_amount             Int64,  -- raw token units
+
+    /* ========= Coin creator fee ========= */
+    coin_creator             String, -- empty on pools without a coin creator
+    coin_creator_fee         UInt64, -- quote units
+
+    /* ========= Timestamp ========= */
+    timestamp                DateTime64(3, 'UTC')
+) ENGINE = MergeTree
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (mint, pool, timestamp)
+SETTINGS index_granularity = 8192;
@@ -0,0 +1,19 @@
+-- PumpSwap pool reserves, one row per pool and slot the vaults changed in.
+CREATE TABLE ps_pool_prices
+(
+    pool                     String,
+    base_mint                String,
+    quote_mint               String,
+    slot                     UInt64,
+    write_version            UInt64,
+    source                   LowCardinality(String),
+
+    price                    UInt64, -- quote smallest units per whole base token
+    base_reserves            UInt64,
+    quote_reserves           UInt64,
+
+    timestamp                DateTime64(3, 'UTC')
+) ENGINE = MergeTree
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (pool, slot, write_version)
+SETTINGS index_granularity = 8192;
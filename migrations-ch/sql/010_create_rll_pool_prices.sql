@@ -0,0 +1,22 @@
+-- Raydium LaunchLab PoolState history from account updates.
+CREATE TABLE rll_pool_prices
+(
+    pool_state               String,
+    base_mint                String,
+    slot                     UInt64,
+    write_version            UInt64,
+    source                   LowCardinality(String),
+
+    status                   UInt8,
+    price                    UInt64, -- quote smallest units per whole base token
+    virtual_base             UInt64,
+    virtual_quote            UInt64,
+    real_base                UInt64,
+    real_quote               UInt64,
+    progress_bps             UInt16,
+
+    timestamp                DateTime64(3, 'UTC')
+) ENGINE = MergeTree
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (pool_state, slot, write_version)
+SETTINGS index_granularity = 8192;
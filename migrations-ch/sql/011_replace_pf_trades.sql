@@ -0,0 +1,80 @@
+-- Trades are keyed by (signature, ix_index, event_ordinal): redelivered copies share the
+-- sorting key and collapse into the latest version on merge. Read with FINAL for exact counts.
+-- Rows written before these columns existed cannot be told apart from their redeliveries;
+-- each gets its own legacy_row (1, 2, ... per transaction) so none of them collapses, and
+-- new rows (legacy_row 0) never share a key with them.
+CREATE TABLE pf_ch_trades_v2
+(
+    /* ========= Transaction identity ========= */
+    signature                String,
+    slot                     UInt64,
+    blockhash                String,
+
+    /* ========= Actors ========= */
+    signer                   String,
+    fee_payer                String,
+    user                     String,
+    creator                  String,
+    fee_recipient            String,
+
+    /* ========= Token / market ========= */
+    mint                     String,
+    bonding_curve            String,
+    is_pump_pool             UInt8,
+
+    /* ========= Instruction semantics ========= */
+    ix_name                  String,
+    is_buy                   UInt8,
+
+    /* ========= Trade amounts ========= */
+    sol_amount               UInt64,
+    token_amount             UInt64,
+    trade_size_lamports      UInt64,
+
+    /* ========= Fees ========= */
+    transaction_fee          UInt64,
+    fee_lamports             UInt64,
+    fee_basis_points         UInt64,
+    creator_fee_lamports     UInt64,
+    creator_fee_basis_points UInt64,
+
+    /* ========= Market / bonding curve state ========= */
+    decimals                 UInt32,
+    virtual_sol_reserves     UInt64,
+    virtual_token_reserves   UInt64,
+    real_sol_reserves        UInt64,
+    real_token_reserves      UInt64,
+    market_cap_lamports      UInt64,
+
+    /* ========= Volume & tracking ========= */
+    track_volume             UInt8,
+    total_unclaimed_tokens   UInt64,
+    total_claimed_tokens     UInt64,
+    current_sol_volume       UInt64,
+    last_update_timestamp    Int64,
+
+    /* ========= Timestamp ========= */
+    timestamp                DateTime64(3, 'UTC'),
+
+    /* ========= Deduplication ========= */
+    ix_index                 UInt32, -- top-level instruction that emitted the trade
+    event_ordinal            UInt32, -- position of the event among those the transaction emitted
+    legacy_row               UInt32 DEFAULT 0, -- 1-based row within the transaction for backfilled rows
+    version                  UInt64  -- processing time (ms); the latest copy survives merges
+) ENGINE = ReplacingMergeTree(version)
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (mint, signature, ix_index, event_ordinal, legacy_row)
+SETTINGS index_granularity = 8192;
+
+INSERT INTO pf_ch_trades_v2
+SELECT
+    *,
+    0                                                                    AS ix_index,
+    0                                                                    AS event_ordinal,
+    row_number() OVER (PARTITION BY signature ORDER BY slot, timestamp) AS legacy_row,
+    0                                                                    AS version
+FROM pf_ch_trades;
+
+EXCHANGE TABLES pf_ch_trades AND pf_ch_trades_v2;
+
+DROP TABLE pf_ch_trades_v2;
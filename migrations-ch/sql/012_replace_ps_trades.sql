@@ -0,0 +1,47 @@
+-- Same (signature, ix_index, event_ordinal) deduplication and legacy_row backfill as
+-- pf_ch_trades (011).
+CREATE TABLE ps_trades_v2
+(
+    /* ========= Transaction identity ========= */
+    signature                String,
+    slot                     UInt64,
+
+    /* ========= Market ========= */
+    mint                     String,
+    pool                     String,
+    user                     String,
+
+    /* ========= Trade ========= */
+    is_buy                   UInt8,
+    sol_amount               Int64,  -- lamports
+    token_amount             Int64,  -- raw token units
+
+    /* ========= Coin creator fee ========= */
+    coin_creator             String, -- empty on pools without a coin creator
+    coin_creator_fee         UInt64, -- quote units
+
+    /* ========= Timestamp ========= */
+    timestamp                DateTime64(3, 'UTC'),
+
+    /* ========= Deduplication ========= */
+    ix_index                 UInt32, -- top-level instruction that emitted the swap
+    event_ordinal            UInt32, -- position of the event among those the transaction emitted
+    legacy_row               UInt32 DEFAULT 0, -- 1-based row within the transaction for backfilled rows
+    version                  UInt64  -- processing time (ms); the latest copy survives merges
+) ENGINE = ReplacingMergeTree(version)
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (mint, signature, ix_index, event_ordinal, legacy_row)
+SETTINGS index_granularity = 8192;
+
+INSERT INTO ps_trades_v2
+SELECT
+    *,
+    0                                                                    AS ix_index,
+    0                                                                    AS event_ordinal,
+    row_number() OVER (PARTITION BY signature ORDER BY slot, timestamp) AS legacy_row,
+    0                                                                    AS version
+FROM ps_trades;
+
+EXCHANGE TABLES ps_trades AND ps_trades_v2;
+
+DROP TABLE ps_trades_v2;
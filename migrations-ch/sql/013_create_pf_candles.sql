@@ -0,0 +1,30 @@
+-- OHLCV candles per mint, one row set per interval (1s, 1m, 5m, 15m, 1h, 1d), fed by
+-- pf_candles_mv. Price is the post-trade marginal price in lamports per whole token.
+-- Query candles with:
+--   SELECT bucket,
+--          argMinMerge(open) AS open, max(high) AS high, min(low) AS low, argMaxMerge(close) AS close,
+--          sum(sol_volume), sum(token_volume), sum(buy_count), sum(sell_count),
+--          uniqMerge(unique_traders)
+--   FROM pf_candles WHERE mint = ? AND interval_secs = ? AND bucket >= ? AND bucket < ?
+--   GROUP BY bucket ORDER BY bucket
+CREATE TABLE pf_candles
+(
+    mint                     String,
+    interval_secs            UInt32,
+    bucket                   DateTime('UTC'),
+
+    /* ========= Price (ordered by slot, then processing time) ========= */
+    open                     AggregateFunction(argMin, Float64, Tuple(UInt64, DateTime64(3, 'UTC'))),
+    high                     SimpleAggregateFunction(max, Float64),
+    low                      SimpleAggregateFunction(min, Float64),
+    close                    AggregateFunction(argMax, Float64, Tuple(UInt64, DateTime64(3, 'UTC'))),
+
+    /* ========= Volume ========= */
+    sol_volume               SimpleAggregateFunction(sum, UInt64),
+    token_volume             SimpleAggregateFunction(sum, UInt64),
+    buy_count                SimpleAggregateFunction(sum, UInt64),
+    sell_count               SimpleAggregateFunction(sum, UInt64),
+    unique_traders           AggregateFunction(uniq, String)
+) ENGINE = AggregatingMergeTree
+PARTITION BY toYYYYMM(bucket)
+ORDER BY (mint, interval_secs, bucket);
@@ -0,0 +1,61 @@
+-- Ledger rows are keyed by the event that moved the balance: redelivered copies share the
+-- sorting key and collapse into the latest version on merge. Rows written before these
+-- columns existed get their own legacy_row (1, 2, ... per key), as in pf_ch_trades (011).
+CREATE TABLE pf_creator_fee_ledger_v2
+(
+    /* ========= Transaction identity ========= */
+    signature                String,
+    slot                     UInt64,
+
+    /* ========= Creator ========= */
+    creator                  String,
+    platform                 LowCardinality(String), -- pump_fun | pump_swap
+    kind                     Enum8('accrual' = 1, 'claim' = 2),
+
+    /* ========= Amounts ========= */
+    mint                     String, -- traded mint for accruals, empty for claims
+    amount_lamports          UInt64,
+
+    /* ========= Timestamp ========= */
+    timestamp                DateTime64(3, 'UTC'),
+
+    /* ========= Deduplication ========= */
+    ix_index                 UInt32, -- top-level instruction that emitted the event
+    event_ordinal            UInt32, -- position of the event among those the transaction emitted
+    legacy_row               UInt32 DEFAULT 0, -- 1-based row within the key for backfilled rows
+    version                  UInt64  -- processing time (ms); the latest copy survives merges
+) ENGINE = ReplacingMergeTree(version)
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (creator, platform, signature, kind, ix_index, event_ordinal, legacy_row)
+SETTINGS index_granularity = 8192;
+
+-- The incremental view would add every redelivered copy to the balances
+DROP VIEW pf_creator_fee_balances_mv;
+
+INSERT INTO pf_creator_fee_ledger_v2
+SELECT
+    *,
+    0                                                                                    AS ix_index,
+    0                                                                                    AS event_ordinal,
+    row_number() OVER (PARTITION BY creator, platform, signature, kind ORDER BY timestamp) AS legacy_row,
+    0                                                                                    AS version
+FROM pf_creator_fee_ledger;
+
+EXCHANGE TABLES pf_creator_fee_ledger AND pf_creator_fee_ledger_v2;
+
+DROP TABLE pf_creator_fee_ledger_v2;
+
+-- Balances are recomputed from the deduplicated ledger; each refresh replaces them whole,
+-- so they trail the ledger by up to a minute.
+CREATE MATERIALIZED VIEW pf_creator_fee_balances_mv
+REFRESH EVERY 1 MINUTE TO pf_creator_fee_balances AS
+SELECT
+    creator,
+    platform,
+    sumIf(amount_lamports, kind = 'accrual') AS accrued_lamports,
+    sumIf(amount_lamports, kind = 'claim')   AS claimed_lamports,
+    countIf(kind = 'accrual')                AS accrual_count,
+    countIf(kind = 'claim')                  AS claim_count,
+    max(timestamp)                           AS updated_at
+FROM pf_creator_fee_ledger FINAL
+GROUP BY creator, platform;
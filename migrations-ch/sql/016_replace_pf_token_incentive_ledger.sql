@@ -0,0 +1,47 @@
+-- Same event deduplication and legacy_row backfill as pf_creator_fee_ledger (015).
+-- Account updates carry no signature: copies of one slot's update collapse, and so do
+-- several updates of one wallet within a slot, keeping the last processed.
+CREATE TABLE pf_token_incentive_ledger_v2
+(
+    /* ========= Transaction identity ========= */
+    signature                String, -- empty for account updates
+    slot                     UInt64,
+
+    /* ========= Wallet ========= */
+    wallet                   String,
+    source                   Enum8('trade' = 1, 'claim' = 2, 'account_update' = 3),
+
+    /* ========= Amounts (raw token units) ========= */
+    mint                     String, -- incentive mint, empty when unknown
+    amount                   UInt64, -- tokens claimed, 0 for other sources
+
+    /* ========= Accumulator state after the update ========= */
+    total_unclaimed_tokens   UInt64,
+    total_claimed_tokens     UInt64,
+    current_sol_volume       UInt64,
+
+    /* ========= Timestamp ========= */
+    timestamp                DateTime64(3, 'UTC'),
+
+    /* ========= Deduplication ========= */
+    ix_index                 UInt32, -- top-level instruction that emitted the event, 0 for account updates
+    event_ordinal            UInt32, -- position of the event among those the transaction emitted
+    legacy_row               UInt32 DEFAULT 0, -- 1-based row within the key for backfilled rows
+    version                  UInt64  -- processing time (ms); the latest copy survives merges
+) ENGINE = ReplacingMergeTree(version)
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (wallet, slot, source, signature, ix_index, event_ordinal, legacy_row)
+SETTINGS index_granularity = 8192;
+
+INSERT INTO pf_token_incentive_ledger_v2
+SELECT
+    *,
+    0                                                                                  AS ix_index,
+    0                                                                                  AS event_ordinal,
+    row_number() OVER (PARTITION BY wallet, slot, source, signature ORDER BY timestamp) AS legacy_row,
+    0                                                                                  AS version
+FROM pf_token_incentive_ledger;
+
+EXCHANGE TABLES pf_token_incentive_ledger AND pf_token_incentive_ledger_v2;
+
+DROP TABLE pf_token_incentive_ledger_v2;
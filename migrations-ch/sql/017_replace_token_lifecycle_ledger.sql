@@ -0,0 +1,28 @@
+-- A token is created, completed and migrated once per account, so the transition itself
+-- is the key: redelivered copies collapse on merge. Read with FINAL for exact counts.
+CREATE TABLE token_lifecycle_ledger_v2
+(
+    /* ========= Token ========= */
+    platform                 LowCardinality(String), -- pump_fun | pump_swap | raydium_launch_lab
+    mint                     String, -- empty when the event does not carry it
+    account                  String, -- bonding curve / pool state the event refers to
+    kind                     Enum8('created' = 1, 'completed' = 2, 'migrated' = 3),
+
+    /* ========= Migration ========= */
+    pool                     String, -- destination pool, empty unless migrated
+    sol_amount               UInt64, -- SOL raised / migrated (lamports)
+    token_amount             UInt64, -- tokens migrated (raw units)
+
+    /* ========= Origin ========= */
+    slot                     UInt64,
+    timestamp                DateTime64(3, 'UTC')
+) ENGINE = ReplacingMergeTree
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (platform, mint, account, kind)
+SETTINGS index_granularity = 8192;
+
+INSERT INTO token_lifecycle_ledger_v2 SELECT * FROM token_lifecycle_ledger;
+
+EXCHANGE TABLES token_lifecycle_ledger AND token_lifecycle_ledger_v2;
+
+DROP TABLE token_lifecycle_ledger_v2;
@@ -0,0 +1,85 @@
+-- Account writes are identified by (account, slot, write_version), already the sorting key
+-- of these tables: redelivered copies of a write now collapse on merge. Read with FINAL for
+-- exact history.
+CREATE TABLE pf_curve_states_v2
+(
+    /* ========= Identity ========= */
+    bonding_curve            String,
+    mint                     String, -- empty until the curve's token is known
+    slot                     UInt64,
+    write_version            UInt64,
+    source                   LowCardinality(String), -- geyser_account_subscriber | snapshot | ...
+
+    /* ========= Curve state ========= */
+    virtual_token_reserves   UInt64,
+    virtual_sol_reserves     UInt64,
+    real_token_reserves      UInt64,
+    real_sol_reserves        UInt64,
+    token_total_supply       UInt64,
+    complete                 UInt8,
+
+    /* ========= Timestamp ========= */
+    timestamp                DateTime64(3, 'UTC')
+) ENGINE = ReplacingMergeTree
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (bonding_curve, slot, write_version)
+SETTINGS index_granularity = 8192;
+
+INSERT INTO pf_curve_states_v2 SELECT * FROM pf_curve_states;
+
+EXCHANGE TABLES pf_curve_states AND pf_curve_states_v2;
+
+DROP TABLE pf_curve_states_v2;
+
+CREATE TABLE ps_pool_prices_v2
+(
+    pool                     String,
+    base_mint                String,
+    quote_mint               String,
+    slot                     UInt64,
+    write_version            UInt64,
+    source                   LowCardinality(String),
+
+    price                    UInt64, -- quote smallest units per whole base token
+    base_reserves            UInt64,
+    quote_reserves           UInt64,
+
+    timestamp                DateTime64(3, 'UTC')
+) ENGINE = ReplacingMergeTree
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (pool, slot, write_version)
+SETTINGS index_granularity = 8192;
+
+INSERT INTO ps_pool_prices_v2 SELECT * FROM ps_pool_prices;
+
+EXCHANGE TABLES ps_pool_prices AND ps_pool_prices_v2;
+
+DROP TABLE ps_pool_prices_v2;
+
+CREATE TABLE rll_pool_prices_v2
+(
+    pool_state               String,
+    base_mint                String,
+    slot                     UInt64,
+    write_version            UInt64,
+    source                   LowCardinality(String),
+
+    status                   UInt8,
+    price                    UInt64, -- quote smallest units per whole base token
+    virtual_base             UInt64,
+    virtual_quote            UInt64,
+    real_base                UInt64,
+    real_quote               UInt64,
+    progress_bps             UInt16,
+
+    timestamp                DateTime64(3, 'UTC')
+) ENGINE = ReplacingMergeTree
+PARTITION BY toYYYYMM(timestamp)
+ORDER BY (pool_state, slot, write_version)
+SETTINGS index_granularity = 8192;
+
+INSERT INTO rll_pool_prices_v2 SELECT * FROM rll_pool_prices;
+
+EXCHANGE TABLES rll_pool_prices AND rll_pool_prices_v2;
+
+DROP TABLE rll_pool_prices_v2;
@@ -0,0 +1,55 @@
+-- The incremental view summed trades as they were inserted, so a trade written twice (a
+-- replayed ClickHouse batch, a redelivery past the Redis window) was counted twice in
+-- volumes and buy/sell counts. Candles are now recomputed from the deduplicated trades;
+-- each refresh replaces them whole, so they trail the trades by up to a minute. One row
+-- per (mint, interval_secs, bucket); query with
+--   SELECT bucket, open, high, low, close, sol_volume, token_volume, buy_count, sell_count,
+--          unique_traders
+--   FROM pf_candles WHERE mint = ? AND interval_secs = ? AND bucket >= ? AND bucket < ?
+--   ORDER BY bucket
+DROP VIEW pf_candles_mv;
+
+DROP TABLE pf_candles;
+
+CREATE TABLE pf_candles
+(
+    mint                     String,
+    interval_secs            UInt32,
+    bucket                   DateTime('UTC'),
+
+    /* ========= Price ========= */
+    open                     Float64,
+    high                     Float64,
+    low                      Float64,
+    close                    Float64,
+
+    /* ========= Volume ========= */
+    sol_volume               UInt64,
+    token_volume             UInt64,
+    buy_count                UInt64,
+    sell_count               UInt64,
+    unique_traders           UInt64
+) ENGINE = MergeTree
+PARTITION BY toYYYYMM(bucket)
+ORDER BY (mint, interval_secs, bucket);
+
+CREATE MATERIALIZED VIEW pf_candles_mv
+REFRESH EVERY 1 MINUTE TO pf_candles AS
+WITH virtual_sol_reserves * exp10(decimals) / virtual_token_reserves AS price
+SELECT
+    mint,
+    interval_secs,
+    toDateTime(intDiv(toUnixTimestamp(timestamp), interval_secs) * interval_secs, 'UTC') AS bucket,
+    argMin(price, (slot, timestamp))                                                   AS open,
+    max(price)                                                                         AS high,
+    min(price)                                                                         AS low,
+    argMax(price, (slot, timestamp))                                                   AS close,
+    sum(sol_amount)                                                                    AS sol_volume,
+    sum(token_amount)                                                                  AS token_volume,
+    countIf(is_buy = 1)                                                                AS buy_count,
+    countIf(is_buy = 0)                                                                AS sell_count,
+    uniqExact(user)                                                                    AS unique_traders
+FROM pf_ch_trades FINAL
+ARRAY JOIN [1, 60, 300, 900, 3600, 86400] AS interval_secs
+WHERE virtual_token_reserves > 0
+GROUP BY mint, interval_secs, bucket;
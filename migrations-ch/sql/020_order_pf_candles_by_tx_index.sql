@@ -0,0 +1,27 @@
+-- Trades carry their position in the slot and their timestamp is the block time. Open and
+-- close follow on-chain order (slot, tx_index, event_ordinal) instead of processing time.
+-- Rows written before tx_index existed keep 0; among them processing time still decides.
+ALTER TABLE pf_ch_trades ADD COLUMN tx_index UInt64 DEFAULT 0 AFTER slot;
+
+DROP VIEW pf_candles_mv;
+
+CREATE MATERIALIZED VIEW pf_candles_mv
+REFRESH EVERY 1 MINUTE TO pf_candles AS
+WITH virtual_sol_reserves * exp10(decimals) / virtual_token_reserves AS price
+SELECT
+    mint,
+    interval_secs,
+    toDateTime(intDiv(toUnixTimestamp(timestamp), interval_secs) * interval_secs, 'UTC') AS bucket,
+    argMin(price, (slot, tx_index, event_ordinal, timestamp))                          AS open,
+    max(price)                                                                         AS high,
+    min(price)                                                                         AS low,
+    argMax(price, (slot, tx_index, event_ordinal, timestamp))                          AS close,
+    sum(sol_amount)                                                                    AS sol_volume,
+    sum(token_amount)                                                                  AS token_volume,
+    countIf(is_buy = 1)                                                                AS buy_count,
+    countIf(is_buy = 0)                                                                AS sell_count,
+    uniqExact(user)                                                                    AS unique_traders
+FROM pf_ch_trades FINAL
+ARRAY JOIN [1, 60, 300, 900, 3600, 86400] AS interval_secs
+WHERE virtual_token_reserves > 0
+GROUP BY mint, interval_secs, bucket;
@@ -0,0 +1,34 @@
+-- Platform a creator fee was earned on
+CREATE TYPE pf_creator_fee_platform AS ENUM (
+    'pump_fun',
+    'pump_swap'
+);
+
+-- Per-creator fee balances. Accruals come from trades, claims from
+-- collect_creator_fee / collect_coin_creator_fee. Amounts are lamports
+-- (PumpSwap coin creator fees are paid in the pool quote mint, WSOL for pump pools).
+CREATE TABLE pf_creator_fees
+(
+    creator                TEXT                    NOT NULL,
+    platform               pf_creator_fee_platform NOT NULL,
+
+    accrued_lamports       BIGINT                  NOT NULL DEFAULT 0,
+    claimed_lamports       BIGINT                  NOT NULL DEFAULT 0,
+    -- Claims can include fees accrued before indexing started, so never go negative
+    unclaimed_lamports     BIGINT GENERATED ALWAYS AS (GREATEST(accrued_lamports - claimed_lamports, 0)) STORED,
+
+    accrual_count          BIGINT                  NOT NULL DEFAULT 0,
+    claim_count            BIGINT                  NOT NULL DEFAULT 0,
+
+    last_accrual_signature TEXT,
+    last_claim_signature   TEXT,
+    last_accrual_ts        TIMESTAMPTZ,
+    last_claim_ts          TIMESTAMPTZ,
+
+    created_at             TIMESTAMPTZ             NOT NULL DEFAULT now(),
+    updated_at             TIMESTAMPTZ             NOT NULL DEFAULT now(),
+
+    PRIMARY KEY (creator, platform)
+);
+
+CREATE INDEX idx_pf_creator_fees_unclaimed ON pf_creator_fees (unclaimed_lamports DESC);
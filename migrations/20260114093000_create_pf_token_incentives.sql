@@ -0,0 +1,26 @@
+-- Per-wallet Pump.fun volume incentive balances, mirroring the wallet's
+-- UserVolumeAccumulator. Fed by trades, claim_token_incentives and accumulator
+-- account updates; the row with the highest slot wins. Token amounts are raw units.
+CREATE TABLE pf_token_incentives
+(
+    wallet                 TEXT        NOT NULL PRIMARY KEY,
+    mint                   TEXT,
+
+    needs_claim            BOOLEAN     NOT NULL DEFAULT FALSE,
+    total_unclaimed_tokens BIGINT      NOT NULL DEFAULT 0,
+    total_claimed_tokens   BIGINT      NOT NULL DEFAULT 0,
+    current_sol_volume     BIGINT      NOT NULL DEFAULT 0,
+    -- On-chain unix time of the last accumulator sync
+    last_update_timestamp  BIGINT      NOT NULL DEFAULT 0,
+    slot                   BIGINT      NOT NULL DEFAULT 0,
+
+    claim_count            BIGINT      NOT NULL DEFAULT 0,
+    last_claim_signature   TEXT,
+    last_claim_amount      BIGINT,
+    last_claim_ts          TIMESTAMPTZ,
+
+    created_at             TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at             TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_pf_token_incentives_unclaimed ON pf_token_incentives (total_unclaimed_tokens DESC);
@@ -0,0 +1,36 @@
+-- Versioned Pump.fun protocol parameters. A new version is written whenever the
+-- Global account (set_params) or the Pump Fees FeeConfig changes. The version
+-- with the highest effective_slot <= S is the one in effect at slot S.
+CREATE TABLE pf_global_params
+(
+    version                        BIGINT      NOT NULL PRIMARY KEY,
+    effective_slot                 BIGINT      NOT NULL UNIQUE,
+
+    fee_recipient                  TEXT        NOT NULL,
+    initial_virtual_token_reserves BIGINT      NOT NULL,
+    initial_virtual_sol_reserves   BIGINT      NOT NULL,
+    initial_real_token_reserves    BIGINT      NOT NULL,
+    token_total_supply             BIGINT      NOT NULL,
+    fee_basis_points               BIGINT      NOT NULL,
+    creator_fee_basis_points       BIGINT      NOT NULL,
+    enable_migrate                 BOOLEAN     NOT NULL,
+    pool_migration_fee             BIGINT      NOT NULL,
+
+    observed_at                    TIMESTAMPTZ NOT NULL,
+    created_at                     TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE pf_fee_configs
+(
+    version               BIGINT      NOT NULL PRIMARY KEY,
+    effective_slot        BIGINT      NOT NULL UNIQUE,
+
+    flat_lp_fee_bps       BIGINT      NOT NULL,
+    flat_protocol_fee_bps BIGINT      NOT NULL,
+    flat_creator_fee_bps  BIGINT      NOT NULL,
+    -- [{market_cap_lamports_threshold, lp_fee_bps, protocol_fee_bps, creator_fee_bps}], ascending
+    fee_tiers             JSONB       NOT NULL DEFAULT '[]',
+
+    observed_at           TIMESTAMPTZ NOT NULL,
+    created_at            TIMESTAMPTZ NOT NULL DEFAULT now()
+);
@@ -0,0 +1,22 @@
+-- Latest PumpSwap pool price, joined from the pool's base and quote vault
+-- balances by geyser_account_subscriber. One row per pool.
+CREATE TABLE ps_prices
+(
+    pool           TEXT        NOT NULL PRIMARY KEY,
+    base_mint      TEXT,
+    quote_mint     TEXT,
+
+    -- Quote smallest units per whole base token
+    price          BIGINT      NOT NULL,
+    base_reserves  BIGINT      NOT NULL,
+    quote_reserves BIGINT      NOT NULL,
+
+    slot           BIGINT      NOT NULL DEFAULT 0,
+    write_version  BIGINT      NOT NULL DEFAULT 0,
+
+    ts             TIMESTAMPTZ NOT NULL,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_ps_prices_base_mint ON ps_prices (base_mint);
@@ -0,0 +1,33 @@
+-- Latest Raydium LaunchLab curve state, one row per PoolState. Seeded by
+-- token creation, then kept current by PoolState account updates.
+CREATE TABLE rll_prices
+(
+    pool_state               TEXT        NOT NULL PRIMARY KEY,
+    base_mint                TEXT,
+    creator                  TEXT,
+
+    -- 0 fund-raising, 1 migrating, 2 trading on the AMM
+    status                   SMALLINT    NOT NULL DEFAULT 0,
+
+    -- Quote smallest units per whole base token
+    price                    BIGINT      NOT NULL,
+    base_decimals            SMALLINT    NOT NULL,
+    quote_decimals           SMALLINT    NOT NULL,
+
+    virtual_base             BIGINT      NOT NULL,
+    virtual_quote            BIGINT      NOT NULL,
+    real_base                BIGINT      NOT NULL DEFAULT 0,
+    real_quote               BIGINT      NOT NULL DEFAULT 0,
+    total_quote_fund_raising BIGINT      NOT NULL DEFAULT 0,
+
+    progress_bps             INTEGER     NOT NULL DEFAULT 0 CHECK (progress_bps BETWEEN 0 AND 10000),
+
+    slot                     BIGINT      NOT NULL DEFAULT 0,
+    write_version            BIGINT      NOT NULL DEFAULT 0,
+
+    ts                       TIMESTAMPTZ NOT NULL,
+    created_at               TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at               TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_rll_prices_base_mint ON rll_prices (base_mint);
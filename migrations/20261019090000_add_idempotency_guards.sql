@@ -0,0 +1,23 @@
+-- Slot of the state each row holds. Upserts carrying an older slot are ignored,
+-- so a redelivered or late event cannot roll the price back.
+ALTER TABLE pf_prices
+    ADD COLUMN slot BIGINT NOT NULL DEFAULT 0;
+
+ALTER TABLE pf_bonding_curve_progress
+    ADD COLUMN slot BIGINT NOT NULL DEFAULT 0;
+
+-- Events already added to a running balance (pf_creator_fees, pf_token_incentives),
+-- keyed by (signature, ix_index). An event is only applied if it can be recorded here
+-- first, in the same transaction, so redeliveries are never counted twice.
+CREATE TABLE applied_ledger_events
+(
+    ledger     TEXT        NOT NULL,
+    signature  TEXT        NOT NULL,
+    ix_index   INTEGER     NOT NULL,
+    slot       BIGINT      NOT NULL,
+    applied_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+
+    PRIMARY KEY (ledger, signature, ix_index)
+);
+
+CREATE INDEX idx_applied_ledger_events_applied_at ON applied_ledger_events (applied_at);
@@ -0,0 +1,5 @@
+-- Position within its slot of the transaction that produced the price. Together with
+-- `slot` it orders prices, so an out-of-order delivery cannot move the price backwards.
+-- Account updates carry no transaction and store the end-of-slot marker i64::MAX.
+ALTER TABLE pf_prices
+    ADD COLUMN tx_index BIGINT NOT NULL DEFAULT 0;
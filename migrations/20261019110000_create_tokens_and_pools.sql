@@ -0,0 +1,80 @@
+-- Platforms a token or pool lives on, mirroring `gap_filler::models::enums::Platform`
+CREATE TYPE platform_type AS ENUM (
+    'pump_fun',
+    'pump_swap',
+
+    'raydium_amm_v4',
+    'raydium_cpmm',
+    'raydium_clmm',
+    'raydium_launch_lab',
+
+    'meteora_dlmm',
+    'meteora_dlmm_launch',
+    'meteora_damm_v1',
+    'meteora_damm_v2',
+    'meteora_memecoin_v1',
+    'meteora_memecoin_v2',
+    'meteora_stake2_earn',
+    'meteora_dbc',
+
+    'unknown'
+);
+
+-- Canonical token registry read by gap_filler's resolver (`models::db::Token`).
+-- Created from creation events; the historian's fulfill requests fill in metadata
+-- and register tokens of platforms no creation event covers.
+CREATE TABLE tokens
+(
+    id                     BIGSERIAL PRIMARY KEY,
+
+    name                   TEXT          NOT NULL,
+    symbol                 TEXT          NOT NULL,
+    uri                    TEXT,
+    creator                TEXT          NOT NULL DEFAULT '',
+    mint                   TEXT          NOT NULL UNIQUE,
+    platform               platform_type NOT NULL,
+
+    bonding_curve          TEXT,
+    pool_state             TEXT,
+    pool                   TEXT,
+
+    user_address           TEXT          NOT NULL DEFAULT '',
+    "decimal"              BIGINT        NOT NULL,
+    ts                     TIMESTAMPTZ   NOT NULL,
+    virtual_token_reserves BIGINT        NOT NULL DEFAULT 0,
+    virtual_sol_reserves   BIGINT        NOT NULL DEFAULT 0,
+    real_token_reserves    BIGINT        NOT NULL DEFAULT 0,
+    token_total_supply     BIGINT        NOT NULL DEFAULT 0,
+
+    description            TEXT          NOT NULL DEFAULT '',
+    twitter                TEXT          NOT NULL DEFAULT '',
+    telegram               TEXT          NOT NULL DEFAULT '',
+    website                TEXT          NOT NULL DEFAULT '',
+    image                  TEXT          NOT NULL DEFAULT '',
+
+    created_at             TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    updated_at             TIMESTAMPTZ   NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_tokens_name_lower ON tokens (LOWER(name));
+CREATE INDEX idx_tokens_symbol_lower ON tokens (LOWER(symbol));
+CREATE INDEX idx_tokens_bonding_curve ON tokens (bonding_curve);
+
+-- DEX pools a token trades in (`models::db::Pool`), e.g. the PumpSwap pool a
+-- Pump.fun token migrated to
+CREATE TABLE pools
+(
+    id         BIGSERIAL PRIMARY KEY,
+
+    mint       TEXT          NOT NULL,
+    platform   platform_type NOT NULL,
+    pool       TEXT          NOT NULL UNIQUE,
+    pair       TEXT          NOT NULL,
+    token_a    TEXT          NOT NULL,
+    token_b    TEXT          NOT NULL,
+
+    created_at TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ   NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_pools_mint ON pools (mint);
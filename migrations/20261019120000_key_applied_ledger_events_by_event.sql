@@ -0,0 +1,10 @@
+-- One instruction can emit several ledger events (a trade accrual and a claim, or
+-- two trades), so (signature, ix_index) alone lets one of them shadow the other.
+-- Rows recorded before this migration keep the empty type and ordinal 0.
+ALTER TABLE applied_ledger_events
+    ADD COLUMN event_type    TEXT    NOT NULL DEFAULT '',
+    ADD COLUMN event_ordinal INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE applied_ledger_events
+    DROP CONSTRAINT applied_ledger_events_pkey,
+    ADD PRIMARY KEY (ledger, event_type, signature, ix_index, event_ordinal);
@@ -0,0 +1,5 @@
+-- Account updates now store tx_index -1 instead of i64::MAX: without their position in the
+-- slot, a mid-slot write must not outrank the slot's later trades.
+UPDATE pf_prices
+SET tx_index = -1
+WHERE tx_index = 9223372036854775807;
@@ -1,7 +1,12 @@
 use crate::config::AppConfig;
+use event_contract::leases::{
+    LEASE_PLATFORMS_KEY, LEASES_KEY, encode_lease_platform, lease_member,
+};
+use event_contract::models::enums::Platform;
 use log::{error, info};
-use redis::Client;
 use redis::aio::ConnectionManager;
+use redis::{AsyncCommands, Client};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 pub async fn init_cache(config: AppConfig) -> Result<ConnectionManager, redis::RedisError> {
     let client = Client::open(config.redis_url).map_err(|e| {
@@ -15,3 +20,42 @@ pub async fn init_cache(config: AppConfig) -> Result<ConnectionManager, redis::R
     info!("Connected to Redis successfully.");
     Ok(conn)
 }
+
+/// Take or renew `holder`'s lease on `account` for `ttl`. Only platforms the account
+/// subscriber tracks can be leased, see `models::enums::Platform::tracked`.
+pub async fn acquire_lease(
+    cache: &mut ConnectionManager,
+    holder: &str,
+    account: &str,
+    platform: Platform,
+    ttl: Duration,
+) -> redis::RedisResult<()> {
+    let expires_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .saturating_add(ttl)
+        .as_millis() as i64;
+    let platform = encode_lease_platform(platform).map_err(|e| {
+        redis::RedisError::from((
+            redis::ErrorKind::TypeError,
+            "failed to serialize Platform",
+            e.to_string(),
+        ))
+    })?;
+
+    redis::pipe()
+        .zadd(LEASES_KEY, lease_member(account, holder), expires_at)
+        .ignore()
+        .hset(LEASE_PLATFORMS_KEY, account, platform)
+        .ignore()
+        .query_async(cache)
+        .await
+}
+
+pub async fn release_lease(
+    cache: &mut ConnectionManager,
+    holder: &str,
+    account: &str,
+) -> redis::RedisResult<()> {
+    cache.zrem(LEASES_KEY, lease_member(account, holder)).await
+}
@@ -1,8 +1,13 @@
+use event_contract::leases::DEFAULT_LEASE_TTL_SECS;
 use std::env;
 
 #[derive(Debug, Clone)]
 pub struct AppConfig {
     pub redis_url: String,
+    /// Lifetime of the subscription lease a price stream holds
+    pub lease_ttl_secs: u64,
+    /// How often a price stream renews its lease; well within `lease_ttl_secs`
+    pub lease_renew_interval_secs: u64,
 }
 
 impl AppConfig {
@@ -11,8 +16,29 @@ impl AppConfig {
         dotenvy::from_filename_override(".env").ok();
 
         let redis_url = env::var("REDIS_URL").expect("REDIS_URL must be set in .env");
+        let lease_ttl_secs = env::var("LEASE_TTL_SECS")
+            .map(|v| {
+                v.parse()
+                    .expect("LEASE_TTL_SECS must be a positive integer")
+            })
+            .unwrap_or(DEFAULT_LEASE_TTL_SECS);
+        let lease_renew_interval_secs = env::var("LEASE_RENEW_INTERVAL_SECS")
+            .map(|v| {
+                v.parse()
+                    .expect("LEASE_RENEW_INTERVAL_SECS must be a positive integer")
+            })
+            .unwrap_or(lease_ttl_secs / 3);
 
-        Self { redis_url }
+        assert!(
+            lease_renew_interval_secs > 0 && lease_renew_interval_secs < lease_ttl_secs,
+            "LEASE_RENEW_INTERVAL_SECS must be positive and below LEASE_TTL_SECS"
+        );
+
+        Self {
+            redis_url,
+            lease_ttl_secs,
+            lease_renew_interval_secs,
+        }
     }
 
     pub fn from_env_with_custom_file(file_name: &str) -> Self {
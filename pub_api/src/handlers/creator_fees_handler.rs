@@ -0,0 +1,48 @@
+use crate::models::creator_fees::{CreatorEarnings, CreatorFeeBalance};
+use crate::state::AppState;
+use axum::http::StatusCode;
+use axum::{
+    Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use log::error;
+use redis::AsyncCommands;
+use serde_json::json;
+use std::collections::HashMap;
+
+pub async fn get_creator_earnings(
+    State(state): State<AppState>,
+    Path(creator): Path<String>,
+) -> impl IntoResponse {
+    let mut conn = state.cache.clone();
+
+    let key = format!("pf_creator_fees:{creator}");
+
+    let fields: HashMap<String, String> = match conn.hgetall(key).await {
+        Ok(fields) => fields,
+        Err(e) => {
+            error!("get_creator_earnings: Redis error: {e}");
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "creator": creator, "error": "internal error" })),
+            );
+        }
+    };
+
+    if fields.is_empty() {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(json!({ "creator": creator, "error": "not found" })),
+        );
+    }
+
+    let balances: Vec<CreatorFeeBalance> = fields
+        .values()
+        .filter_map(|v| serde_json::from_str(v).ok())
+        .collect();
+
+    let earnings = CreatorEarnings::from_balances(creator, balances);
+
+    (StatusCode::OK, Json(json!(earnings)))
+}
@@ -1,5 +1,7 @@
 pub mod bonding_curves_handler;
+pub mod creator_fees_handler;
 pub mod prices_handler;
+pub mod token_incentives_handler;
 pub mod ws_creation_stream_handler;
 pub mod ws_migration_stream_handler;
 pub mod ws_ping_stream_handler;
@@ -0,0 +1,58 @@
+use crate::models::token_incentives::{
+    GlobalVolumeAccumulator, TokenIncentiveBalance, WalletIncentives,
+};
+use crate::state::AppState;
+use axum::http::StatusCode;
+use axum::{
+    Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use log::error;
+use serde_json::json;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub async fn get_wallet_incentives(
+    State(state): State<AppState>,
+    Path(wallet): Path<String>,
+) -> impl IntoResponse {
+    let mut conn = state.cache.clone();
+
+    let key = format!("pf_token_incentives:{wallet}");
+
+    let (balance, global): (Option<String>, Option<String>) = match redis::pipe()
+        .get(key)
+        .get("pf_volume_accumulator:global")
+        .query_async(&mut conn)
+        .await
+    {
+        Ok(values) => values,
+        Err(e) => {
+            error!("get_wallet_incentives: Redis error: {e}");
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "wallet": wallet, "error": "internal error" })),
+            );
+        }
+    };
+
+    let Some(balance) =
+        balance.and_then(|v| serde_json::from_str::<TokenIncentiveBalance>(&v).ok())
+    else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(json!({ "wallet": wallet, "error": "not found" })),
+        );
+    };
+
+    let global = global.and_then(|v| serde_json::from_str::<GlobalVolumeAccumulator>(&v).ok());
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+
+    let incentives = WalletIncentives::from_balance(balance, global.as_ref(), now);
+
+    (StatusCode::OK, Json(json!(incentives)))
+}
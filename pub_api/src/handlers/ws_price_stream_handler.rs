@@ -1,19 +1,34 @@
+use crate::cache::{acquire_lease, release_lease};
 use crate::models::resolver::EnrichedResolvedToken;
 use crate::state::AppState;
 use axum::extract::ws::{Message, WebSocket};
 use axum::extract::{Path, State, WebSocketUpgrade};
 use axum::response::IntoResponse;
-use log::error;
+use log::{error, warn};
 use redis::AsyncCommands;
+use redis::aio::ConnectionManager;
+use std::time::Duration;
+use tokio::task::JoinHandle;
 use tokio_stream::StreamExt;
 use uuid::Uuid;
 
+/// Lease a session holds on its token's account, renewed well within the TTL
+#[derive(Debug, Clone, Copy)]
+struct LeaseTiming {
+    ttl: Duration,
+    renew_interval: Duration,
+}
+
 pub async fn ws_price(
     State(state): State<AppState>,
     Path(identifier): Path<String>,
     ws: WebSocketUpgrade,
 ) -> impl IntoResponse {
     let redis_url = state.config.redis_url.clone();
+    let lease_timing = LeaseTiming {
+        ttl: Duration::from_secs(state.config.lease_ttl_secs),
+        renew_interval: Duration::from_secs(state.config.lease_renew_interval_secs),
+    };
 
     ws.on_upgrade(move |socket| async move {
         let channel_id = Uuid::new_v4().to_string();
@@ -22,7 +37,14 @@ pub async fn ws_price(
             .await
             .unwrap();
 
-        subscribe_for_price(socket, redis_url, channel_id.clone()).await;
+        subscribe_for_price(
+            socket,
+            redis_url,
+            state.cache.clone(),
+            channel_id.clone(),
+            lease_timing,
+        )
+        .await;
     })
 }
 
@@ -41,7 +63,13 @@ async fn publish_identifier(
     Ok(())
 }
 
-async fn subscribe_for_price(mut socket: WebSocket, redis_url: String, channel_id: String) {
+async fn subscribe_for_price(
+    mut socket: WebSocket,
+    redis_url: String,
+    cache: ConnectionManager,
+    channel_id: String,
+    lease_timing: LeaseTiming,
+) {
     let redis_url_clone = redis_url.clone();
     let client = redis::Client::open(redis_url).unwrap();
     let mut pubsub = client.get_async_pubsub().await.unwrap();
@@ -55,7 +83,19 @@ async fn subscribe_for_price(mut socket: WebSocket, redis_url: String, channel_i
             let token: EnrichedResolvedToken =
                 serde_json::from_str(msg.get_payload::<String>().unwrap().as_str()).unwrap();
             send_init_price(&mut socket, token.clone()).await;
+
+            let holder = format!("ws:{channel_id}");
+            let lease = spawn_lease_heartbeat(cache.clone(), holder.clone(), &token, lease_timing);
+
             create_price_subscription(socket, redis_url_clone, token.clone()).await;
+
+            // Session is gone: stop renewing and give the lease back right away
+            if let Some((heartbeat, account)) = lease {
+                heartbeat.abort();
+                if let Err(e) = release_lease(&mut cache.clone(), &holder, &account).await {
+                    warn!("Failed to release lease on {account}: {e}");
+                }
+            }
         }
     }
 
@@ -76,15 +116,73 @@ async fn create_price_subscription(
     pubsub.subscribe(&channel).await.unwrap();
 
     let mut stream = pubsub.on_message();
-    while let Some(msg) = stream.next().await {
-        if let Ok(payload) = msg.get_payload::<String>() {
-            if socket.send(Message::Text(payload.into())).await.is_err() {
-                break;
+    loop {
+        tokio::select! {
+            msg = stream.next() => {
+                let Some(msg) = msg else {
+                    break;
+                };
+                if let Ok(payload) = msg.get_payload::<String>()
+                    && socket.send(Message::Text(payload.into())).await.is_err()
+                {
+                    break;
+                }
+            }
+            // Notice closed sessions even while the token is quiet, so the lease is released
+            incoming = socket.recv() => {
+                if matches!(incoming, None | Some(Err(_)) | Some(Ok(Message::Close(_)))) {
+                    break;
+                }
             }
         }
     }
 }
 
+/// Hold a lease on the token's tracked account (bonding curve, pool or pool state)
+/// for as long as the session lives. Tokens on platforms the account subscriber cannot
+/// track get no lease, and so no live updates past the initial price.
+fn spawn_lease_heartbeat(
+    cache: ConnectionManager,
+    holder: String,
+    token: &EnrichedResolvedToken,
+    timing: LeaseTiming,
+) -> Option<(JoinHandle<()>, String)> {
+    let account = token
+        .bonding_curve
+        .clone()
+        .or_else(|| token.pool.clone())
+        .or_else(|| token.pool_state.clone())?;
+    let Some(platform) = token.platform.tracked() else {
+        warn!(
+            "Not leasing {account} of {}: platform {:?} is not tracked",
+            token.mint, token.platform
+        );
+        return None;
+    };
+
+    let heartbeat_account = account.clone();
+    let heartbeat = tokio::spawn(async move {
+        let mut cache = cache;
+        let mut interval = tokio::time::interval(timing.renew_interval);
+        loop {
+            interval.tick().await;
+            if let Err(e) = acquire_lease(
+                &mut cache,
+                &holder,
+                &heartbeat_account,
+                platform,
+                timing.ttl,
+            )
+            .await
+            {
+                warn!("Failed to renew lease on {heartbeat_account}: {e}");
+            }
+        }
+    });
+
+    Some((heartbeat, account))
+}
+
 async fn send_init_price(socket: &mut WebSocket, token: EnrichedResolvedToken) {
     let token_str = serde_json::to_string(&token).unwrap(); // TODO re-do the unwrap()
 
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// Creator fee balance for one platform, as written by `data_processor`
+/// into the `pf_creator_fees:{creator}` hash (field = platform).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatorFeeBalance {
+    pub creator: String,
+    pub platform: String, // pump_fun | pump_swap
+
+    pub accrued_lamports: i64,
+    pub claimed_lamports: i64,
+    pub unclaimed_lamports: i64,
+
+    pub accrual_count: i64,
+    pub claim_count: i64,
+
+    pub last_accrual_ts: Option<String>,
+    pub last_claim_ts: Option<String>,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatorEarnings {
+    pub creator: String,
+
+    pub accrued_lamports: i64,
+    pub claimed_lamports: i64,
+    pub unclaimed_lamports: i64,
+
+    pub platforms: Vec<CreatorFeeBalance>,
+}
+
+impl CreatorEarnings {
+    pub fn from_balances(creator: String, mut platforms: Vec<CreatorFeeBalance>) -> Self {
+        platforms.sort_by(|a, b| a.platform.cmp(&b.platform));
+
+        Self {
+            creator,
+            accrued_lamports: platforms.iter().map(|p| p.accrued_lamports).sum(),
+            claimed_lamports: platforms.iter().map(|p| p.claimed_lamports).sum(),
+            unclaimed_lamports: platforms.iter().map(|p| p.unclaimed_lamports).sum(),
+            platforms,
+        }
+    }
+}
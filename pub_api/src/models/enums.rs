@@ -1,3 +1,4 @@
+use event_contract::models::enums::Platform as TrackedPlatform;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -25,3 +26,16 @@ pub enum Platform {
     // Fallback
     Unknown,
 }
+
+impl Platform {
+    /// Platform as the account subscriber knows it; `None` for platforms it cannot decode,
+    /// whose accounts can therefore not be leased
+    pub fn tracked(self) -> Option<TrackedPlatform> {
+        match self {
+            Platform::PumpFun => Some(TrackedPlatform::PumpFun),
+            Platform::PumpSwap => Some(TrackedPlatform::PumpSwap),
+            Platform::RaydiumLaunchLab => Some(TrackedPlatform::RaydiumLaunchLab),
+            _ => None,
+        }
+    }
+}
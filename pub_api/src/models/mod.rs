@@ -1,2 +1,4 @@
+pub mod creator_fees;
 pub mod enums;
 pub mod resolver;
+pub mod token_incentives;
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+/// Wallet incentive state, as written by `data_processor` into `pf_token_incentives:{wallet}`.
+/// Token amounts are raw units, volumes are lamports.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenIncentiveBalance {
+    pub wallet: String,
+    pub mint: Option<String>,
+
+    pub needs_claim: bool,
+    pub total_unclaimed_tokens: i64,
+    pub total_claimed_tokens: i64,
+    pub current_sol_volume: i64,
+    pub last_update_timestamp: i64,
+
+    pub claim_count: i64,
+    pub last_claim_ts: Option<String>,
+    pub updated_at: String,
+}
+
+/// Pump.fun `GlobalVolumeAccumulator`, cached in `pf_volume_accumulator:global`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlobalVolumeAccumulator {
+    pub mint: String,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub seconds_in_a_day: i64,
+    pub total_token_supply: Vec<u64>,
+    pub sol_volumes: Vec<u64>,
+}
+
+impl GlobalVolumeAccumulator {
+    /// Incentive period `ts` falls in, `None` before the program started
+    fn day_index(&self, ts: i64) -> Option<i64> {
+        if self.seconds_in_a_day <= 0 || ts < self.start_time {
+            return None;
+        }
+        Some((ts - self.start_time) / self.seconds_in_a_day)
+    }
+
+    /// Tokens earned by `sol_volume` traded during a finished period.
+    /// Volume from the running period is only priced once the period closes.
+    pub fn pending_tokens(&self, sol_volume: i64, last_update_timestamp: i64, now: i64) -> i64 {
+        let (Some(day), Some(today)) = (self.day_index(last_update_timestamp), self.day_index(now))
+        else {
+            return 0;
+        };
+
+        if sol_volume <= 0 || day >= today {
+            return 0;
+        }
+
+        let (Some(&supply), Some(&volume)) = (
+            self.total_token_supply.get(day as usize),
+            self.sol_volumes.get(day as usize),
+        ) else {
+            return 0;
+        };
+
+        if volume == 0 {
+            return 0;
+        }
+
+        (sol_volume as u128 * supply as u128 / volume as u128) as i64
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletIncentives {
+    pub wallet: String,
+    pub mint: Option<String>,
+
+    /// Tokens `claim_token_incentives` would pay out right now
+    pub claimable_tokens: i64,
+    /// Synced on-chain balance (`total_unclaimed_tokens`)
+    pub unclaimed_tokens: i64,
+    /// Earned in a finished period but not yet synced on-chain
+    pub pending_tokens: i64,
+    pub claimed_tokens: i64,
+
+    pub current_sol_volume: i64,
+    pub last_update_timestamp: i64,
+    pub claim_count: i64,
+    pub last_claim_ts: Option<String>,
+    pub updated_at: String,
+}
+
+impl WalletIncentives {
+    pub fn from_balance(
+        balance: TokenIncentiveBalance,
+        global: Option<&GlobalVolumeAccumulator>,
+        now: i64,
+    ) -> Self {
+        let pending_tokens = global
+            .map(|g| {
+                g.pending_tokens(
+                    balance.current_sol_volume,
+                    balance.last_update_timestamp,
+                    now,
+                )
+            })
+            .unwrap_or(0);
+
+        Self {
+            wallet: balance.wallet,
+            mint: balance.mint.or_else(|| global.map(|g| g.mint.clone())),
+            claimable_tokens: balance.total_unclaimed_tokens + pending_tokens,
+            unclaimed_tokens: balance.total_unclaimed_tokens,
+            pending_tokens,
+            claimed_tokens: balance.total_claimed_tokens,
+            current_sol_volume: balance.current_sol_volume,
+            last_update_timestamp: balance.last_update_timestamp,
+            claim_count: balance.claim_count,
+            last_claim_ts: balance.last_claim_ts,
+            updated_at: balance.updated_at,
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use crate::handlers::creator_fees_handler::get_creator_earnings;
+use crate::state::AppState;
+use axum::{Router, routing::get};
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/creator/{creator}/earnings", get(get_creator_earnings))
+}
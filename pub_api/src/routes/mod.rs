@@ -1,5 +1,7 @@
 mod bonding_curves;
+mod creator_fees;
 mod ping;
 mod prices;
+mod token_incentives;
 pub mod v1;
 mod ws_streams;
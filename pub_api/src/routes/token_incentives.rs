@@ -0,0 +1,7 @@
+use crate::handlers::token_incentives_handler::get_wallet_incentives;
+use crate::state::AppState;
+use axum::{Router, routing::get};
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/wallet/{wallet}/incentives", get(get_wallet_incentives))
+}
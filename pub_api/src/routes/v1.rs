@@ -1,4 +1,4 @@
-use super::{bonding_curves, ping, prices, ws_streams};
+use super::{bonding_curves, creator_fees, ping, prices, token_incentives, ws_streams};
 use crate::state::AppState;
 use axum::Router;
 
@@ -7,5 +7,7 @@ pub fn routes() -> Router<AppState> {
         .merge(ping::routes())
         .merge(prices::routes())
         .merge(bonding_curves::routes())
+        .merge(creator_fees::routes())
+        .merge(token_incentives::routes())
         .merge(ws_streams::routes())
 }